@@ -129,6 +129,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CreateCollection.replication_factor", "range(min = 1)"),
             ("CreateCollection.write_consistency_factor", "range(min = 1)"),
             ("CreateCollection.strict_mode_config", ""),
+            ("CreateCollection.sparse_vectors_config", ""),
             ("UpdateCollection.collection_name", "length(min = 1, max = 255)"),
             ("UpdateCollection.optimizers_config", ""),
             ("UpdateCollection.params", ""),
@@ -137,6 +138,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("UpdateCollection.vectors_config", ""),
             ("UpdateCollection.quantization_config", ""),
             ("UpdateCollection.strict_mode_config", ""),
+            ("UpdateCollection.sparse_vectors_config", ""),
             ("CollectionParamsDiff.replication_factor", "range(min = 1)"),
             ("CollectionParamsDiff.write_consistency_factor", "range(min = 1)"),
             ("DeleteCollection.collection_name", "length(min = 1, max = 255)"),
@@ -147,9 +149,20 @@ fn configure_validation(builder: Builder) -> Builder {
             ("CollectionConfig.quantization_config", ""),
             ("CollectionConfig.strict_mode_config", ""),
             ("CollectionParams.vectors_config", ""),
+            ("CollectionParams.sparse_vectors_config", ""),
             ("ChangeAliases.timeout", "range(min = 1)"),
             ("ListCollectionAliasesRequest.collection_name", "length(min = 1, max = 255)"),
             ("HnswConfigDiff.ef_construct", "range(min = 4)"),
+            ("IvfConfig.nprobe", "range(min = 1)"),
+            ("VamanaConfig.max_degree", "range(min = 1)"),
+            ("VamanaConfig.build_search_list", "range(min = 1)"),
+            ("VamanaConfig.search_list", "range(min = 1)"),
+            ("VamanaConfig.beam_width", "range(min = 1)"),
+            ("SparseVectorConfig.map", ""),
+            ("SparseVectorParams.index", ""),
+            ("SparseIndexConfig.pruning", ""),
+            ("SparsePruningConfig.max_postings", "range(min = 1)"),
+            ("SparsePruningConfig.min_weight_ratio", "custom(function = \"crate::grpc::validate::validate_f32_range_0_1\")"),
             ("WalConfigDiff.wal_capacity_mb", "range(min = 1)"),
             ("OptimizersConfigDiff.deleted_threshold", "range(min = 0.0, max = 1.0)"),
             ("OptimizersConfigDiff.vacuum_min_vector_number", "range(min = 100)"),
@@ -159,6 +172,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("VectorParams.size", "range(min = 1, max = 65536)"),
             ("VectorParams.hnsw_config", ""),
             ("VectorParams.quantization_config", ""),
+            ("VectorParams.ivf_config", ""),
+            ("VectorParams.vamana_config", ""),
             ("VectorParamsMap.map", ""),
             ("VectorParamsDiff.hnsw_config", ""),
             ("VectorParamsDiff.quantization_config", ""),
@@ -229,6 +244,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchPointGroups.limit", "range(min = 1)"),
             ("SearchPointGroups.timeout", "range(min = 1)"),
             ("SearchParams.quantization", ""),
+            ("SearchParams.range", ""),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255)"),
             ("ScrollPoints.filter", ""),
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, NamedVectorStruct, VectorInternal, VectorStructInternal,
+    WeightedMultiDenseVector,
 };
 use sparse::common::sparse_vector::SparseVector;
 use tonic::Status;
@@ -111,7 +112,8 @@ impl From<VectorInternal> for grpc::VectorOutput {
                 vectors_count: None,
                 vector: None,
             },
-            VectorInternal::MultiDense(vector) => {
+            VectorInternal::MultiDense(vector)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, .. }) => {
                 let vector_count = vector.multi_vectors().count() as u32;
                 Self {
                     data: vector.flattened_vectors,
@@ -413,7 +415,8 @@ impl From<VectorInternal> for grpc::Vector {
                 vectors_count: None,
                 vector: None,
             },
-            VectorInternal::MultiDense(vector) => {
+            VectorInternal::MultiDense(vector)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, .. }) => {
                 let vector_count = vector.multi_vectors().count() as u32;
                 Self {
                     data: vector.flattened_vectors,
@@ -519,27 +522,45 @@ impl From<grpc::SparseVector> for SparseVector {
 
 impl From<MultiDenseVectorInternal> for grpc::MultiDenseVector {
     fn from(value: MultiDenseVectorInternal) -> Self {
-        let vectors = value
-            .flattened_vectors
+        let MultiDenseVectorInternal {
+            flattened_vectors,
+            dim,
+        } = value;
+        let vectors = flattened_vectors
             .into_iter()
-            .chunks(value.dim)
+            .chunks(dim)
             .into_iter()
             .map(Iterator::collect::<Vec<_>>)
             .map(grpc::DenseVector::from)
             .collect();
-        Self { vectors }
+        Self {
+            vectors,
+            weights: Vec::new(),
+        }
+    }
+}
+
+impl From<WeightedMultiDenseVector> for grpc::MultiDenseVector {
+    fn from(value: WeightedMultiDenseVector) -> Self {
+        let WeightedMultiDenseVector { vector, weights } = value;
+        Self {
+            weights,
+            ..Self::from(vector)
+        }
     }
 }
 
 impl From<grpc::MultiDenseVector> for MultiDenseVectorInternal {
     /// Uses the equivalent of [`MultiDenseVectorInternal::new_unchecked`], but rewritten to avoid collecting twice
+    ///
+    /// Weights are dropped, use [`VectorInternal::from`] to keep them.
     fn from(value: grpc::MultiDenseVector) -> Self {
-        let dim = value.vectors[0].data.len();
-        let inner_vector = value
-            .vectors
-            .into_iter()
-            .flat_map(DenseVector::from)
-            .collect();
+        let grpc::MultiDenseVector {
+            vectors,
+            weights: _,
+        } = value;
+        let dim = vectors[0].data.len();
+        let inner_vector = vectors.into_iter().flat_map(DenseVector::from).collect();
         Self {
             flattened_vectors: inner_vector,
             dim,
@@ -547,6 +568,19 @@ impl From<grpc::MultiDenseVector> for MultiDenseVectorInternal {
     }
 }
 
+impl From<grpc::MultiDenseVector> for VectorInternal {
+    /// Multivector with weights becomes a [`VectorInternal::WeightedMultiDense`] query
+    fn from(mut value: grpc::MultiDenseVector) -> Self {
+        let weights = std::mem::take(&mut value.weights);
+        let vector = MultiDenseVectorInternal::from(value);
+        if weights.is_empty() {
+            VectorInternal::MultiDense(vector)
+        } else {
+            VectorInternal::WeightedMultiDense(WeightedMultiDenseVector::new(vector, weights))
+        }
+    }
+}
+
 impl From<VectorInternal> for grpc::RawVector {
     fn from(value: VectorInternal) -> Self {
         use crate::grpc::qdrant::raw_vector::Variant;
@@ -557,6 +591,9 @@ impl From<VectorInternal> for grpc::RawVector {
             VectorInternal::MultiDense(vector) => {
                 Variant::MultiDense(grpc::MultiDenseVector::from(vector))
             }
+            VectorInternal::WeightedMultiDense(vector) => {
+                Variant::MultiDense(grpc::MultiDenseVector::from(vector))
+            }
         };
 
         Self {
@@ -580,9 +617,7 @@ impl TryFrom<grpc::RawVector> for VectorInternal {
             Variant::Sparse(sparse) => {
                 VectorInternal::Sparse(sparse::common::sparse_vector::SparseVector::from(sparse))
             }
-            Variant::MultiDense(multi_dense) => {
-                VectorInternal::MultiDense(MultiDenseVectorInternal::from(multi_dense))
-            }
+            Variant::MultiDense(multi_dense) => VectorInternal::from(multi_dense),
         };
 
         Ok(vector)
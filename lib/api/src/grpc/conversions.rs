@@ -8,7 +8,7 @@ use itertools::Itertools;
 use segment::common::operation_error::OperationError;
 use segment::data_types::index::{
     BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
-    KeywordIndexType, TextIndexType, UuidIndexType,
+    KeywordIndexType, SnowballType, TextIndexType, UuidIndexType,
 };
 use segment::data_types::{facets as segment_facets, vectors as segment_vectors};
 use segment::types::{default_quantization_ignore_value, DateTimePayloadType, FloatPayloadType};
@@ -19,13 +19,15 @@ use uuid::Uuid;
 
 use super::qdrant::raw_query::RawContextPair;
 use super::qdrant::{
-    raw_query, start_from, BinaryQuantization, BoolIndexParams, CompressionRatio,
-    DatetimeIndexParams, DatetimeRange, Direction, FacetHit, FacetHitInternal, FacetValue,
-    FacetValueInternal, FieldType, FloatIndexParams, GeoIndexParams, GeoLineString, GroupId,
-    HardwareUsage, HasVectorCondition, KeywordIndexParams, LookupLocation, MaxOptimizationThreads,
-    MultiVectorComparator, MultiVectorConfig, OrderBy, OrderValue, Range, RawVector,
-    RecommendStrategy, RetrievedPoint, SearchMatrixPair, SearchPointGroups, SearchPoints,
-    ShardKeySelector, SparseIndices, StartFrom, StrictModeMultivector, StrictModeMultivectorConfig,
+    binary_quantization_query_encoding, raw_query, start_from, stemming_algorithm,
+    BinaryQuantization, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, Bm25,
+    BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange, Direction, FacetHit,
+    FacetHitInternal, FacetValue, FacetValueInternal, FieldType, FloatIndexParams, GeoIndexParams,
+    GeoLineString, GroupId, HardwareUsage, HasVectorCondition, KeywordIndexParams, KeywordRange,
+    Language, LookupLocation, MaxOptimizationThreads, MultiVectorComparator, MultiVectorConfig,
+    OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint, SearchMatrixPair,
+    SearchPointGroups, SearchPoints, ShardKeySelector, SnowballParams, SparseIndices, StartFrom,
+    StemmingAlgorithm, StopwordsSet, StrictModeMultivector, StrictModeMultivectorConfig,
     StrictModeSparse, StrictModeSparseConfig, UuidIndexParams, VectorsOutput, WithLookup,
 };
 use crate::conversions::json;
@@ -38,14 +40,14 @@ use crate::grpc::qdrant::{
     shard_key, with_vectors_selector, CollectionDescription, CollectionOperationResponse,
     Condition, Distance, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
     HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition,
-    IsNullCondition, ListCollectionsResponse, Match, MinShould, NamedVectors, NestedCondition,
-    PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams, PayloadSchemaInfo,
-    PayloadSchemaType, PointId, PointStruct, PointsOperationResponse,
-    PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
-    QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
-    ScalarQuantization, ScoredPoint, SearchParams, ShardKey, StrictModeConfig, TextIndexParams,
-    TokenizerType, UpdateResult, UpdateResultInternal, ValuesCount, VectorsSelector,
-    WithPayloadSelector, WithVectorsSelector,
+    IsNullCondition, IvfConfig, ListCollectionsResponse, Match, MatchFuzzy, MatchPhrase, MinShould,
+    NamedVectors, NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct,
+    PointsOperationResponse, PointsOperationResponseInternal, ProductQuantization,
+    QuantizationConfig, QuantizationSearchParams, QuantizationType, RangeSearchParams,
+    RepeatedIntegers, RepeatedStrings, ScalarQuantization, ScoredPoint, SearchParams, ShardKey,
+    StrictModeConfig, TextIndexParams, TokenizerType, UpdateResult, UpdateResultInternal,
+    ValuesCount, VamanaConfig, VectorsSelector, WithPayloadSelector, WithVectorsSelector,
 };
 use crate::rest::models::{CollectionsResponse, VersionInfo};
 use crate::rest::schema as rest;
@@ -139,6 +141,49 @@ impl From<segment::data_types::index::TokenizerType> for TokenizerType {
     }
 }
 
+impl From<segment::data_types::index::Language> for Language {
+    fn from(language: segment::data_types::index::Language) -> Self {
+        match language {
+            segment::data_types::index::Language::Dutch => Language::Dutch,
+            segment::data_types::index::Language::English => Language::English,
+            segment::data_types::index::Language::French => Language::French,
+            segment::data_types::index::Language::German => Language::German,
+            segment::data_types::index::Language::Italian => Language::Italian,
+            segment::data_types::index::Language::Portuguese => Language::Portuguese,
+            segment::data_types::index::Language::Russian => Language::Russian,
+            segment::data_types::index::Language::Spanish => Language::Spanish,
+        }
+    }
+}
+
+impl From<segment::data_types::index::StopwordsInterface> for StopwordsSet {
+    fn from(stopwords: segment::data_types::index::StopwordsInterface) -> Self {
+        StopwordsSet {
+            languages: stopwords
+                .languages()
+                .iter()
+                .map(|language| Language::from(*language) as i32)
+                .collect(),
+            custom: stopwords.custom().to_vec(),
+        }
+    }
+}
+
+impl From<segment::data_types::index::StemmingAlgorithm> for StemmingAlgorithm {
+    fn from(stemmer: segment::data_types::index::StemmingAlgorithm) -> Self {
+        let stemming_params = match stemmer {
+            segment::data_types::index::StemmingAlgorithm::Snowball(params) => {
+                stemming_algorithm::StemmingParams::Snowball(SnowballParams {
+                    language: Language::from(params.language) as i32,
+                })
+            }
+        };
+        StemmingAlgorithm {
+            stemming_params: Some(stemming_params),
+        }
+    }
+}
+
 impl From<segment::data_types::index::KeywordIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::index::KeywordIndexParams) -> Self {
         PayloadIndexParams {
@@ -194,6 +239,9 @@ impl From<segment::data_types::index::TextIndexParams> for PayloadIndexParams {
                 min_token_len: params.min_token_len.map(|x| x as u64),
                 max_token_len: params.max_token_len.map(|x| x as u64),
                 on_disk: params.on_disk,
+                ascii_folding: params.ascii_folding,
+                stopwords: params.stopwords.map(StopwordsSet::from),
+                stemmer: params.stemmer.map(StemmingAlgorithm::from),
             })),
         }
     }
@@ -286,6 +334,62 @@ impl TryFrom<TokenizerType> for segment::data_types::index::TokenizerType {
     }
 }
 
+impl TryFrom<Language> for segment::data_types::index::Language {
+    type Error = Status;
+    fn try_from(language: Language) -> Result<Self, Self::Error> {
+        match language {
+            Language::UnknownLanguage => Err(Status::invalid_argument("unknown language")),
+            Language::Dutch => Ok(segment::data_types::index::Language::Dutch),
+            Language::English => Ok(segment::data_types::index::Language::English),
+            Language::French => Ok(segment::data_types::index::Language::French),
+            Language::German => Ok(segment::data_types::index::Language::German),
+            Language::Italian => Ok(segment::data_types::index::Language::Italian),
+            Language::Portuguese => Ok(segment::data_types::index::Language::Portuguese),
+            Language::Russian => Ok(segment::data_types::index::Language::Russian),
+            Language::Spanish => Ok(segment::data_types::index::Language::Spanish),
+        }
+    }
+}
+
+fn convert_language(language: i32) -> Result<segment::data_types::index::Language, Status> {
+    Language::try_from(language)
+        .map(|x| x.try_into())
+        .unwrap_or_else(|_| Err(Status::invalid_argument("unknown language")))
+}
+
+impl TryFrom<StopwordsSet> for segment::data_types::index::StopwordsInterface {
+    type Error = Status;
+    fn try_from(stopwords: StopwordsSet) -> Result<Self, Self::Error> {
+        let StopwordsSet { languages, custom } = stopwords;
+        Ok(segment::data_types::index::StopwordsInterface::Set(
+            segment::data_types::index::StopwordsSet {
+                languages: languages
+                    .into_iter()
+                    .map(convert_language)
+                    .collect::<Result<_, _>>()?,
+                custom,
+            },
+        ))
+    }
+}
+
+impl TryFrom<StemmingAlgorithm> for segment::data_types::index::StemmingAlgorithm {
+    type Error = Status;
+    fn try_from(stemmer: StemmingAlgorithm) -> Result<Self, Self::Error> {
+        match stemmer.stemming_params {
+            Some(stemming_algorithm::StemmingParams::Snowball(params)) => {
+                Ok(segment::data_types::index::StemmingAlgorithm::Snowball(
+                    segment::data_types::index::SnowballParams {
+                        r#type: SnowballType::Snowball,
+                        language: convert_language(params.language)?,
+                    },
+                ))
+            }
+            None => Err(Status::invalid_argument("stemming params are required")),
+        }
+    }
+}
+
 impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
     fn from(params: segment::types::PayloadSchemaParams) -> Self {
         match params {
@@ -357,6 +461,9 @@ impl TryFrom<TextIndexParams> for segment::data_types::index::TextIndexParams {
             lowercase: params.lowercase,
             min_token_len: params.min_token_len.map(|x| x as usize),
             max_token_len: params.max_token_len.map(|x| x as usize),
+            ascii_folding: params.ascii_folding,
+            stopwords: params.stopwords.map(TryInto::try_into).transpose()?,
+            stemmer: params.stemmer.map(TryInto::try_into).transpose()?,
             on_disk: params.on_disk,
         })
     }
@@ -563,13 +670,32 @@ impl From<segment::types::QuantizationSearchParams> for QuantizationSearchParams
     }
 }
 
+impl From<RangeSearchParams> for segment::types::RangeSearchParams {
+    fn from(params: RangeSearchParams) -> Self {
+        Self {
+            after: params.after,
+        }
+    }
+}
+
+impl From<segment::types::RangeSearchParams> for RangeSearchParams {
+    fn from(params: segment::types::RangeSearchParams) -> Self {
+        Self {
+            after: params.after,
+        }
+    }
+}
+
 impl From<SearchParams> for segment::types::SearchParams {
     fn from(params: SearchParams) -> Self {
         Self {
             hnsw_ef: params.hnsw_ef.map(|x| x as usize),
+            ivf_nprobe: params.ivf_nprobe.map(|x| x as usize),
+            vamana_search_list: params.vamana_search_list.map(|x| x as usize),
             exact: params.exact.unwrap_or(false),
             quantization: params.quantization.map(|q| q.into()),
             indexed_only: params.indexed_only.unwrap_or(false),
+            range: params.range.map(|range| range.into()),
         }
     }
 }
@@ -578,9 +704,12 @@ impl From<segment::types::SearchParams> for SearchParams {
     fn from(params: segment::types::SearchParams) -> Self {
         Self {
             hnsw_ef: params.hnsw_ef.map(|x| x as u64),
+            ivf_nprobe: params.ivf_nprobe.map(|x| x as u64),
+            vamana_search_list: params.vamana_search_list.map(|x| x as u64),
             exact: Some(params.exact),
             quantization: params.quantization.map(|q| q.into()),
             indexed_only: Some(params.indexed_only),
+            range: params.range.map(|range| range.into()),
         }
     }
 }
@@ -654,6 +783,7 @@ impl From<segment::data_types::order_by::OrderValue> for OrderValue {
         let variant = match value {
             segment::OrderValue::Float(value) => Variant::Float(value),
             segment::OrderValue::Int(value) => Variant::Int(value),
+            segment::OrderValue::Keyword(value) => Variant::Keyword(value),
         };
 
         Self {
@@ -677,6 +807,7 @@ impl TryFrom<OrderValue> for segment::data_types::order_by::OrderValue {
         let value = match variant {
             Variant::Float(value) => segment::OrderValue::Float(value),
             Variant::Int(value) => segment::OrderValue::Int(value),
+            Variant::Keyword(value) => segment::OrderValue::Keyword(value),
         };
 
         Ok(value)
@@ -805,6 +936,12 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
                 segment::types::ScalarType::Int8 => {
                     crate::grpc::qdrant::QuantizationType::Int8 as i32
                 }
+                segment::types::ScalarType::Int4 => {
+                    crate::grpc::qdrant::QuantizationType::Int4 as i32
+                }
+                segment::types::ScalarType::Int2 => {
+                    crate::grpc::qdrant::QuantizationType::Int2 as i32
+                }
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
@@ -820,6 +957,8 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::try_from(value.r#type).ok() {
                     Some(QuantizationType::Int8) => segment::types::ScalarType::Int8,
+                    Some(QuantizationType::Int4) => segment::types::ScalarType::Int4,
+                    Some(QuantizationType::Int2) => segment::types::ScalarType::Int2,
                     Some(QuantizationType::UnknownQuantization) | None => {
                         return Err(Status::invalid_argument("Unknown quantization type"));
                     }
@@ -843,6 +982,7 @@ impl From<segment::types::ProductQuantization> for ProductQuantization {
                 segment::types::CompressionRatio::X64 => CompressionRatio::X64 as i32,
             },
             always_ram: config.always_ram,
+            opq: config.opq,
         }
     }
 }
@@ -866,16 +1006,92 @@ impl TryFrom<ProductQuantization> for segment::types::ProductQuantization {
                     Ok(CompressionRatio::X64) => segment::types::CompressionRatio::X64,
                 },
                 always_ram: value.always_ram,
+                opq: value.opq,
             },
         })
     }
 }
 
+impl From<segment::types::BinaryQuantizationEncoding> for BinaryQuantizationEncoding {
+    fn from(value: segment::types::BinaryQuantizationEncoding) -> Self {
+        match value {
+            segment::types::BinaryQuantizationEncoding::OneBit => {
+                BinaryQuantizationEncoding::OneBit
+            }
+            segment::types::BinaryQuantizationEncoding::TwoBits => {
+                BinaryQuantizationEncoding::TwoBits
+            }
+            segment::types::BinaryQuantizationEncoding::OneAndHalfBits => {
+                BinaryQuantizationEncoding::OneAndHalfBits
+            }
+        }
+    }
+}
+
+impl From<BinaryQuantizationEncoding> for segment::types::BinaryQuantizationEncoding {
+    fn from(value: BinaryQuantizationEncoding) -> Self {
+        match value {
+            BinaryQuantizationEncoding::OneBit => {
+                segment::types::BinaryQuantizationEncoding::OneBit
+            }
+            BinaryQuantizationEncoding::TwoBits => {
+                segment::types::BinaryQuantizationEncoding::TwoBits
+            }
+            BinaryQuantizationEncoding::OneAndHalfBits => {
+                segment::types::BinaryQuantizationEncoding::OneAndHalfBits
+            }
+        }
+    }
+}
+
+impl From<segment::types::BinaryQuantizationQueryEncoding> for BinaryQuantizationQueryEncoding {
+    fn from(value: segment::types::BinaryQuantizationQueryEncoding) -> Self {
+        use binary_quantization_query_encoding::{Setting, Variant};
+
+        let setting = match value {
+            segment::types::BinaryQuantizationQueryEncoding::Default => Setting::Default,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar4Bits => Setting::Scalar4Bits,
+            segment::types::BinaryQuantizationQueryEncoding::Scalar8Bits => Setting::Scalar8Bits,
+        };
+        BinaryQuantizationQueryEncoding {
+            variant: Some(Variant::Setting(setting as i32)),
+        }
+    }
+}
+
+impl TryFrom<BinaryQuantizationQueryEncoding> for segment::types::BinaryQuantizationQueryEncoding {
+    type Error = Status;
+
+    fn try_from(value: BinaryQuantizationQueryEncoding) -> Result<Self, Self::Error> {
+        use binary_quantization_query_encoding::{Setting, Variant};
+
+        let BinaryQuantizationQueryEncoding { variant } = value;
+        let variant = variant
+            .ok_or_else(|| Status::invalid_argument("Malformed BinaryQuantizationQueryEncoding"))?;
+        match variant {
+            Variant::Setting(setting) => match Setting::try_from(setting) {
+                Ok(Setting::Default) => Ok(Self::Default),
+                Ok(Setting::Scalar4Bits) => Ok(Self::Scalar4Bits),
+                Ok(Setting::Scalar8Bits) => Ok(Self::Scalar8Bits),
+                Err(err) => Err(Status::invalid_argument(format!(
+                    "Invalid BinaryQuantizationQueryEncoding setting: {err}"
+                ))),
+            },
+        }
+    }
+}
+
 impl From<segment::types::BinaryQuantization> for BinaryQuantization {
     fn from(value: segment::types::BinaryQuantization) -> Self {
         let config = value.binary;
         BinaryQuantization {
             always_ram: config.always_ram,
+            encoding: config
+                .encoding
+                .map(|encoding| BinaryQuantizationEncoding::from(encoding) as i32),
+            query_encoding: config
+                .query_encoding
+                .map(BinaryQuantizationQueryEncoding::from),
         }
     }
 }
@@ -887,6 +1103,22 @@ impl TryFrom<BinaryQuantization> for segment::types::BinaryQuantization {
         Ok(segment::types::BinaryQuantization {
             binary: segment::types::BinaryQuantizationConfig {
                 always_ram: value.always_ram,
+                encoding: value
+                    .encoding
+                    .map(|encoding| {
+                        BinaryQuantizationEncoding::try_from(encoding)
+                            .map(segment::types::BinaryQuantizationEncoding::from)
+                            .map_err(|_| {
+                                Status::invalid_argument(format!(
+                                    "Unknown binary quantization encoding: {encoding}"
+                                ))
+                            })
+                    })
+                    .transpose()?,
+                query_encoding: value
+                    .query_encoding
+                    .map(segment::types::BinaryQuantizationQueryEncoding::try_from)
+                    .transpose()?,
             },
         })
     }
@@ -947,6 +1179,11 @@ impl From<segment::types::MultiVectorComparator> for MultiVectorComparator {
     fn from(value: segment::types::MultiVectorComparator) -> Self {
         match value {
             segment::types::MultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+            segment::types::MultiVectorComparator::WeightedMaxSim => {
+                MultiVectorComparator::WeightedMaxSim
+            }
+            segment::types::MultiVectorComparator::Chamfer => MultiVectorComparator::Chamfer,
+            segment::types::MultiVectorComparator::MeanPool => MultiVectorComparator::MeanPool,
         }
     }
 }
@@ -967,6 +1204,11 @@ impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
     fn from(value: MultiVectorComparator) -> Self {
         match value {
             MultiVectorComparator::MaxSim => segment::types::MultiVectorComparator::MaxSim,
+            MultiVectorComparator::WeightedMaxSim => {
+                segment::types::MultiVectorComparator::WeightedMaxSim
+            }
+            MultiVectorComparator::Chamfer => segment::types::MultiVectorComparator::Chamfer,
+            MultiVectorComparator::MeanPool => segment::types::MultiVectorComparator::MeanPool,
         }
     }
 }
@@ -1224,6 +1466,7 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             values_count,
             geo_polygon,
             datetime_range,
+            keyword_range,
         } = value;
 
         let geo_bounding_box =
@@ -1237,6 +1480,9 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
                 .map(segment::types::RangeInterface::try_from)
                 .transpose()?;
         }
+        if range.is_none() {
+            range = keyword_range.map(Into::into);
+        }
 
         Ok(Self {
             key: json::json_path_from_proto(&key)?,
@@ -1262,10 +1508,15 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             values_count,
         } = value;
 
-        let (range, datetime_range) = match range {
-            Some(segment::types::RangeInterface::Float(range)) => (Some(range.into()), None),
-            Some(segment::types::RangeInterface::DateTime(range)) => (None, Some(range.into())),
-            None => (None, None),
+        let (range, datetime_range, keyword_range) = match range {
+            Some(segment::types::RangeInterface::Float(range)) => (Some(range.into()), None, None),
+            Some(segment::types::RangeInterface::DateTime(range)) => {
+                (None, Some(range.into()), None)
+            }
+            Some(segment::types::RangeInterface::Keyword(range)) => {
+                (None, None, Some(range.into()))
+            }
+            None => (None, None, None),
         };
 
         Self {
@@ -1277,6 +1528,7 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             geo_polygon: geo_polygon.map(Into::into),
             values_count: values_count.map(Into::into),
             datetime_range,
+            keyword_range,
         }
     }
 }
@@ -1443,6 +1695,28 @@ impl From<segment::types::Range<DateTimePayloadType>> for DatetimeRange {
     }
 }
 
+impl From<KeywordRange> for segment::types::RangeInterface {
+    fn from(value: KeywordRange) -> Self {
+        Self::Keyword(segment::types::Range {
+            lt: value.lt,
+            gt: value.gt,
+            gte: value.gte,
+            lte: value.lte,
+        })
+    }
+}
+
+impl From<segment::types::Range<String>> for KeywordRange {
+    fn from(value: segment::types::Range<String>) -> Self {
+        Self {
+            lt: value.lt,
+            gt: value.gt,
+            gte: value.gte,
+            lte: value.lte,
+        }
+    }
+}
+
 impl From<ValuesCount> for segment::types::ValuesCount {
     fn from(value: ValuesCount) -> Self {
         Self {
@@ -1483,6 +1757,40 @@ impl TryFrom<Match> for segment::types::Match {
                 MatchValue::ExceptKeywords(ints) => {
                     segment::types::Match::Except(ints.strings.into())
                 }
+                MatchValue::Phrase(MatchPhrase { phrase, slop }) => {
+                    if slop.is_some_and(|slop| slop > segment::types::MAX_PHRASE_SLOP) {
+                        return Err(Status::invalid_argument(format!(
+                            "Phrase match allows slop of at most {}",
+                            segment::types::MAX_PHRASE_SLOP,
+                        )));
+                    }
+                    segment::types::Match::Phrase(segment::types::MatchPhrase { phrase, slop })
+                }
+                MatchValue::Fuzzy(MatchFuzzy { fuzzy, max_edits }) => {
+                    let max_edits = max_edits
+                        .map(|max_edits| {
+                            u8::try_from(max_edits)
+                                .ok()
+                                .filter(|&max_edits| max_edits <= segment::types::MAX_FUZZY_EDITS)
+                                .ok_or_else(|| {
+                                    Status::invalid_argument(format!(
+                                        "Fuzzy match allows at most {} edits",
+                                        segment::types::MAX_FUZZY_EDITS,
+                                    ))
+                                })
+                        })
+                        .transpose()?;
+                    segment::types::Match::Fuzzy(segment::types::MatchFuzzy { fuzzy, max_edits })
+                }
+                MatchValue::Prefix(prefix) => segment::types::Match::new_prefix(&prefix),
+                MatchValue::Substring(substring) => {
+                    segment::types::Match::new_substring(&substring)
+                }
+                MatchValue::Regex(regex) => {
+                    let regex = segment::types::RegexPattern::new(&regex)
+                        .map_err(|err| Status::invalid_argument(format!("Invalid regex: {err}")))?;
+                    segment::types::Match::new_regex(regex)
+                }
             }),
             _ => Err(Status::invalid_argument("Malformed Match condition")),
         }
@@ -1500,6 +1808,24 @@ impl From<segment::types::Match> for Match {
             segment::types::Match::Text(segment::types::MatchText { text }) => {
                 MatchValue::Text(text)
             }
+            segment::types::Match::Phrase(segment::types::MatchPhrase { phrase, slop }) => {
+                MatchValue::Phrase(MatchPhrase { phrase, slop })
+            }
+            segment::types::Match::Fuzzy(segment::types::MatchFuzzy { fuzzy, max_edits }) => {
+                MatchValue::Fuzzy(MatchFuzzy {
+                    fuzzy,
+                    max_edits: max_edits.map(u32::from),
+                })
+            }
+            segment::types::Match::Prefix(segment::types::MatchPrefix { prefix }) => {
+                MatchValue::Prefix(prefix)
+            }
+            segment::types::Match::Substring(segment::types::MatchSubstring { substring }) => {
+                MatchValue::Substring(substring)
+            }
+            segment::types::Match::Regex(segment::types::MatchRegex { regex }) => {
+                MatchValue::Regex(regex.as_str().to_string())
+            }
             segment::types::Match::Any(any) => match any.any {
                 segment::types::AnyVariants::Strings(strings) => {
                     let strings = strings.into_iter().collect();
@@ -1575,6 +1901,7 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
                             Status::invalid_argument(format!("Malformed datetime: {e}"))
                         })?,
                     )),
+                    Value::Keyword(keyword) => Ok(StartFrom::Keyword(keyword)),
                 }
             })
             .transpose()?;
@@ -1610,11 +1937,53 @@ impl From<segment::data_types::order_by::StartFrom> for StartFrom {
                 segment::data_types::order_by::StartFrom::Datetime(datetime) => {
                     start_from::Value::Timestamp(date_time_to_proto(datetime))
                 }
+                segment::data_types::order_by::StartFrom::Keyword(keyword) => {
+                    start_from::Value::Keyword(keyword)
+                }
             }),
         }
     }
 }
 
+impl TryFrom<Bm25> for segment::data_types::bm25::Bm25 {
+    type Error = Status;
+
+    fn try_from(value: Bm25) -> Result<Self, Self::Error> {
+        let Bm25 { key, text, k1, b } = value;
+
+        if k1.is_some_and(|k1| k1 < 0.0) {
+            return Err(Status::invalid_argument(
+                "bm25 k1 parameter must be non-negative",
+            ));
+        }
+
+        if b.is_some_and(|b| !(0.0..=1.0).contains(&b)) {
+            return Err(Status::invalid_argument(
+                "bm25 b parameter must be between 0 and 1",
+            ));
+        }
+
+        Ok(Self {
+            key: json::json_path_from_proto(&key)?,
+            text,
+            k1,
+            b,
+        })
+    }
+}
+
+impl From<segment::data_types::bm25::Bm25> for Bm25 {
+    fn from(value: segment::data_types::bm25::Bm25) -> Self {
+        let segment::data_types::bm25::Bm25 { key, text, k1, b } = value;
+        Self {
+            key: key.to_string(),
+            text,
+            k1,
+            b,
+        }
+    }
+}
+
 impl TryFrom<MaxOptimizationThreads> for rest::MaxOptimizationThreads {
     type Error = Status;
 
@@ -1698,6 +2067,56 @@ impl From<HnswConfigDiff> for segment::types::HnswConfig {
     }
 }
 
+impl From<IvfConfig> for segment::types::IvfConfig {
+    fn from(ivf_config: IvfConfig) -> Self {
+        let default = segment::types::IvfConfig::default();
+        Self {
+            nlist: ivf_config.nlist.map_or(default.nlist, |x| x as usize),
+            nprobe: ivf_config.nprobe.map_or(default.nprobe, |x| x as usize),
+        }
+    }
+}
+
+impl From<segment::types::IvfConfig> for IvfConfig {
+    fn from(ivf_config: segment::types::IvfConfig) -> Self {
+        Self {
+            nlist: Some(ivf_config.nlist as u64),
+            nprobe: Some(ivf_config.nprobe as u64),
+        }
+    }
+}
+
+impl From<VamanaConfig> for segment::types::VamanaConfig {
+    fn from(vamana_config: VamanaConfig) -> Self {
+        let default = segment::types::VamanaConfig::default();
+        Self {
+            max_degree: vamana_config
+                .max_degree
+                .map_or(default.max_degree, |x| x as usize),
+            build_search_list: vamana_config
+                .build_search_list
+                .map_or(default.build_search_list, |x| x as usize),
+            search_list: vamana_config
+                .search_list
+                .map_or(default.search_list, |x| x as usize),
+            beam_width: vamana_config
+                .beam_width
+                .map_or(default.beam_width, |x| x as usize),
+        }
+    }
+}
+
+impl From<segment::types::VamanaConfig> for VamanaConfig {
+    fn from(vamana_config: segment::types::VamanaConfig) -> Self {
+        Self {
+            max_degree: Some(vamana_config.max_degree as u64),
+            build_search_list: Some(vamana_config.build_search_list as u64),
+            search_list: Some(vamana_config.search_list as u64),
+            beam_width: Some(vamana_config.beam_width as u64),
+        }
+    }
+}
+
 impl From<StrictModeConfig> for segment::types::StrictModeConfig {
     fn from(value: StrictModeConfig) -> Self {
         Self {
@@ -1871,6 +2290,8 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
         })
     }
 }
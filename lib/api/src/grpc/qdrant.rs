@@ -28,6 +28,14 @@ pub struct VectorParams {
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// If set - IVF index is used for the vectors instead of HNSW
+    #[prost(message, optional, tag = "8")]
+    #[validate(nested)]
+    pub ivf_config: ::core::option::Option<IvfConfig>,
+    /// If set - Vamana graph index is stored on disk and used for the vectors instead of HNSW
+    #[prost(message, optional, tag = "9")]
+    #[validate(nested)]
+    pub vamana_config: ::core::option::Option<VamanaConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -109,22 +117,29 @@ pub mod vectors_config_diff {
         ParamsMap(super::VectorParamsDiffMap),
     }
 }
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseVectorParams {
     /// Configuration of sparse index
     #[prost(message, optional, tag = "1")]
+    #[validate(nested)]
     pub index: ::core::option::Option<SparseIndexConfig>,
     /// If set - apply modifier to the vector values
     #[prost(enumeration = "Modifier", optional, tag = "2")]
     pub modifier: ::core::option::Option<i32>,
+    /// Distance function used to score vectors, only Dot and Cosine are supported. Default: Dot
+    #[prost(enumeration = "Distance", optional, tag = "3")]
+    pub distance: ::core::option::Option<i32>,
 }
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SparseVectorConfig {
     #[prost(map = "string, message", tag = "1")]
+    #[validate(nested)]
     pub map: ::std::collections::HashMap<
         ::prost::alloc::string::String,
         SparseVectorParams,
@@ -300,6 +315,47 @@ pub struct HnswConfigDiff {
     #[prost(uint64, optional, tag = "6")]
     pub payload_m: ::core::option::Option<u64>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IvfConfig {
+    /// Number of clusters the vectors are partitioned into.
+    /// If 0 - selected automatically as the square root of the number of vectors.
+    #[prost(uint64, optional, tag = "1")]
+    pub nlist: ::core::option::Option<u64>,
+    /// Number of clusters closest to the query, which are scanned during the search.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 1))]
+    pub nprobe: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VamanaConfig {
+    /// Maximal number of links per node in the graph.
+    /// Larger the value - more accurate the search, more disk space and reads required.
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(range(min = 1))]
+    pub max_degree: ::core::option::Option<u64>,
+    /// Size of the candidate list, used to find links of a node during the index build.
+    /// Larger the value - more accurate the search, more time required to build the index.
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 1))]
+    pub build_search_list: ::core::option::Option<u64>,
+    /// Default size of the candidate list during the search.
+    /// Larger the value - more accurate the result, more disk reads required for search.
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(range(min = 1))]
+    pub search_list: ::core::option::Option<u64>,
+    /// Number of nodes, which are read from disk in parallel on each step of the search.
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1))]
+    pub beam_width: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -314,6 +370,25 @@ pub struct SparseIndexConfig {
     /// Datatype used to store weights in the index.
     #[prost(enumeration = "Datatype", optional, tag = "3")]
     pub datatype: ::core::option::Option<i32>,
+    /// Static pruning of the posting lists, applied to immutable indexes only.
+    #[prost(message, optional, tag = "4")]
+    #[validate(nested)]
+    pub pruning: ::core::option::Option<SparsePruningConfig>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SparsePruningConfig {
+    /// Keep at most this number of postings with the largest weights in each dimension.
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(range(min = 1))]
+    pub max_postings: ::core::option::Option<u64>,
+    /// Drop postings with weight below this fraction of the largest weight in the dimension.
+    /// Expected value range in \[0.0, 1.0\]
+    #[prost(float, optional, tag = "2")]
+    #[validate(custom(function = "crate::grpc::validate::validate_f32_range_0_1"))]
+    pub min_weight_ratio: ::core::option::Option<f32>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -421,6 +496,66 @@ pub struct ProductQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "2")]
     pub always_ram: ::core::option::Option<bool>,
+    /// If true - learn an orthogonal rotation of vectors before quantization (OPQ)
+    #[prost(bool, optional, tag = "3")]
+    pub opq: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BinaryQuantizationQueryEncoding {
+    #[prost(oneof = "binary_quantization_query_encoding::Variant", tags = "1")]
+    pub variant: ::core::option::Option<binary_quantization_query_encoding::Variant>,
+}
+/// Nested message and enum types in `BinaryQuantizationQueryEncoding`.
+pub mod binary_quantization_query_encoding {
+    #[derive(serde::Serialize)]
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Setting {
+        Default = 0,
+        Scalar4Bits = 1,
+        Scalar8Bits = 2,
+    }
+    impl Setting {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Setting::Default => "Default",
+                Setting::Scalar4Bits => "Scalar4Bits",
+                Setting::Scalar8Bits => "Scalar8Bits",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "Default" => Some(Self::Default),
+                "Scalar4Bits" => Some(Self::Scalar4Bits),
+                "Scalar8Bits" => Some(Self::Scalar8Bits),
+                _ => None,
+            }
+        }
+    }
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        #[prost(enumeration = "Setting", tag = "1")]
+        Setting(i32),
+    }
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -430,6 +565,12 @@ pub struct BinaryQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "1")]
     pub always_ram: ::core::option::Option<bool>,
+    /// Number of bits used to encode each dimension
+    #[prost(enumeration = "BinaryQuantizationEncoding", optional, tag = "2")]
+    pub encoding: ::core::option::Option<i32>,
+    /// Encoding of the query. Asymmetric encodings keep the query in higher precision than the stored vectors
+    #[prost(message, optional, tag = "3")]
+    pub query_encoding: ::core::option::Option<BinaryQuantizationQueryEncoding>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -622,6 +763,7 @@ pub struct CreateCollection {
     pub sharding_method: ::core::option::Option<i32>,
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "16")]
+    #[validate(nested)]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
     /// Configuration for strict mode
     #[prost(message, optional, tag = "17")]
@@ -663,6 +805,7 @@ pub struct UpdateCollection {
     pub quantization_config: ::core::option::Option<QuantizationConfigDiff>,
     /// New sparse vector parameters
     #[prost(message, optional, tag = "8")]
+    #[validate(nested)]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
     /// New strict mode configuration
     #[prost(message, optional, tag = "9")]
@@ -723,6 +866,7 @@ pub struct CollectionParams {
     pub sharding_method: ::core::option::Option<i32>,
     /// Configuration for sparse vectors
     #[prost(message, optional, tag = "10")]
+    #[validate(nested)]
     pub sparse_vectors_config: ::core::option::Option<SparseVectorConfig>,
 }
 #[derive(validator::Validate)]
@@ -839,6 +983,52 @@ pub struct TextIndexParams {
     /// If true - store index on disk.
     #[prost(bool, optional, tag = "5")]
     pub on_disk: ::core::option::Option<bool>,
+    /// If true - fold accented characters to their ASCII equivalents
+    #[prost(bool, optional, tag = "6")]
+    pub ascii_folding: ::core::option::Option<bool>,
+    /// Stop words to ignore
+    #[prost(message, optional, tag = "7")]
+    pub stopwords: ::core::option::Option<StopwordsSet>,
+    /// Algorithm for stemming
+    #[prost(message, optional, tag = "8")]
+    pub stemmer: ::core::option::Option<StemmingAlgorithm>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StopwordsSet {
+    /// Set of languages to use predefined stop words from
+    #[prost(enumeration = "Language", repeated, tag = "1")]
+    pub languages: ::prost::alloc::vec::Vec<i32>,
+    /// Custom stop words
+    #[prost(string, repeated, tag = "2")]
+    pub custom: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StemmingAlgorithm {
+    #[prost(oneof = "stemming_algorithm::StemmingParams", tags = "1")]
+    pub stemming_params: ::core::option::Option<stemming_algorithm::StemmingParams>,
+}
+/// Nested message and enum types in `StemmingAlgorithm`.
+pub mod stemming_algorithm {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum StemmingParams {
+        /// Parameters for snowball stemming
+        #[prost(message, tag = "1")]
+        Snowball(super::SnowballParams),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnowballParams {
+    /// Language for stemming
+    #[prost(enumeration = "Language", tag = "1")]
+    pub language: i32,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1372,6 +1562,10 @@ pub enum Datatype {
     Float32 = 1,
     Uint8 = 2,
     Float16 = 3,
+    /// Single bits, 8 dimensions are packed into a byte
+    Bit = 4,
+    /// Signed 8-bit integers
+    Sint8 = 5,
 }
 impl Datatype {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1384,6 +1578,8 @@ impl Datatype {
             Datatype::Float32 => "Float32",
             Datatype::Uint8 => "Uint8",
             Datatype::Float16 => "Float16",
+            Datatype::Bit => "Bit",
+            Datatype::Sint8 => "Sint8",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1393,6 +1589,8 @@ impl Datatype {
             "Float32" => Some(Self::Float32),
             "Uint8" => Some(Self::Uint8),
             "Float16" => Some(Self::Float16),
+            "Bit" => Some(Self::Bit),
+            "Sint8" => Some(Self::Sint8),
             _ => None,
         }
     }
@@ -1430,6 +1628,12 @@ impl Modifier {
 #[repr(i32)]
 pub enum MultiVectorComparator {
     MaxSim = 0,
+    /// MaxSim weighted by the weights of query vectors
+    WeightedMaxSim = 1,
+    /// Symmetric Chamfer similarity
+    Chamfer = 2,
+    /// Similarity of mean-pooled vectors, only `Dot` distance with quantization
+    MeanPool = 3,
 }
 impl MultiVectorComparator {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1439,12 +1643,18 @@ impl MultiVectorComparator {
     pub fn as_str_name(&self) -> &'static str {
         match self {
             MultiVectorComparator::MaxSim => "MaxSim",
+            MultiVectorComparator::WeightedMaxSim => "WeightedMaxSim",
+            MultiVectorComparator::Chamfer => "Chamfer",
+            MultiVectorComparator::MeanPool => "MeanPool",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
             "MaxSim" => Some(Self::MaxSim),
+            "WeightedMaxSim" => Some(Self::WeightedMaxSim),
+            "Chamfer" => Some(Self::Chamfer),
+            "MeanPool" => Some(Self::MeanPool),
             _ => None,
         }
     }
@@ -1458,6 +1668,10 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    /// Number of differing bits, only for bit vectors
+    Hamming = 5,
+    /// Ratio of common set bits to all set bits, only for bit vectors
+    Jaccard = 6,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1471,6 +1685,8 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1481,6 +1697,8 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
             _ => None,
         }
     }
@@ -1579,6 +1797,10 @@ impl PayloadSchemaType {
 pub enum QuantizationType {
     UnknownQuantization = 0,
     Int8 = 1,
+    /// 4-bit quantization, two dimensions per byte
+    Int4 = 2,
+    /// 2-bit quantization, four dimensions per byte
+    Int2 = 3,
 }
 impl QuantizationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1589,6 +1811,8 @@ impl QuantizationType {
         match self {
             QuantizationType::UnknownQuantization => "UnknownQuantization",
             QuantizationType::Int8 => "Int8",
+            QuantizationType::Int4 => "Int4",
+            QuantizationType::Int2 => "Int2",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1596,6 +1820,8 @@ impl QuantizationType {
         match value {
             "UnknownQuantization" => Some(Self::UnknownQuantization),
             "Int8" => Some(Self::Int8),
+            "Int4" => Some(Self::Int4),
+            "Int2" => Some(Self::Int2),
             _ => None,
         }
     }
@@ -1639,6 +1865,36 @@ impl CompressionRatio {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum BinaryQuantizationEncoding {
+    OneBit = 0,
+    TwoBits = 1,
+    OneAndHalfBits = 2,
+}
+impl BinaryQuantizationEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BinaryQuantizationEncoding::OneBit => "OneBit",
+            BinaryQuantizationEncoding::TwoBits => "TwoBits",
+            BinaryQuantizationEncoding::OneAndHalfBits => "OneAndHalfBits",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OneBit" => Some(Self::OneBit),
+            "TwoBits" => Some(Self::TwoBits),
+            "OneAndHalfBits" => Some(Self::OneAndHalfBits),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ShardingMethod {
     /// Auto-sharding based on record ids
     Auto = 0,
@@ -1704,6 +1960,54 @@ impl TokenizerType {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum Language {
+    UnknownLanguage = 0,
+    Dutch = 1,
+    English = 2,
+    French = 3,
+    German = 4,
+    Italian = 5,
+    Portuguese = 6,
+    Russian = 7,
+    Spanish = 8,
+}
+impl Language {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Language::UnknownLanguage => "UnknownLanguage",
+            Language::Dutch => "Dutch",
+            Language::English => "English",
+            Language::French => "French",
+            Language::German => "German",
+            Language::Italian => "Italian",
+            Language::Portuguese => "Portuguese",
+            Language::Russian => "Russian",
+            Language::Spanish => "Spanish",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "UnknownLanguage" => Some(Self::UnknownLanguage),
+            "Dutch" => Some(Self::Dutch),
+            "English" => Some(Self::English),
+            "French" => Some(Self::French),
+            "German" => Some(Self::German),
+            "Italian" => Some(Self::Italian),
+            "Portuguese" => Some(Self::Portuguese),
+            "Russian" => Some(Self::Russian),
+            "Spanish" => Some(Self::Spanish),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum ReplicaState {
     /// Active and sound
     Active = 0,
@@ -4126,6 +4430,9 @@ pub struct SparseVector {
 pub struct MultiDenseVector {
     #[prost(message, repeated, tag = "1")]
     pub vectors: ::prost::alloc::vec::Vec<DenseVector>,
+    /// Weight of each vector of a nearest query, used by the `WeightedMaxSim` comparator
+    #[prost(float, repeated, tag = "2")]
+    pub weights: ::prost::alloc::vec::Vec<f32>,
 }
 /// Vector type to be used in queries. Ids will be substituted with their corresponding vectors from the collection.
 #[derive(serde::Serialize)]
@@ -4571,6 +4878,16 @@ pub struct QuantizationSearchParams {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RangeSearchParams {
+    /// Score of the last point of the previous page, only points with a worse score are returned.
+    /// Points with equal scores are never split between pages, so a page may contain more than `limit` points.
+    #[prost(float, optional, tag = "1")]
+    pub after: ::core::option::Option<f32>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchParams {
     /// Params relevant to HNSW index. Size of the beam in a beam-search.
     /// Larger the value - more accurate the result, more time required for search.
@@ -4588,6 +4905,19 @@ pub struct SearchParams {
     /// guarantee that all uploaded vectors will be included in search results
     #[prost(bool, optional, tag = "4")]
     pub indexed_only: ::core::option::Option<bool>,
+    /// Range search: return all points within the `score_threshold` of the request, best first,
+    /// page by page instead of a fixed number of nearest ones. `limit` is the size of a page.
+    #[prost(message, optional, tag = "5")]
+    #[validate(nested)]
+    pub range: ::core::option::Option<RangeSearchParams>,
+    /// Params relevant to IVF index. Number of clusters to scan.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[prost(uint64, optional, tag = "6")]
+    pub ivf_nprobe: ::core::option::Option<u64>,
+    /// Params relevant to Vamana index. Size of the candidate list in a beam-search.
+    /// Larger the value - more accurate the result, more disk reads required for search.
+    #[prost(uint64, optional, tag = "7")]
+    pub vamana_search_list: ::core::option::Option<u64>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -4739,7 +5069,7 @@ pub struct SearchPointGroups {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartFrom {
-    #[prost(oneof = "start_from::Value", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "start_from::Value", tags = "1, 2, 3, 4, 5")]
     pub value: ::core::option::Option<start_from::Value>,
 }
 /// Nested message and enum types in `StartFrom`.
@@ -4756,6 +5086,8 @@ pub mod start_from {
         Timestamp(::prost_wkt_types::Timestamp),
         #[prost(string, tag = "4")]
         Datetime(::prost::alloc::string::String),
+        #[prost(string, tag = "5")]
+        Keyword(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
@@ -5172,11 +5504,110 @@ pub struct ContextInput {
     #[prost(message, repeated, tag = "1")]
     pub pairs: ::prost::alloc::vec::Vec<ContextInputPair>,
 }
+/// Fusion with parameters.
+///
+/// Weights are applied to the prefetches in the order they are listed. Prefetches without a weight have weight 1.0.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FusionParams {
+    #[prost(oneof = "fusion_params::Variant", tags = "1, 2, 3")]
+    pub variant: ::core::option::Option<fusion_params::Variant>,
+}
+/// Nested message and enum types in `FusionParams`.
+pub mod fusion_params {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        /// Reciprocal Rank Fusion
+        #[prost(message, tag = "1")]
+        Rrf(super::Rrf),
+        /// Distribution-Based Score Fusion
+        #[prost(message, tag = "2")]
+        Dbsf(super::Dbsf),
+        /// Weighted sum of normalized scores
+        #[prost(message, tag = "3")]
+        Linear(super::LinearFusion),
+    }
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rrf {
+    /// Constant added to the rank of each result. Higher values reduce the impact of top ranks. Default is 2
+    #[prost(uint32, optional, tag = "1")]
+    pub k: ::core::option::Option<u32>,
+    /// Weights of the prefetches
+    #[prost(float, repeated, tag = "2")]
+    pub weights: ::prost::alloc::vec::Vec<f32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Dbsf {
+    /// Weights of the prefetches
+    #[prost(float, repeated, tag = "1")]
+    pub weights: ::prost::alloc::vec::Vec<f32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LinearFusion {
+    /// Normalization of the scores of each prefetch. Default is MinMax
+    #[prost(enumeration = "ScoreNormalization", optional, tag = "1")]
+    pub normalization: ::core::option::Option<i32>,
+    /// Weights of the prefetches
+    #[prost(float, repeated, tag = "2")]
+    pub weights: ::prost::alloc::vec::Vec<f32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Bm25 {
+    /// Payload key with a full-text index to score against
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Text to score the documents with. It is tokenized with the tokenizer of the full-text index.
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+    /// Term frequency saturation parameter. Default is 1.2
+    #[prost(float, optional, tag = "3")]
+    pub k1: ::core::option::Option<f32>,
+    /// Document length normalization parameter, between 0 and 1. Default is 0.75
+    #[prost(float, optional, tag = "4")]
+    pub b: ::core::option::Option<f32>,
+}
+/// Maximal Marginal Relevance (MMR) parameters.
+///
+/// Results are selected one by one, balancing the similarity to the query against the similarity to the already selected results.
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Mmr {
+    /// Trade-off between relevance and diversity, between 0 and 1. 1.0 only considers relevance, 0.0 only considers diversity. Default is 0.5
+    #[prost(float, optional, tag = "1")]
+    pub lambda: ::core::option::Option<f32>,
+    /// Number of nearest points to select the diverse results from. Ignored when there are prefetches. Default is 100
+    #[prost(uint32, optional, tag = "2")]
+    pub candidates_limit: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NearestInputWithMmr {
+    /// The vector to search for nearest neighbors.
+    #[prost(message, optional, tag = "1")]
+    pub nearest: ::core::option::Option<VectorInput>,
+    /// Diversify the nearest neighbors with Maximal Marginal Relevance.
+    #[prost(message, optional, tag = "2")]
+    pub mmr: ::core::option::Option<Mmr>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
     pub variant: ::core::option::Option<query::Variant>,
 }
 /// Nested message and enum types in `Query`.
@@ -5206,6 +5637,15 @@ pub mod query {
         /// Sample points from the collection.
         #[prost(enumeration = "super::Sample", tag = "7")]
         Sample(i32),
+        /// Score points by BM25 relevance of a full-text indexed payload field.
+        #[prost(message, tag = "8")]
+        Bm25(super::Bm25),
+        /// Fuse the results of multiple prefetches, with parameters.
+        #[prost(message, tag = "9")]
+        FusionParams(super::FusionParams),
+        /// Find the nearest neighbors to this vector, diversified with Maximal Marginal Relevance.
+        #[prost(message, tag = "10")]
+        NearestWithMmr(super::NearestInputWithMmr),
     }
 }
 #[derive(serde::Serialize)]
@@ -5702,7 +6142,7 @@ pub struct UpdateResult {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OrderValue {
-    #[prost(oneof = "order_value::Variant", tags = "1, 2")]
+    #[prost(oneof = "order_value::Variant", tags = "1, 2, 3")]
     pub variant: ::core::option::Option<order_value::Variant>,
 }
 /// Nested message and enum types in `OrderValue`.
@@ -5715,6 +6155,8 @@ pub mod order_value {
         Int(i64),
         #[prost(double, tag = "2")]
         Float(f64),
+        #[prost(string, tag = "3")]
+        Keyword(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
@@ -6164,12 +6606,18 @@ pub struct FieldCondition {
     /// Check if datetime is within a given range
     #[prost(message, optional, tag = "8")]
     pub datetime_range: ::core::option::Option<DatetimeRange>,
+    /// Check if keyword is within a given lexicographic range
+    #[prost(message, optional, tag = "9")]
+    pub keyword_range: ::core::option::Option<KeywordRange>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Match {
-    #[prost(oneof = "r#match::MatchValue", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    #[prost(
+        oneof = "r#match::MatchValue",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13"
+    )]
     pub match_value: ::core::option::Option<r#match::MatchValue>,
 }
 /// Nested message and enum types in `Match`.
@@ -6202,11 +6650,48 @@ pub mod r#match {
         /// Match any other value except those keywords
         #[prost(message, tag = "8")]
         ExceptKeywords(super::RepeatedStrings),
+        /// Match phrase
+        #[prost(message, tag = "9")]
+        Phrase(super::MatchPhrase),
+        /// Match words within an edit distance
+        #[prost(message, tag = "10")]
+        Fuzzy(super::MatchFuzzy),
+        /// Match strings starting with the prefix
+        #[prost(string, tag = "11")]
+        Prefix(::prost::alloc::string::String),
+        /// Match strings containing the substring
+        #[prost(string, tag = "12")]
+        Substring(::prost::alloc::string::String),
+        /// Match strings by a regular expression
+        #[prost(string, tag = "13")]
+        Regex(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatchPhrase {
+    /// Phrase to match, tokens must occur in the same order
+    #[prost(string, tag = "1")]
+    pub phrase: ::prost::alloc::string::String,
+    /// Maximum number of other tokens allowed between the phrase tokens in total, at most 64. Default is 0
+    #[prost(uint32, optional, tag = "2")]
+    pub slop: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatchFuzzy {
+    /// Text to match, each word may contain typos
+    #[prost(string, tag = "1")]
+    pub fuzzy: ::prost::alloc::string::String,
+    /// Maximum Levenshtein distance per word, at most 2. Default depends on the word length
+    #[prost(uint32, optional, tag = "2")]
+    pub max_edits: ::core::option::Option<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepeatedStrings {
     #[prost(string, repeated, tag = "1")]
     pub strings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
@@ -6252,6 +6737,19 @@ pub struct DatetimeRange {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeywordRange {
+    #[prost(string, optional, tag = "1")]
+    pub lt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub gt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub gte: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub lte: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoBoundingBox {
     /// north-west corner
     #[prost(message, optional, tag = "1")]
@@ -6577,6 +7075,35 @@ impl Fusion {
         }
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScoreNormalization {
+    /// Scale the scores to the range from 0 to 1, using the minimum and maximum scores
+    MinMax = 0,
+    /// Replace the scores with the number of standard deviations from the mean score, points missing from a prefetch get its lowest score
+    ZScore = 1,
+}
+impl ScoreNormalization {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ScoreNormalization::MinMax => "MinMax",
+            ScoreNormalization::ZScore => "ZScore",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MinMax" => Some(Self::MinMax),
+            "ZScore" => Some(Self::ZScore),
+            _ => None,
+        }
+    }
+}
 /// Sample points from the collection
 ///
 /// Available sampling methods:
@@ -9492,6 +10019,20 @@ pub mod raw_query {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MmrInternal {
+    /// Query vector to measure the relevance of the candidates
+    #[prost(message, optional, tag = "1")]
+    pub vector: ::core::option::Option<RawVector>,
+    /// Trade-off between relevance (1.0) and diversity (0.0)
+    #[prost(float, tag = "2")]
+    pub lambda: f32,
+    /// Number of nearest points to select from, when there are no prefetches
+    #[prost(uint64, tag = "3")]
+    pub candidates_limit: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryShardPoints {
     #[prost(message, repeated, tag = "1")]
     pub prefetch: ::prost::alloc::vec::Vec<query_shard_points::Prefetch>,
@@ -9520,7 +10061,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -9541,6 +10082,15 @@ pub mod query_shard_points {
             /// Sample points
             #[prost(enumeration = "super::super::Sample", tag = "4")]
             Sample(i32),
+            /// Score by BM25 relevance of a full-text field
+            #[prost(message, tag = "5")]
+            Bm25(super::super::Bm25),
+            /// One of the fusion methods, with parameters
+            #[prost(message, tag = "6")]
+            FusionParams(super::super::FusionParams),
+            /// Diversify the candidates with Maximal Marginal Relevance
+            #[prost(message, tag = "7")]
+            Mmr(super::super::MmrInternal),
         }
     }
     #[derive(serde::Serialize)]
@@ -202,6 +202,7 @@ impl Validate for grpc::FieldCondition {
             r#match,
             range,
             datetime_range,
+            keyword_range,
             geo_bounding_box,
             geo_radius,
             geo_polygon,
@@ -211,6 +212,7 @@ impl Validate for grpc::FieldCondition {
         let all_fields_none = r#match.is_none()
             && range.is_none()
             && datetime_range.is_none()
+            && keyword_range.is_none()
             && geo_bounding_box.is_none()
             && geo_radius.is_none()
             && geo_polygon.is_none()
@@ -309,6 +311,11 @@ pub fn validate_geo_polygon_interiors(
     Ok(())
 }
 
+/// Validate that the value is within `[0, 1]`, NaN is rejected as well
+pub fn validate_f32_range_0_1(value: &f32) -> Result<(), ValidationError> {
+    validate_range_generic(*value, Some(0.0), Some(1.0))
+}
+
 /// Validate that the timestamp is within the range specified in the protobuf docs.
 /// <https://protobuf.dev/reference/protobuf/google.protobuf/#timestamp>
 pub fn validate_timestamp(ts: &prost_wkt_types::Timestamp) -> Result<(), ValidationError> {
@@ -323,11 +330,14 @@ pub fn validate_timestamp(ts: &prost_wkt_types::Timestamp) -> Result<(), Validat
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use validator::Validate;
 
     use crate::grpc::qdrant::{
         CreateCollection, CreateFieldIndexCollection, GeoLineString, GeoPoint, GeoPolygon,
-        SearchPoints, UpdateCollection,
+        SearchPoints, SparseIndexConfig, SparsePruningConfig, SparseVectorConfig,
+        SparseVectorParams, UpdateCollection,
     };
 
     #[test]
@@ -394,6 +404,43 @@ mod tests {
             bad_request.validate().is_err(),
             "bad collection request should error on validation"
         );
+
+        // Pruning must keep postings
+        for pruning in [
+            SparsePruningConfig {
+                max_postings: Some(0),
+                min_weight_ratio: None,
+            },
+            SparsePruningConfig {
+                max_postings: None,
+                min_weight_ratio: Some(1.5),
+            },
+            SparsePruningConfig {
+                max_postings: None,
+                min_weight_ratio: Some(f32::NAN),
+            },
+        ] {
+            let bad_request = CreateCollection {
+                collection_name: "test_collection".into(),
+                sparse_vectors_config: Some(SparseVectorConfig {
+                    map: HashMap::from([(
+                        "sparse".to_string(),
+                        SparseVectorParams {
+                            index: Some(SparseIndexConfig {
+                                pruning: Some(pruning),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    )]),
+                }),
+                ..Default::default()
+            };
+            assert!(
+                bad_request.validate().is_err(),
+                "bad sparse pruning should error on validation"
+            );
+        }
     }
 
     #[test]
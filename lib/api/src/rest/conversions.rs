@@ -1,5 +1,7 @@
 use segment::data_types::order_by::OrderBy;
-use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
+use segment::data_types::vectors::{
+    VectorInternal, VectorStructInternal, WeightedMultiDenseVector,
+};
 use uuid::Uuid;
 
 use super::schema::{ScoredPoint, Vector};
@@ -14,7 +16,8 @@ impl From<VectorInternal> for VectorOutput {
         match value {
             VectorInternal::Dense(vector) => VectorOutput::Dense(vector),
             VectorInternal::Sparse(vector) => VectorOutput::Sparse(vector),
-            VectorInternal::MultiDense(vector) => {
+            VectorInternal::MultiDense(vector)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, .. }) => {
                 VectorOutput::MultiDense(vector.into_multi_vectors())
             }
         }
@@ -113,7 +116,10 @@ impl From<OrderByInterface> for OrderBy {
 impl From<QueryInterface> for Query {
     fn from(value: QueryInterface) -> Self {
         match value {
-            QueryInterface::Nearest(vector) => Query::Nearest(NearestQuery { nearest: vector }),
+            QueryInterface::Nearest(vector) => Query::Nearest(NearestQuery {
+                nearest: vector,
+                mmr: None,
+            }),
             QueryInterface::Query(query) => query,
         }
     }
@@ -5,6 +5,7 @@ use common::types::ScoreType;
 use common::validation::validate_multi_vector;
 use schemars::JsonSchema;
 use segment::common::utils::MaybeOneOrMany;
+use segment::data_types::bm25::Bm25;
 use segment::data_types::order_by::OrderBy;
 use segment::json_path::JsonPath;
 use segment::types::{
@@ -172,6 +173,7 @@ pub struct Document {
     pub text: String,
     /// Name of the model used to generate the vector
     /// List of available models depends on a provider
+    /// Model `qdrant/bm25` is built-in: the text is converted into a BM25 sparse vector without inference service
     #[validate(length(min = 1))]
     #[schemars(length(min = 1), example = "model_example")]
     pub model: String,
@@ -359,18 +361,90 @@ pub enum Fusion {
     Dbsf,
 }
 
+/// Fusion algorithm with parameters.
+///
+/// Weights are applied to the prefetches in the order they are listed. Prefetches without a weight have weight 1.0.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FusionParams {
+    /// Reciprocal Rank Fusion
+    Rrf(Rrf),
+    /// Distribution-Based Score Fusion
+    Dbsf(Dbsf),
+    /// Weighted sum of normalized scores
+    Linear(LinearFusion),
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct Rrf {
+    /// Constant added to the rank of each result. Higher values reduce the impact of top ranks. Default is 2
+    #[validate(range(min = 1))]
+    pub k: Option<usize>,
+
+    /// Weights of the prefetches
+    #[validate(custom(function = "crate::rest::validate::validate_fusion_weights"))]
+    pub weights: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct Dbsf {
+    /// Weights of the prefetches
+    #[validate(custom(function = "crate::rest::validate::validate_fusion_weights"))]
+    pub weights: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct LinearFusion {
+    /// Normalization of the scores of each prefetch. Default is `min_max`
+    pub normalization: Option<ScoreNormalization>,
+
+    /// Weights of the prefetches
+    #[validate(custom(function = "crate::rest::validate::validate_fusion_weights"))]
+    pub weights: Option<Vec<f32>>,
+}
+
+/// Normalization of the scores before the fusion.
+///
+/// * `min_max` - Scales the scores to the range from 0 to 1, using the minimum and maximum scores
+/// * `z_score` - Replaces the scores with the number of standard deviations from the mean score,
+///   points missing from a prefetch get its lowest score
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreNormalization {
+    MinMax,
+    ZScore,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum FusionInterface {
+    Fusion(Fusion),
+    Params(FusionParams),
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum VectorInput {
     DenseVector(DenseVector),
     SparseVector(SparseVector),
     MultiDenseVector(MultiDenseVector),
+    WeightedMultiDenseVector(WeightedMultiDenseVector),
     Id(segment::types::PointIdType),
     Document(Document),
     Image(Image),
     Object(InferenceObject),
 }
 
+/// Multivector query with a weight of each vector.
+/// Weights are used by the `weighted_max_sim` comparator in nearest queries, other comparators and queries ignore them.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WeightedMultiDenseVector {
+    /// Vectors of the multivector
+    pub multivector: MultiDenseVector,
+    /// Weight of each vector of the multivector, in the same order
+    pub weights: Vec<ScoreType>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct QueryRequestInternal {
     /// Sub-requests to perform first. If present, the query will be performed on the results of the prefetch(es).
@@ -467,12 +541,35 @@ pub enum Query {
 
     /// Sample points from the collection, non-deterministically.
     Sample(SampleQuery),
+
+    /// Score points by BM25 relevance of a full-text indexed payload field.
+    Bm25(Bm25Query),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct NearestQuery {
     pub nearest: VectorInput,
+
+    /// Diversify the nearest points with Maximal Marginal Relevance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mmr: Option<Mmr>,
+}
+
+/// Maximal Marginal Relevance (MMR) parameters.
+///
+/// Results are selected one by one, balancing the similarity to the query
+/// against the similarity to the already selected results.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct Mmr {
+    /// Trade-off between relevance and diversity, between 0 and 1.
+    /// 1.0 only considers relevance, 0.0 only considers diversity. Default is 0.5
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub lambda: Option<f32>,
+
+    /// Number of nearest points to select the diverse results from. Ignored when there are prefetches. Default is 100
+    #[validate(range(min = 1))]
+    pub candidates_limit: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -502,7 +599,7 @@ pub struct OrderByQuery {
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct FusionQuery {
-    pub fusion: Fusion,
+    pub fusion: FusionInterface,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -511,6 +608,12 @@ pub struct SampleQuery {
     pub sample: Sample,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Bm25Query {
+    pub bm25: Bm25,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
 pub struct Prefetch {
     /// Sub-requests to perform first. If present, the query will be performed on the results of the prefetches.
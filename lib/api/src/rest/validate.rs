@@ -5,8 +5,8 @@ use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::schema::BatchVectorStruct;
 use super::{
-    Batch, ContextInput, Fusion, OrderByInterface, PointVectors, Query, QueryInterface,
-    RecommendInput, Sample, VectorInput,
+    Batch, ContextInput, Fusion, FusionInterface, FusionParams, OrderByInterface, PointVectors,
+    Query, QueryInterface, RecommendInput, Sample, VectorInput, WeightedMultiDenseVector,
 };
 use crate::rest::NamedVectorStruct;
 
@@ -32,13 +32,20 @@ impl Validate for QueryInterface {
 impl Validate for Query {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         match self {
-            Query::Nearest(vector) => vector.nearest.validate(),
+            Query::Nearest(nearest) => {
+                nearest.nearest.validate()?;
+                if let Some(mmr) = &nearest.mmr {
+                    mmr.validate()?;
+                }
+                Ok(())
+            }
             Query::Recommend(recommend) => recommend.recommend.validate(),
             Query::Discover(discover) => discover.discover.validate(),
             Query::Context(context) => context.context.validate(),
             Query::Fusion(fusion) => fusion.fusion.validate(),
             Query::OrderBy(order_by) => order_by.order_by.validate(),
             Query::Sample(sample) => sample.sample.validate(),
+            Query::Bm25(bm25) => bm25.bm25.validate(),
         }
     }
 }
@@ -50,6 +57,7 @@ impl Validate for VectorInput {
             VectorInput::DenseVector(_dense) => Ok(()),
             VectorInput::SparseVector(sparse) => sparse.validate(),
             VectorInput::MultiDenseVector(multi) => validate_multi_vector(multi),
+            VectorInput::WeightedMultiDenseVector(weighted) => weighted.validate(),
             VectorInput::Document(doc) => doc.validate(),
             VectorInput::Image(image) => image.validate(),
             VectorInput::Object(obj) => obj.validate(),
@@ -57,6 +65,29 @@ impl Validate for VectorInput {
     }
 }
 
+impl Validate for WeightedMultiDenseVector {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let WeightedMultiDenseVector {
+            multivector,
+            weights,
+        } = self;
+        validate_multi_vector(multivector)?;
+
+        let message = if weights.len() != multivector.len() {
+            "weights count must be equal to the vectors count"
+        } else if weights.iter().any(|weight| !weight.is_finite()) {
+            "weights must be finite"
+        } else {
+            return Ok(());
+        };
+        let mut errors = ValidationErrors::new();
+        let mut error = ValidationError::new("invalid_weights");
+        error.add_param(Cow::from("message"), &message);
+        errors.add("weights", error);
+        Err(errors)
+    }
+}
+
 impl Validate for RecommendInput {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         let no_positives = self.positive.as_ref().map(|p| p.is_empty()).unwrap_or(true);
@@ -99,6 +130,25 @@ impl Validate for Fusion {
     }
 }
 
+impl Validate for FusionInterface {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            FusionInterface::Fusion(fusion) => fusion.validate(),
+            FusionInterface::Params(params) => params.validate(),
+        }
+    }
+}
+
+impl Validate for FusionParams {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            FusionParams::Rrf(rrf) => rrf.validate(),
+            FusionParams::Dbsf(dbsf) => dbsf.validate(),
+            FusionParams::Linear(linear) => linear.validate(),
+        }
+    }
+}
+
 impl Validate for OrderByInterface {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         match self {
@@ -212,3 +262,19 @@ impl Validate for PointVectors {
         }
     }
 }
+
+/// Fusion weights must be non-negative finite numbers
+pub fn validate_fusion_weights(weights: &[f32]) -> Result<(), ValidationError> {
+    if weights
+        .iter()
+        .all(|weight| weight.is_finite() && *weight >= 0.0)
+    {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("weights");
+        error.message = Some(Cow::from(
+            "fusion weights must be non-negative finite numbers",
+        ));
+        Err(error)
+    }
+}
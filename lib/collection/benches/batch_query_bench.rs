@@ -258,7 +258,7 @@ fn batch_rrf_query_bench(c: &mut Criterion) {
                                     score_threshold: None,
                                 },
                             ],
-                            query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+                            query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
                             filter: filter.clone(),
                             params: None,
                             limit: 10,
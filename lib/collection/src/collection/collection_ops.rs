@@ -68,9 +68,10 @@ impl Collection {
     ) -> CollectionResult<()> {
         let mut config = self.collection_config.write().await;
         update_vectors_diff.check_vector_names(&config.params)?;
-        config
-            .params
-            .update_vectors_from_diff(update_vectors_diff)?;
+        let mut params = config.params.clone();
+        params.update_vectors_from_diff(update_vectors_diff)?;
+        params.check_quantization(config.quantization_config.as_ref())?;
+        config.params = params;
         config.save(&self.path)?;
         Ok(())
     }
@@ -138,26 +139,18 @@ impl Collection {
     ) -> CollectionResult<()> {
         {
             let mut config = self.collection_config.write().await;
-            match quantization_config_diff {
-                QuantizationConfigDiff::Scalar(scalar) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Scalar(scalar));
-                }
+            let quantization_config = match quantization_config_diff {
+                QuantizationConfigDiff::Scalar(scalar) => Some(QuantizationConfig::Scalar(scalar)),
                 QuantizationConfigDiff::Product(product) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Product(product));
-                }
-                QuantizationConfigDiff::Binary(binary) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Binary(binary));
+                    Some(QuantizationConfig::Product(product))
                 }
-                QuantizationConfigDiff::Disabled(_) => {
-                    config.quantization_config = None;
-                }
-            }
+                QuantizationConfigDiff::Binary(binary) => Some(QuantizationConfig::Binary(binary)),
+                QuantizationConfigDiff::Disabled(_) => None,
+            };
+            config
+                .params
+                .check_quantization(quantization_config.as_ref())?;
+            config.quantization_config = quantization_config;
         }
         self.collection_config.read().await.save(&self.path)?;
         Ok(())
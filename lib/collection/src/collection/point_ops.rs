@@ -325,11 +325,11 @@ impl Collection {
                         records.into_iter().map(|mut record| {
                             let value;
                             if local_only {
-                                value = record.order_value.unwrap_or_else(|| {
+                                value = record.order_value.clone().unwrap_or_else(|| {
                                     order_by.get_order_value_from_payload(record.payload.as_ref())
                                 });
                             } else {
-                                value = if let Some(order_value) = record.order_value {
+                                value = if let Some(order_value) = record.order_value.clone() {
                                     order_by
                                         .remove_order_value_from_payload(record.payload.as_mut());
                                     order_value
@@ -7,9 +7,7 @@ use common::types::ScoreType;
 use futures::{future, TryFutureExt};
 use itertools::{Either, Itertools};
 use rand::Rng;
-use segment::common::reciprocal_rank_fusion::rrf_scoring;
-use segment::common::score_fusion::{score_fusion, ScoreFusion};
-use segment::types::{Order, ScoredPoint};
+use segment::types::{Order, ScoredPoint, WithVector};
 use segment::utils::scored_point_ties::ScoredPointTies;
 use tokio::sync::RwLockReadGuard;
 use tokio::time::Instant;
@@ -21,12 +19,13 @@ use crate::common::fetch_vectors::{
 };
 use crate::common::retrieve_request_trait::RetrieveRequest;
 use crate::common::transpose_iterator::transposed_iter;
+use crate::config::CollectionParams;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::collection_query::CollectionQueryRequest;
 use crate::operations::universal_query::shard_query::{
-    FusionInternal, ScoringQuery, ShardQueryRequest, ShardQueryResponse,
+    ScoringQuery, ShardQueryRequest, ShardQueryResponse,
 };
 
 struct IntermediateQueryInfo<'a> {
@@ -111,6 +110,7 @@ impl Collection {
         let instant = Instant::now();
 
         let requests_batch = Arc::new(requests_batch);
+        let collection_params = self.collection_config.read().await.params.clone();
 
         let all_shards_results = self
             .batch_query_shards_concurrently(
@@ -136,6 +136,8 @@ impl Collection {
                     request.limit,
                     request.offset,
                     request.score_threshold,
+                    &request.with_vector,
+                    &collection_params,
                 )?;
 
                 let filter_refs = request.filter_refs();
@@ -154,14 +156,13 @@ impl Collection {
         limit: usize,
         offset: usize,
         score_threshold: Option<ScoreType>,
+        with_vector: &WithVector,
+        collection_params: &CollectionParams,
     ) -> CollectionResult<Vec<ScoredPoint>> {
         let result = match query {
             Some(ScoringQuery::Fusion(fusion)) => {
                 // If the root query is a Fusion, the returned results correspond to each the prefetches.
-                let mut fused = match fusion {
-                    FusionInternal::Rrf => rrf_scoring(intermediates),
-                    FusionInternal::Dbsf => score_fusion(intermediates, ScoreFusion::dbsf()),
-                };
+                let mut fused = fusion.clone().fuse(intermediates);
                 if let Some(score_threshold) = score_threshold {
                     fused = fused
                         .into_iter()
@@ -170,6 +171,16 @@ impl Collection {
                 }
                 fused
             }
+            Some(ScoringQuery::Mmr(mmr)) => {
+                // Candidates of all shards are diversified together
+                mmr.diversify(
+                    intermediates.into_iter().flatten(),
+                    collection_params,
+                    offset + limit,
+                    score_threshold,
+                    with_vector,
+                )?
+            }
             _ => {
                 // Otherwise, it will be a list with a single list of scored points.
                 debug_assert_eq!(intermediates.len(), 1);
@@ -395,21 +406,27 @@ fn intermediate_query_infos(request: &ShardQueryRequest) -> Vec<IntermediateQuer
         .map(|sq| sq.needs_intermediate_results())
         .unwrap_or(false);
 
-    if needs_intermediate_results {
-        // In case of Fusion, expect the propagated intermediate results
-        request
+    match &request.query {
+        Some(ScoringQuery::Mmr(mmr)) if request.prefetches.is_empty() => {
+            // MMR without prefetches expects the candidates of its own search
+            vec![IntermediateQueryInfo {
+                scoring_query: request.query.as_ref(),
+                take: mmr.candidates_limit(request.offset + request.limit),
+            }]
+        }
+        // In case of Fusion or MMR, expect the propagated intermediate results
+        _ if needs_intermediate_results => request
             .prefetches
             .iter()
             .map(|prefetch| IntermediateQueryInfo {
                 scoring_query: prefetch.query.as_ref(),
                 take: prefetch.limit,
             })
-            .collect_vec()
-    } else {
+            .collect_vec(),
         // Otherwise, we expect the root result
-        vec![IntermediateQueryInfo {
+        _ => vec![IntermediateQueryInfo {
             scoring_query: request.query.as_ref(),
             take: request.offset + request.limit,
-        }]
+        }],
     }
 }
@@ -7,6 +7,7 @@ use ahash::AHashSet;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::{future, TryFutureExt};
 use itertools::{Either, Itertools};
+use segment::common::score_range::truncate_with_ties;
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
     ExtendedPointId, Filter, Order, ScoredPoint, WithPayloadInterface, WithVector,
@@ -296,7 +297,12 @@ impl Collection {
 
             // Skip `offset` only for client requests
             // to avoid applying `offset` twice in distributed mode.
-            let top_res = if is_client_request && request.offset > 0 {
+            let top_res = if request.params.is_some_and(|params| params.range.is_some()) {
+                // Pages of the range search keep the points tied with the last one
+                let mut top_res = merged_iter.collect_vec();
+                truncate_with_ties(&mut top_res, request.limit, |point| point.score);
+                top_res
+            } else if is_client_request && request.offset > 0 {
                 merged_iter
                     .skip(request.offset)
                     .take(request.limit)
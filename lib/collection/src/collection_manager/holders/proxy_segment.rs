@@ -7,10 +7,11 @@ use std::sync::Arc;
 use bitvec::prelude::BitVec;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::tar_ext;
-use common::types::{PointOffsetType, TelemetryDetail};
+use common::types::{PointOffsetType, ScoreType, TelemetryDetail};
 use itertools::Itertools;
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 use segment::common::operation_error::{OperationResult, SegmentFailedState};
+use segment::data_types::bm25::Bm25;
 use segment::data_types::facets::{FacetParams, FacetValue};
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::order_by::OrderValue;
@@ -765,6 +766,49 @@ impl SegmentEntry for ProxySegment {
         Ok(read_points)
     }
 
+    fn read_scored_by_bm25<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        bm25: &'a Bm25,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let deleted_points = self.deleted_points.read();
+        let mut read_points = if deleted_points.is_empty() {
+            self.wrapped_segment
+                .get()
+                .read()
+                .read_scored_by_bm25(limit, filter, bm25, is_stopped, hw_counter)?
+        } else {
+            let wrapped_filter = Self::add_deleted_points_condition_to_filter(
+                filter,
+                deleted_points.keys().copied(),
+            );
+            self.wrapped_segment.get().read().read_scored_by_bm25(
+                limit,
+                Some(&wrapped_filter),
+                bm25,
+                is_stopped,
+                hw_counter,
+            )?
+        };
+        let mut write_segment_points = self
+            .write_segment
+            .get()
+            .read()
+            .read_scored_by_bm25(limit, filter, bm25, is_stopped, hw_counter)?;
+        read_points.append(&mut write_segment_points);
+        read_points.sort_unstable_by(|(score_a, _), (score_b, _)| score_b.total_cmp(score_a));
+        // Keep only the best score of each point
+        let mut seen_points = HashSet::new();
+        read_points.retain(|(_, point_id)| seen_points.insert(*point_id));
+        if let Some(limit) = limit {
+            read_points.truncate(limit);
+        }
+        Ok(read_points)
+    }
+
     fn read_random_filtered<'a>(
         &'a self,
         limit: usize,
@@ -5,8 +5,10 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 use segment::common::operation_time_statistics::OperationDurationsAggregator;
-use segment::index::sparse_index::sparse_index_config::SparseIndexType;
-use segment::types::{HnswConfig, Indexes, QuantizationConfig, SegmentType, VectorName};
+use segment::index::sparse_index::sparse_index_config::{SparseIndexType, SparsePruningConfig};
+use segment::types::{
+    HnswConfig, Indexes, IvfConfig, QuantizationConfig, SegmentType, VamanaConfig, VectorName,
+};
 
 use crate::collection_manager::holders::segment_holder::{LockedSegmentHolder, SegmentId};
 use crate::collection_manager::optimizers::segment_optimizer::{
@@ -68,6 +70,16 @@ impl ConfigMismatchOptimizer {
             .and_then(|index| index.on_disk)
     }
 
+    /// Pruning of sparse vectors index, required by current configuration
+    fn get_required_sparse_pruning(&self, vector_name: &VectorName) -> Option<SparsePruningConfig> {
+        self.collection_params
+            .sparse_vectors
+            .as_ref()
+            .and_then(|vector_params| vector_params.get(vector_name))
+            .and_then(|params| params.index)
+            .and_then(|index| index.pruning)
+    }
+
     /// Calculates and HNSW config that should be used for a given vector
     /// with current configuration.
     ///
@@ -96,6 +108,22 @@ impl ConfigMismatchOptimizer {
         }
     }
 
+    /// IVF config that should be used for a given vector, if IVF index is configured for it
+    fn get_required_ivf_config(&self, vector_name: &VectorName) -> Option<&IvfConfig> {
+        self.collection_params
+            .vectors
+            .get_params(vector_name)
+            .and_then(|vector_params| vector_params.ivf_config.as_ref())
+    }
+
+    /// Vamana config that should be used for a given vector, if Vamana index is configured for it
+    fn get_required_vamana_config(&self, vector_name: &VectorName) -> Option<&VamanaConfig> {
+        self.collection_params
+            .vectors
+            .get_params(vector_name)
+            .and_then(|vector_params| vector_params.vamana_config.as_ref())
+    }
+
     fn worst_segment(
         &self,
         segments: LockedSegmentHolder,
@@ -131,16 +159,40 @@ impl ConfigMismatchOptimizer {
                         .vector_data
                         .iter()
                         .any(|(vector_name, vector_data)| {
-                            // Check HNSW mismatch
+                            // Check HNSW, IVF and Vamana mismatch
+                            let target_ivf = self.get_required_ivf_config(vector_name);
+                            let target_vamana = self.get_required_vamana_config(vector_name);
                             match &vector_data.index {
                                 Indexes::Plain {} => {}
                                 Indexes::Hnsw(effective_hnsw) => {
+                                    // Select segment if IVF or Vamana index is required instead
+                                    if target_ivf.is_some() || target_vamana.is_some() {
+                                        return true;
+                                    }
                                     // Select segment if we have an HNSW mismatch that requires rebuild
                                     let target_hnsw = self.get_required_hnsw_config(vector_name);
                                     if effective_hnsw.mismatch_requires_rebuild(&target_hnsw) {
                                         return true;
                                     }
                                 }
+                                Indexes::Ivf(effective_ivf) => {
+                                    // Select segment if HNSW index is required instead,
+                                    // or if we have an IVF mismatch that requires rebuild
+                                    if target_ivf.map_or(true, |target_ivf| {
+                                        effective_ivf.mismatch_requires_rebuild(target_ivf)
+                                    }) {
+                                        return true;
+                                    }
+                                }
+                                Indexes::Vamana(effective_vamana) => {
+                                    // Select segment if another index is required instead,
+                                    // or if we have a Vamana mismatch that requires rebuild
+                                    if target_vamana.map_or(true, |target_vamana| {
+                                        effective_vamana.mismatch_requires_rebuild(target_vamana)
+                                    }) {
+                                        return true;
+                                    }
+                                }
                             }
 
                             if let Some(is_required_on_disk) =
@@ -185,6 +237,15 @@ impl ConfigMismatchOptimizer {
                         .sparse_vector_data
                         .iter()
                         .any(|(vector_name, vector_data)| {
+                            // Pruning is only applied when building immutable indexes
+                            let pruning_mismatch = vector_data.index.index_type
+                                != SparseIndexType::MutableRam
+                                && vector_data.index.pruning
+                                    != self.get_required_sparse_pruning(vector_name);
+                            if pruning_mismatch {
+                                return true;
+                            }
+
                             let Some(is_required_on_disk) =
                                 self.check_if_sparse_vectors_index_on_disk(vector_name)
                             else {
@@ -702,6 +763,7 @@ mod tests {
             product: ProductQuantizationConfig {
                 compression: CompressionRatio::X32,
                 always_ram: Some(true),
+                opq: None,
             },
         });
         match config_mismatch_optimizer.collection_params.vectors {
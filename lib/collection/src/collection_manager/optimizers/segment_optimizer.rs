@@ -210,26 +210,31 @@ pub trait SegmentOptimizer {
         let mut vector_data = collection_params.to_base_vector_data()?;
         let mut sparse_vector_data = collection_params.to_sparse_vector_data()?;
 
-        // If indexing, change to HNSW index and quantization
+        // If indexing, change to HNSW, IVF or Vamana index and quantization
         if threshold_is_indexed {
             let collection_hnsw = self.hnsw_config();
             let collection_quantization = self.quantization_config();
             vector_data.iter_mut().for_each(|(vector_name, config)| {
-                // Assign HNSW index
-                let param_hnsw = collection_params
-                    .vectors
-                    .get_params(vector_name)
-                    .and_then(|params| params.hnsw_config);
-                let vector_hnsw = param_hnsw
-                    .and_then(|c| c.update(collection_hnsw).ok())
-                    .unwrap_or_else(|| collection_hnsw.clone());
-                config.index = Indexes::Hnsw(vector_hnsw);
+                let vector_params = collection_params.vectors.get_params(vector_name);
+
+                // Assign IVF or Vamana index, if configured for the vector, or HNSW index
+                let vector_ivf = vector_params.and_then(|params| params.ivf_config.clone());
+                let vector_vamana = vector_params.and_then(|params| params.vamana_config.clone());
+                config.index = match (vector_ivf, vector_vamana) {
+                    (Some(vector_ivf), _) => Indexes::Ivf(vector_ivf),
+                    (None, Some(vector_vamana)) => Indexes::Vamana(vector_vamana),
+                    (None, None) => {
+                        let param_hnsw = vector_params.and_then(|params| params.hnsw_config);
+                        let vector_hnsw = param_hnsw
+                            .and_then(|c| c.update(collection_hnsw).ok())
+                            .unwrap_or_else(|| collection_hnsw.clone());
+                        Indexes::Hnsw(vector_hnsw)
+                    }
+                };
 
                 // Assign quantization config
-                let param_quantization = collection_params
-                    .vectors
-                    .get_params(vector_name)
-                    .and_then(|params| params.quantization_config.as_ref());
+                let param_quantization =
+                    vector_params.and_then(|params| params.quantization_config.as_ref());
                 let vector_quantization = param_quantization
                     .or(collection_quantization.as_ref())
                     .cloned();
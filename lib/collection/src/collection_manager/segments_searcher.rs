@@ -298,7 +298,14 @@ impl SegmentsSearcher {
             batch_request
                 .searches
                 .iter()
-                .map(|request| request.limit + request.offset)
+                .map(|request| {
+                    // Pages of the range search are cut by the shard, so that ties are kept
+                    if request.params.is_some_and(|params| params.range.is_some()) {
+                        usize::MAX
+                    } else {
+                        request.limit + request.offset
+                    }
+                })
                 .collect(),
             &further_results,
         );
@@ -532,6 +539,8 @@ struct BatchSearchParams<'a> {
     pub with_vector: WithVector,
     pub top: usize,
     pub params: Option<&'a SearchParams>,
+    /// `score_threshold` of the range search, which bounds the pages found in the segments
+    pub range_score_threshold: Option<ScoreType>,
 }
 
 /// Returns suggested search sampling size for a given number of points and required limit.
@@ -604,6 +613,11 @@ fn search_in_segment(
             with_vector: search_query.with_vector.clone().unwrap_or_default(),
             top: search_query.limit + search_query.offset,
             params: search_query.params.as_ref(),
+            range_score_threshold: search_query
+                .params
+                .is_some_and(|params| params.range.is_some())
+                .then_some(search_query.score_threshold)
+                .flatten(),
         };
 
         let query = search_query.query.clone().into();
@@ -661,7 +675,12 @@ fn execute_batch_search(
     let segment_points = read_segment.available_point_count();
     let segment_config = read_segment.config();
 
-    let top = if use_sampling {
+    // Sampling would cut the ties of the range search pages
+    let is_range_search = search_params
+        .params
+        .is_some_and(|params| params.range.is_some());
+
+    let top = if use_sampling && !is_range_search {
         let ef_limit = search_params
             .params
             .and_then(|p| p.hnsw_ef)
@@ -685,7 +704,9 @@ fn execute_batch_search(
         search_params.filter,
         top,
         search_params.params,
-        segment_query_context,
+        &segment_query_context
+            .fork()
+            .with_range_score_threshold(search_params.range_score_threshold),
     )?;
 
     let further_results = res
@@ -704,7 +725,7 @@ fn get_hnsw_ef_construct(config: &SegmentConfig, vector_name: &VectorName) -> Op
         .vector_data
         .get(vector_name)
         .and_then(|config| match &config.index {
-            Indexes::Plain {} => None,
+            Indexes::Plain {} | Indexes::Ivf(_) | Indexes::Vamana(_) => None,
             Indexes::Hnsw(hnsw) => Some(hnsw),
         })
         .map(|hnsw| hnsw.ef_construct)
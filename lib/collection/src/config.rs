@@ -12,9 +12,9 @@ use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
     default_replication_factor_const, default_shard_number_const,
-    default_write_consistency_factor_const, Distance, HnswConfig, Indexes, PayloadStorageType,
-    QuantizationConfig, SparseVectorDataConfig, StrictModeConfig, VectorDataConfig, VectorName,
-    VectorNameBuf, VectorStorageDatatype, VectorStorageType,
+    default_write_consistency_factor_const, Distance, HnswConfig, Indexes, MultiVectorComparator,
+    PayloadStorageType, QuantizationConfig, SparseVectorDataConfig, StrictModeConfig,
+    VectorDataConfig, VectorName, VectorNameBuf, VectorStorageDatatype, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -23,8 +23,8 @@ use wal::WalOptions;
 
 use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
 use crate::operations::types::{
-    CollectionError, CollectionResult, SparseVectorParams, SparseVectorsConfig, VectorParams,
-    VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
+    CollectionError, CollectionResult, Datatype, SparseVectorParams, SparseVectorsConfig,
+    VectorParams, VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
 };
 use crate::operations::validation;
 use crate::optimizers_builder::OptimizersConfig;
@@ -321,8 +321,8 @@ impl CollectionParams {
             Some(params) => Ok(params.distance),
             None => {
                 if let Some(sparse_vectors) = &self.sparse_vectors {
-                    if let Some(_params) = sparse_vectors.get(vector_name) {
-                        return Ok(Distance::Dot);
+                    if let Some(params) = sparse_vectors.get(vector_name) {
+                        return Ok(Distance::from(params.distance.unwrap_or_default()));
                     }
                 }
                 Err(self.missing_vector_error(vector_name))
@@ -373,6 +373,52 @@ impl CollectionParams {
             })
     }
 
+    /// Check vectors config against the quantization of the collection.
+    ///
+    /// Vamana graph is navigated with quantized vectors, kept in RAM.
+    /// Without quantization every hop would read full-precision vectors from disk.
+    ///
+    /// Quantized multivectors keep no pooled vectors of points, so mean pooling over them
+    /// averages similarities to each vector of a point, which is only exact for `Dot` distance.
+    ///
+    /// Bit vectors are already as compact as binary quantization, and can't be quantized.
+    pub fn check_quantization(
+        &self,
+        quantization_config: Option<&QuantizationConfig>,
+    ) -> CollectionResult<()> {
+        for (vector_name, params) in self.vectors.params_iter() {
+            let is_quantized =
+                params.quantization_config.is_some() || quantization_config.is_some();
+            if params.vamana_config.is_some() && !is_quantized {
+                return Err(CollectionError::BadInput {
+                    description: format!(
+                        "Vamana index of vector `{vector_name}` requires quantization to be configured"
+                    ),
+                });
+            }
+
+            let is_mean_pool = params
+                .multivector_config
+                .is_some_and(|config| config.comparator == MultiVectorComparator::MeanPool);
+            if is_mean_pool && is_quantized && params.distance != Distance::Dot {
+                return Err(CollectionError::BadInput {
+                    description: format!(
+                        "Quantization of vector `{vector_name}` with mean_pool comparator requires Dot distance"
+                    ),
+                });
+            }
+
+            if params.datatype == Some(Datatype::Bit) && is_quantized {
+                return Err(CollectionError::BadInput {
+                    description: format!(
+                        "Quantization is not supported for vector `{vector_name}` of bit datatype"
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
     /// Update collection vectors from the given update vectors config
     pub fn update_vectors_from_diff(
         &mut self,
@@ -423,7 +469,21 @@ impl CollectionParams {
     ) -> CollectionResult<()> {
         for (vector_name, update_params) in update_vectors.0.iter() {
             let sparse_vector_params = self.get_sparse_vector_params_mut(vector_name)?;
-            let SparseVectorParams { index, modifier } = update_params.clone();
+            let SparseVectorParams {
+                index,
+                modifier,
+                distance,
+            } = update_params.clone();
+
+            if let Some(distance) = distance {
+                if distance != sparse_vector_params.distance.unwrap_or_default() {
+                    return Err(CollectionError::BadInput {
+                        description: format!(
+                            "Distance of sparse vector `{vector_name}` can not be changed"
+                        ),
+                    });
+                }
+            }
 
             if let Some(modifier) = modifier {
                 sparse_vector_params.modifier = Some(modifier);
@@ -497,8 +557,10 @@ impl CollectionParams {
                                     .index
                                     .and_then(|index| index.datatype)
                                     .map(VectorStorageDatatype::from),
+                                pruning: params.index.and_then(|index| index.pruning),
                             },
                             storage_type: params.storage_type(),
+                            distance: params.distance.unwrap_or_default(),
                         },
                     ))
                 })
@@ -20,8 +20,9 @@ use segment::common::operation_error::OperationError;
 use segment::data_types::vectors::{
     BatchVectorStructInternal, NamedQuery, VectorInternal, VectorStructInternal,
 };
+use segment::index::sparse_index::sparse_index_config::SparsePruningConfig;
 use segment::types::{
-    Distance, HnswConfig, MultiVectorConfig, QuantizationConfig, StrictModeConfig,
+    Distance, HnswConfig, MultiVectorConfig, QuantizationConfig, SparseDistance, StrictModeConfig,
 };
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
 use sparse::common::sparse_vector::{validate_sparse_vector_impl, SparseVector};
@@ -597,6 +598,8 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             })?,
             distance: from_grpc_dist(vector_params.distance)?,
             hnsw_config: vector_params.hnsw_config.map(Into::into),
+            ivf_config: vector_params.ivf_config.map(Into::into),
+            vamana_config: vector_params.vamana_config.map(Into::into),
             quantization_config: vector_params
                 .quantization_config
                 .map(grpc_to_segment_quantization_config)
@@ -619,6 +622,8 @@ fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datatype>
                 api::grpc::qdrant::Datatype::Uint8 => Ok(Some(Datatype::Uint8)),
                 api::grpc::qdrant::Datatype::Float32 => Ok(Some(Datatype::Float32)),
                 api::grpc::qdrant::Datatype::Float16 => Ok(Some(Datatype::Float16)),
+                api::grpc::qdrant::Datatype::Bit => Ok(Some(Datatype::Bit)),
+                api::grpc::qdrant::Datatype::Sint8 => Ok(Some(Datatype::Int8)),
                 api::grpc::qdrant::Datatype::Default => Ok(None),
             }
         } else {
@@ -669,6 +674,7 @@ impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
                         full_scan_threshold: index_config.full_scan_threshold.map(|v| v as usize),
                         on_disk: index_config.on_disk,
                         datatype: convert_datatype_from_proto(index_config.datatype)?,
+                        pruning: index_config.pruning.map(SparsePruningConfig::from),
                     })
                 })
                 .transpose()?,
@@ -678,10 +684,60 @@ impl TryFrom<api::grpc::qdrant::SparseVectorParams> for SparseVectorParams {
                     // XXX: Invalid values silently converted to None
                     api::grpc::qdrant::Modifier::try_from(x).ok())
                 .map(Modifier::from),
+            distance: sparse_vector_params
+                .distance
+                .map(sparse_distance_from_grpc)
+                .transpose()?,
         })
     }
 }
 
+fn sparse_distance_from_grpc(distance: i32) -> Result<SparseDistance, Status> {
+    match from_grpc_dist(distance)? {
+        Distance::Dot => Ok(SparseDistance::Dot),
+        Distance::Cosine => Ok(SparseDistance::Cosine),
+        distance @ (Distance::Euclid
+        | Distance::Manhattan
+        | Distance::Hamming
+        | Distance::Jaccard) => Err(Status::invalid_argument(format!(
+            "Distance {distance:?} is not supported for sparse vectors"
+        ))),
+    }
+}
+
+fn sparse_distance_to_grpc(distance: SparseDistance) -> api::grpc::qdrant::Distance {
+    match distance {
+        SparseDistance::Dot => api::grpc::qdrant::Distance::Dot,
+        SparseDistance::Cosine => api::grpc::qdrant::Distance::Cosine,
+    }
+}
+
+impl From<api::grpc::qdrant::SparsePruningConfig> for SparsePruningConfig {
+    fn from(value: api::grpc::qdrant::SparsePruningConfig) -> Self {
+        let api::grpc::qdrant::SparsePruningConfig {
+            max_postings,
+            min_weight_ratio,
+        } = value;
+        Self {
+            max_postings: max_postings.map(|v| v as usize),
+            min_weight_ratio,
+        }
+    }
+}
+
+impl From<SparsePruningConfig> for api::grpc::qdrant::SparsePruningConfig {
+    fn from(value: SparsePruningConfig) -> Self {
+        let SparsePruningConfig {
+            max_postings,
+            min_weight_ratio,
+        } = value;
+        Self {
+            max_postings: max_postings.map(|v| v as u64),
+            min_weight_ratio,
+        }
+    }
+}
+
 impl From<Modifier> for api::grpc::qdrant::Modifier {
     fn from(value: Modifier) -> Self {
         match value {
@@ -701,11 +757,17 @@ impl From<SparseVectorParams> for api::grpc::qdrant::SparseVectorParams {
                     datatype: index_config
                         .datatype
                         .map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
+                    pruning: index_config
+                        .pruning
+                        .map(api::grpc::qdrant::SparsePruningConfig::from),
                 }
             }),
             modifier: sparse_vector_params
                 .modifier
                 .map(|modifier| api::grpc::qdrant::Modifier::from(modifier) as i32),
+            distance: sparse_vector_params
+                .distance
+                .map(|distance| sparse_distance_to_grpc(distance) as i32),
         }
     }
 }
@@ -1276,9 +1338,9 @@ impl TryFrom<api::grpc::qdrant::Vector> for RecommendExample {
         match vector {
             VectorInternal::Dense(vector) => Ok(Self::Dense(vector)),
             VectorInternal::Sparse(vector) => Ok(Self::Sparse(vector)),
-            VectorInternal::MultiDense(_vector) => Err(Status::invalid_argument(
-                "MultiDense vector is not supported in search request",
-            )),
+            VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => Err(
+                Status::invalid_argument("MultiDense vector is not supported in search request"),
+            ),
         }
     }
 }
@@ -1456,6 +1518,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
             }
             .into(),
             hnsw_config: value.hnsw_config.map(Into::into),
@@ -1467,6 +1531,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             multivector_config: value
                 .multivector_config
                 .map(api::grpc::qdrant::MultiVectorConfig::from),
+            ivf_config: value.ivf_config.map(Into::into),
+            vamana_config: value.vamana_config.map(Into::into),
         }
     }
 }
@@ -1477,6 +1543,8 @@ impl From<Datatype> for api::grpc::qdrant::Datatype {
             Datatype::Float32 => api::grpc::qdrant::Datatype::Float32,
             Datatype::Uint8 => api::grpc::qdrant::Datatype::Uint8,
             Datatype::Float16 => api::grpc::qdrant::Datatype::Float16,
+            Datatype::Bit => api::grpc::qdrant::Datatype::Bit,
+            Datatype::Int8 => api::grpc::qdrant::Datatype::Sint8,
         }
     }
 }
@@ -13,7 +13,7 @@ use segment::common::utils::transpose_map_into_named_vector;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::data_types::vectors::{
     BatchVectorStructInternal, MultiDenseVectorInternal, VectorInternal, VectorStructInternal,
-    DEFAULT_VECTOR_NAME,
+    WeightedMultiDenseVector, DEFAULT_VECTOR_NAME,
 };
 use segment::types::{Filter, Payload, PointIdType, VectorNameBuf};
 use serde::{Deserialize, Serialize};
@@ -110,7 +110,8 @@ impl From<VectorInternal> for VectorPersisted {
         match value {
             VectorInternal::Dense(vector) => VectorPersisted::Dense(vector),
             VectorInternal::Sparse(vector) => VectorPersisted::Sparse(vector),
-            VectorInternal::MultiDense(vector) => {
+            VectorInternal::MultiDense(vector)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, .. }) => {
                 VectorPersisted::MultiDense(vector.into_multi_vectors())
             }
         }
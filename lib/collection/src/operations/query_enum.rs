@@ -42,7 +42,9 @@ impl QueryEnum {
                 for vector in reco_query.query.flat_iter() {
                     match vector {
                         VectorInternal::Sparse(sparse_vector) => f(name, sparse_vector),
-                        VectorInternal::Dense(_) | VectorInternal::MultiDense(_) => {}
+                        VectorInternal::Dense(_)
+                        | VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_) => {}
                     }
                 }
             }
@@ -51,7 +53,9 @@ impl QueryEnum {
                 for pair in discovery_query.query.flat_iter() {
                     match pair {
                         VectorInternal::Sparse(sparse_vector) => f(name, sparse_vector),
-                        VectorInternal::Dense(_) | VectorInternal::MultiDense(_) => {}
+                        VectorInternal::Dense(_)
+                        | VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_) => {}
                     }
                 }
             }
@@ -60,7 +64,9 @@ impl QueryEnum {
                 for pair in context_query.query.flat_iter() {
                     match pair {
                         VectorInternal::Sparse(sparse_vector) => f(name, sparse_vector),
-                        VectorInternal::Dense(_) | VectorInternal::MultiDense(_) => {}
+                        VectorInternal::Dense(_)
+                        | VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_) => {}
                     }
                 }
             }
@@ -1,4 +1,5 @@
 use std::backtrace::Backtrace;
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use std::error::Error as _;
 use std::fmt::{Debug, Write as _};
@@ -22,16 +23,18 @@ use merge::Merge;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
 use segment::common::operation_error::OperationError;
+use segment::data_types::bm25::Bm25;
 use segment::data_types::groups::GroupId;
 use segment::data_types::order_by::{OrderBy, OrderValue};
 use segment::data_types::vectors::{
     DenseVector, QueryVector, VectorRef, VectorStructInternal, DEFAULT_VECTOR_NAME,
 };
+use segment::index::sparse_index::sparse_index_config::SparsePruningConfig;
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType,
-    PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
-    SparseVectorStorageType, StrictModeConfig, VectorName, VectorNameBuf, VectorStorageDatatype,
-    WithPayloadInterface, WithVector,
+    Distance, Filter, HnswConfig, IvfConfig, MultiVectorConfig, Payload, PayloadIndexInfo,
+    PayloadKeyType, PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
+    SparseDistance, SparseVectorStorageType, StrictModeConfig, VamanaConfig, VectorName,
+    VectorNameBuf, VectorStorageDatatype, WithPayloadInterface, WithVector,
 };
 use semver::Version;
 use serde;
@@ -477,6 +480,10 @@ pub enum ScrollOrder {
     ById,
     ByField(OrderBy),
     Random,
+    ByBm25 {
+        bm25: Bm25,
+        score_threshold: Option<ScoreType>,
+    },
 }
 
 /// Scroll request, used as a part of query request
@@ -1233,6 +1240,9 @@ impl From<OperationError> for CollectionError {
             },
             OperationError::MissingRangeIndexForOrderBy { .. } => Self::bad_input(format!("{err}")),
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(format!("{err}")),
+            OperationError::MissingFullTextIndexForBm25 { .. } => Self::bad_input(format!("{err}")),
+            OperationError::FormulaVariableError { .. } => Self::bad_input(format!("{err}")),
+            OperationError::NonFiniteNumber { .. } => Self::bad_input(format!("{err}")),
         }
     }
 }
@@ -1446,6 +1456,8 @@ pub enum Datatype {
     Float32,
     Uint8,
     Float16,
+    Bit,
+    Int8,
 }
 
 impl From<Datatype> for VectorStorageDatatype {
@@ -1454,6 +1466,8 @@ impl From<Datatype> for VectorStorageDatatype {
             Datatype::Float32 => VectorStorageDatatype::Float32,
             Datatype::Uint8 => VectorStorageDatatype::Uint8,
             Datatype::Float16 => VectorStorageDatatype::Float16,
+            Datatype::Bit => VectorStorageDatatype::Bit,
+            Datatype::Int8 => VectorStorageDatatype::Int8,
         }
     }
 }
@@ -1461,6 +1475,9 @@ impl From<Datatype> for VectorStorageDatatype {
 /// Params of single vector data storage
 #[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[validate(schema(function = "validate_bit_vector_params"))]
+#[validate(schema(function = "validate_ivf_vector_params"))]
+#[validate(schema(function = "validate_vamana_vector_params"))]
 pub struct VectorParams {
     /// Size of a vectors used
     #[validate(custom(function = "validate_nonzerou64_range_min_1_max_65536"))]
@@ -1471,6 +1488,17 @@ pub struct VectorParams {
     #[serde(default, skip_serializing_if = "is_hnsw_diff_empty")]
     #[validate(nested)]
     pub hnsw_config: Option<HnswConfigDiff>,
+    /// If set, IVF index is built for these vectors instead of HNSW.
+    /// IVF is cheaper to build and has predictable memory usage, suitable for large write-once collections.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub ivf_config: Option<IvfConfig>,
+    /// If set, Vamana graph index is built for these vectors instead of HNSW.
+    /// The graph and full-precision vectors are kept on disk, only quantized vectors are needed in RAM.
+    /// Requires quantization to be configured for these vectors or for the collection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub vamana_config: Option<VamanaConfig>,
     /// Custom params for quantization. If none - values from collection configuration are used.
     #[serde(
         default,
@@ -1494,6 +1522,11 @@ pub struct VectorParams {
     ///   2 bytes.
     /// - For `uint8` datatype - vectors are stored as unsigned 8-bit integers, 1 byte.
     ///   It expects vector elements to be in range `[0, 255]`.
+    /// - For `bit` datatype - vectors are stored as single bits, 8 dimensions per byte.
+    ///   Positive elements are stored as set bits. Requires `Hamming` or `Jaccard` distance,
+    ///   and the size to be a multiple of 8. Can't be quantized.
+    /// - For `int8` datatype - vectors are stored as signed 8-bit integers, 1 byte.
+    ///   It expects vector elements to be in range `[-128, 127]`, values outside are clamped.
     pub datatype: Option<Datatype>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1507,6 +1540,62 @@ pub fn validate_nonzerou64_range_min_1_max_65536(
     validate_range_generic(value.get(), Some(1), Some(65536))
 }
 
+/// Bit vectors can only be compared bitwise, and bitwise distances require bit vectors
+fn validate_bit_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    let is_bit_datatype = params.datatype == Some(Datatype::Bit);
+
+    let message = if is_bit_datatype != params.distance.is_bitwise() {
+        "Hamming and Jaccard distances can only be used with bit datatype, and vice versa"
+    } else if is_bit_datatype && params.size.get() % 8 != 0 {
+        "size of bit vectors must be a multiple of 8"
+    } else if is_bit_datatype && params.multivector_config.is_some() {
+        "multivectors are not supported for bit datatype"
+    } else if is_bit_datatype && params.quantization_config.is_some() {
+        "quantization is not supported for bit datatype"
+    } else {
+        return Ok(());
+    };
+
+    let mut error = ValidationError::new("bit_datatype");
+    error.add_param(Cow::from("message"), &message);
+    Err(error)
+}
+
+/// IVF clusters are trained on single dense vectors
+fn validate_ivf_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if params.ivf_config.is_none() || params.multivector_config.is_none() {
+        return Ok(());
+    }
+
+    let mut error = ValidationError::new("ivf_multivector");
+    error.add_param(
+        Cow::from("message"),
+        &"IVF index is not supported for multivectors",
+    );
+    Err(error)
+}
+
+/// Vamana graph is built over single float vectors, and replaces any other vector index
+fn validate_vamana_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    if params.vamana_config.is_none() {
+        return Ok(());
+    }
+
+    let message = if params.multivector_config.is_some() {
+        "Vamana index is not supported for multivectors"
+    } else if params.datatype == Some(Datatype::Bit) {
+        "Vamana index is not supported for bit datatype"
+    } else if params.ivf_config.is_some() {
+        "IVF and Vamana indexes can't be configured for the same vectors"
+    } else {
+        return Ok(());
+    };
+
+    let mut error = ValidationError::new("vamana_vector_params");
+    error.add_param(Cow::from("message"), &message);
+    Err(error)
+}
+
 /// Is considered empty if `None` or if diff has no field specified
 fn is_hnsw_diff_empty(hnsw_config: &Option<HnswConfigDiff>) -> bool {
     hnsw_config
@@ -1538,12 +1627,20 @@ pub enum Modifier {
 pub struct SparseVectorParams {
     /// Custom params for index. If none - values from collection configuration are used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
     pub index: Option<SparseIndexParams>,
 
     /// Configures addition value modifications for sparse vectors.
     /// Default: none
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modifier: Option<Modifier>,
+
+    /// Similarity function used to score this sparse vector.
+    /// Cosine puts scores on the same scale as dense vectors, e.g. for formula-based fusion.
+    /// Can not be changed after the collection is created.
+    /// Default: Dot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<SparseDistance>,
 }
 
 impl SparseVectorParams {
@@ -1557,12 +1654,15 @@ impl Anonymize for SparseVectorParams {
         Self {
             index: self.index.anonymize(),
             modifier: self.modifier.clone(),
+            distance: self.distance,
         }
     }
 }
 
 /// Configuration for sparse inverted index.
-#[derive(Debug, Hash, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(
+    Debug, Hash, Deserialize, Serialize, JsonSchema, Validate, Copy, Clone, PartialEq, Eq, Default,
+)]
 #[serde(rename_all = "snake_case")]
 pub struct SparseIndexParams {
     /// We prefer a full scan search upto (excluding) this number of vectors.
@@ -1585,6 +1685,12 @@ pub struct SparseIndexParams {
     ///   actual vector data does not need to conform to this range.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<Datatype>,
+    /// Static pruning of the posting lists, to make the index smaller.
+    /// Applied when building immutable indexes, mutable index keeps all postings.
+    /// Default: no pruning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub pruning: Option<SparsePruningConfig>,
 }
 
 impl Anonymize for SparseIndexParams {
@@ -1593,6 +1699,7 @@ impl Anonymize for SparseIndexParams {
             full_scan_threshold: self.full_scan_threshold,
             on_disk: self.on_disk,
             datatype: self.datatype,
+            pruning: self.pruning,
         }
     }
 }
@@ -1603,12 +1710,14 @@ impl SparseIndexParams {
             full_scan_threshold,
             on_disk,
             datatype,
+            pruning,
         } = other;
 
         self.full_scan_threshold
             .replace_if_some(full_scan_threshold);
         self.on_disk.replace_if_some(on_disk);
         self.datatype.replace_if_some(datatype);
+        self.pruning.replace_if_some(pruning);
     }
 }
 
@@ -1729,10 +1838,16 @@ pub fn check_sparse_compatible(
     self_config: &BTreeMap<VectorNameBuf, SparseVectorParams>,
     other_config: &BTreeMap<VectorNameBuf, SparseVectorParams>,
 ) -> CollectionResult<()> {
-    for (vector_name, _this) in self_config.iter() {
-        let Some(_other) = other_config.get(vector_name) else {
+    for (vector_name, this) in self_config.iter() {
+        let Some(other) = other_config.get(vector_name) else {
             return Err(missing_vector_error(vector_name));
         };
+
+        check_sparse_distance(
+            vector_name,
+            this.distance.unwrap_or_default(),
+            other.distance.unwrap_or_default(),
+        )?;
     }
 
     Ok(())
@@ -1750,15 +1865,38 @@ pub fn check_sparse_compatible_with_segment_config(
         ));
     }
 
-    for (vector_name, _) in self_config.iter() {
-        if other.get(vector_name).is_none() {
+    for (vector_name, this) in self_config.iter() {
+        let Some(other) = other.get(vector_name) else {
             return Err(missing_vector_error(vector_name));
         };
+
+        check_sparse_distance(
+            vector_name,
+            this.distance.unwrap_or_default(),
+            other.distance,
+        )?;
     }
 
     Ok(())
 }
 
+fn check_sparse_distance(
+    vector_name: &VectorName,
+    this: SparseDistance,
+    other: SparseDistance,
+) -> CollectionResult<()> {
+    if this != other {
+        return Err(CollectionError::BadInput {
+            description: format!(
+                "Vectors configuration is not compatible: \
+                 origin sparse vector {vector_name} uses {this:?} distance, \
+                 while other uses {other:?}"
+            ),
+        });
+    }
+    Ok(())
+}
+
 fn incompatible_vectors_error<'a, 'b>(
     this: impl Iterator<Item = &'a VectorName>,
     other: impl Iterator<Item = &'b VectorName>,
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use api::rest::LookupLocation;
 use common::types::ScoreType;
 use itertools::Itertools;
+use segment::data_types::bm25::Bm25;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{
     NamedQuery, NamedVectorStruct, VectorInternal, VectorRef, DEFAULT_VECTOR_NAME,
@@ -15,7 +16,7 @@ use segment::types::{
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
 
 use super::shard_query::{
-    FusionInternal, SampleInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest,
+    FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest,
 };
 use crate::common::fetch_vectors::ReferencedVectors;
 use crate::lookup::WithLookup;
@@ -93,6 +94,9 @@ pub enum Query {
 
     /// Sample points
     Sample(SampleInternal),
+
+    /// Score points by BM25 relevance of a full-text indexed payload field
+    Bm25(Bm25),
 }
 
 impl Query {
@@ -105,16 +109,16 @@ impl Query {
     ) -> CollectionResult<ScoringQuery> {
         let scoring_query = match self {
             Query::Vector(vector_query) => {
-                let query_enum = vector_query
+                vector_query
                     // Homogenize the input into raw vectors
                     .ids_into_vectors(ids_to_vectors, lookup_vector_name, lookup_collection)?
-                    // Turn into QueryEnum
-                    .into_query_enum(using)?;
-                ScoringQuery::Vector(query_enum)
+                    // Turn into ScoringQuery
+                    .into_scoring_query(using)?
             }
             Query::Fusion(fusion) => ScoringQuery::Fusion(fusion),
             Query::OrderBy(order_by) => ScoringQuery::OrderBy(order_by),
             Query::Sample(sample) => ScoringQuery::Sample(sample),
+            Query::Bm25(bm25) => ScoringQuery::Bm25(bm25),
         };
 
         Ok(scoring_query)
@@ -138,17 +142,38 @@ impl VectorInputInternal {
 #[derive(Clone, Debug, PartialEq)]
 pub enum VectorQuery<T> {
     Nearest(T),
+    NearestWithMmr(NearestWithMmr<T>),
     RecommendAverageVector(RecoQuery<T>),
     RecommendBestScore(RecoQuery<T>),
     Discover(DiscoveryQuery<T>),
     Context(ContextQuery<T>),
 }
 
+/// Nearest points, diversified with Maximal Marginal Relevance
+#[derive(Clone, Debug, PartialEq)]
+pub struct NearestWithMmr<T> {
+    pub nearest: T,
+    pub mmr: Mmr,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mmr {
+    /// Trade-off between relevance (1.0) and diversity (0.0)
+    pub lambda: f32,
+    /// Number of nearest points to select from, when there are no prefetches
+    pub candidates_limit: usize,
+}
+
+impl Mmr {
+    pub const DEFAULT_CANDIDATES_LIMIT: usize = 100;
+}
+
 impl<T> VectorQuery<T> {
     /// Iterate through all items, without any kind of structure
     pub fn flat_iter(&self) -> Box<dyn Iterator<Item = &T> + '_> {
         match self {
             VectorQuery::Nearest(input) => Box::new(std::iter::once(input)),
+            VectorQuery::NearestWithMmr(query) => Box::new(std::iter::once(&query.nearest)),
             VectorQuery::RecommendAverageVector(query) => Box::new(query.flat_iter()),
             VectorQuery::RecommendBestScore(query) => Box::new(query.flat_iter()),
             VectorQuery::Discover(query) => Box::new(query.flat_iter()),
@@ -175,6 +200,13 @@ impl VectorQuery<VectorInputInternal> {
 
                 Ok(VectorQuery::Nearest(vector))
             }
+            VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }) => {
+                let nearest = ids_to_vectors
+                    .resolve_reference(lookup_collection, lookup_vector_name, nearest)
+                    .ok_or_else(|| vector_not_found_error(lookup_vector_name))?;
+
+                Ok(VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }))
+            }
             VectorQuery::RecommendAverageVector(reco) => {
                 let (positives, negatives) = Self::resolve_reco_reference(
                     reco,
@@ -293,11 +325,24 @@ fn vector_not_found_error(vector_name: &VectorName) -> CollectionError {
 }
 
 impl VectorQuery<VectorInternal> {
-    fn into_query_enum(self, using: VectorNameBuf) -> CollectionResult<QueryEnum> {
+    fn into_scoring_query(self, using: VectorNameBuf) -> CollectionResult<ScoringQuery> {
         let query_enum = match self {
             VectorQuery::Nearest(vector) => {
                 QueryEnum::Nearest(NamedVectorStruct::new_from_vector(vector, using))
             }
+            VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }) => {
+                let Mmr {
+                    lambda,
+                    candidates_limit,
+                } = mmr;
+
+                return Ok(ScoringQuery::Mmr(MmrInternal {
+                    vector: nearest,
+                    using,
+                    lambda,
+                    candidates_limit,
+                }));
+            }
             VectorQuery::RecommendAverageVector(reco) => {
                 // Get average vector
                 let search_vector = avg_vector_for_recommendation(
@@ -320,7 +365,7 @@ impl VectorQuery<VectorInternal> {
             }),
         };
 
-        Ok(query_enum)
+        Ok(ScoringQuery::Vector(query_enum))
     }
 }
 
@@ -395,6 +440,7 @@ impl CollectionPrefetch {
             &self.using,
             &self.prefetch,
             self.score_threshold,
+            self.params.as_ref(),
         )?;
 
         let lookup_vector_name = self.get_lookup_vector_name();
@@ -499,6 +545,7 @@ impl CollectionQueryRequest {
             &self.using,
             &self.prefetch,
             self.score_threshold,
+            self.params.as_ref(),
         )?;
 
         let mut offset = self.offset;
@@ -555,7 +602,15 @@ impl CollectionQueryRequest {
         using: &VectorNameBuf,
         prefetch: &[CollectionPrefetch],
         score_threshold: Option<ScoreType>,
+        params: Option<&SearchParams>,
     ) -> CollectionResult<()> {
+        // Pages of the range search are only supported by the plain search
+        if params.is_some_and(|params| params.range.is_some()) {
+            return Err(CollectionError::bad_request(
+                "Range search is only supported by the search API",
+            ));
+        }
+
         // Check no prefetches without a query
         if !prefetch.is_empty() && query.is_none() {
             return Err(CollectionError::bad_request(
@@ -581,12 +636,22 @@ impl CollectionQueryRequest {
         }
 
         // Check that fusion queries are not combined with a using vector name
-        if let Some(Query::Fusion(_)) = query {
+        if let Some(Query::Fusion(fusion)) = query {
             if using != DEFAULT_VECTOR_NAME {
                 return Err(CollectionError::bad_request(
                     "Fusion queries cannot be combined with the 'using' field.",
                 ));
             }
+
+            // Check that there is a weight for each prefetch, if weights are given
+            let weights = fusion.weights();
+            if !weights.is_empty() && weights.len() != prefetch.len() {
+                return Err(CollectionError::bad_request(format!(
+                    "Number of fusion weights ({}) must match the number of prefetches ({})",
+                    weights.len(),
+                    prefetch.len(),
+                )));
+            }
         }
 
         Ok(())
@@ -595,14 +660,60 @@ impl CollectionQueryRequest {
 
 mod from_rest {
     use api::rest::schema as rest;
+    use segment::common::maximal_marginal_relevance::DEFAULT_MMR_LAMBDA;
+    use segment::common::reciprocal_rank_fusion::DEFAULT_RRF_K;
 
     use super::*;
+    use crate::operations::universal_query::shard_query::LinearNormalization;
 
     impl From<rest::Fusion> for FusionInternal {
         fn from(value: rest::Fusion) -> Self {
             match value {
-                rest::Fusion::Rrf => FusionInternal::Rrf,
-                rest::Fusion::Dbsf => FusionInternal::Dbsf,
+                rest::Fusion::Rrf => FusionInternal::default_rrf(),
+                rest::Fusion::Dbsf => FusionInternal::Dbsf {
+                    weights: Vec::new(),
+                },
+            }
+        }
+    }
+
+    impl From<rest::FusionParams> for FusionInternal {
+        fn from(value: rest::FusionParams) -> Self {
+            match value {
+                rest::FusionParams::Rrf(rest::Rrf { k, weights }) => FusionInternal::Rrf {
+                    k: k.unwrap_or(DEFAULT_RRF_K),
+                    weights: weights.unwrap_or_default(),
+                },
+                rest::FusionParams::Dbsf(rest::Dbsf { weights }) => FusionInternal::Dbsf {
+                    weights: weights.unwrap_or_default(),
+                },
+                rest::FusionParams::Linear(rest::LinearFusion {
+                    normalization,
+                    weights,
+                }) => FusionInternal::Linear {
+                    normalization: normalization
+                        .map(LinearNormalization::from)
+                        .unwrap_or(LinearNormalization::MinMax),
+                    weights: weights.unwrap_or_default(),
+                },
+            }
+        }
+    }
+
+    impl From<rest::FusionInterface> for FusionInternal {
+        fn from(value: rest::FusionInterface) -> Self {
+            match value {
+                rest::FusionInterface::Fusion(fusion) => FusionInternal::from(fusion),
+                rest::FusionInterface::Params(params) => FusionInternal::from(params),
+            }
+        }
+    }
+
+    impl From<rest::ScoreNormalization> for LinearNormalization {
+        fn from(value: rest::ScoreNormalization) -> Self {
+            match value {
+                rest::ScoreNormalization::MinMax => LinearNormalization::MinMax,
+                rest::ScoreNormalization::ZScore => LinearNormalization::ZScore,
             }
         }
     }
@@ -614,4 +725,59 @@ mod from_rest {
             }
         }
     }
+
+    impl From<rest::Mmr> for Mmr {
+        fn from(value: rest::Mmr) -> Self {
+            let rest::Mmr {
+                lambda,
+                candidates_limit,
+            } = value;
+
+            Mmr {
+                lambda: lambda.unwrap_or(DEFAULT_MMR_LAMBDA),
+                candidates_limit: candidates_limit.unwrap_or(Mmr::DEFAULT_CANDIDATES_LIMIT),
+            }
+        }
+    }
+}
+
+mod from_grpc {
+    use api::grpc::qdrant as grpc;
+    use segment::common::maximal_marginal_relevance::DEFAULT_MMR_LAMBDA;
+    use tonic::Status;
+
+    use super::*;
+
+    impl TryFrom<grpc::Mmr> for Mmr {
+        type Error = Status;
+
+        fn try_from(value: grpc::Mmr) -> Result<Self, Self::Error> {
+            let grpc::Mmr {
+                lambda,
+                candidates_limit,
+            } = value;
+
+            let lambda = lambda.unwrap_or(DEFAULT_MMR_LAMBDA);
+            if !(0.0..=1.0).contains(&lambda) {
+                return Err(Status::invalid_argument(
+                    "MMR lambda must be between 0 and 1",
+                ));
+            }
+
+            let candidates_limit = match candidates_limit {
+                Some(0) => {
+                    return Err(Status::invalid_argument(
+                        "MMR candidates_limit must be at least 1",
+                    ))
+                }
+                Some(limit) => limit as usize,
+                None => Mmr::DEFAULT_CANDIDATES_LIMIT,
+            };
+
+            Ok(Mmr {
+                lambda,
+                candidates_limit,
+            })
+        }
+    }
 }
@@ -1,9 +1,11 @@
 //! Types used within `LocalShard` to represent a planned `ShardQueryRequest`
 
 use common::types::ScoreType;
+use segment::data_types::vectors::NamedVectorStruct;
 use segment::types::{Filter, SearchParams, WithPayloadInterface, WithVector};
 
 use super::shard_query::{SampleInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest};
+use crate::operations::query_enum::QueryEnum;
 use crate::operations::types::{
     CollectionError, CollectionResult, CoreSearchRequest, QueryScrollRequestInternal, ScrollOrder,
 };
@@ -107,6 +109,12 @@ impl PlannedQuery {
             })?;
 
             if rescore.needs_intermediate_results() {
+                // MMR compares the candidates at collection level, so they need the vector
+                let with_vector = match &rescore {
+                    ScoringQuery::Mmr(mmr) => mmr.candidates_with_vector(&with_vector),
+                    _ => with_vector,
+                };
+
                 // pass `with_vector` and `with_payload` down one level, as the sources will be sent as intermediate results to the collection
                 let sources = recurse_prefetches(
                     &mut self.searches,
@@ -200,6 +208,45 @@ impl PlannedQuery {
 
                     vec![Source::ScrollsIdx(idx)]
                 }
+                Some(ScoringQuery::Bm25(bm25)) => {
+                    // Everything should come from 1 scroll
+                    let scroll = QueryScrollRequestInternal {
+                        scroll_order: ScrollOrder::ByBm25 {
+                            bm25,
+                            score_threshold,
+                        },
+                        limit,
+                        filter,
+                        with_vector,
+                        with_payload,
+                    };
+
+                    let idx = self.scrolls.len();
+                    self.scrolls.push(scroll);
+
+                    vec![Source::ScrollsIdx(idx)]
+                }
+                Some(ScoringQuery::Mmr(mmr)) => {
+                    // Candidates come from 1 core search, they are diversified at collection level
+                    let core_search = CoreSearchRequest {
+                        query: QueryEnum::Nearest(NamedVectorStruct::new_from_vector(
+                            mmr.vector.clone(),
+                            mmr.using.clone(),
+                        )),
+                        filter,
+                        score_threshold,
+                        with_vector: Some(mmr.candidates_with_vector(&with_vector)),
+                        with_payload: Some(with_payload),
+                        offset: 0,
+                        params,
+                        limit: mmr.candidates_limit(limit),
+                    };
+
+                    let idx = self.searches.len();
+                    self.searches.push(core_search);
+
+                    vec![Source::SearchesIdx(idx)]
+                }
                 None => {
                     // Everything should come from 1 scroll
                     let scroll = QueryScrollRequestInternal {
@@ -343,6 +390,54 @@ fn recurse_prefetches(
 
                     Source::ScrollsIdx(idx)
                 }
+                Some(ScoringQuery::Bm25(bm25)) => {
+                    let scroll = QueryScrollRequestInternal {
+                        scroll_order: ScrollOrder::ByBm25 {
+                            bm25,
+                            score_threshold,
+                        },
+                        filter,
+                        with_vector: with_vector.clone(),
+                        with_payload: with_payload.clone(),
+                        limit,
+                    };
+
+                    let idx = scrolls.len();
+                    scrolls.push(scroll);
+
+                    Source::ScrollsIdx(idx)
+                }
+                Some(ScoringQuery::Mmr(mmr)) => {
+                    // Fetch the nearest candidates, and diversify them with a rescore
+                    let core_search = CoreSearchRequest {
+                        query: QueryEnum::Nearest(NamedVectorStruct::new_from_vector(
+                            mmr.vector.clone(),
+                            mmr.using.clone(),
+                        )),
+                        filter,
+                        params: params.clone(),
+                        limit: mmr.candidates_limit(limit),
+                        offset: 0,
+                        with_vector: Some(WithVector::Bool(false)),
+                        with_payload: Some(WithPayloadInterface::Bool(false)),
+                        score_threshold,
+                    };
+
+                    let idx = core_searches.len();
+                    core_searches.push(core_search);
+
+                    Source::Prefetch(Box::new(MergePlan {
+                        sources: vec![Source::SearchesIdx(idx)],
+                        rescore_params: Some(RescoreParams {
+                            rescore: ScoringQuery::Mmr(mmr),
+                            limit,
+                            score_threshold,
+                            with_vector: with_vector.clone(),
+                            with_payload: with_payload.clone(),
+                            params,
+                        }),
+                    }))
+                }
                 None => {
                     let scroll = QueryScrollRequestInternal {
                         scroll_order: Default::default(),
@@ -393,7 +488,7 @@ mod tests {
 
     use super::*;
     use crate::operations::query_enum::QueryEnum;
-    use crate::operations::universal_query::shard_query::FusionInternal;
+    use crate::operations::universal_query::shard_query::{FusionInternal, MmrInternal};
 
     #[test]
     fn test_try_from_double_rescore() {
@@ -613,7 +708,7 @@ mod tests {
                     score_threshold: None,
                 },
             ],
-            query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+            query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
             filter: Some(filter_outer.clone()),
             score_threshold: None,
             limit: 50,
@@ -670,7 +765,7 @@ mod tests {
     fn test_try_from_rrf_without_source() {
         let request = ShardQueryRequest {
             prefetches: vec![],
-            query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+            query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
             filter: Some(Filter::default()),
             score_threshold: None,
             limit: 50,
@@ -685,6 +780,97 @@ mod tests {
         assert!(planned_query.is_err())
     }
 
+    #[test]
+    fn test_try_from_mmr() {
+        let dummy_vector = vec![1.0, 2.0, 3.0];
+        let mmr = MmrInternal {
+            vector: VectorInternal::Dense(dummy_vector.clone()),
+            using: "dense".to_string(),
+            lambda: 0.5,
+            candidates_limit: 100,
+        };
+
+        // Root MMR diversifies the candidates at collection level
+        let root_request = ShardQueryRequest {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Mmr(mmr.clone())),
+            filter: None,
+            score_threshold: None,
+            limit: 10,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Bool(false),
+            with_payload: WithPayloadInterface::Bool(true),
+        };
+
+        // Nested MMR diversifies the candidates on the shard
+        let nested_request = ShardQueryRequest {
+            prefetches: vec![ShardPrefetch {
+                prefetches: vec![],
+                query: Some(ScoringQuery::Mmr(mmr.clone())),
+                limit: 20,
+                params: None,
+                filter: None,
+                score_threshold: None,
+            }],
+            query: Some(ScoringQuery::Vector(QueryEnum::Nearest(
+                NamedVectorStruct::new_from_vector(
+                    VectorInternal::Dense(dummy_vector.clone()),
+                    "dense",
+                ),
+            ))),
+            filter: None,
+            score_threshold: None,
+            limit: 10,
+            offset: 0,
+            params: None,
+            with_vector: WithVector::Bool(false),
+            with_payload: WithPayloadInterface::Bool(false),
+        };
+
+        let planned_query = PlannedQuery::try_from(vec![root_request, nested_request]).unwrap();
+
+        let candidates_search = |with_vector, with_payload| CoreSearchRequest {
+            query: QueryEnum::Nearest(NamedVectorStruct::new_from_vector(
+                VectorInternal::Dense(dummy_vector.clone()),
+                "dense",
+            )),
+            filter: None,
+            params: None,
+            limit: 100,
+            offset: 0,
+            with_vector: Some(with_vector),
+            with_payload: Some(WithPayloadInterface::Bool(with_payload)),
+            score_threshold: None,
+        };
+
+        assert_eq!(
+            planned_query.searches,
+            vec![
+                candidates_search(WithVector::Selector(vec!["dense".to_string()]), true),
+                candidates_search(WithVector::Bool(false), false),
+            ]
+        );
+
+        assert_eq!(
+            planned_query.root_plans[0],
+            MergePlan {
+                sources: vec![Source::SearchesIdx(0)],
+                rescore_params: None,
+            }
+        );
+
+        let Source::Prefetch(nested_plan) = &planned_query.root_plans[1].sources[0] else {
+            panic!("MMR prefetch must be rescored on the shard");
+        };
+        assert_eq!(nested_plan.sources, vec![Source::SearchesIdx(1)],);
+        assert_eq!(
+            nested_plan.rescore_params.as_ref().unwrap().rescore,
+            ScoringQuery::Mmr(mmr),
+        );
+        assert_eq!(nested_plan.rescore_params.as_ref().unwrap().limit, 20);
+    }
+
     #[test]
     fn test_base_params_mapping_in_try_from() {
         let dummy_vector = vec![1.0, 2.0, 3.0];
@@ -713,7 +899,7 @@ mod tests {
                 filter: dummy_filter.clone(),
                 score_threshold: Some(0.1),
             }],
-            query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+            query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
             filter: Some(Filter::default()),
             score_threshold: Some(0.666),
             limit: 50,
@@ -934,7 +1120,7 @@ mod tests {
                 prefetches: vec![
                     ShardPrefetch {
                         prefetches: vec![dummy_core_prefetch(30), dummy_core_prefetch(40)],
-                        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+                        query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
                         filter: None,
                         params: None,
                         score_threshold: None,
@@ -942,7 +1128,7 @@ mod tests {
                     },
                     dummy_scroll_prefetch(50),
                 ],
-                query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+                query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
                 filter: None,
                 score_threshold: None,
                 limit: 10,
@@ -974,7 +1160,7 @@ mod tests {
                         Source::Prefetch(Box::from(MergePlan {
                             sources: vec![Source::SearchesIdx(1), Source::SearchesIdx(2),],
                             rescore_params: Some(RescoreParams {
-                                rescore: ScoringQuery::Fusion(FusionInternal::Rrf),
+                                rescore: ScoringQuery::Fusion(FusionInternal::default_rrf()),
                                 limit: 10,
                                 score_threshold: None,
                                 with_vector: WithVector::Bool(true),
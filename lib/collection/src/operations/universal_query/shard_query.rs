@@ -1,9 +1,13 @@
 use api::grpc::qdrant as grpc;
 use common::types::ScoreType;
 use itertools::Itertools;
+use segment::common::maximal_marginal_relevance::maximal_marginal_relevance;
+use segment::common::reciprocal_rank_fusion::{rrf_scoring, DEFAULT_RRF_K};
+use segment::common::score_fusion::{score_fusion, Normalization, ScoreFusion};
+use segment::data_types::bm25::Bm25;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{
-    NamedQuery, NamedVectorStruct, VectorInternal, DEFAULT_VECTOR_NAME,
+    NamedQuery, NamedVectorStruct, VectorInternal, VectorStructInternal, DEFAULT_VECTOR_NAME,
 };
 use segment::types::{
     Filter, Order, ScoredPoint, SearchParams, VectorName, VectorNameBuf, WithPayloadInterface,
@@ -48,12 +52,80 @@ impl ShardQueryRequest {
     }
 }
 
+/// Fusion of the results of multiple prefetches.
+///
+/// Weights are applied to the prefetches in order, missing weights are 1.0.
 #[derive(Debug, Clone, PartialEq)]
 pub enum FusionInternal {
     /// Reciprocal Rank Fusion
-    Rrf,
+    Rrf { k: usize, weights: Vec<f32> },
     /// Distribution-based score fusion
-    Dbsf,
+    Dbsf { weights: Vec<f32> },
+    /// Weighted sum of normalized scores
+    Linear {
+        normalization: LinearNormalization,
+        weights: Vec<f32>,
+    },
+}
+
+/// Normalization of the scores of each prefetch for the linear fusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearNormalization {
+    MinMax,
+    ZScore,
+}
+
+impl FusionInternal {
+    /// Reciprocal Rank Fusion with default parameters
+    pub fn default_rrf() -> Self {
+        Self::Rrf {
+            k: DEFAULT_RRF_K,
+            weights: Vec::new(),
+        }
+    }
+
+    pub fn weights(&self) -> &[f32] {
+        match self {
+            Self::Rrf { weights, .. } | Self::Dbsf { weights } | Self::Linear { weights, .. } => {
+                weights
+            }
+        }
+    }
+
+    /// Fuse the results of the prefetches into a single sorted list
+    pub fn fuse(self, sources: impl IntoIterator<Item = Vec<ScoredPoint>>) -> Vec<ScoredPoint> {
+        match self {
+            Self::Rrf { k, weights } => rrf_scoring(sources, k, &weights),
+            Self::Dbsf { weights } => score_fusion(sources, ScoreFusion::dbsf(weights)),
+            Self::Linear {
+                normalization,
+                weights,
+            } => score_fusion(
+                sources,
+                ScoreFusion::linear(Normalization::from(normalization), weights),
+            ),
+        }
+    }
+
+    /// Representation in the parameterless gRPC `Fusion`, if the parameters are the defaults
+    fn to_grpc_fusion(&self) -> Option<grpc::Fusion> {
+        match self {
+            Self::Rrf { k, weights } if *k == DEFAULT_RRF_K && weights.is_empty() => {
+                Some(grpc::Fusion::Rrf)
+            }
+            Self::Dbsf { weights } if weights.is_empty() => Some(grpc::Fusion::Dbsf),
+            Self::Rrf { .. } | Self::Dbsf { .. } | Self::Linear { .. } => None,
+        }
+    }
+}
+
+impl From<LinearNormalization> for Normalization {
+    fn from(value: LinearNormalization) -> Self {
+        match value {
+            LinearNormalization::MinMax => Normalization::MinMax,
+            LinearNormalization::ZScore => Normalization::ZScore,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -61,6 +133,87 @@ pub enum SampleInternal {
     Random,
 }
 
+/// Maximal Marginal Relevance diversification of the nearest candidates
+#[derive(Debug, Clone, PartialEq)]
+pub struct MmrInternal {
+    /// Query vector, to measure the relevance of the candidates
+    pub vector: VectorInternal,
+    /// Name of the vector to compare the candidates with
+    pub using: VectorNameBuf,
+    /// Trade-off between relevance (1.0) and diversity (0.0)
+    pub lambda: f32,
+    /// Number of nearest points to select from, when there are no prefetches
+    pub candidates_limit: usize,
+}
+
+impl MmrInternal {
+    /// Number of nearest points to select from, to return `limit` points
+    pub fn candidates_limit(&self, limit: usize) -> usize {
+        self.candidates_limit.max(limit)
+    }
+
+    /// Vectors to fetch with the candidates, so that they can be compared to each other
+    pub fn candidates_with_vector(&self, with_vector: &WithVector) -> WithVector {
+        match with_vector {
+            WithVector::Bool(true) => WithVector::Bool(true),
+            WithVector::Bool(false) => WithVector::Selector(vec![self.using.clone()]),
+            WithVector::Selector(names) => {
+                let mut names = names.clone();
+                if !names.contains(&self.using) {
+                    names.push(self.using.clone());
+                }
+                WithVector::Selector(names)
+            }
+        }
+    }
+
+    /// Select up to `limit` diverse points out of the candidates
+    ///
+    /// Removes the vector, which was only fetched to compare the candidates, if it is not requested in `with_vector`.
+    pub fn diversify(
+        &self,
+        candidates: impl IntoIterator<Item = ScoredPoint>,
+        collection_params: &CollectionParams,
+        limit: usize,
+        score_threshold: Option<ScoreType>,
+        with_vector: &WithVector,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let distance = collection_params.get_distance(&self.using)?;
+        let multi_vector_config = collection_params
+            .vectors
+            .get_params(&self.using)
+            .and_then(|params| params.multivector_config);
+        let mut selected = maximal_marginal_relevance(
+            candidates,
+            &self.vector,
+            &self.using,
+            distance,
+            multi_vector_config,
+            self.lambda,
+            limit,
+            score_threshold,
+        );
+
+        let is_requested = match with_vector {
+            WithVector::Bool(enabled) => *enabled,
+            WithVector::Selector(names) => names.contains(&self.using),
+        };
+
+        if !is_requested {
+            for point in &mut selected {
+                match &mut point.vector {
+                    Some(VectorStructInternal::Named(vectors)) if vectors.len() > 1 => {
+                        vectors.remove(&self.using);
+                    }
+                    _ => point.vector = None,
+                }
+            }
+        }
+
+        Ok(selected)
+    }
+}
+
 /// Same as `Query`, but with the resolved vector references.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScoringQuery {
@@ -75,16 +228,25 @@ pub enum ScoringQuery {
 
     /// Sample points
     Sample(SampleInternal),
+
+    /// Score points by BM25 relevance of a full-text indexed payload field
+    Bm25(Bm25),
+
+    /// Diversify the candidates with Maximal Marginal Relevance
+    Mmr(MmrInternal),
 }
 
 impl ScoringQuery {
     pub fn needs_intermediate_results(&self) -> bool {
         match self {
             Self::Fusion(fusion) => match fusion {
-                FusionInternal::Rrf => true,
-                FusionInternal::Dbsf => true,
+                FusionInternal::Rrf { .. } => true,
+                FusionInternal::Dbsf { .. } => true,
+                FusionInternal::Linear { .. } => true,
             },
-            Self::Vector(_) | Self::OrderBy(_) | Self::Sample(_) => false,
+            // Diversity is only meaningful over the candidates from all shards
+            Self::Mmr(_) => true,
+            Self::Vector(_) | Self::OrderBy(_) | Self::Sample(_) | Self::Bm25(_) => false,
         }
     }
 
@@ -92,6 +254,7 @@ impl ScoringQuery {
     pub fn get_vector_name(&self) -> Option<&VectorName> {
         match self {
             Self::Vector(query) => Some(query.get_vector_name()),
+            Self::Mmr(mmr) => Some(&mmr.using),
             _ => None,
         }
     }
@@ -115,11 +278,17 @@ impl ScoringQuery {
                     }
                 }
                 ScoringQuery::Fusion(fusion) => match fusion {
-                    FusionInternal::Rrf | FusionInternal::Dbsf => Some(Order::LargeBetter),
+                    FusionInternal::Rrf { .. }
+                    | FusionInternal::Dbsf { .. }
+                    | FusionInternal::Linear { .. } => Some(Order::LargeBetter),
                 },
                 ScoringQuery::OrderBy(order_by) => Some(Order::from(order_by.direction())),
                 // Random sample does not require ordering
                 ScoringQuery::Sample(SampleInternal::Random) => None,
+                ScoringQuery::Bm25(_) => Some(Order::LargeBetter),
+                ScoringQuery::Mmr(mmr) => {
+                    Some(collection_params.get_distance(&mmr.using)?.distance_order())
+                }
             },
             None => {
                 // Order by ID
@@ -273,12 +442,16 @@ impl QueryEnum {
                     (
                         Some(name),
                         VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_)
                         | VectorInternal::Sparse(_)
                         | VectorInternal::Dense(_),
                     ) => name,
-                    (None, VectorInternal::MultiDense(_) | VectorInternal::Dense(_)) => {
-                        DEFAULT_VECTOR_NAME.to_owned()
-                    }
+                    (
+                        None,
+                        VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_)
+                        | VectorInternal::Dense(_),
+                    ) => DEFAULT_VECTOR_NAME.to_owned(),
                 };
                 let named_vector = NamedVectorStruct::new_from_vector(vector, name);
                 QueryEnum::Nearest(named_vector)
@@ -327,17 +500,115 @@ impl TryFrom<i32> for SampleInternal {
 impl From<api::grpc::qdrant::Fusion> for FusionInternal {
     fn from(fusion: api::grpc::qdrant::Fusion) -> Self {
         match fusion {
-            api::grpc::qdrant::Fusion::Rrf => FusionInternal::Rrf,
-            api::grpc::qdrant::Fusion::Dbsf => FusionInternal::Dbsf,
+            api::grpc::qdrant::Fusion::Rrf => FusionInternal::default_rrf(),
+            api::grpc::qdrant::Fusion::Dbsf => FusionInternal::Dbsf {
+                weights: Vec::new(),
+            },
         }
     }
 }
 
-impl From<FusionInternal> for api::grpc::qdrant::Fusion {
-    fn from(fusion: FusionInternal) -> Self {
-        match fusion {
-            FusionInternal::Rrf => api::grpc::qdrant::Fusion::Rrf,
-            FusionInternal::Dbsf => api::grpc::qdrant::Fusion::Dbsf,
+fn validate_fusion_weights(weights: &[f32]) -> Result<(), Status> {
+    if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err(Status::invalid_argument(
+            "fusion weights must be non-negative finite numbers",
+        ));
+    }
+    Ok(())
+}
+
+impl TryFrom<grpc::FusionParams> for FusionInternal {
+    type Error = Status;
+
+    fn try_from(value: grpc::FusionParams) -> Result<Self, Self::Error> {
+        use grpc::fusion_params::Variant;
+
+        let variant = value
+            .variant
+            .ok_or_else(|| Status::invalid_argument("FusionParams variant is missing"))?;
+
+        let fusion = match variant {
+            Variant::Rrf(grpc::Rrf { k, weights }) => {
+                validate_fusion_weights(&weights)?;
+                let k = match k {
+                    Some(0) => return Err(Status::invalid_argument("RRF k must be at least 1")),
+                    Some(k) => k as usize,
+                    None => DEFAULT_RRF_K,
+                };
+                FusionInternal::Rrf { k, weights }
+            }
+            Variant::Dbsf(grpc::Dbsf { weights }) => {
+                validate_fusion_weights(&weights)?;
+                FusionInternal::Dbsf { weights }
+            }
+            Variant::Linear(grpc::LinearFusion {
+                normalization,
+                weights,
+            }) => {
+                validate_fusion_weights(&weights)?;
+                let normalization = normalization
+                    .map(|normalization| {
+                        grpc::ScoreNormalization::try_from(normalization).map_err(|_| {
+                            Status::invalid_argument(format!(
+                                "invalid score normalization value {normalization}",
+                            ))
+                        })
+                    })
+                    .transpose()?
+                    .unwrap_or(grpc::ScoreNormalization::MinMax);
+                FusionInternal::Linear {
+                    normalization: LinearNormalization::from(normalization),
+                    weights,
+                }
+            }
+        };
+
+        Ok(fusion)
+    }
+}
+
+impl From<FusionInternal> for grpc::FusionParams {
+    fn from(value: FusionInternal) -> Self {
+        use grpc::fusion_params::Variant;
+
+        let variant = match value {
+            FusionInternal::Rrf { k, weights } => Variant::Rrf(grpc::Rrf {
+                k: Some(k as u32),
+                weights,
+            }),
+            FusionInternal::Dbsf { weights } => Variant::Dbsf(grpc::Dbsf { weights }),
+            FusionInternal::Linear {
+                normalization,
+                weights,
+            } => Variant::Linear(grpc::LinearFusion {
+                normalization: Some(grpc::ScoreNormalization::from(normalization) as i32),
+                weights,
+            }),
+        };
+
+        Self {
+            variant: Some(variant),
+        }
+    }
+}
+
+impl From<grpc::ScoreNormalization> for LinearNormalization {
+    fn from(value: grpc::ScoreNormalization) -> Self {
+        match value {
+            grpc::ScoreNormalization::MinMax => LinearNormalization::MinMax,
+            grpc::ScoreNormalization::ZScore => LinearNormalization::ZScore,
+        }
+    }
+}
+
+impl From<LinearNormalization> for grpc::ScoreNormalization {
+    fn from(value: LinearNormalization) -> Self {
+        match value {
+            LinearNormalization::MinMax => grpc::ScoreNormalization::MinMax,
+            LinearNormalization::ZScore => grpc::ScoreNormalization::ZScore,
         }
     }
 }
@@ -373,18 +644,63 @@ impl ScoringQuery {
             grpc::query_shard_points::query::Score::Fusion(fusion) => {
                 ScoringQuery::Fusion(FusionInternal::try_from(fusion)?)
             }
+            grpc::query_shard_points::query::Score::FusionParams(fusion) => {
+                ScoringQuery::Fusion(FusionInternal::try_from(fusion)?)
+            }
             grpc::query_shard_points::query::Score::OrderBy(order_by) => {
                 ScoringQuery::OrderBy(OrderBy::try_from(order_by)?)
             }
             grpc::query_shard_points::query::Score::Sample(sample) => {
                 ScoringQuery::Sample(SampleInternal::try_from(sample)?)
             }
+            grpc::query_shard_points::query::Score::Bm25(bm25) => {
+                ScoringQuery::Bm25(Bm25::try_from(bm25)?)
+            }
+            grpc::query_shard_points::query::Score::Mmr(mmr) => {
+                ScoringQuery::Mmr(MmrInternal::try_from_grpc(mmr, using)?)
+            }
         };
 
         Ok(scoring_query)
     }
 }
 
+impl MmrInternal {
+    fn try_from_grpc(mmr: grpc::MmrInternal, using: Option<VectorNameBuf>) -> Result<Self, Status> {
+        let grpc::MmrInternal {
+            vector,
+            lambda,
+            candidates_limit,
+        } = mmr;
+
+        let vector = vector.ok_or_else(|| Status::invalid_argument("missing field: vector"))?;
+
+        Ok(Self {
+            vector: VectorInternal::try_from(vector)?,
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            lambda,
+            candidates_limit: candidates_limit as usize,
+        })
+    }
+}
+
+impl From<MmrInternal> for grpc::MmrInternal {
+    fn from(value: MmrInternal) -> Self {
+        let MmrInternal {
+            vector,
+            using: _, // transferred as `using` of the request
+            lambda,
+            candidates_limit,
+        } = value;
+
+        Self {
+            vector: Some(grpc::RawVector::from(vector)),
+            lambda,
+            candidates_limit: candidates_limit as u64,
+        }
+    }
+}
+
 impl From<QueryEnum> for grpc::RawQuery {
     fn from(value: QueryEnum) -> Self {
         use api::grpc::qdrant::raw_query::Variant;
@@ -416,8 +732,14 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Vector(query) => Self {
                 score: Some(Score::Vector(grpc::RawQuery::from(query))),
             },
-            ScoringQuery::Fusion(fusion) => Self {
-                score: Some(Score::Fusion(api::grpc::qdrant::Fusion::from(fusion) as i32)),
+            // Parameterless fusion is sent in the old format, to be understood by older peers
+            ScoringQuery::Fusion(fusion) => match fusion.to_grpc_fusion() {
+                Some(grpc_fusion) => Self {
+                    score: Some(Score::Fusion(grpc_fusion as i32)),
+                },
+                None => Self {
+                    score: Some(Score::FusionParams(grpc::FusionParams::from(fusion))),
+                },
             },
             ScoringQuery::OrderBy(order_by) => Self {
                 score: Some(Score::OrderBy(grpc::OrderBy::from(order_by))),
@@ -425,6 +747,12 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Sample(sample) => Self {
                 score: Some(Score::Sample(api::grpc::qdrant::Sample::from(sample) as i32)),
             },
+            ScoringQuery::Bm25(bm25) => Self {
+                score: Some(Score::Bm25(grpc::Bm25::from(bm25))),
+            },
+            ScoringQuery::Mmr(mmr) => Self {
+                score: Some(Score::Mmr(grpc::MmrInternal::from(mmr))),
+            },
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::num::NonZeroU64;
 
-use segment::types::{Distance, MultiVectorConfig, QuantizationConfig};
+use segment::types::{Distance, IvfConfig, MultiVectorConfig, QuantizationConfig, VamanaConfig};
 
 use crate::operations::config_diff::HnswConfigDiff;
 use crate::operations::types::{Datatype, VectorParams};
@@ -16,6 +16,8 @@ impl VectorParamsBuilder {
                 size: NonZeroU64::new(size).unwrap(),
                 distance,
                 hnsw_config: None,
+                ivf_config: None,
+                vamana_config: None,
                 quantization_config: None,
                 on_disk: None,
                 datatype: None,
@@ -29,6 +31,16 @@ impl VectorParamsBuilder {
         self
     }
 
+    pub fn with_ivf_config(mut self, ivf_config: IvfConfig) -> Self {
+        self.vector_params.ivf_config = Some(ivf_config);
+        self
+    }
+
+    pub fn with_vamana_config(mut self, vamana_config: VamanaConfig) -> Self {
+        self.vector_params.vamana_config = Some(vamana_config);
+        self
+    }
+
     pub fn with_quantization_config(mut self, quantization_config: QuantizationConfig) -> Self {
         self.vector_params.quantization_config = Some(quantization_config);
         self
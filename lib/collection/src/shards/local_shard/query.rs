@@ -7,8 +7,6 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use parking_lot::Mutex;
-use segment::common::reciprocal_rank_fusion::rrf_scoring;
-use segment::common::score_fusion::{score_fusion, ScoreFusion};
 use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
 use tokio::runtime::Handle;
 use tokio::time::error::Elapsed;
@@ -23,7 +21,7 @@ use crate::operations::universal_query::planned_query::{
     MergePlan, PlannedQuery, RescoreParams, Source,
 };
 use crate::operations::universal_query::shard_query::{
-    FusionInternal, SampleInternal, ScoringQuery, ShardQueryResponse,
+    FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardQueryResponse,
 };
 
 pub enum FetchedSource {
@@ -344,9 +342,98 @@ impl LocalShard {
                     })
                 }
             },
+            ScoringQuery::Bm25(bm25) => {
+                // create single scroll request for rescoring query
+                let filter = filter_with_sources_ids(sources.into_iter());
+
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload,
+                    with_vector,
+                    scroll_order: ScrollOrder::ByBm25 {
+                        bm25,
+                        score_threshold,
+                    },
+                };
+
+                self.query_scroll_batch(
+                    Arc::new(vec![scroll_request]),
+                    search_runtime_handle,
+                    timeout,
+                    hw_counter_acc.clone(),
+                )
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Rescoring with bm25 query didn't return expected batch of results",
+                    )
+                })
+            }
+            ScoringQuery::Mmr(mmr) => {
+                self.mmr_rescore(
+                    sources.into_iter().flatten(),
+                    mmr,
+                    score_threshold,
+                    limit,
+                    with_payload,
+                    with_vector,
+                    timeout,
+                    hw_counter_acc,
+                )
+                .await
+            }
         }
     }
 
+    /// Diversify the sources with Maximal Marginal Relevance
+    #[allow(clippy::too_many_arguments)]
+    async fn mmr_rescore(
+        &self,
+        candidates: impl Iterator<Item = ScoredPoint>,
+        mmr: MmrInternal,
+        score_threshold: Option<f32>,
+        limit: usize,
+        with_payload: WithPayloadInterface,
+        with_vector: WithVector,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let start_time = std::time::Instant::now();
+
+        // Candidates from the sources don't have vectors, fetch the one to compare them with
+        let candidates = self
+            .fill_with_payload_or_vectors(
+                candidates.collect(),
+                WithPayloadInterface::Bool(false),
+                WithVector::Selector(vec![mmr.using.clone()]),
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let collection_params = self.collection_config.read().await.params.clone();
+        let selected = mmr.diversify(
+            candidates,
+            &collection_params,
+            limit,
+            score_threshold,
+            &WithVector::Bool(false),
+        )?;
+
+        let timeout = timeout.saturating_sub(start_time.elapsed());
+
+        self.fill_with_payload_or_vectors(
+            selected,
+            with_payload,
+            with_vector,
+            timeout,
+            hw_measurement_acc,
+        )
+        .await
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn fusion_rescore(
         &self,
@@ -359,10 +446,7 @@ impl LocalShard {
         timeout: Duration,
         hw_measurement_acc: HwMeasurementAcc,
     ) -> Result<Vec<ScoredPoint>, CollectionError> {
-        let fused = match fusion {
-            FusionInternal::Rrf => rrf_scoring(sources),
-            FusionInternal::Dbsf => score_fusion(sources, ScoreFusion::dbsf()),
-        };
+        let fused = fusion.fuse(sources);
 
         let top_fused: Vec<_> = if let Some(score_threshold) = score_threshold {
             fused
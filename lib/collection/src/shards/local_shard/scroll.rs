@@ -4,11 +4,13 @@ use std::time::{Duration, Instant};
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoreType;
 use futures::future::try_join_all;
 use itertools::Itertools as _;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
+use segment::data_types::bm25::Bm25;
 use segment::data_types::order_by::{Direction, OrderBy, OrderValue};
 use segment::types::{
     ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
@@ -153,6 +155,38 @@ impl LocalShard {
                     })
                     .collect()
             }
+            ScrollOrder::ByBm25 {
+                bm25,
+                score_threshold,
+            } => {
+                let (records, scores) = self
+                    .scroll_by_bm25(
+                        limit,
+                        with_payload,
+                        with_vector,
+                        filter.as_ref(),
+                        search_runtime_handle,
+                        bm25,
+                        *score_threshold,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await?;
+
+                records
+                    .into_iter()
+                    .zip(scores)
+                    .map(|(record, score)| ScoredPoint {
+                        id: record.id,
+                        version: 0,
+                        score,
+                        payload: record.payload,
+                        vector: record.vector,
+                        shard_key: record.shard_key,
+                        order_value: None,
+                    })
+                    .collect()
+            }
         };
 
         Ok(point_results)
@@ -332,6 +366,105 @@ impl LocalShard {
         Ok((ordered_records, values))
     }
 
+    /// Read points with the best BM25 scores across all segments.
+    ///
+    /// Scores are computed with per-segment term statistics.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn scroll_by_bm25(
+        &self,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        search_runtime_handle: &Handle,
+        bm25: &Bm25,
+        score_threshold: Option<ScoreType>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<(Vec<RecordInternal>, Vec<ScoreType>)> {
+        let start = Instant::now();
+        let timeout = timeout.unwrap_or(self.shared_storage_config.search_timeout);
+        let stopping_guard = StoppingGuard::new();
+        let segments = self.segments.clone();
+
+        let (non_appendable, appendable) = segments.read().split_segments();
+
+        let read_scored_by_bm25 = |segment: LockedSegment, hw_counter: &HardwareCounterCell| {
+            let is_stopped = stopping_guard.get_is_stopped();
+            let filter = filter.cloned();
+            let bm25 = bm25.clone();
+
+            let hw_counter = hw_counter.fork();
+            search_runtime_handle.spawn_blocking(move || {
+                segment.get().read().read_scored_by_bm25(
+                    Some(limit),
+                    filter.as_ref(),
+                    &bm25,
+                    &is_stopped,
+                    &hw_counter,
+                )
+            })
+        };
+
+        let hw_counter = hw_measurement_acc.get_counter_cell();
+
+        let all_reads = tokio::time::timeout(
+            timeout,
+            try_join_all(
+                non_appendable
+                    .into_iter()
+                    .chain(appendable)
+                    .map(|segment| read_scored_by_bm25(segment, &hw_counter)),
+            ),
+        )
+        .await
+        .map_err(|_: Elapsed| {
+            CollectionError::timeout(timeout.as_secs() as usize, "scroll_by_bm25")
+        })??;
+
+        let all_reads = all_reads.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        let mut seen_points = HashSet::new();
+        let (scores, point_ids): (Vec<_>, Vec<_>) = all_reads
+            .into_iter()
+            .kmerge_by(|(score_a, _), (score_b, _)| score_a >= score_b)
+            .take_while(|(score, _)| score_threshold.map_or(true, |threshold| *score >= threshold))
+            .filter(|(_, point_id)| seen_points.insert(*point_id))
+            .take(limit)
+            .unzip();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+
+        // update timeout
+        let timeout = timeout.saturating_sub(start.elapsed());
+
+        // Fetch with the requested vector and payload
+        let mut records_map = tokio::time::timeout(
+            timeout,
+            SegmentsSearcher::retrieve(
+                segments,
+                &point_ids,
+                &with_payload,
+                with_vector,
+                search_runtime_handle,
+                hw_measurement_acc,
+            ),
+        )
+        .await
+        .map_err(|_| CollectionError::timeout(timeout.as_secs() as usize, "retrieve"))??;
+
+        let (ordered_records, scores) = point_ids
+            .iter()
+            .zip(scores)
+            // Use remove to avoid cloning, we take each point ID only once
+            .filter_map(|(point_id, score)| {
+                records_map.remove(point_id).map(|record| (record, score))
+            })
+            .unzip();
+
+        Ok((ordered_records, scores))
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn scroll_randomly(
         &self,
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::common::score_range::truncate_with_ties;
 use segment::types::ScoredPoint;
 use tokio::runtime::Handle;
 
@@ -9,7 +10,9 @@ use super::LocalShard;
 use crate::collection_manager::segments_searcher::SegmentsSearcher;
 use crate::common::stopping_guard::StoppingGuard;
 use crate::operations::query_enum::QueryEnum;
-use crate::operations::types::{CollectionError, CollectionResult, CoreSearchRequestBatch};
+use crate::operations::types::{
+    CollectionError, CollectionResult, CoreSearchRequest, CoreSearchRequestBatch,
+};
 
 impl LocalShard {
     pub async fn do_search(
@@ -19,6 +22,8 @@ impl LocalShard {
         timeout: Option<Duration>,
         hw_counter_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<Vec<ScoredPoint>>> {
+        check_range_searches(&core_request)?;
+
         let is_stopped_guard = StoppingGuard::new();
 
         let (query_context, collection_params) = {
@@ -78,7 +83,7 @@ impl LocalShard {
                     scored_point
                 });
 
-                if let Some(threshold) = req.score_threshold {
+                let mut res: Vec<_> = if let Some(threshold) = req.score_threshold {
                     processed_res
                         .take_while(|scored_point| {
                             distance.check_threshold(scored_point.score, threshold)
@@ -86,9 +91,35 @@ impl LocalShard {
                         .collect()
                 } else {
                     processed_res.collect()
+                };
+
+                // Segments return whole pages of the range search, so it is cut to the page size here
+                if req.params.is_some_and(|params| params.range.is_some()) {
+                    truncate_with_ties(&mut res, req.limit, |scored_point| scored_point.score);
                 }
+                res
             })
             .collect();
         Ok(top_results)
     }
 }
+
+/// Range search pages are bounded by the `score_threshold` of the request,
+/// which is passed to the segments in their query context.
+fn check_range_searches(core_request: &CoreSearchRequestBatch) -> CollectionResult<()> {
+    let is_range_search =
+        |req: &&CoreSearchRequest| req.params.is_some_and(|params| params.range.is_some());
+    for req in core_request.searches.iter().filter(is_range_search) {
+        if req.score_threshold.is_none() {
+            return Err(CollectionError::bad_request(
+                "Range search requires `score_threshold`",
+            ));
+        }
+        if req.offset > 0 {
+            return Err(CollectionError::bad_request(
+                "Range search doesn't support `offset`, use `range.after` to get the next page",
+            ));
+        }
+    }
+    Ok(())
+}
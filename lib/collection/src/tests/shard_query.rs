@@ -58,7 +58,7 @@ async fn test_shard_query_rrf_rescoring() {
     // RRF query without prefetches
     let query = ShardQueryRequest {
         prefetches: vec![],
-        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+        query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
         filter: None,
         score_threshold: None,
         limit: 0,
@@ -93,7 +93,7 @@ async fn test_shard_query_rrf_rescoring() {
     let outer_limit = 2;
     let query = ShardQueryRequest {
         prefetches: vec![nearest_query_prefetch.clone()],
-        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+        query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
         filter: None,
         score_threshold: None,
         limit: outer_limit,
@@ -140,7 +140,7 @@ async fn test_shard_query_rrf_rescoring() {
             nearest_query_prefetch.clone(),
             nearest_query_prefetch.clone(),
         ],
-        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+        query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
         filter: None,
         score_threshold: None,
         limit: outer_limit,
@@ -184,7 +184,7 @@ async fn test_shard_query_rrf_rescoring() {
                 ..nearest_query_prefetch.clone()
             },
         ],
-        query: Some(ScoringQuery::Fusion(FusionInternal::Rrf)),
+        query: Some(ScoringQuery::Fusion(FusionInternal::default_rrf())),
         filter: None,
         score_threshold: None,
         limit: outer_limit,
@@ -231,6 +231,11 @@ impl<K: Key + ?Sized, V: Sized + FromBytes + Immutable + IntoBytes + KnownLayout
         })
     }
 
+    /// Key at the given position, positions are in range `0..keys_count()`.
+    pub fn key_at(&self, index: usize) -> Option<&K> {
+        K::from_bytes(self.get_entry(index).ok()?)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&K, &[V])> {
         (0..self.keys_count()).filter_map(|i| {
             let entry = self.get_entry(i).ok()?;
@@ -4,7 +4,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use criterion::{criterion_group, criterion_main, Criterion};
 use permutation_iterator::Permutor;
 use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
-use quantization::encoded_vectors_binary::EncodedVectorsBin;
+use quantization::encoded_vectors_binary::{EncodedVectorsBin, Encoding, QueryEncoding};
 use rand::{Rng, SeedableRng};
 
 fn generate_number(rng: &mut rand::rngs::StdRng) -> f32 {
@@ -43,6 +43,8 @@ fn binary_bench(c: &mut Criterion) {
             distance_type: DistanceType::Dot,
             invert: false,
         },
+        Encoding::OneBit,
+        QueryEncoding::SameAsStorage,
         &AtomicBool::new(false),
     )
     .unwrap();
@@ -82,6 +84,8 @@ fn binary_bench(c: &mut Criterion) {
             distance_type: DistanceType::Dot,
             invert: false,
         },
+        Encoding::OneBit,
+        QueryEncoding::SameAsStorage,
         &AtomicBool::new(false),
     )
     .unwrap();
@@ -120,3 +120,77 @@ EXPORT float impl_score_l1_avx(
 
     return (float) sum;
 }
+
+// Packed codes are decoded with a lookup table into the values on the query grid.
+// Within a block of 16 bytes, bits `[k * bits, (k + 1) * bits)` of all bytes
+// encode 16 consecutive dimensions `[k * 16, (k + 1) * 16)` of the block.
+// Two parts of a block are decoded at once, into the lower and the upper lanes.
+
+EXPORT float impl_score_dot_packed_avx(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const __m256i lut = _mm256_broadcastsi128_si256(_mm_loadu_si128((const __m128i*)lookup_table));
+    const __m128i mask = _mm_set1_epi8((char)((1 << bits) - 1));
+    const __m256i ones = _mm256_set1_epi16(1);
+    const uint32_t parts = 8 / bits;
+
+    __m256i sum = _mm256_setzero_si256();
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        __m128i codes = _mm_loadu_si128((const __m128i*)vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k += 2) {
+            __m128i idx_lo = _mm_and_si128(_mm_srl_epi16(codes, _mm_cvtsi32_si128((int)(k * bits))), mask);
+            __m128i idx_hi = _mm_and_si128(_mm_srl_epi16(codes, _mm_cvtsi32_si128((int)((k + 1) * bits))), mask);
+            __m256i idx = _mm256_inserti128_si256(_mm256_castsi128_si256(idx_lo), idx_hi, 1);
+            __m256i v = _mm256_shuffle_epi8(lut, idx);
+            __m256i q = _mm256_loadu_si256((const __m256i*)query_ptr);
+            query_ptr += 32;
+
+            __m256i s = _mm256_maddubs_epi16(v, q);
+            sum = _mm256_add_epi32(sum, _mm256_madd_epi16(s, ones));
+        }
+    }
+    __m256 sum_ps = _mm256_cvtepi32_ps(sum);
+    HSUM256_PS(sum_ps, sum_scalar);
+    return sum_scalar;
+}
+
+EXPORT float impl_score_l1_packed_avx(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const __m256i lut = _mm256_broadcastsi128_si256(_mm_loadu_si128((const __m128i*)lookup_table));
+    const __m128i mask = _mm_set1_epi8((char)((1 << bits) - 1));
+    const uint32_t parts = 8 / bits;
+
+    __m256i sum = _mm256_setzero_si256();
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        __m128i codes = _mm_loadu_si128((const __m128i*)vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k += 2) {
+            __m128i idx_lo = _mm_and_si128(_mm_srl_epi16(codes, _mm_cvtsi32_si128((int)(k * bits))), mask);
+            __m128i idx_hi = _mm_and_si128(_mm_srl_epi16(codes, _mm_cvtsi32_si128((int)((k + 1) * bits))), mask);
+            __m256i idx = _mm256_inserti128_si256(_mm256_castsi128_si256(idx_lo), idx_hi, 1);
+            __m256i v = _mm256_shuffle_epi8(lut, idx);
+            __m256i q = _mm256_loadu_si256((const __m256i*)query_ptr);
+            query_ptr += 32;
+
+            // Compute the difference in both directions and take the maximum for abs
+            __m256i abs_diff = _mm256_max_epu8(_mm256_subs_epu8(v, q), _mm256_subs_epu8(q, v));
+            sum = _mm256_add_epi64(sum, _mm256_sad_epu8(abs_diff, _mm256_setzero_si256()));
+        }
+    }
+    __m128i sum128 = _mm_add_epi64(_mm256_extracti128_si256(sum, 1), _mm256_castsi256_si128(sum));
+    uint64_t sum_scalar = (uint64_t)_mm_cvtsi128_si64(sum128)
+        + (uint64_t)_mm_cvtsi128_si64(_mm_unpackhi_epi64(sum128, sum128));
+    return (float)sum_scalar;
+}
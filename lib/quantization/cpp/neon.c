@@ -105,3 +105,68 @@ EXPORT float impl_score_l1_neon(
 
     return (float) sum;
 }
+
+// Packed codes are decoded with a lookup table into the values on the query grid.
+// Within a block of 16 bytes, bits `[k * bits, (k + 1) * bits)` of all bytes
+// encode 16 consecutive dimensions `[k * 16, (k + 1) * 16)` of the block.
+
+EXPORT float impl_score_dot_packed_neon(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const uint8x16_t lut = vld1q_u8(lookup_table);
+    const uint8x16_t mask = vdupq_n_u8((uint8_t)((1 << bits) - 1));
+    const uint32_t parts = 8 / bits;
+
+    uint32x4_t mul1 = vdupq_n_u32(0);
+    uint32x4_t mul2 = vdupq_n_u32(0);
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        uint8x16_t codes = vld1q_u8(vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k++) {
+            uint8x16_t idx = vandq_u8(vshlq_u8(codes, vdupq_n_s8(-(int8_t)(k * bits))), mask);
+            uint8x16_t v = vqtbl1q_u8(lut, idx);
+            uint8x16_t q = vld1q_u8(query_ptr);
+            query_ptr += 16;
+
+            uint16x8_t mul_low = vmull_u8(vget_low_u8(q), vget_low_u8(v));
+            uint16x8_t mul_high = vmull_u8(vget_high_u8(q), vget_high_u8(v));
+            mul1 = vpadalq_u16(mul1, mul_low);
+            mul2 = vpadalq_u16(mul2, mul_high);
+        }
+    }
+    return (float)vaddvq_u32(vaddq_u32(mul1, mul2));
+}
+
+EXPORT float impl_score_l1_packed_neon(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const uint8x16_t lut = vld1q_u8(lookup_table);
+    const uint8x16_t mask = vdupq_n_u8((uint8_t)((1 << bits) - 1));
+    const uint32_t parts = 8 / bits;
+
+    uint32x4_t sum = vdupq_n_u32(0);
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        uint8x16_t codes = vld1q_u8(vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k++) {
+            uint8x16_t idx = vandq_u8(vshlq_u8(codes, vdupq_n_s8(-(int8_t)(k * bits))), mask);
+            uint8x16_t v = vqtbl1q_u8(lut, idx);
+            uint8x16_t q = vld1q_u8(query_ptr);
+            query_ptr += 16;
+
+            uint8x16_t abs_diff = vabdq_u8(q, v);
+            sum = vpadalq_u16(sum, vpaddlq_u8(abs_diff));
+        }
+    }
+    return (float)vaddvq_u32(sum);
+}
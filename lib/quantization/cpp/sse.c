@@ -147,3 +147,73 @@ EXPORT float impl_score_l1_sse(
 
     return (float) sum;
 }
+
+// Packed codes are decoded with a lookup table into the values on the query grid.
+// Within a block of 16 bytes, bits `[k * bits, (k + 1) * bits)` of all bytes
+// encode 16 consecutive dimensions `[k * 16, (k + 1) * 16)` of the block.
+
+EXPORT float impl_score_dot_packed_sse(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const __m128i lut = _mm_loadu_si128((const __m128i*)lookup_table);
+    const __m128i mask = _mm_set1_epi8((char)((1 << bits) - 1));
+    const __m128i ones = _mm_set1_epi16(1);
+    const uint32_t parts = 8 / bits;
+
+    __m128i sum = _mm_setzero_si128();
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        __m128i codes = _mm_loadu_si128((const __m128i*)vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k++) {
+            __m128i shift = _mm_cvtsi32_si128((int)(k * bits));
+            __m128i idx = _mm_and_si128(_mm_srl_epi16(codes, shift), mask);
+            __m128i v = _mm_shuffle_epi8(lut, idx);
+            __m128i q = _mm_loadu_si128((const __m128i*)query_ptr);
+            query_ptr += 16;
+
+            __m128i s = _mm_maddubs_epi16(v, q);
+            sum = _mm_add_epi32(sum, _mm_madd_epi16(s, ones));
+        }
+    }
+    __m128 sum_ps = _mm_cvtepi32_ps(sum);
+    HSUM128_PS(sum_ps, sum_scalar);
+    return sum_scalar;
+}
+
+EXPORT float impl_score_l1_packed_sse(
+    const uint8_t* query_ptr,
+    const uint8_t* vector_ptr,
+    const uint8_t* lookup_table,
+    uint32_t bits,
+    uint32_t blocks
+) {
+    const __m128i lut = _mm_loadu_si128((const __m128i*)lookup_table);
+    const __m128i mask = _mm_set1_epi8((char)((1 << bits) - 1));
+    const uint32_t parts = 8 / bits;
+
+    __m128i sum = _mm_setzero_si128();
+    for (uint32_t _i = 0; _i < blocks; _i++) {
+        __m128i codes = _mm_loadu_si128((const __m128i*)vector_ptr);
+        vector_ptr += 16;
+
+        for (uint32_t k = 0; k < parts; k++) {
+            __m128i shift = _mm_cvtsi32_si128((int)(k * bits));
+            __m128i idx = _mm_and_si128(_mm_srl_epi16(codes, shift), mask);
+            __m128i v = _mm_shuffle_epi8(lut, idx);
+            __m128i q = _mm_loadu_si128((const __m128i*)query_ptr);
+            query_ptr += 16;
+
+            // Compute the difference in both directions and take the maximum for abs
+            __m128i abs_diff = _mm_max_epu8(_mm_subs_epu8(v, q), _mm_subs_epu8(q, v));
+            sum = _mm_add_epi64(sum, _mm_sad_epu8(abs_diff, _mm_setzero_si128()));
+        }
+    }
+    uint64_t sum_scalar = (uint64_t)_mm_cvtsi128_si64(sum)
+        + (uint64_t)_mm_cvtsi128_si64(_mm_unpackhi_epi64(sum, sum));
+    return (float)sum_scalar;
+}
@@ -23,9 +23,75 @@ pub struct EncodedBinVector<TBitsStoreType: BitsStoreType> {
     encoded_vector: Vec<TBitsStoreType>,
 }
 
+/// Query, quantized into a few bits per position and stored as bit planes
+pub struct EncodedScalarVector<TBitsStoreType: BitsStoreType> {
+    /// Bit planes of the quantized query, from the lowest bit to the highest.
+    /// Each plane has the same layout as an encoded vector.
+    encoded_vector: Vec<TBitsStoreType>,
+    alpha: f32,
+    offset: f32,
+    /// Sum of the query weights before quantization
+    weights_sum: f32,
+}
+
+pub enum EncodedQueryBQ<TBitsStoreType: BitsStoreType> {
+    Binary(EncodedBinVector<TBitsStoreType>),
+    Scalar(EncodedScalarVector<TBitsStoreType>),
+}
+
+/// How many bits are used to encode each dimension of the stored vectors
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Encoding {
+    /// One bit per dimension, set if the value is positive
+    #[default]
+    OneBit,
+    /// Two bits per dimension, which encode three levels of the value
+    /// relative to the dimension mean and standard deviation
+    TwoBits,
+    /// One bit per dimension, plus one bit per pair of dimensions,
+    /// which is set if the sum of the pair is positive
+    OneAndHalfBits,
+}
+
+/// How the query is encoded for scoring against the stored vectors
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryEncoding {
+    /// Query is encoded the same way as the stored vectors
+    #[default]
+    SameAsStorage,
+    /// Query is quantized into 4 bits per position
+    Scalar4bits,
+    /// Query is quantized into 8 bits per position
+    Scalar8bits,
+}
+
+/// Threshold of the normalized value, which separates the levels of `TwoBits` encoding.
+/// Three levels are equally probable for normally distributed values.
+const TWO_BITS_THRESHOLD: f32 = 0.43;
+
 #[derive(Serialize, Deserialize)]
 struct Metadata {
     vector_parameters: VectorParameters,
+    #[serde(default)]
+    encoding: Encoding,
+    #[serde(default)]
+    query_encoding: QueryEncoding,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vector_stats: Option<VectorStats>,
+}
+
+/// Per-dimension statistics of the encoded vectors
+#[derive(Serialize, Deserialize)]
+struct VectorStats {
+    elements_stats: Vec<VectorElementStats>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct VectorElementStats {
+    mean: f32,
+    stddev: f32,
 }
 
 pub trait BitsStoreType:
@@ -42,6 +108,12 @@ pub trait BitsStoreType:
     /// So it does not affect the resulting number of bits set to 1
     fn xor_popcnt(v1: &[Self], v2: &[Self]) -> usize;
 
+    /// And vectors and return the number of bits set to 1
+    fn and_popcnt(v1: &[Self], v2: &[Self]) -> usize;
+
+    /// Number of bits set to 1
+    fn popcnt(v: &[Self]) -> usize;
+
     /// Estimates how many `StorageType` elements are needed to store `size` bits
     fn get_storage_size(size: usize) -> usize;
 }
@@ -101,6 +173,18 @@ impl BitsStoreType for u8 {
         result
     }
 
+    fn and_popcnt(v1: &[Self], v2: &[Self]) -> usize {
+        debug_assert!(v1.len() == v2.len());
+        v1.iter()
+            .zip(v2.iter())
+            .map(|(&b1, &b2)| (b1 & b2).count_ones() as usize)
+            .sum()
+    }
+
+    fn popcnt(v: &[Self]) -> usize {
+        v.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
     fn get_storage_size(size: usize) -> usize {
         let bytes_count = if size > 128 {
             std::mem::size_of::<u128>()
@@ -154,6 +238,18 @@ impl BitsStoreType for u128 {
         result
     }
 
+    fn and_popcnt(v1: &[Self], v2: &[Self]) -> usize {
+        debug_assert!(v1.len() == v2.len());
+        v1.iter()
+            .zip(v2.iter())
+            .map(|(&b1, &b2)| (b1 & b2).count_ones() as usize)
+            .sum()
+    }
+
+    fn popcnt(v: &[Self]) -> usize {
+        v.iter().map(|b| b.count_ones() as usize).sum()
+    }
+
     fn get_storage_size(size: usize) -> usize {
         let bits_count = 8 * std::mem::size_of::<Self>();
         let mut result = size / bits_count;
@@ -164,6 +260,86 @@ impl BitsStoreType for u128 {
     }
 }
 
+impl Encoding {
+    /// Number of bits, used to encode a vector of `dim` dimensions
+    pub fn get_bits_count(self, dim: usize) -> usize {
+        match self {
+            Encoding::OneBit => dim,
+            Encoding::TwoBits => 2 * dim,
+            Encoding::OneAndHalfBits => dim + dim.div_ceil(2),
+        }
+    }
+
+    fn requires_stats(self) -> bool {
+        match self {
+            Encoding::OneBit => false,
+            Encoding::TwoBits | Encoding::OneAndHalfBits => true,
+        }
+    }
+}
+
+impl QueryEncoding {
+    fn bits_count(self) -> Option<usize> {
+        match self {
+            QueryEncoding::SameAsStorage => None,
+            QueryEncoding::Scalar4bits => Some(4),
+            QueryEncoding::Scalar8bits => Some(8),
+        }
+    }
+}
+
+impl VectorStats {
+    fn build<'a>(data: impl Iterator<Item = impl AsRef<[f32]> + 'a>, dim: usize) -> Self {
+        let mut count = 0usize;
+        let mut sums = vec![0.0f64; dim];
+        let mut squared_sums = vec![0.0f64; dim];
+        for vector in data {
+            count += 1;
+            for ((sum, squared_sum), &value) in sums
+                .iter_mut()
+                .zip(squared_sums.iter_mut())
+                .zip(vector.as_ref())
+            {
+                *sum += f64::from(value);
+                *squared_sum += f64::from(value) * f64::from(value);
+            }
+        }
+
+        let count = count.max(1) as f64;
+        let elements_stats = sums
+            .into_iter()
+            .zip(squared_sums)
+            .map(|(sum, squared_sum)| {
+                let mean = sum / count;
+                let variance = (squared_sum / count - mean * mean).max(0.0);
+                VectorElementStats {
+                    mean: mean as f32,
+                    stddev: variance.sqrt() as f32,
+                }
+            })
+            .collect();
+        Self { elements_stats }
+    }
+}
+
+/// Value of the dimension, normalized by the dimension statistics if they are present
+fn normalize(vector_stats: Option<&VectorStats>, i: usize, value: f32) -> f32 {
+    let Some(stats) = vector_stats.and_then(|stats| stats.elements_stats.get(i)) else {
+        return value;
+    };
+    let centered = value - stats.mean;
+    if stats.stddev > f32::EPSILON {
+        centered / stats.stddev
+    } else {
+        centered
+    }
+}
+
+fn set_bit<TBitsStoreType: BitsStoreType>(encoded_vector: &mut [TBitsStoreType], position: usize) {
+    let bits_count = u8::BITS as usize * std::mem::size_of::<TBitsStoreType>();
+    encoded_vector[position / bits_count] |= TBitsStoreType::one() << (position % bits_count);
+}
+
 impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
     EncodedVectorsBin<TBitsStoreType, TStorage>
 {
@@ -171,16 +347,23 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
         orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
         mut storage_builder: impl EncodedStorageBuilder<TStorage>,
         vector_parameters: &VectorParameters,
+        encoding: Encoding,
+        query_encoding: QueryEncoding,
         stopped: &AtomicBool,
     ) -> Result<Self, EncodingError> {
         debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
 
+        let vector_stats = encoding
+            .requires_stats()
+            .then(|| VectorStats::build(orig_data.clone(), vector_parameters.dim));
+
         for vector in orig_data {
             if stopped.load(Ordering::Relaxed) {
                 return Err(EncodingError::Stopped);
             }
 
-            let encoded_vector = Self::encode_vector(vector.as_ref());
+            let encoded_vector =
+                Self::encode_vector(vector.as_ref(), encoding, vector_stats.as_ref());
             let encoded_vector_slice = encoded_vector.encoded_vector.as_slice();
             let bytes = transmute_to_u8_slice(encoded_vector_slice);
             storage_builder.push_vector_data(bytes);
@@ -190,35 +373,174 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
             encoded_vectors: storage_builder.build(),
             metadata: Metadata {
                 vector_parameters: vector_parameters.clone(),
+                encoding,
+                query_encoding,
+                vector_stats,
             },
             bits_store_type: PhantomData,
         })
     }
 
-    fn encode_vector(vector: &[f32]) -> EncodedBinVector<TBitsStoreType> {
-        let mut encoded_vector =
-            vec![Default::default(); TBitsStoreType::get_storage_size(vector.len())];
-
-        let bits_count = u8::BITS as usize * std::mem::size_of::<TBitsStoreType>();
-        let one = TBitsStoreType::one();
-        for (i, &v) in vector.iter().enumerate() {
-            // flag is true if the value is positive
-            // It's expected that the vector value is in range [-1; 1]
-            if v > 0.0 {
-                encoded_vector[i / bits_count] |= one << (i % bits_count);
+    fn encode_vector(
+        vector: &[f32],
+        encoding: Encoding,
+        vector_stats: Option<&VectorStats>,
+    ) -> EncodedBinVector<TBitsStoreType> {
+        let dim = vector.len();
+        let mut encoded_vector = vec![
+            Default::default();
+            TBitsStoreType::get_storage_size(encoding.get_bits_count(dim))
+        ];
+
+        match encoding {
+            Encoding::OneBit => {
+                for (i, &v) in vector.iter().enumerate() {
+                    // flag is true if the value is positive
+                    // It's expected that the vector value is in range [-1; 1]
+                    if v > 0.0 {
+                        set_bit(&mut encoded_vector, i);
+                    }
+                }
+            }
+            Encoding::TwoBits => {
+                // Levels are encoded as 00, 10 and 11 in the bits `i` and `dim + i`,
+                // so that XOR of two encodings is the distance between the levels
+                for (i, &v) in vector.iter().enumerate() {
+                    let v = normalize(vector_stats, i, v);
+                    if v > -TWO_BITS_THRESHOLD {
+                        set_bit(&mut encoded_vector, i);
+                    }
+                    if v > TWO_BITS_THRESHOLD {
+                        set_bit(&mut encoded_vector, dim + i);
+                    }
+                }
+            }
+            Encoding::OneAndHalfBits => {
+                for (i, &v) in vector.iter().enumerate() {
+                    if normalize(vector_stats, i, v) > 0.0 {
+                        set_bit(&mut encoded_vector, i);
+                    }
+                }
+                // Extra bit is the sign of the pair sum, which refines the angle
+                // between vectors in the plane of each pair of dimensions
+                for (pair_index, pair) in vector.chunks(2).enumerate() {
+                    let sum = pair
+                        .iter()
+                        .enumerate()
+                        .map(|(j, &v)| normalize(vector_stats, 2 * pair_index + j, v))
+                        .sum::<f32>();
+                    if sum > 0.0 {
+                        set_bit(&mut encoded_vector, dim + pair_index);
+                    }
+                }
             }
         }
 
         EncodedBinVector { encoded_vector }
     }
 
-    pub fn get_quantized_vector_size_from_params(vector_parameters: &VectorParameters) -> usize {
-        TBitsStoreType::get_storage_size(vector_parameters.dim)
+    /// Weights of the query for each encoded bit position.
+    ///
+    /// A stored bit contributes `+weight` to the score if it is set and `-weight` otherwise,
+    /// the same way as a query bit of the symmetric encoding does.
+    fn query_weights(&self, query: &[f32]) -> Vec<f32> {
+        let vector_stats = self.metadata.vector_stats.as_ref();
+        let normalized = query
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| normalize(vector_stats, i, v));
+
+        match self.metadata.encoding {
+            Encoding::OneBit => normalized.collect(),
+            Encoding::TwoBits => {
+                let normalized: Vec<f32> = normalized.collect();
+                normalized
+                    .iter()
+                    .chain(normalized.iter())
+                    .copied()
+                    .collect()
+            }
+            Encoding::OneAndHalfBits => {
+                let mut weights: Vec<f32> = normalized.collect();
+                let extra_weights: Vec<f32> = weights
+                    .chunks(2)
+                    .map(|pair| pair.iter().sum::<f32>() / (pair.len() as f32).sqrt())
+                    .collect();
+                weights.extend(extra_weights);
+                weights
+            }
+        }
+    }
+
+    fn encode_scalar_query(
+        &self,
+        query: &[f32],
+        query_bits_count: usize,
+    ) -> EncodedScalarVector<TBitsStoreType> {
+        let weights = self.query_weights(query);
+        let weights_sum = weights.iter().sum();
+
+        let (min, max) = weights.iter().fold((f32::MAX, f32::MIN), |(min, max), &w| {
+            (min.min(w), max.max(w))
+        });
+        let (min, max) = if weights.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (min, max)
+        };
+        let levels_count = (1usize << query_bits_count) - 1;
+        let alpha = (max - min) / levels_count as f32;
+
+        let storage_size = TBitsStoreType::get_storage_size(weights.len());
+        let mut encoded_vector = vec![Default::default(); storage_size * query_bits_count];
+        for (position, &weight) in weights.iter().enumerate() {
+            let level = if alpha > 0.0 {
+                (((weight - min) / alpha).round() as usize).min(levels_count)
+            } else {
+                0
+            };
+            for (bit, plane) in encoded_vector.chunks_exact_mut(storage_size).enumerate() {
+                if (level >> bit) & 1 == 1 {
+                    set_bit(plane, position);
+                }
+            }
+        }
+
+        EncodedScalarVector {
+            encoded_vector,
+            alpha,
+            offset: min,
+            weights_sum,
+        }
+    }
+
+    pub fn get_quantized_vector_size_from_params(
+        vector_parameters: &VectorParameters,
+        encoding: Encoding,
+    ) -> usize {
+        TBitsStoreType::get_storage_size(encoding.get_bits_count(vector_parameters.dim))
             * std::mem::size_of::<TBitsStoreType>()
     }
 
     fn get_quantized_vector_size(&self) -> usize {
-        Self::get_quantized_vector_size_from_params(&self.metadata.vector_parameters)
+        Self::get_quantized_vector_size_from_params(
+            &self.metadata.vector_parameters,
+            self.metadata.encoding,
+        )
+    }
+
+    /// Converts the sum of `+1 * +1` products of the encoded positions into the score
+    fn score_from_similarity(&self, similarity: f32) -> f32 {
+        match (
+            self.metadata.vector_parameters.distance_type,
+            self.metadata.vector_parameters.invert,
+        ) {
+            (DistanceType::Dot, true) => -similarity,
+            (DistanceType::Dot, false) => similarity,
+            // This also results in exact ordering as L1 and L2 but reversed.
+            (DistanceType::L1 | DistanceType::L2, true) => similarity,
+            (DistanceType::L1 | DistanceType::L2, false) => -similarity,
+        }
     }
 
     fn calculate_metric(&self, v1: &[TBitsStoreType], v2: &[TBitsStoreType]) -> f32 {
@@ -241,20 +563,31 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
 
         let xor_product = TBitsStoreType::xor_popcnt(v1, v2) as f32;
 
-        let dim = self.metadata.vector_parameters.dim as f32;
-        let zeros_count = dim - xor_product;
+        let bits_count = self.get_bits_count() as f32;
+        let zeros_count = bits_count - xor_product;
 
-        match (
-            self.metadata.vector_parameters.distance_type,
-            self.metadata.vector_parameters.invert,
-        ) {
-            // So if `invert` is true we return XOR, otherwise we return (dim - XOR)
-            (DistanceType::Dot, true) => xor_product - zeros_count,
-            (DistanceType::Dot, false) => zeros_count - xor_product,
-            // This also results in exact ordering as L1 and L2 but reversed.
-            (DistanceType::L1 | DistanceType::L2, true) => zeros_count - xor_product,
-            (DistanceType::L1 | DistanceType::L2, false) => xor_product - zeros_count,
+        self.score_from_similarity(zeros_count - xor_product)
+    }
+
+    fn calculate_metric_scalar(
+        &self,
+        vector: &[TBitsStoreType],
+        query: &EncodedScalarVector<TBitsStoreType>,
+    ) -> f32 {
+        // Query weight `w` is quantized as `alpha * level + offset`,
+        // so the sum of weights over the set bits of the vector is
+        // `alpha * sum(2^bit * popcnt(plane & vector)) + offset * popcnt(vector)`
+        let mut levels_sum = 0;
+        if !vector.is_empty() {
+            for (bit, plane) in query.encoded_vector.chunks_exact(vector.len()).enumerate() {
+                levels_sum += TBitsStoreType::and_popcnt(vector, plane) << bit;
+            }
         }
+        let ones_count = TBitsStoreType::popcnt(vector) as f32;
+        let set_weights_sum = query.alpha * levels_sum as f32 + query.offset * ones_count;
+
+        // Set bits contribute `+w`, unset bits contribute `-w`
+        self.score_from_similarity(2.0 * set_weights_sum - query.weights_sum)
     }
 
     pub fn get_quantized_vector(&self, i: u32) -> &[u8] {
@@ -266,14 +599,24 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
         &self.metadata.vector_parameters
     }
 
+    pub fn get_encoding(&self) -> Encoding {
+        self.metadata.encoding
+    }
+
+    /// Number of meaningful bits in each encoded vector
+    pub fn get_bits_count(&self) -> usize {
+        self.metadata
+            .encoding
+            .get_bits_count(self.metadata.vector_parameters.dim)
+    }
+
     pub fn vectors_count(&self) -> usize {
         self.metadata.vector_parameters.count
     }
 }
 
 impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
-    EncodedVectors<EncodedBinVector<TBitsStoreType>>
-    for EncodedVectorsBin<TBitsStoreType, TStorage>
+    EncodedVectors<EncodedQueryBQ<TBitsStoreType>> for EncodedVectorsBin<TBitsStoreType, TStorage>
 {
     fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
         meta_path.parent().map(std::fs::create_dir_all);
@@ -291,7 +634,8 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
     ) -> std::io::Result<Self> {
         let contents = std::fs::read_to_string(meta_path)?;
         let metadata: Metadata = serde_json::from_str(&contents)?;
-        let quantized_vector_size = Self::get_quantized_vector_size_from_params(vector_parameters);
+        let quantized_vector_size =
+            Self::get_quantized_vector_size_from_params(vector_parameters, metadata.encoding);
         let encoded_vectors =
             TStorage::from_file(data_path, quantized_vector_size, vector_parameters.count)?;
         let result = Self {
@@ -302,14 +646,23 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
         Ok(result)
     }
 
-    fn encode_query(&self, query: &[f32]) -> EncodedBinVector<TBitsStoreType> {
+    fn encode_query(&self, query: &[f32]) -> EncodedQueryBQ<TBitsStoreType> {
         debug_assert!(query.len() == self.metadata.vector_parameters.dim);
-        Self::encode_vector(query)
+        match self.metadata.query_encoding.bits_count() {
+            None => EncodedQueryBQ::Binary(Self::encode_vector(
+                query,
+                self.metadata.encoding,
+                self.metadata.vector_stats.as_ref(),
+            )),
+            Some(query_bits_count) => {
+                EncodedQueryBQ::Scalar(self.encode_scalar_query(query, query_bits_count))
+            }
+        }
     }
 
     fn score_point(
         &self,
-        query: &EncodedBinVector<TBitsStoreType>,
+        query: &EncodedQueryBQ<TBitsStoreType>,
         i: u32,
         hw_counter: &HardwareCounterCell,
     ) -> f32 {
@@ -318,11 +671,22 @@ impl<TBitsStoreType: BitsStoreType, TStorage: EncodedStorage>
             .get_vector_data(i as _, self.get_quantized_vector_size());
         let vector_data_usize_1 = transmute_from_u8_to_slice(vector_data_1);
 
-        hw_counter
-            .cpu_counter()
-            .incr_delta(query.encoded_vector.len());
+        match query {
+            EncodedQueryBQ::Binary(query) => {
+                hw_counter
+                    .cpu_counter()
+                    .incr_delta(query.encoded_vector.len());
+
+                self.calculate_metric(vector_data_usize_1, &query.encoded_vector)
+            }
+            EncodedQueryBQ::Scalar(query) => {
+                hw_counter
+                    .cpu_counter()
+                    .incr_delta(query.encoded_vector.len());
 
-        self.calculate_metric(vector_data_usize_1, &query.encoded_vector)
+                self.calculate_metric_scalar(vector_data_usize_1, query)
+            }
+        }
     }
 
     fn score_internal(&self, i: u32, j: u32, hw_counter: &HardwareCounterCell) -> f32 {
@@ -0,0 +1,530 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use io::file_operations::atomic_save_json;
+use serde::{Deserialize, Serialize};
+
+use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
+use crate::encoded_vectors::{
+    validate_vector_parameters, DistanceType, EncodedVectors, VectorParameters,
+};
+use crate::quantile::{find_min_max_from_iter, find_quantile_interval};
+use crate::EncodingError;
+
+/// Number of bytes of packed codes, which are processed at once.
+///
+/// Within a block, byte `j` holds the codes of dimensions `j`, `j + 16`, `j + 32`, ...
+/// starting from the lowest bits, so that each unpacked part of the block
+/// contains 16 consecutive dimensions.
+pub const BLOCK_SIZE: usize = 16;
+
+/// Upper bound of the grid, on which queries are quantized.
+/// Products of grid values must fit into `i16` pairwise sums of `maddubs`.
+const GRID_LIMIT: u8 = 127;
+
+/// Number of bits per dimension in packed scalar quantization
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackedType {
+    /// 4 bits per dimension, 2 dimensions per byte
+    Int4,
+    /// 2 bits per dimension, 4 dimensions per byte
+    Int2,
+}
+
+impl PackedType {
+    pub fn bits(self) -> usize {
+        match self {
+            PackedType::Int4 => 4,
+            PackedType::Int2 => 2,
+        }
+    }
+
+    fn codes_per_byte(self) -> usize {
+        u8::BITS as usize / self.bits()
+    }
+
+    fn mask(self) -> u8 {
+        (1 << self.bits()) - 1
+    }
+
+    /// Number of dimensions, encoded in one block of packed codes
+    fn block_dim(self) -> usize {
+        BLOCK_SIZE * self.codes_per_byte()
+    }
+
+    /// Distance between two neighbouring codes on the query grid
+    fn grid_step(self) -> u8 {
+        GRID_LIMIT / self.mask()
+    }
+
+    /// Largest value of the query grid.
+    /// It is a multiple of the largest code, so that all codes are exactly on the grid.
+    fn grid_max(self) -> f32 {
+        f32::from(self.mask() * self.grid_step())
+    }
+
+    /// Values of the codes on the query grid, indexed by code
+    fn lookup_table(self) -> [u8; 16] {
+        let mut lookup_table = [0; 16];
+        for (code, value) in lookup_table
+            .iter_mut()
+            .enumerate()
+            .take(usize::from(self.mask()) + 1)
+        {
+            *value = code as u8 * self.grid_step();
+        }
+        lookup_table
+    }
+}
+
+/// Scalar quantization with less than 8 bits per dimension.
+///
+/// Vectors are quantized into `2^bits` levels and packed into bytes, queries are quantized
+/// into a finer grid of the same range. On scoring, codes are decoded into the query grid
+/// with a lookup table, so that query and vector values can be compared directly.
+pub struct EncodedVectorsPacked<TStorage: EncodedStorage> {
+    encoded_vectors: TStorage,
+    metadata: Metadata,
+    lookup_table: [u8; 16],
+}
+
+pub struct EncodedQueryPacked {
+    offset: f32,
+    encoded_query: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    packed_type: PackedType,
+    actual_dim: usize,
+    alpha: f32,
+    offset: f32,
+    multiplier: f32,
+    vector_parameters: VectorParameters,
+}
+
+impl<TStorage: EncodedStorage> EncodedVectorsPacked<TStorage> {
+    pub fn encode<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        mut storage_builder: impl EncodedStorageBuilder<TStorage>,
+        vector_parameters: &VectorParameters,
+        packed_type: PackedType,
+        quantile: Option<f32>,
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        let actual_dim = Self::get_actual_dim(vector_parameters, packed_type);
+        let lookup_table = packed_type.lookup_table();
+
+        if vector_parameters.count == 0 {
+            return Ok(EncodedVectorsPacked {
+                encoded_vectors: storage_builder.build(),
+                metadata: Metadata {
+                    packed_type,
+                    actual_dim,
+                    alpha: 0.0,
+                    offset: 0.0,
+                    multiplier: 0.0,
+                    vector_parameters: vector_parameters.clone(),
+                },
+                lookup_table,
+            });
+        }
+
+        debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
+        let (min, max) = quantile
+            .and_then(|quantile| {
+                find_quantile_interval(
+                    orig_data.clone(),
+                    vector_parameters.dim,
+                    vector_parameters.count,
+                    quantile,
+                )
+            })
+            .unwrap_or_else(|| find_min_max_from_iter(orig_data.clone()));
+        let grid_max = packed_type.grid_max();
+        let alpha = (max - min) / grid_max;
+        let offset = min;
+
+        let quantized_vector_size = Self::get_quantized_vector_size(vector_parameters, packed_type);
+        let mut encoded_vector = vec![0; quantized_vector_size];
+        for vector in orig_data {
+            if stopped.load(Ordering::Relaxed) {
+                return Err(EncodingError::Stopped);
+            }
+
+            encoded_vector.fill(0);
+            let (vector_offset_bytes, codes) =
+                encoded_vector.split_at_mut(std::mem::size_of::<f32>());
+
+            let mut sum = 0.0;
+            let mut sum_squares = 0.0;
+            for (i, &value) in vector.as_ref().iter().enumerate() {
+                let grid_value = f32_to_grid(value, alpha, offset, grid_max);
+                let code = (grid_value / f32::from(packed_type.grid_step())).round() as u8;
+                let (byte, shift) = code_position(i, packed_type);
+                codes[byte] |= code << shift;
+
+                let decoded = f32::from(lookup_table[usize::from(code)]);
+                sum += decoded;
+                sum_squares += decoded * decoded;
+            }
+
+            let vector_offset = match vector_parameters.distance_type {
+                DistanceType::Dot => alpha * offset * sum,
+                DistanceType::L1 => 0.0,
+                DistanceType::L2 => alpha * alpha * sum_squares,
+            };
+            let vector_offset = if vector_parameters.invert {
+                -vector_offset
+            } else {
+                vector_offset
+            };
+            vector_offset_bytes.copy_from_slice(&vector_offset.to_ne_bytes());
+            storage_builder.push_vector_data(&encoded_vector);
+        }
+
+        let multiplier = match vector_parameters.distance_type {
+            DistanceType::Dot => alpha * alpha,
+            DistanceType::L1 => alpha,
+            DistanceType::L2 => -2.0 * alpha * alpha,
+        };
+        let multiplier = if vector_parameters.invert {
+            -multiplier
+        } else {
+            multiplier
+        };
+
+        Ok(EncodedVectorsPacked {
+            encoded_vectors: storage_builder.build(),
+            metadata: Metadata {
+                packed_type,
+                actual_dim,
+                alpha,
+                offset,
+                multiplier,
+                vector_parameters: vector_parameters.clone(),
+            },
+            lookup_table,
+        })
+    }
+
+    pub fn score_point_simple(&self, query: &EncodedQueryPacked, i: u32) -> f32 {
+        let (vector_offset, codes) = self.get_quantized_vector(i);
+        let score = self.score_codes_simple(&query.encoded_query, codes);
+        self.metadata.multiplier * score + query.offset + vector_offset
+    }
+
+    /// Raw score of the query grid values against packed codes:
+    /// sum of products for dot and L2, sum of absolute differences for L1.
+    fn score_codes(&self, query: &[u8], codes: &[u8]) -> f32 {
+        #[cfg(any(
+            target_arch = "x86_64",
+            all(target_arch = "aarch64", target_feature = "neon")
+        ))]
+        let (q_ptr, v_ptr, lut_ptr, bits, blocks) = (
+            query.as_ptr(),
+            codes.as_ptr(),
+            self.lookup_table.as_ptr(),
+            self.metadata.packed_type.bits() as u32,
+            (codes.len() / BLOCK_SIZE) as u32,
+        );
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx2") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_packed_avx(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_packed_avx(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                };
+            }
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("ssse3") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_packed_sse(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_packed_sse(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            unsafe {
+                return match self.metadata.vector_parameters.distance_type {
+                    DistanceType::Dot | DistanceType::L2 => {
+                        impl_score_dot_packed_neon(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                    DistanceType::L1 => {
+                        impl_score_l1_packed_neon(q_ptr, v_ptr, lut_ptr, bits, blocks)
+                    }
+                };
+            }
+        }
+
+        self.score_codes_simple(query, codes)
+    }
+
+    fn score_codes_simple(&self, query: &[u8], codes: &[u8]) -> f32 {
+        let distance_type = self.metadata.vector_parameters.distance_type;
+        let mut score = 0u64;
+        self.for_each_decoded(codes, |i, value| {
+            let query_value = query[i];
+            score += match distance_type {
+                DistanceType::Dot | DistanceType::L2 => u64::from(query_value) * u64::from(value),
+                DistanceType::L1 => u64::from(query_value.abs_diff(value)),
+            };
+        });
+        score as f32
+    }
+
+    /// Decode packed codes into the values on the query grid
+    fn decode_codes(&self, codes: &[u8]) -> Vec<u8> {
+        let mut decoded = vec![0; self.metadata.actual_dim];
+        self.for_each_decoded(codes, |i, value| decoded[i] = value);
+        decoded
+    }
+
+    fn for_each_decoded(&self, codes: &[u8], mut f: impl FnMut(usize, u8)) {
+        let packed_type = self.metadata.packed_type;
+        let mask = packed_type.mask();
+        for (block_index, block) in codes.chunks_exact(BLOCK_SIZE).enumerate() {
+            let block_start = block_index * packed_type.block_dim();
+            for part in 0..packed_type.codes_per_byte() {
+                let shift = part * packed_type.bits();
+                let part_start = block_start + part * BLOCK_SIZE;
+                for (j, &byte) in block.iter().enumerate() {
+                    let code = (byte >> shift) & mask;
+                    f(part_start + j, self.lookup_table[usize::from(code)]);
+                }
+            }
+        }
+    }
+
+    pub fn get_quantized_vector(&self, i: u32) -> (f32, &[u8]) {
+        let vector_data_size = Self::get_quantized_vector_size(
+            &self.metadata.vector_parameters,
+            self.metadata.packed_type,
+        );
+        let vector_data = self
+            .encoded_vectors
+            .get_vector_data(i as usize, vector_data_size);
+        let (vector_offset, codes) = vector_data.split_at(std::mem::size_of::<f32>());
+        let vector_offset = f32::from_ne_bytes(vector_offset.try_into().unwrap());
+        (vector_offset, codes)
+    }
+
+    pub fn get_quantized_vector_size(
+        vector_parameters: &VectorParameters,
+        packed_type: PackedType,
+    ) -> usize {
+        let actual_dim = Self::get_actual_dim(vector_parameters, packed_type);
+        actual_dim / packed_type.codes_per_byte() + std::mem::size_of::<f32>()
+    }
+
+    /// Dimension, padded to whole blocks of packed codes
+    pub fn get_actual_dim(vector_parameters: &VectorParameters, packed_type: PackedType) -> usize {
+        vector_parameters
+            .dim
+            .next_multiple_of(packed_type.block_dim())
+    }
+
+    /// Constant part of the score, which is not included into vector and query offsets
+    fn get_diff(&self) -> f32 {
+        let diff = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => {
+                self.metadata.vector_parameters.dim as f32
+                    * self.metadata.offset
+                    * self.metadata.offset
+            }
+            DistanceType::L1 | DistanceType::L2 => 0.0,
+        };
+        if self.metadata.vector_parameters.invert {
+            -diff
+        } else {
+            diff
+        }
+    }
+
+    pub fn get_packed_type(&self) -> PackedType {
+        self.metadata.packed_type
+    }
+
+    pub fn vectors_count(&self) -> usize {
+        self.metadata.vector_parameters.count
+    }
+}
+
+impl<TStorage: EncodedStorage> EncodedVectors<EncodedQueryPacked>
+    for EncodedVectorsPacked<TStorage>
+{
+    fn save(&self, data_path: &Path, meta_path: &Path) -> std::io::Result<()> {
+        meta_path.parent().map(std::fs::create_dir_all);
+        atomic_save_json(meta_path, &self.metadata)?;
+
+        data_path.parent().map(std::fs::create_dir_all);
+        self.encoded_vectors.save_to_file(data_path)?;
+        Ok(())
+    }
+
+    fn load(
+        data_path: &Path,
+        meta_path: &Path,
+        vector_parameters: &VectorParameters,
+    ) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(meta_path)?;
+        let metadata: Metadata = serde_json::from_str(&contents)?;
+        let quantized_vector_size =
+            Self::get_quantized_vector_size(vector_parameters, metadata.packed_type);
+        let encoded_vectors =
+            TStorage::from_file(data_path, quantized_vector_size, vector_parameters.count)?;
+        let result = Self {
+            encoded_vectors,
+            lookup_table: metadata.packed_type.lookup_table(),
+            metadata,
+        };
+        Ok(result)
+    }
+
+    fn encode_query(&self, query: &[f32]) -> EncodedQueryPacked {
+        let Metadata {
+            packed_type,
+            alpha,
+            offset,
+            ..
+        } = self.metadata;
+        let grid_max = packed_type.grid_max();
+        let mut encoded_query = vec![0; self.metadata.actual_dim];
+        for (encoded, &value) in encoded_query.iter_mut().zip(query) {
+            *encoded = f32_to_grid(value, alpha, offset, grid_max).round() as u8;
+        }
+
+        let query_offset = match self.metadata.vector_parameters.distance_type {
+            DistanceType::Dot => {
+                encoded_query.iter().map(|&x| f32::from(x)).sum::<f32>() * alpha * offset
+                    + self.metadata.vector_parameters.dim as f32 * offset * offset
+            }
+            DistanceType::L1 => 0.0,
+            DistanceType::L2 => {
+                encoded_query
+                    .iter()
+                    .map(|&x| f32::from(x) * f32::from(x))
+                    .sum::<f32>()
+                    * alpha
+                    * alpha
+            }
+        };
+        let query_offset = if self.metadata.vector_parameters.invert {
+            -query_offset
+        } else {
+            query_offset
+        };
+
+        EncodedQueryPacked {
+            offset: query_offset,
+            encoded_query,
+        }
+    }
+
+    fn score_point(
+        &self,
+        query: &EncodedQueryPacked,
+        i: u32,
+        hw_counter: &HardwareCounterCell,
+    ) -> f32 {
+        hw_counter
+            .cpu_counter()
+            .incr_delta(self.metadata.vector_parameters.dim);
+
+        let (vector_offset, codes) = self.get_quantized_vector(i);
+        let score = self.score_codes(&query.encoded_query, codes);
+        self.metadata.multiplier * score + query.offset + vector_offset
+    }
+
+    fn score_internal(&self, i: u32, j: u32, hw_counter: &HardwareCounterCell) -> f32 {
+        hw_counter
+            .cpu_counter()
+            .incr_delta(self.metadata.vector_parameters.dim);
+
+        let (offset_i, codes_i) = self.get_quantized_vector(i);
+        let (offset_j, codes_j) = self.get_quantized_vector(j);
+        // Codes are exactly representable on the query grid
+        let decoded_i = self.decode_codes(codes_i);
+        let score = self.score_codes(&decoded_i, codes_j);
+        self.metadata.multiplier * score + offset_i + offset_j + self.get_diff()
+    }
+}
+
+fn f32_to_grid(value: f32, alpha: f32, offset: f32, grid_max: f32) -> f32 {
+    ((value - offset) / alpha).clamp(0.0, grid_max)
+}
+
+/// Byte index and bit shift of the code of `i`-th dimension
+fn code_position(i: usize, packed_type: PackedType) -> (usize, usize) {
+    let block_dim = packed_type.block_dim();
+    let in_block = i % block_dim;
+    let byte = i / block_dim * BLOCK_SIZE + in_block % BLOCK_SIZE;
+    let shift = in_block / BLOCK_SIZE * packed_type.bits();
+    (byte, shift)
+}
+
+#[cfg(target_arch = "x86_64")]
+extern "C" {
+    fn impl_score_dot_packed_avx(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+    fn impl_score_l1_packed_avx(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+
+    fn impl_score_dot_packed_sse(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+    fn impl_score_l1_packed_sse(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+extern "C" {
+    fn impl_score_dot_packed_neon(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+    fn impl_score_l1_packed_neon(
+        query_ptr: *const u8,
+        vector_ptr: *const u8,
+        lookup_table: *const u8,
+        bits: u32,
+        blocks: u32,
+    ) -> f32;
+}
@@ -13,8 +13,11 @@ use io::file_operations::atomic_save_json;
 use serde::{Deserialize, Serialize};
 
 use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
-use crate::encoded_vectors::{validate_vector_parameters, EncodedVectors, VectorParameters};
+use crate::encoded_vectors::{
+    validate_vector_parameters, DistanceType, EncodedVectors, VectorParameters,
+};
 use crate::kmeans::kmeans;
+use crate::opq::{rotate, train_rotation, OPQ_SAMPLE_SIZE};
 use crate::{ConditionalVariable, EncodingError};
 
 pub const KMEANS_SAMPLE_SIZE: usize = 10_000;
@@ -39,6 +42,9 @@ pub struct Metadata {
     pub centroids: Vec<Vec<f32>>,
     pub vector_division: Vec<Range<usize>>,
     pub vector_parameters: VectorParameters,
+    /// Row-major orthogonal matrix, applied to vectors before encoding (OPQ)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<Vec<f32>>,
 }
 
 impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
@@ -93,6 +99,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
                     centroids,
                     vector_division,
                     vector_parameters: vector_parameters.clone(),
+                    rotation: None,
                 },
             })
         } else {
@@ -100,6 +107,84 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
         }
     }
 
+    /// Encode vector data using optimized product quantization (OPQ).
+    ///
+    /// Before encoding, vectors are rotated by a learned orthogonal matrix,
+    /// which balances variance between chunks and reduces the quantization error.
+    /// Rotation preserves dot product and euclidean distance, so for L1 distance
+    /// and for too small datasets this method falls back to plain product quantization.
+    /// Datasets with fewer vectors than dimensions don't determine the rotation,
+    /// and fall back as well.
+    ///
+    /// Arguments are the same as for [`Self::encode`].
+    pub fn encode_opq<'a>(
+        data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone + Send,
+        storage_builder: impl EncodedStorageBuilder<TStorage> + Send,
+        vector_parameters: &VectorParameters,
+        chunk_size: usize,
+        max_kmeans_threads: usize,
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        if vector_parameters.distance_type == DistanceType::L1
+            || vector_parameters.count <= CENTROIDS_COUNT
+            || vector_parameters.count < vector_parameters.dim
+            || vector_parameters.dim == 0
+        {
+            return Self::encode(
+                data,
+                storage_builder,
+                vector_parameters,
+                chunk_size,
+                max_kmeans_threads,
+                stopped,
+            );
+        }
+
+        let vector_division = Self::get_vector_division(vector_parameters.dim, chunk_size);
+
+        // learn rotation on a random subset of data
+        let sample_size = OPQ_SAMPLE_SIZE.min(vector_parameters.count);
+        let permutor = permutation_iterator::Permutor::new(vector_parameters.count as u64);
+        let mut selected_vectors: Vec<usize> =
+            permutor.map(|i| i as usize).take(sample_size).collect();
+        selected_vectors.sort_unstable();
+
+        let mut sample = Vec::with_capacity(sample_size * vector_parameters.dim);
+        let mut selected_index: usize = 0;
+        for (vector_index, vector_data) in data.clone().enumerate() {
+            if vector_index == selected_vectors[selected_index] {
+                sample.extend_from_slice(vector_data.as_ref());
+                selected_index += 1;
+                if selected_index == sample_size {
+                    break;
+                }
+            }
+        }
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        let rotation = train_rotation(
+            &sample,
+            vector_parameters.dim,
+            &vector_division,
+            CENTROIDS_COUNT,
+            std::cmp::max(1, max_kmeans_threads),
+            stopped,
+        )?;
+
+        let mut result = Self::encode(
+            data.map(|vector| rotate(&rotation, vector.as_ref())),
+            storage_builder,
+            vector_parameters,
+            chunk_size,
+            max_kmeans_threads,
+            stopped,
+        )?;
+        result.metadata.rotation = Some(rotation);
+        Ok(result)
+    }
+
     pub fn get_quantized_vector_size(
         vector_parameters: &VectorParameters,
         chunk_size: usize,
@@ -468,6 +553,15 @@ impl<TStorage: EncodedStorage> EncodedVectors<EncodedQueryPQ> for EncodedVectors
     }
 
     fn encode_query(&self, query: &[f32]) -> EncodedQueryPQ {
+        let rotated_query;
+        let query = match &self.metadata.rotation {
+            Some(rotation) => {
+                rotated_query = rotate(rotation, query);
+                rotated_query.as_slice()
+            }
+            None => query,
+        };
+
         let lut_capacity = self.metadata.vector_division.len() * self.metadata.centroids.len();
         let mut lut = Vec::with_capacity(lut_capacity);
         for range in &self.metadata.vector_division {
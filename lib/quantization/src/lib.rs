@@ -1,9 +1,11 @@
 pub mod encoded_storage;
 pub mod encoded_vectors;
 pub mod encoded_vectors_binary;
+pub mod encoded_vectors_packed;
 pub mod encoded_vectors_pq;
 pub mod encoded_vectors_u8;
 pub mod kmeans;
+pub mod opq;
 pub mod quantile;
 mod utils;
 
@@ -12,6 +14,7 @@ use std::sync::{Arc, Condvar, Mutex};
 
 pub use encoded_storage::{EncodedStorage, EncodedStorageBuilder};
 pub use encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
+pub use encoded_vectors_packed::{EncodedQueryPacked, EncodedVectorsPacked, PackedType};
 pub use encoded_vectors_pq::{EncodedQueryPQ, EncodedVectorsPQ};
 pub use encoded_vectors_u8::{EncodedQueryU8, EncodedVectorsU8};
 
@@ -0,0 +1,310 @@
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use crate::kmeans::kmeans;
+use crate::EncodingError;
+
+/// Count of alternating steps, each step trains codebooks and then updates the rotation
+pub const OPQ_ITERATIONS: usize = 4;
+/// Max iterations of k-means for intermediate codebooks
+pub const OPQ_KMEANS_ITERATIONS: usize = 10;
+/// Max count of vectors, used to learn the rotation
+pub const OPQ_SAMPLE_SIZE: usize = 4096;
+
+/// Each iteration costs two `dim x dim` matrix multiplications, which dominate the training time.
+/// Small singular values grow about 1.5 times per iteration, so singular values
+/// down to `1e-4` of the largest one converge within this limit.
+const POLAR_MAX_ITERATIONS: usize = 32;
+const POLAR_ACCURACY: f32 = 1e-3;
+const POWER_ITERATIONS: usize = 16;
+/// Rows of the left matrix, multiplied together by a single thread
+const MATMUL_ROWS_BLOCK: usize = 16;
+/// Rows of the right matrix, kept in cache while they are multiplied by a block of rows
+const MATMUL_INNER_BLOCK: usize = 64;
+const KMEANS_ACCURACY: f32 = 1e-5;
+
+/// Learn an orthogonal rotation for optimized product quantization (OPQ).
+///
+/// Returns a row-major `dim x dim` orthogonal matrix `R`, vectors are rotated as `x * R`.
+/// Rotation is learned by alternating two steps on the sample:
+/// - train PQ codebooks for the rotated sample and reconstruct it from the codebooks
+/// - find the rotation, which maps the sample closest to the reconstruction,
+///   which is the orthogonal Procrustes problem
+///
+/// Sample must contain more vectors than `centroids_count`.
+pub fn train_rotation(
+    sample: &[f32],
+    dim: usize,
+    vector_division: &[Range<usize>],
+    centroids_count: usize,
+    max_threads: usize,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .thread_name(|idx| format!("opq-{idx}"))
+        .num_threads(max_threads)
+        .build()
+        .map_err(|e| {
+            EncodingError::EncodingError(format!("Failed OPQ training while thread pool init: {e}"))
+        })?;
+
+    let count = sample.len() / dim;
+    debug_assert!(count > centroids_count);
+    let sample_transposed = transpose(sample, count, dim);
+
+    let mut rotation = identity(dim);
+    for _ in 0..OPQ_ITERATIONS {
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        let rotated = matmul(&pool, sample, &rotation, count, dim, dim);
+        let reconstructed = reconstruct(
+            &rotated,
+            dim,
+            vector_division,
+            centroids_count,
+            max_threads,
+            stopped,
+        )?;
+
+        // Rotation `R`, which minimizes `|X * R - Y|`, is the orthogonal factor
+        // of the polar decomposition of `X^T * Y`
+        let correlation = matmul(&pool, &sample_transposed, &reconstructed, dim, count, dim);
+        rotation = orthogonal_polar_factor(&pool, &correlation, dim, stopped)?;
+    }
+
+    Ok(rotation)
+}
+
+/// Rotate vector by the row-major rotation matrix: `vector * rotation`
+pub fn rotate(rotation: &[f32], vector: &[f32]) -> Vec<f32> {
+    let dim = vector.len();
+    let mut result = vec![0.0; dim];
+    for (&value, row) in vector.iter().zip(rotation.chunks_exact(dim)) {
+        for (r, &w) in result.iter_mut().zip(row) {
+            *r += value * w;
+        }
+    }
+    result
+}
+
+/// Train codebooks for each chunk of the data and replace each chunk by its nearest centroid
+fn reconstruct(
+    data: &[f32],
+    dim: usize,
+    vector_division: &[Range<usize>],
+    centroids_count: usize,
+    max_threads: usize,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    let mut reconstructed = vec![0.0; data.len()];
+    for range in vector_division {
+        let chunk_data: Vec<f32> = data
+            .chunks_exact(dim)
+            .flat_map(|vector| &vector[range.clone()])
+            .copied()
+            .collect();
+
+        let centroids = kmeans(
+            &chunk_data,
+            centroids_count,
+            range.len(),
+            OPQ_KMEANS_ITERATIONS,
+            max_threads,
+            KMEANS_ACCURACY,
+            stopped,
+        )?;
+
+        for (chunk, reconstructed_vector) in chunk_data
+            .chunks_exact(range.len())
+            .zip(reconstructed.chunks_exact_mut(dim))
+        {
+            let nearest = centroids
+                .chunks_exact(range.len())
+                .min_by(|a, b| squared_distance(chunk, a).total_cmp(&squared_distance(chunk, b)))
+                .unwrap_or(chunk);
+            reconstructed_vector[range.clone()].copy_from_slice(nearest);
+        }
+    }
+    Ok(reconstructed)
+}
+
+/// Orthogonal factor `U` of the polar decomposition `matrix = U * P`.
+///
+/// Computed with Newton-Schulz iterations `Z = Z * (3I - Z^T * Z) / 2`,
+/// which converge for the matrix normalized by its spectral norm.
+/// Normalization by the Frobenius norm would shrink all singular values by up to `sqrt(dim)`
+/// and take many more iterations to converge.
+pub fn orthogonal_polar_factor(
+    pool: &ThreadPool,
+    matrix: &[f32],
+    dim: usize,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    let norm = spectral_norm(matrix, dim);
+    if norm <= f32::EPSILON {
+        return Ok(identity(dim));
+    }
+
+    // Power iterations underestimate the norm, but the iterations converge
+    // for singular values up to `sqrt(3)`, so a small margin is enough
+    let scale = 1.0 / (1.01 * norm);
+    let mut factor: Vec<f32> = matrix.iter().map(|v| v * scale).collect();
+    let accuracy = POLAR_ACCURACY * (dim as f32).sqrt();
+    for _ in 0..POLAR_MAX_ITERATIONS {
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        let mut step = matmul(pool, &transpose(&factor, dim, dim), &factor, dim, dim, dim);
+        let mut error = 0.0;
+        for (i, row) in step.chunks_exact_mut(dim).enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let target = if i == j { 1.0 } else { 0.0 };
+                error += (*value - target) * (*value - target);
+                *value = if i == j { 1.5 } else { 0.0 } - 0.5 * *value;
+            }
+        }
+        if error.sqrt() < accuracy {
+            break;
+        }
+        factor = matmul(pool, &factor, &step, dim, dim, dim);
+    }
+
+    // Directions with zero singular values do not converge, complete them to an orthogonal matrix
+    orthonormalize_rows(&mut factor, dim);
+    Ok(factor)
+}
+
+/// Estimate the largest singular value of a square matrix with power iterations on `M^T * M`
+fn spectral_norm(matrix: &[f32], dim: usize) -> f32 {
+    let mut vector = vec![1.0 / (dim as f32).sqrt(); dim];
+    let mut norm = 0.0;
+    for _ in 0..POWER_ITERATIONS {
+        let product = transposed_matvec(matrix, &matvec(matrix, &vector, dim), dim);
+        let product_norm = product.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if product_norm <= f32::EPSILON {
+            return 0.0;
+        }
+        // `|M^T * M * v| = sigma^2` for the unit top singular vector `v`
+        norm = product_norm.sqrt();
+        vector = product.into_iter().map(|v| v / product_norm).collect();
+    }
+    norm
+}
+
+/// `matrix * vector` for a row-major square matrix
+fn matvec(matrix: &[f32], vector: &[f32], dim: usize) -> Vec<f32> {
+    matrix
+        .chunks_exact(dim)
+        .map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+/// `matrix^T * vector` for a row-major square matrix
+fn transposed_matvec(matrix: &[f32], vector: &[f32], dim: usize) -> Vec<f32> {
+    let mut result = vec![0.0; dim];
+    for (&value, row) in vector.iter().zip(matrix.chunks_exact(dim)) {
+        for (r, &w) in result.iter_mut().zip(row) {
+            *r += value * w;
+        }
+    }
+    result
+}
+
+/// Modified Gram-Schmidt process over matrix rows.
+/// Degenerate rows are replaced by basis vectors, orthogonal to the previous rows.
+fn orthonormalize_rows(matrix: &mut [f32], dim: usize) {
+    for i in 0..dim {
+        let (done, rest) = matrix.split_at_mut(i * dim);
+        let row = &mut rest[..dim];
+        if orthonormalize_row(row, done, dim) {
+            continue;
+        }
+        for basis_index in 0..dim {
+            row.fill(0.0);
+            row[basis_index] = 1.0;
+            if orthonormalize_row(row, done, dim) {
+                break;
+            }
+        }
+    }
+}
+
+/// Make `row` orthogonal to all `done` rows and normalize it.
+/// Returns false if the row is linearly dependent on the `done` rows.
+fn orthonormalize_row(row: &mut [f32], done: &[f32], dim: usize) -> bool {
+    let initial_norm = row.iter().map(|v| v * v).sum::<f32>().sqrt();
+    for prev in done.chunks_exact(dim) {
+        let projection: f32 = row.iter().zip(prev).map(|(a, b)| a * b).sum();
+        for (r, &p) in row.iter_mut().zip(prev) {
+            *r -= projection * p;
+        }
+    }
+    let norm = row.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm <= 1e-3 * initial_norm || norm <= f32::EPSILON {
+        return false;
+    }
+    row.iter_mut().for_each(|v| *v /= norm);
+    true
+}
+
+fn identity(dim: usize) -> Vec<f32> {
+    let mut result = vec![0.0; dim * dim];
+    for i in 0..dim {
+        result[i * dim + i] = 1.0;
+    }
+    result
+}
+
+fn transpose(matrix: &[f32], rows: usize, columns: usize) -> Vec<f32> {
+    let mut result = vec![0.0; matrix.len()];
+    for (i, row) in matrix.chunks_exact(columns).enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            result[j * rows + i] = value;
+        }
+    }
+    result
+}
+
+/// Multiply row-major matrices `a` (`n x k`) and `b` (`k x m`).
+///
+/// Rows of `b` are processed in blocks, so that each block is reused from cache
+/// by a whole block of rows of `a` instead of being read again for every row.
+fn matmul(pool: &ThreadPool, a: &[f32], b: &[f32], n: usize, k: usize, m: usize) -> Vec<f32> {
+    debug_assert_eq!(a.len(), n * k);
+    debug_assert_eq!(b.len(), k * m);
+    let mut result = vec![0.0; n * m];
+    pool.install(|| {
+        result
+            .par_chunks_mut(MATMUL_ROWS_BLOCK * m)
+            .zip(a.par_chunks(MATMUL_ROWS_BLOCK * k))
+            .for_each(|(result_rows, a_rows)| {
+                for inner_start in (0..k).step_by(MATMUL_INNER_BLOCK) {
+                    let inner_end = (inner_start + MATMUL_INNER_BLOCK).min(k);
+                    let b_block = &b[inner_start * m..inner_end * m];
+                    for (result_row, a_row) in
+                        result_rows.chunks_exact_mut(m).zip(a_rows.chunks_exact(k))
+                    {
+                        for (&value, b_row) in a_row[inner_start..inner_end]
+                            .iter()
+                            .zip(b_block.chunks_exact(m))
+                        {
+                            for (r, &w) in result_row.iter_mut().zip(b_row) {
+                                *r += value * w;
+                            }
+                        }
+                    }
+                }
+            });
+    });
+    result
+}
+
+fn squared_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
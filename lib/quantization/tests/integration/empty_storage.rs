@@ -3,7 +3,7 @@ mod tests {
     use std::sync::atomic::AtomicBool;
 
     use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
-    use quantization::encoded_vectors_binary::EncodedVectorsBin;
+    use quantization::encoded_vectors_binary::{EncodedVectorsBin, Encoding, QueryEncoding};
     use quantization::encoded_vectors_u8::EncodedVectorsU8;
     use quantization::EncodedVectorsPQ;
     use tempfile::Builder;
@@ -101,6 +101,8 @@ mod tests {
             vector_data.iter(),
             Vec::<u8>::new(),
             &vector_parameters,
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
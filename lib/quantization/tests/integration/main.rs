@@ -11,6 +11,8 @@ pub mod test_binary;
 #[cfg(test)]
 pub mod test_neon;
 #[cfg(test)]
+pub mod test_packed;
+#[cfg(test)]
 pub mod test_pq;
 #[cfg(test)]
 pub mod test_simple;
@@ -4,7 +4,9 @@ mod tests {
 
     use common::counter::hardware_counter::HardwareCounterCell;
     use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
-    use quantization::encoded_vectors_binary::{BitsStoreType, EncodedVectorsBin};
+    use quantization::encoded_vectors_binary::{
+        BitsStoreType, EncodedVectorsBin, Encoding, QueryEncoding,
+    };
     use rand::{Rng, SeedableRng};
 
     use crate::metrics::{dot_similarity, l1_similarity, l2_similarity};
@@ -53,6 +55,8 @@ mod tests {
                 distance_type: DistanceType::Dot,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -99,6 +103,8 @@ mod tests {
                 distance_type: DistanceType::Dot,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -145,6 +151,8 @@ mod tests {
                 distance_type: DistanceType::Dot,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -188,6 +196,8 @@ mod tests {
                 distance_type: DistanceType::Dot,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -230,6 +240,8 @@ mod tests {
                 distance_type: DistanceType::L1,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -291,6 +303,8 @@ mod tests {
                 distance_type: DistanceType::L1,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -352,6 +366,8 @@ mod tests {
                 distance_type: DistanceType::L1,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -410,6 +426,8 @@ mod tests {
                 distance_type: DistanceType::L1,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -468,6 +486,8 @@ mod tests {
                 distance_type: DistanceType::L2,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -529,6 +549,8 @@ mod tests {
                 distance_type: DistanceType::L2,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -590,6 +612,8 @@ mod tests {
                 distance_type: DistanceType::L2,
                 invert: false,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -648,6 +672,8 @@ mod tests {
                 distance_type: DistanceType::L2,
                 invert: true,
             },
+            Encoding::OneBit,
+            QueryEncoding::SameAsStorage,
             &AtomicBool::new(false),
         )
         .unwrap();
@@ -675,4 +701,190 @@ mod tests {
 
         assert_eq!(sorted_original_indices, sorted_indices);
     }
+
+    fn generate_normal_vector(dim: usize, rng: &mut rand::rngs::StdRng) -> Vec<f32> {
+        // Box-Muller transform
+        (0..dim)
+            .map(|_| {
+                let u1: f32 = rng.random_range(f32::EPSILON..1.0);
+                let u2: f32 = rng.random();
+                (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+            })
+            .collect()
+    }
+
+    /// Share of the exact top, found in the top of quantized scores with oversampling
+    fn encoding_recall(encoding: Encoding, query_encoding: QueryEncoding) -> f32 {
+        let vectors_count = 2000;
+        let vector_dim = 64;
+        let queries_count = 50;
+        let top = 10;
+        let oversampled_top = 30;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data: Vec<Vec<f32>> = (0..vectors_count)
+            .map(|_| generate_normal_vector(vector_dim, &mut rng))
+            .collect();
+
+        let encoded = EncodedVectorsBin::<u128, _>::encode(
+            vector_data.iter(),
+            Vec::<u8>::new(),
+            &VectorParameters {
+                dim: vector_dim,
+                count: vectors_count,
+                distance_type: DistanceType::Dot,
+                invert: false,
+            },
+            encoding,
+            query_encoding,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let counter = HardwareCounterCell::new();
+        let mut found = 0;
+        for _ in 0..queries_count {
+            let query = generate_normal_vector(vector_dim, &mut rng);
+            let query_encoded = encoded.encode_query(&query);
+
+            let mut original_scores: Vec<_> = vector_data
+                .iter()
+                .enumerate()
+                .map(|(i, vector)| (dot_similarity(&query, vector), i))
+                .collect();
+            original_scores.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            let mut scores: Vec<_> = (0..vectors_count)
+                .map(|i| (encoded.score_point(&query_encoded, i as u32, &counter), i))
+                .collect();
+            scores.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            found += original_scores[..top]
+                .iter()
+                .filter(|(_, i)| scores[..oversampled_top].iter().any(|(_, j)| i == j))
+                .count();
+        }
+        found as f32 / (queries_count * top) as f32
+    }
+
+    #[test]
+    fn test_binary_encodings_recall() {
+        let baseline_recall = encoding_recall(Encoding::OneBit, QueryEncoding::SameAsStorage);
+
+        for (encoding, query_encoding) in [
+            (Encoding::TwoBits, QueryEncoding::SameAsStorage),
+            (Encoding::OneBit, QueryEncoding::Scalar4bits),
+            (Encoding::OneBit, QueryEncoding::Scalar8bits),
+            (Encoding::OneAndHalfBits, QueryEncoding::Scalar8bits),
+            (Encoding::TwoBits, QueryEncoding::Scalar4bits),
+            (Encoding::TwoBits, QueryEncoding::Scalar8bits),
+        ] {
+            let recall = encoding_recall(encoding, query_encoding);
+            assert!(
+                recall > baseline_recall,
+                "{encoding:?} {query_encoding:?}: {recall} <= {baseline_recall}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_binary_encodings_self_score() {
+        let vectors_count = 16;
+        for vector_dim in [1, 7, 33, 130] {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+            let vector_data: Vec<Vec<f32>> = (0..vectors_count)
+                .map(|_| generate_normal_vector(vector_dim, &mut rng))
+                .collect();
+
+            for encoding in [
+                Encoding::OneBit,
+                Encoding::OneAndHalfBits,
+                Encoding::TwoBits,
+            ] {
+                let encoded = EncodedVectorsBin::<u8, _>::encode(
+                    vector_data.iter(),
+                    Vec::<u8>::new(),
+                    &VectorParameters {
+                        dim: vector_dim,
+                        count: vectors_count,
+                        distance_type: DistanceType::Dot,
+                        invert: false,
+                    },
+                    encoding,
+                    QueryEncoding::SameAsStorage,
+                    &AtomicBool::new(false),
+                )
+                .unwrap();
+
+                // All encoded bits match, so the score is the number of bits
+                let bits_count = encoded.get_bits_count() as f32;
+                assert_eq!(bits_count, encoding.get_bits_count(vector_dim) as f32);
+                let counter = HardwareCounterCell::new();
+                for (index, vector) in vector_data.iter().enumerate() {
+                    let query_encoded = encoded.encode_query(vector);
+                    let score = encoded.score_point(&query_encoded, index as u32, &counter);
+                    assert_eq!(score, bits_count);
+                    let score = encoded.score_internal(index as u32, index as u32, &counter);
+                    assert_eq!(score, bits_count);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binary_encodings_save_load() {
+        let dir = tempfile::Builder::new()
+            .prefix("storage_dir")
+            .tempdir()
+            .unwrap();
+
+        let vectors_count = 64;
+        let vector_dim = 65;
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            count: vectors_count,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data: Vec<Vec<f32>> = (0..vectors_count)
+            .map(|_| generate_normal_vector(vector_dim, &mut rng))
+            .collect();
+        let query = generate_normal_vector(vector_dim, &mut rng);
+
+        let encoded = EncodedVectorsBin::<u128, _>::encode(
+            vector_data.iter(),
+            Vec::<u8>::new(),
+            &vector_parameters,
+            Encoding::TwoBits,
+            QueryEncoding::Scalar4bits,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let data_path = dir.path().join("data.bin");
+        let meta_path = dir.path().join("meta.json");
+        encoded
+            .save(data_path.as_path(), meta_path.as_path())
+            .unwrap();
+
+        let loaded = EncodedVectorsBin::<u128, Vec<u8>>::load(
+            data_path.as_path(),
+            meta_path.as_path(),
+            &vector_parameters,
+        )
+        .unwrap();
+        assert_eq!(loaded.get_encoding(), Encoding::TwoBits);
+
+        let counter = HardwareCounterCell::new();
+        let query_encoded = encoded.encode_query(&query);
+        let loaded_query_encoded = loaded.encode_query(&query);
+        for index in 0..vectors_count as u32 {
+            assert_eq!(
+                encoded.score_point(&query_encoded, index, &counter),
+                loaded.score_point(&loaded_query_encoded, index, &counter),
+            );
+        }
+    }
 }
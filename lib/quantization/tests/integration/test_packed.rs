@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use common::counter::hardware_counter::HardwareCounterCell;
+    use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
+    use quantization::encoded_vectors_packed::{EncodedVectorsPacked, PackedType};
+    use rand::{Rng, SeedableRng};
+    use tempfile::Builder;
+
+    use crate::metrics::{dot_similarity, l1_similarity, l2_similarity};
+
+    fn original_score(distance_type: DistanceType, invert: bool, a: &[f32], b: &[f32]) -> f32 {
+        let score = match distance_type {
+            DistanceType::Dot => dot_similarity(a, b),
+            DistanceType::L1 => l1_similarity(a, b),
+            DistanceType::L2 => l2_similarity(a, b),
+        };
+        if invert {
+            -score
+        } else {
+            score
+        }
+    }
+
+    fn check_packed_scoring(
+        packed_type: PackedType,
+        distance_type: DistanceType,
+        invert: bool,
+        vector_dim: usize,
+    ) {
+        let vectors_count = 129;
+        // One quantization step is a third of the range for 2 bits
+        let error = vector_dim as f32
+            * match packed_type {
+                PackedType::Int4 => 0.1,
+                PackedType::Int2 => 0.4,
+            };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data: Vec<Vec<f32>> = (0..vectors_count)
+            .map(|_| {
+                (0..vector_dim)
+                    .map(|_| rng.random_range(-1.0..=1.0))
+                    .collect()
+            })
+            .collect();
+        let query: Vec<f32> = (0..vector_dim)
+            .map(|_| rng.random_range(-1.0..=1.0))
+            .collect();
+
+        let encoded = EncodedVectorsPacked::encode(
+            vector_data.iter(),
+            Vec::<u8>::new(),
+            &VectorParameters {
+                dim: vector_dim,
+                count: vectors_count,
+                distance_type,
+                invert,
+            },
+            packed_type,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let query_packed = encoded.encode_query(&query);
+
+        let counter = HardwareCounterCell::new();
+        for (index, vector) in vector_data.iter().enumerate() {
+            let score = encoded.score_point(&query_packed, index as u32, &counter);
+            let score_simple = encoded.score_point_simple(&query_packed, index as u32);
+            assert!((score - score_simple).abs() < 1e-3 * score.abs().max(1.0));
+
+            let orginal_score = original_score(distance_type, invert, &query, vector);
+            assert!((score - orginal_score).abs() < error);
+
+            let score_internal = encoded.score_internal(0, index as u32, &counter);
+            let orginal_score = original_score(distance_type, invert, &vector_data[0], vector);
+            assert!((score_internal - orginal_score).abs() < error);
+        }
+    }
+
+    #[test]
+    fn test_packed_scoring() {
+        for packed_type in [PackedType::Int4, PackedType::Int2] {
+            for (distance_type, invert) in [
+                (DistanceType::Dot, false),
+                (DistanceType::L1, true),
+                (DistanceType::L2, true),
+            ] {
+                for vector_dim in [33, 65, 130] {
+                    check_packed_scoring(packed_type, distance_type, invert, vector_dim);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_packed_exact_levels() {
+        // Values on the quantization levels are encoded without loss
+        let vector_dim = 40;
+        let vector_data: Vec<Vec<f32>> = vec![
+            (0..vector_dim).map(|i| (i % 16) as f32).collect(),
+            (0..vector_dim).map(|i| (i * 7 % 16) as f32).collect(),
+        ];
+
+        let encoded = EncodedVectorsPacked::encode(
+            vector_data.iter(),
+            Vec::<u8>::new(),
+            &VectorParameters {
+                dim: vector_dim,
+                count: vector_data.len(),
+                distance_type: DistanceType::L1,
+                invert: false,
+            },
+            PackedType::Int4,
+            None,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let counter = HardwareCounterCell::new();
+        let score = encoded.score_internal(0, 1, &counter);
+        let orginal_score = l1_similarity(&vector_data[0], &vector_data[1]);
+        assert!((score - orginal_score).abs() < 1e-3);
+
+        let query_packed = encoded.encode_query(&vector_data[0]);
+        let score = encoded.score_point(&query_packed, 1, &counter);
+        assert!((score - orginal_score).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_packed_save_load() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+        let vectors_count = 64;
+        let vector_dim = 100;
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            count: vectors_count,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data: Vec<Vec<f32>> = (0..vectors_count)
+            .map(|_| (0..vector_dim).map(|_| rng.random()).collect())
+            .collect();
+        let query: Vec<f32> = (0..vector_dim).map(|_| rng.random()).collect();
+
+        let encoded = EncodedVectorsPacked::encode(
+            vector_data.iter(),
+            Vec::<u8>::new(),
+            &vector_parameters,
+            PackedType::Int2,
+            Some(0.99),
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let data_path = dir.path().join("data.bin");
+        let meta_path = dir.path().join("meta.json");
+        encoded
+            .save(data_path.as_path(), meta_path.as_path())
+            .unwrap();
+
+        let loaded = EncodedVectorsPacked::<Vec<u8>>::load(
+            data_path.as_path(),
+            meta_path.as_path(),
+            &vector_parameters,
+        )
+        .unwrap();
+        assert_eq!(loaded.get_packed_type(), PackedType::Int2);
+
+        let counter = HardwareCounterCell::new();
+        let query_packed = encoded.encode_query(&query);
+        let loaded_query_packed = loaded.encode_query(&query);
+        for index in 0..vectors_count as u32 {
+            assert_eq!(
+                encoded.score_point(&query_packed, index, &counter),
+                loaded.score_point(&loaded_query_packed, index, &counter),
+            );
+        }
+    }
+}
@@ -5,8 +5,10 @@ mod tests {
 
     use common::counter::hardware_counter::HardwareCounterCell;
     use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
-    use quantization::encoded_vectors_pq::EncodedVectorsPQ;
+    use quantization::encoded_vectors_pq::{EncodedVectorsPQ, CENTROIDS_COUNT};
+    use quantization::opq::{orthogonal_polar_factor, rotate, train_rotation};
     use rand::{Rng, SeedableRng};
+    use tempfile::Builder;
 
     use crate::metrics::{dot_similarity, l1_similarity, l2_similarity};
 
@@ -274,6 +276,270 @@ mod tests {
         }
     }
 
+    /// Vectors with correlated dimensions and unbalanced variance,
+    /// which are hard for plain product quantization
+    fn correlated_vectors(rng: &mut impl Rng, count: usize, dim: usize) -> Vec<Vec<f32>> {
+        let latent_dim = dim / 4;
+        let mixing: Vec<Vec<f32>> = (0..latent_dim)
+            .map(|_| (0..dim).map(|_| rng.random_range(-1.0..1.0)).collect())
+            .collect();
+        (0..count)
+            .map(|_| {
+                let mut vector = vec![0.0; dim];
+                for row in &mixing {
+                    let weight: f32 = rng.random_range(-1.0..1.0);
+                    for (v, m) in vector.iter_mut().zip(row) {
+                        *v += weight * m;
+                    }
+                }
+                // concentrate variance in the first chunk
+                vector.iter_mut().take(4).for_each(|v| *v *= 4.0);
+                vector
+            })
+            .collect()
+    }
+
+    fn mean_score_error(
+        encoded: &EncodedVectorsPQ<Vec<u8>>,
+        vector_data: &[Vec<f32>],
+        query: &[f32],
+    ) -> f32 {
+        let counter = HardwareCounterCell::new();
+        let query_encoded = encoded.encode_query(query);
+        let total: f32 = vector_data
+            .iter()
+            .enumerate()
+            .map(|(index, vector)| {
+                let score = encoded.score_point(&query_encoded, index as u32, &counter);
+                (score - l2_similarity(query, vector)).abs()
+            })
+            .sum();
+        total / vector_data.len() as f32
+    }
+
+    #[test]
+    fn test_opq_rotation_orthogonal() {
+        let vectors_count = 300;
+        let vector_dim = 32;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let sample: Vec<f32> = correlated_vectors(&mut rng, vectors_count, vector_dim)
+            .into_iter()
+            .flatten()
+            .collect();
+        let vector_division: Vec<_> = (0..vector_dim).step_by(4).map(|i| i..i + 4).collect();
+
+        let rotation = train_rotation(
+            &sample,
+            vector_dim,
+            &vector_division,
+            CENTROIDS_COUNT,
+            1,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        // R * R^T = I
+        for (i, row_i) in rotation.chunks_exact(vector_dim).enumerate() {
+            for (j, row_j) in rotation.chunks_exact(vector_dim).enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot_similarity(row_i, row_j) - expected).abs() < 1e-3);
+            }
+        }
+
+        // rotation preserves norms
+        let vector = &sample[..vector_dim];
+        let rotated = rotate(&rotation, vector);
+        let norm = dot_similarity(vector, vector);
+        assert!((dot_similarity(&rotated, &rotated) - norm).abs() < 1e-3 * norm);
+    }
+
+    fn random_unit_vector(rng: &mut impl Rng, dim: usize) -> Vec<f32> {
+        let vector: Vec<f32> = (0..dim).map(|_| rng.random_range(-1.0..1.0)).collect();
+        let norm = dot_similarity(&vector, &vector).sqrt();
+        vector.into_iter().map(|v| v / norm).collect()
+    }
+
+    /// Polar factor of `H1 * S * H2` with Householder reflections `H = I - 2 * u * u^T`
+    /// and a positive diagonal `S` is `H1 * H2`
+    fn check_polar_factor(dim: usize) {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let u1 = random_unit_vector(&mut rng, dim);
+        let u2 = random_unit_vector(&mut rng, dim);
+        // singular values from 1 down to 1e-3
+        let scales: Vec<f32> = (0..dim)
+            .map(|i| 10f32.powf(-3.0 * i as f32 / dim as f32))
+            .collect();
+
+        // H1 * S * H2 = S - 2 * u1 * (S * u1)^T - 2 * (H1 * S * u2) * u2^T
+        let scaled_u2: Vec<f32> = scales.iter().zip(&u2).map(|(s, u)| s * u).collect();
+        let projection = dot_similarity(&u1, &scaled_u2);
+        let w: Vec<f32> = scaled_u2
+            .iter()
+            .zip(&u1)
+            .map(|(su, u)| su - 2.0 * projection * u)
+            .collect();
+        let mut matrix = vec![0.0; dim * dim];
+        for i in 0..dim {
+            for j in 0..dim {
+                let diagonal = if i == j { scales[i] } else { 0.0 };
+                matrix[i * dim + j] =
+                    diagonal - 2.0 * u1[i] * u1[j] * scales[j] - 2.0 * w[i] * u2[j];
+            }
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new().build().unwrap();
+        let factor = orthogonal_polar_factor(&pool, &matrix, dim, &AtomicBool::new(false)).unwrap();
+
+        // H1 * H2 = I - 2 * u1 * u1^T - 2 * u2 * u2^T + 4 * (u1 . u2) * u1 * u2^T
+        let cosine = dot_similarity(&u1, &u2);
+        for (i, row) in factor.chunks_exact(dim).enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let identity = if i == j { 1.0 } else { 0.0 };
+                let expected = identity - 2.0 * u1[i] * u1[j] - 2.0 * u2[i] * u2[j]
+                    + 4.0 * cosine * u1[i] * u2[j];
+                assert!(
+                    (value - expected).abs() < 1e-2,
+                    "{i} {j}: {value} {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_opq_polar_factor() {
+        check_polar_factor(128);
+    }
+
+    #[ignore = "too slow without optimizations, run in release mode"]
+    #[test]
+    fn test_opq_polar_factor_high_dim() {
+        let dim = 1536;
+        let start = std::time::Instant::now();
+        check_polar_factor(dim);
+        // Converges in about 20 iterations of two `dim x dim` multiplications each
+        assert!(
+            start.elapsed() < Duration::from_secs(120),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn test_opq_l2() {
+        let vectors_count = 1000;
+        let vector_dim = 32;
+        let chunk_size = 4;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = correlated_vectors(&mut rng, vectors_count, vector_dim);
+        let query = correlated_vectors(&mut rng, 1, vector_dim).pop().unwrap();
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            count: vectors_count,
+            distance_type: DistanceType::L2,
+            invert: false,
+        };
+
+        let encoded_pq = EncodedVectorsPQ::encode(
+            vector_data.iter(),
+            vec![],
+            &vector_parameters,
+            chunk_size,
+            1,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let encoded_opq = EncodedVectorsPQ::encode_opq(
+            vector_data.iter(),
+            vec![],
+            &vector_parameters,
+            chunk_size,
+            1,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        assert!(encoded_opq.get_metadata().rotation.is_some());
+
+        let pq_error = mean_score_error(&encoded_pq, &vector_data, &query);
+        let opq_error = mean_score_error(&encoded_opq, &vector_data, &query);
+        assert!(opq_error < pq_error, "{opq_error} >= {pq_error}");
+    }
+
+    #[test]
+    fn test_opq_fallback() {
+        // Rotation does not preserve L1 distance, plain PQ is used
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = correlated_vectors(&mut rng, VECTORS_COUNT, 32);
+        let encoded = EncodedVectorsPQ::<Vec<u8>>::encode_opq(
+            vector_data.iter(),
+            vec![],
+            &VectorParameters {
+                dim: 32,
+                count: VECTORS_COUNT,
+                distance_type: DistanceType::L1,
+                invert: true,
+            },
+            4,
+            1,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        assert!(encoded.get_metadata().rotation.is_none());
+    }
+
+    #[test]
+    fn test_opq_save_load() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+
+        let vectors_count = 400;
+        let vector_dim = 16;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let vector_data = correlated_vectors(&mut rng, vectors_count, vector_dim);
+        let query = correlated_vectors(&mut rng, 1, vector_dim).pop().unwrap();
+        let vector_parameters = VectorParameters {
+            dim: vector_dim,
+            count: vectors_count,
+            distance_type: DistanceType::Dot,
+            invert: false,
+        };
+
+        let encoded = EncodedVectorsPQ::encode_opq(
+            vector_data.iter(),
+            vec![],
+            &vector_parameters,
+            2,
+            1,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+
+        let data_path = dir.path().join("data.bin");
+        let meta_path = dir.path().join("meta.json");
+        encoded
+            .save(data_path.as_path(), meta_path.as_path())
+            .unwrap();
+
+        let loaded = EncodedVectorsPQ::<Vec<u8>>::load(
+            data_path.as_path(),
+            meta_path.as_path(),
+            &vector_parameters,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.get_metadata().rotation,
+            encoded.get_metadata().rotation,
+        );
+
+        let counter = HardwareCounterCell::new();
+        let query_encoded = encoded.encode_query(&query);
+        let loaded_query_encoded = loaded.encode_query(&query);
+        for index in 0..vectors_count as u32 {
+            assert_eq!(
+                encoded.score_point(&query_encoded, index, &counter),
+                loaded.score_point(&loaded_query_encoded, index, &counter),
+            );
+        }
+    }
+
     // ignore this test because it requires long time
     // this test should be started separately of with `--test-threads=1` flag
     // because `num_threads::num_threads()` is used to check that all encode threads finished
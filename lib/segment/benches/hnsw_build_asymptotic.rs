@@ -7,7 +7,7 @@ use itertools::Itertools;
 use rand::{rng, Rng};
 use segment::data_types::vectors::VectorElementType;
 use segment::fixtures::index_fixtures::{random_vector, FakeFilterContext, TestRawScorerProducer};
-use segment::index::hnsw_index::graph_layers::GraphLayers;
+use segment::index::hnsw_index::graph_layers::{GraphLayers, SearchAlgorithm};
 use segment::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use segment::index::hnsw_index::graph_links::GraphLinksFormat;
 use segment::index::hnsw_index::point_scorer::FilteredScorer;
@@ -59,7 +59,7 @@ fn hnsw_build_asymptotic(c: &mut Criterion) {
             let query = random_vector(&mut rng, DIM);
             let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
             let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
-            graph_layers.search(TOP, EF, scorer, None);
+            graph_layers.search(TOP, EF, SearchAlgorithm::Hnsw, scorer, None);
         })
     });
 
@@ -68,7 +68,7 @@ fn hnsw_build_asymptotic(c: &mut Criterion) {
         let query = random_vector(&mut rng, DIM);
         let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
         let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
-        graph_layers.search(TOP, EF, scorer, None);
+        graph_layers.search(TOP, EF, SearchAlgorithm::Hnsw, scorer, None);
     }
 
     let (vector_holder, graph_layers) = build_index::<CosineMetric>(NUM_VECTORS * 10);
@@ -79,7 +79,7 @@ fn hnsw_build_asymptotic(c: &mut Criterion) {
             let query = random_vector(&mut rng, DIM);
             let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
             let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
-            graph_layers.search(TOP, EF, scorer, None);
+            graph_layers.search(TOP, EF, SearchAlgorithm::Hnsw, scorer, None);
         })
     });
 
@@ -102,7 +102,7 @@ fn hnsw_build_asymptotic(c: &mut Criterion) {
         let query = random_vector(&mut rng, DIM);
         let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
         let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
-        graph_layers.search(TOP, EF, scorer, None);
+        graph_layers.search(TOP, EF, SearchAlgorithm::Hnsw, scorer, None);
     }
 }
 
@@ -20,7 +20,7 @@ use segment::index::sparse_index::sparse_vector_index::{
 use segment::index::struct_payload_index::StructPayloadIndex;
 use segment::index::VectorIndex;
 use segment::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
-use segment::types::VectorStorageDatatype;
+use segment::types::{SparseDistance, VectorStorageDatatype};
 use segment::vector_storage::sparse::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use segment::vector_storage::VectorStorage;
 use sparse::common::sparse_vector_fixture::random_sparse_vector;
@@ -58,7 +58,8 @@ fn sparse_vector_index_build_benchmark(c: &mut Criterion) {
     let wrapped_payload_index = Arc::new(AtomicRefCell::new(payload_index));
 
     let db = open_db(storage_dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let mut vector_storage = open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &stopped).unwrap();
+    let mut vector_storage =
+        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, SparseDistance::Dot, &stopped).unwrap();
 
     // add points to storage only once
     for idx in 0..NUM_VECTORS {
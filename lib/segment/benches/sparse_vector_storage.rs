@@ -8,6 +8,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use segment::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+use segment::types::SparseDistance;
 use segment::vector_storage::sparse::mmap_sparse_vector_storage::MmapSparseVectorStorage;
 use segment::vector_storage::sparse::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use segment::vector_storage::VectorStorage;
@@ -26,7 +27,7 @@ fn sparse_vector_storage_benchmark(c: &mut Criterion) {
     let db = open_db(storage_dir.path(), &[DB_VECTOR_CF]).unwrap();
 
     let mut rocksdb_sparse_vector_storage =
-        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &stopped).unwrap();
+        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, SparseDistance::Dot, &stopped).unwrap();
 
     group.bench_function("insert-rocksdb", |b| {
         b.iter(|| {
@@ -52,7 +53,7 @@ fn sparse_vector_storage_benchmark(c: &mut Criterion) {
 
     let storage_dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
     let mut mmap_sparse_vector_storage =
-        MmapSparseVectorStorage::open_or_create(storage_dir.path()).unwrap();
+        MmapSparseVectorStorage::open_or_create(storage_dir.path(), SparseDistance::Dot).unwrap();
 
     group.bench_function("insert-mmap-compression", |b| {
         b.iter(|| {
@@ -0,0 +1,450 @@
+//! Maximal Marginal Relevance (MMR) is a method for diversifying a list of results.
+//! Points are selected greedily, so that each next point is similar to the query,
+//! but dissimilar to the points selected before.
+//! See <https://www.cs.cmu.edu/~jgc/publication/The_Use_MMR_Diversity_Based_LTMIR_1998.pdf>
+
+use std::collections::HashSet;
+
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVectorInternal, PackedBits, VectorElementType, VectorElementTypeBit,
+    VectorInternal, VectorRef, WeightedMultiDenseVector,
+};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::types::{Distance, MultiVectorConfig, Order, ScoredPoint, VectorName};
+use crate::vector_storage::query_scorer::score_multi;
+
+/// Equal balance between relevance and diversity
+pub const DEFAULT_MMR_LAMBDA: f32 = 0.5;
+
+/// Select up to `limit` points out of the candidates, in the order of selection.
+///
+/// Each next point maximizes `lambda * sim(query, point) - (1 - lambda) * max(sim(point, selected))`,
+/// so `lambda = 1.0` ranks by relevance only, and `lambda = 0.0` ranks by diversity only.
+///
+/// The score of each selected point is its similarity to the query, candidates with a worse
+/// score than `score_threshold` are not selected.
+/// Candidates are deduplicated by id, and candidates without the `using` vector are skipped.
+/// Multivectors are compared with the comparator of `multi_vector_config`, like in the search.
+#[allow(clippy::too_many_arguments)]
+pub fn maximal_marginal_relevance(
+    candidates: impl IntoIterator<Item = ScoredPoint>,
+    query: &VectorInternal,
+    using: &VectorName,
+    distance: Distance,
+    multi_vector_config: Option<MultiVectorConfig>,
+    lambda: f32,
+    limit: usize,
+    score_threshold: Option<ScoreType>,
+) -> Vec<ScoredPoint> {
+    let query = preprocess(query.clone(), distance);
+    let multi_vector_config = multi_vector_config.unwrap_or_default();
+
+    let mut seen = HashSet::new();
+    let (mut points, vectors): (Vec<_>, Vec<_>) = candidates
+        .into_iter()
+        .filter(|point| seen.insert(point.id))
+        .filter_map(|point| {
+            let vector = point.vector.as_ref()?.get(using)?.to_owned();
+            Some((Some(point), preprocess(vector, distance)))
+        })
+        .unzip();
+
+    let relevance: Vec<ScoreType> = vectors
+        .iter()
+        .map(|vector| {
+            similarity(
+                distance,
+                &multi_vector_config,
+                VectorRef::from(&query),
+                query.weights(),
+                VectorRef::from(vector),
+            )
+        })
+        .collect();
+
+    // Similarity of each candidate to the closest selected point
+    let mut redundancy = vec![ScoreType::NEG_INFINITY; vectors.len()];
+    // Indices of the candidates, which are not selected yet
+    let mut remaining: Vec<usize> = (0..vectors.len())
+        .filter(|&idx| {
+            let score = distance.postprocess_score(relevance[idx]);
+            score_threshold.map_or(true, |threshold| match distance.distance_order() {
+                Order::LargeBetter => score >= threshold,
+                Order::SmallBetter => score <= threshold,
+            })
+        })
+        .collect();
+    let mut selected = Vec::with_capacity(limit.min(vectors.len()));
+
+    while selected.len() < limit && !remaining.is_empty() {
+        let mmr_score = |idx: usize| {
+            if selected.is_empty() {
+                relevance[idx]
+            } else {
+                lambda * relevance[idx] - (1.0 - lambda) * redundancy[idx]
+            }
+        };
+
+        let (best_pos, _) = remaining
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| mmr_score(**a).total_cmp(&mmr_score(**b)))
+            .expect("remaining candidates are not empty");
+        let best = remaining.swap_remove(best_pos);
+
+        for &idx in &remaining {
+            let sim = similarity(
+                distance,
+                &multi_vector_config,
+                VectorRef::from(&vectors[idx]),
+                None,
+                VectorRef::from(&vectors[best]),
+            );
+            redundancy[idx] = redundancy[idx].max(sim);
+        }
+
+        selected.push(best);
+    }
+
+    selected
+        .into_iter()
+        .filter_map(|idx| {
+            let mut point = points[idx].take()?;
+            point.score = distance.postprocess_score(relevance[idx]);
+            Some(point)
+        })
+        .collect()
+}
+
+/// Bring the vector into the form, in which it is compared with the stored vectors
+fn preprocess(vector: VectorInternal, distance: Distance) -> VectorInternal {
+    match vector {
+        VectorInternal::Dense(vector) => VectorInternal::Dense(preprocess_dense(vector, distance)),
+        VectorInternal::Sparse(mut vector) => {
+            if !vector.is_sorted() {
+                vector.sort_by_indices();
+            }
+            if distance == Distance::Cosine {
+                vector.normalize_by(vector.norm());
+            }
+            VectorInternal::Sparse(vector)
+        }
+        VectorInternal::MultiDense(multi_vector) => {
+            VectorInternal::MultiDense(preprocess_multi(multi_vector, distance))
+        }
+        VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, weights }) => {
+            VectorInternal::WeightedMultiDense(WeightedMultiDenseVector {
+                vector: preprocess_multi(vector, distance),
+                weights,
+            })
+        }
+    }
+}
+
+fn preprocess_multi(
+    mut multi_vector: MultiDenseVectorInternal,
+    distance: Distance,
+) -> MultiDenseVectorInternal {
+    for dense in multi_vector.multi_vectors_mut() {
+        let preprocessed = preprocess_dense(dense.to_vec(), distance);
+        dense.copy_from_slice(&preprocessed);
+    }
+    multi_vector
+}
+
+fn preprocess_dense(vector: DenseVector, distance: Distance) -> DenseVector {
+    match distance {
+        Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::preprocess(vector),
+        Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::preprocess(vector),
+        Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::preprocess(vector),
+        Distance::Manhattan => <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector),
+        // Bits are packed on comparison
+        Distance::Hamming | Distance::Jaccard => vector,
+    }
+}
+
+/// Similarity of two preprocessed vectors, larger is more similar
+///
+/// `weights_a` are the weights of the `a` vectors, if `a` is a weighted multivector query.
+fn similarity(
+    distance: Distance,
+    multi_vector_config: &MultiVectorConfig,
+    a: VectorRef,
+    weights_a: Option<&[ScoreType]>,
+    b: VectorRef,
+) -> ScoreType {
+    match (a, b) {
+        (VectorRef::Dense(a), VectorRef::Dense(b)) => match distance {
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::similarity(a, b),
+            Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::similarity(a, b),
+            Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::similarity(a, b),
+            Distance::Manhattan => <ManhattanMetric as Metric<VectorElementType>>::similarity(a, b),
+            Distance::Hamming => <HammingMetric as Metric<VectorElementTypeBit>>::similarity(
+                &PackedBits::pack(a),
+                &PackedBits::pack(b),
+            ),
+            Distance::Jaccard => <JaccardMetric as Metric<VectorElementTypeBit>>::similarity(
+                &PackedBits::pack(a),
+                &PackedBits::pack(b),
+            ),
+        },
+        // Sparse vectors are compared by dot product, cosine ones are normalized on preprocessing
+        (VectorRef::Sparse(a), VectorRef::Sparse(b)) => a.score(b).unwrap_or(0.0),
+        (VectorRef::MultiDense(a), VectorRef::MultiDense(b)) => {
+            if a.is_empty() || b.is_empty() {
+                return 0.0;
+            }
+            match distance {
+                Distance::Cosine => {
+                    score_multi::<_, CosineMetric>(multi_vector_config, a, weights_a, b)
+                }
+                Distance::Euclid => {
+                    score_multi::<_, EuclidMetric>(multi_vector_config, a, weights_a, b)
+                }
+                Distance::Dot => {
+                    score_multi::<_, DotProductMetric>(multi_vector_config, a, weights_a, b)
+                }
+                Distance::Manhattan => {
+                    score_multi::<_, ManhattanMetric>(multi_vector_config, a, weights_a, b)
+                }
+                // Bit vectors can't be multivectors
+                Distance::Hamming | Distance::Jaccard => 0.0,
+            }
+        }
+        // Vectors with the same name always have the same type
+        (VectorRef::Dense(_) | VectorRef::Sparse(_) | VectorRef::MultiDense(_), _) => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sparse::common::sparse_vector::SparseVector;
+
+    use super::*;
+    use crate::data_types::vectors::{VectorStructInternal, DEFAULT_VECTOR_NAME};
+    use crate::types::MultiVectorComparator;
+
+    fn make_point(id: u64, vector: DenseVector) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score: 0.0,
+            payload: None,
+            vector: Some(VectorStructInternal::Single(vector)),
+            shard_key: None,
+            order_value: None,
+        }
+    }
+
+    fn candidates() -> Vec<ScoredPoint> {
+        vec![
+            make_point(1, vec![1.0, 0.1]),
+            // Near duplicate of the first point
+            make_point(2, vec![1.0, 0.12]),
+            make_point(3, vec![1.0, -0.3]),
+            make_point(4, vec![0.0, 1.0]),
+        ]
+    }
+
+    fn ids(points: &[ScoredPoint]) -> Vec<u64> {
+        points
+            .iter()
+            .map(|point| match point.id {
+                crate::types::ExtendedPointId::NumId(id) => id,
+                crate::types::ExtendedPointId::Uuid(_) => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_mmr_relevance_only() {
+        let query = VectorInternal::Dense(vec![1.0, 0.0]);
+        let result = maximal_marginal_relevance(
+            candidates(),
+            &query,
+            DEFAULT_VECTOR_NAME,
+            Distance::Cosine,
+            None,
+            1.0,
+            3,
+            None,
+        );
+
+        assert_eq!(ids(&result), vec![1, 2, 3]);
+        assert!(result.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let result = maximal_marginal_relevance(
+            candidates(),
+            &query,
+            DEFAULT_VECTOR_NAME,
+            Distance::Cosine,
+            None,
+            1.0,
+            3,
+            Some(0.99),
+        );
+        assert_eq!(ids(&result), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_mmr_diversity() {
+        let query = VectorInternal::Dense(vec![1.0, 0.0]);
+
+        // The near duplicate of the first point is more relevant, but not novel
+        let result = maximal_marginal_relevance(
+            candidates(),
+            &query,
+            DEFAULT_VECTOR_NAME,
+            Distance::Cosine,
+            None,
+            0.5,
+            2,
+            None,
+        );
+        assert_eq!(ids(&result), vec![1, 3]);
+
+        // Only diversity matters after the first point
+        let result = maximal_marginal_relevance(
+            candidates(),
+            &query,
+            DEFAULT_VECTOR_NAME,
+            Distance::Cosine,
+            None,
+            0.0,
+            2,
+            None,
+        );
+        assert_eq!(ids(&result), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_mmr_sparse_distance() {
+        let sparse = |indices: Vec<u32>, values: Vec<f32>| {
+            VectorInternal::Sparse(SparseVector::new(indices, values).unwrap())
+        };
+        let make_sparse_point = |id: u64, vector: VectorInternal| ScoredPoint {
+            vector: Some(VectorStructInternal::Named(
+                [("sparse".into(), vector)].into_iter().collect(),
+            )),
+            ..make_point(id, vec![])
+        };
+        let points = vec![
+            // Large dot product, but mostly about another dimension
+            make_sparse_point(1, sparse(vec![0, 1], vec![1.0, 10.0])),
+            make_sparse_point(2, sparse(vec![0], vec![0.5])),
+        ];
+        let query = sparse(vec![0], vec![1.0]);
+
+        let result = maximal_marginal_relevance(
+            points.clone(),
+            &query,
+            "sparse",
+            Distance::Dot,
+            None,
+            1.0,
+            2,
+            None,
+        );
+        assert_eq!(ids(&result), vec![1, 2]);
+
+        let result = maximal_marginal_relevance(
+            points,
+            &query,
+            "sparse",
+            Distance::Cosine,
+            None,
+            1.0,
+            2,
+            None,
+        );
+        assert_eq!(ids(&result), vec![2, 1]);
+        assert!((result[0].score - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mmr_multivector_comparator() {
+        let multi = |vectors: Vec<Vec<f32>>| {
+            VectorInternal::MultiDense(MultiDenseVectorInternal::new_unchecked(vectors))
+        };
+        let make_multi_point = |id: u64, vector: VectorInternal| ScoredPoint {
+            vector: Some(VectorStructInternal::Named(
+                [("multi".into(), vector)].into_iter().collect(),
+            )),
+            ..make_point(id, vec![])
+        };
+        let points = vec![
+            make_multi_point(
+                1,
+                multi(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![-1.0, -1.0]]),
+            ),
+            make_multi_point(2, multi(vec![vec![0.9, 0.9]])),
+            make_multi_point(3, multi(vec![vec![1.5, 0.0]])),
+        ];
+        let query = MultiDenseVectorInternal::new_unchecked(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        let weighted_query = VectorInternal::WeightedMultiDense(WeightedMultiDenseVector::new(
+            query.clone(),
+            vec![2.0, 0.5],
+        ));
+        let query = VectorInternal::MultiDense(query);
+
+        let ranking = |query: &VectorInternal, comparator: Option<MultiVectorComparator>| {
+            let result = maximal_marginal_relevance(
+                points.clone(),
+                query,
+                "multi",
+                Distance::Dot,
+                comparator.map(|comparator| MultiVectorConfig { comparator }),
+                1.0,
+                3,
+                None,
+            );
+            ids(&result)
+        };
+
+        assert_eq!(ranking(&query, None), vec![1, 2, 3]);
+        assert_eq!(
+            ranking(&query, Some(MultiVectorComparator::Chamfer)),
+            vec![3, 2, 1]
+        );
+        // Weights are only used by the weighted comparator
+        assert_eq!(
+            ranking(&weighted_query, Some(MultiVectorComparator::MaxSim)),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            ranking(&weighted_query, Some(MultiVectorComparator::WeightedMaxSim)),
+            vec![3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_mmr_dedup_and_missing_vectors() {
+        let query = VectorInternal::Dense(vec![1.0, 0.0]);
+        let mut points = candidates();
+        points.extend(candidates());
+        points.push(ScoredPoint {
+            vector: None,
+            ..make_point(5, vec![])
+        });
+
+        let result = maximal_marginal_relevance(
+            points,
+            &query,
+            DEFAULT_VECTOR_NAME,
+            Distance::Dot,
+            None,
+            0.5,
+            10,
+            None,
+        );
+
+        let mut result_ids = ids(&result);
+        result_ids.sort_unstable();
+        assert_eq!(result_ids, vec![1, 2, 3, 4]);
+    }
+}
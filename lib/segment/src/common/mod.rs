@@ -1,6 +1,7 @@
 pub mod anonymize;
 pub mod error_logging;
 pub mod macros;
+pub mod maximal_marginal_relevance;
 pub mod mmap_bitslice_buffered_update_wrapper;
 pub mod mmap_slice_buffered_update_wrapper;
 pub mod operation_error;
@@ -10,6 +11,7 @@ pub mod rocksdb_buffered_delete_wrapper;
 pub mod rocksdb_buffered_update_wrapper;
 pub mod rocksdb_wrapper;
 pub mod score_fusion;
+pub mod score_range;
 pub mod utils;
 pub mod validate_snapshot_archive;
 pub mod vector_utils;
@@ -56,6 +56,15 @@ pub enum OperationError {
     MissingRangeIndexForOrderBy { key: String },
     #[error("No appropriate index for faceting: `{key}`. Please create one to facet on this field. Check https://qdrant.tech/documentation/concepts/indexing/#payload-index to see which payload schemas support Match conditions")]
     MissingMapIndexForFacet { key: String },
+    #[error("No full-text index for `bm25` key: `{key}`. Please create one to use `bm25`. Check https://qdrant.tech/documentation/concepts/indexing/#full-text-index to see how to create a full-text index")]
+    MissingFullTextIndexForBm25 { key: String },
+    #[error("Can't use `{field_name}` in the formula: {description}")]
+    FormulaVariableError {
+        field_name: PayloadKeyType,
+        description: String,
+    },
+    #[error("The formula evaluated to a non-finite number in `{operation}`")]
+    NonFiniteNumber { operation: String },
 }
 
 impl OperationError {
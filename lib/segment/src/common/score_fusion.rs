@@ -20,11 +20,21 @@ pub struct ScoreFusion {
 
 impl ScoreFusion {
     /// Params for the distribution-based score fusion
-    pub fn dbsf() -> Self {
+    pub fn dbsf(weights: Vec<f32>) -> Self {
         Self {
             method: Aggregation::Sum,
             norm: Normalization::Distr,
-            weights: vec![],
+            weights,
+            order: Order::LargeBetter,
+        }
+    }
+
+    /// Params for the weighted sum of normalized scores
+    pub fn linear(norm: Normalization, weights: Vec<f32>) -> Self {
+        Self {
+            method: Aggregation::Sum,
+            norm,
+            weights,
             order: Order::LargeBetter,
         }
     }
@@ -41,6 +51,25 @@ pub enum Normalization {
     MinMax,
     /// Uses the 3rd standard deviation as extremes
     Distr,
+    /// Standard score: distance from the mean in standard deviations
+    ZScore,
+}
+
+impl Normalization {
+    /// Normalized score of the points, which are missing from the list.
+    ///
+    /// Standard scores are centered around zero, so the missing points get the lowest score of the list,
+    /// otherwise they would outrank the points below the mean.
+    fn missing_score(&self, points: &[ScoredPoint]) -> ScoreType {
+        match self {
+            Normalization::MinMax | Normalization::Distr => 0.0,
+            Normalization::ZScore => points
+                .iter()
+                .map(|p| p.score)
+                .min_by(|a, b| a.total_cmp(b))
+                .unwrap_or(0.0),
+        }
+    }
 }
 
 pub fn score_fusion(
@@ -56,18 +85,25 @@ pub fn score_fusion(
 
     let weights = weights.into_iter().chain(iter::repeat(1.0));
 
-    all_results
+    // Sum of the scores of a point, which is missing from all lists
+    let mut total_missing_score = 0.0;
+
+    let fused = all_results
         .into_iter()
         // normalize
         .map(|points| match norm {
             Normalization::MinMax => min_max_norm(points),
             Normalization::Distr => distr_norm(points),
+            Normalization::ZScore => z_score_norm(points),
         })
         // weight each list of points
         .zip(weights)
         .flat_map(|(points, weight)| {
+            let missing_score = norm.missing_score(&points) * weight;
+            total_missing_score += missing_score;
+            // Relative to the missing score, which is added to all points in the end
             points.into_iter().map(move |p| ScoredPoint {
-                score: p.score * weight,
+                score: p.score * weight - missing_score,
                 ..p
             })
         })
@@ -83,9 +119,15 @@ pub fn score_fusion(
 
                 acc
             },
-        )
-        // sort and return
+        );
+
+    fused
         .into_values()
+        .map(|p| ScoredPoint {
+            score: p.score + total_missing_score,
+            ..p
+        })
+        // sort and return
         .sorted_by(|a, b| match order {
             Order::SmallBetter => a.cmp(b),
             Order::LargeBetter => b.cmp(a),
@@ -163,6 +205,28 @@ pub fn distr_norm(mut points: Vec<ScoredPoint>) -> Vec<ScoredPoint> {
     norm(points, min, max)
 }
 
+/// Replaces the scores with their standard scores. Points with equal scores get 0.0.
+pub fn z_score_norm(mut points: Vec<ScoredPoint>) -> Vec<ScoredPoint> {
+    if points.len() < 2 {
+        points.iter_mut().for_each(|p| p.score = 0.0);
+        return points;
+    }
+
+    let (mean, variance) = welfords_mean_variance(&points);
+
+    let std_dev = variance.sqrt();
+    if std_dev == 0.0 {
+        points.iter_mut().for_each(|p| p.score = 0.0);
+        return points;
+    }
+
+    points.iter_mut().for_each(|p| {
+        p.score = (p.score - mean) / std_dev;
+    });
+
+    points
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
@@ -185,6 +249,55 @@ mod tests {
         assert!((a - b).abs() < 1e-2, "{a} is not close to {b}");
     }
 
+    #[test]
+    fn test_linear_fusion() {
+        let results = vec![
+            vec![point(1, 10.0), point(2, 5.0), point(3, 0.0)],
+            vec![point(3, 0.9), point(2, 0.1)],
+        ];
+
+        let fused = score_fusion(
+            results.clone(),
+            ScoreFusion::linear(Normalization::MinMax, vec![1.0, 2.0]),
+        );
+        let scores = fused.iter().map(|p| (p.id, p.score)).collect_vec();
+        assert_eq!(
+            scores,
+            vec![
+                (PointIdType::NumId(3), 2.0),
+                (PointIdType::NumId(1), 1.0),
+                (PointIdType::NumId(2), 0.5),
+            ],
+        );
+
+        let fused = score_fusion(results, ScoreFusion::linear(Normalization::ZScore, vec![]));
+        let scores = fused.iter().map(|p| (p.id, p.score)).collect_vec();
+        assert_eq!(scores[0].0, PointIdType::NumId(1));
+        assert_close(scores[0].1, 1.0 - 0.70710677);
+        assert_eq!(scores[1].0, PointIdType::NumId(3));
+        assert_close(scores[1].1, -1.0 + 0.70710677);
+        assert_eq!(scores[2].0, PointIdType::NumId(2));
+        assert_close(scores[2].1, -0.70710677);
+    }
+
+    #[test]
+    fn test_z_score_fusion_missing_points() {
+        let results = vec![
+            vec![point(1, 3.0), point(2, 2.0), point(3, 1.0)],
+            vec![point(1, 3.0), point(3, 2.0), point(4, 1.0)],
+        ];
+
+        let fused = score_fusion(results, ScoreFusion::linear(Normalization::ZScore, vec![]));
+        let scores: HashMap<_, _> = fused.iter().map(|p| (p.id, p.score)).collect();
+
+        // Missing points get the lowest score of the list instead of the mean
+        assert_close(scores[&PointIdType::NumId(1)], 2.0);
+        assert_close(scores[&PointIdType::NumId(2)], -1.0);
+        assert_close(scores[&PointIdType::NumId(3)], -1.0);
+        assert_close(scores[&PointIdType::NumId(4)], -2.0);
+        assert_eq!(fused.last().unwrap().id, PointIdType::NumId(4));
+    }
+
     proptest! {
         #[test]
         fn welford_calc_vs_naive(scores in prop::collection::vec(-100.0..100.0f32, 2..1000)) {
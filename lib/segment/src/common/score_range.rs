@@ -0,0 +1,144 @@
+//! Range search returns all points with a score not worse than a threshold, page by page.
+//!
+//! Pages are ordered by score: each page continues after the score of the last point of the
+//! previous page. Points with equal scores are never split between pages, so a page may contain
+//! more than `limit` points.
+
+use common::types::{ScoreType, ScoredPointOffset};
+
+use crate::data_types::vectors::QueryVector;
+use crate::types::{Distance, SearchParams};
+
+/// Range of internal scores, in which a range search looks for points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreRange {
+    /// Worst accepted score
+    pub threshold: ScoreType,
+    /// Score of the last point of the previous page, only worse scores are accepted
+    pub after: Option<ScoreType>,
+}
+
+impl ScoreRange {
+    /// Range of a range search in the scale of the internal scores of the query, if requested.
+    ///
+    /// `score_threshold` is the threshold of the search request, in the scale of the distance.
+    pub fn from_params(
+        vector: &QueryVector,
+        params: Option<&SearchParams>,
+        score_threshold: Option<ScoreType>,
+        distance: Distance,
+    ) -> Option<Self> {
+        let range = params?.range?;
+        Some(Self {
+            threshold: score_threshold.map_or(ScoreType::NEG_INFINITY, |threshold| {
+                vector.internal_score(threshold, distance)
+            }),
+            after: range
+                .after
+                .map(|after| vector.internal_score(after, distance)),
+        })
+    }
+
+    /// Whether the points with this score can be within range.
+    /// Points better than the previous page still have to be traversed to reach the next ones.
+    pub fn is_reachable(&self, score: ScoreType) -> bool {
+        score >= self.threshold
+    }
+
+    /// Whether the points with this score belong to the requested page
+    pub fn contains(&self, score: ScoreType) -> bool {
+        self.is_reachable(score) && self.after.map_or(true, |after| score < after)
+    }
+}
+
+/// Keep the best `top` points of the `sorted` ones, together with all points which have the same
+/// score as the last kept one, so that equal scores are never split between pages.
+pub fn truncate_with_ties<T>(sorted: &mut Vec<T>, top: usize, score: impl Fn(&T) -> ScoreType) {
+    if top == 0 {
+        sorted.clear();
+        return;
+    }
+    let Some(last_score) = sorted.get(top - 1).map(&score) else {
+        return;
+    };
+    let len = top
+        + sorted[top..]
+            .iter()
+            .take_while(|item| score(item) == last_score)
+            .count();
+    sorted.truncate(len);
+}
+
+/// Page of a range search out of the points within range, best first
+pub fn select_page(mut points: Vec<ScoredPointOffset>, top: usize) -> Vec<ScoredPointOffset> {
+    points.sort_unstable_by(|a, b| b.cmp(a));
+    truncate_with_ties(&mut points, top, |point| point.score);
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::PointOffsetType;
+
+    use super::*;
+
+    fn points(scores: &[ScoreType]) -> Vec<ScoredPointOffset> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(idx, &score)| ScoredPointOffset {
+                idx: idx as PointOffsetType,
+                score,
+            })
+            .collect()
+    }
+
+    fn scores(points: &[ScoredPointOffset]) -> Vec<ScoreType> {
+        points.iter().map(|point| point.score).collect()
+    }
+
+    #[test]
+    fn test_select_page_keeps_ties() {
+        let candidates = points(&[0.5, 0.9, 0.7, 0.7, 0.1, 0.7]);
+
+        assert_eq!(scores(&select_page(candidates.clone(), 1)), [0.9]);
+        assert_eq!(
+            scores(&select_page(candidates.clone(), 2)),
+            [0.9, 0.7, 0.7, 0.7],
+        );
+        assert_eq!(
+            scores(&select_page(candidates.clone(), 5)),
+            [0.9, 0.7, 0.7, 0.7, 0.5],
+        );
+        assert_eq!(scores(&select_page(candidates.clone(), 10)).len(), 6);
+        assert!(select_page(candidates, 0).is_empty());
+    }
+
+    #[test]
+    fn test_score_range_pages() {
+        let candidates = points(&[0.5, 0.9, 0.7, 0.7, 0.1, 0.7]);
+        let mut range = ScoreRange {
+            threshold: 0.3,
+            after: None,
+        };
+
+        let mut pages = Vec::new();
+        loop {
+            let page = select_page(
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|point| range.contains(point.score))
+                    .collect(),
+                2,
+            );
+            let Some(last) = page.last() else {
+                break;
+            };
+            range.after = Some(last.score);
+            pages.push(scores(&page));
+        }
+
+        assert_eq!(pages, [vec![0.9, 0.7, 0.7, 0.7], vec![0.5]]);
+    }
+}
@@ -0,0 +1,54 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::json_path::JsonPath;
+
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct Bm25 {
+    /// Payload key with a full-text index to score against
+    pub key: JsonPath,
+
+    /// Text to score the documents with. It is tokenized with the tokenizer of the full-text index.
+    pub text: String,
+
+    /// Term frequency saturation parameter. Default is 1.2
+    #[validate(range(min = 0.0))]
+    pub k1: Option<f32>,
+
+    /// Document length normalization parameter, between 0 and 1. Default is 0.75
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub b: Option<f32>,
+}
+
+impl Bm25 {
+    pub fn params(&self) -> Bm25Params {
+        Bm25Params {
+            k1: self.k1.unwrap_or(Bm25Params::DEFAULT_K1),
+            b: self.b.unwrap_or(Bm25Params::DEFAULT_B),
+        }
+    }
+}
+
+/// Resolved BM25 parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Params {
+    pub k1: f32,
+    pub b: f32,
+}
+
+impl Bm25Params {
+    pub const DEFAULT_K1: f32 = 1.2;
+
+    pub const DEFAULT_B: f32 = 0.75;
+}
+
+impl Default for Bm25Params {
+    fn default() -> Self {
+        Self {
+            k1: Self::DEFAULT_K1,
+            b: Self::DEFAULT_B,
+        }
+    }
+}
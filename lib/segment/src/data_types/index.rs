@@ -163,11 +163,97 @@ pub struct TextIndexParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lowercase: Option<bool>,
 
+    /// If true, normalize tokens by folding accented characters to their ASCII equivalents.
+    /// E.g. "Café" -> "Cafe". Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascii_folding: Option<bool>,
+
+    /// Ignore this set of tokens. Can select from predefined languages and/or provide a custom set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<StopwordsInterface>,
+
+    /// Algorithm for stemming. Default: disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stemmer: Option<StemmingAlgorithm>,
+
     /// If true, store the index on disk. Default: false.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_disk: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    Dutch,
+    English,
+    French,
+    German,
+    Italian,
+    Portuguese,
+    Russian,
+    Spanish,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(untagged)]
+pub enum StopwordsInterface {
+    Language(Language),
+    Set(StopwordsSet),
+}
+
+impl StopwordsInterface {
+    /// Predefined languages, which stop words should be ignored
+    pub fn languages(&self) -> &[Language] {
+        match self {
+            StopwordsInterface::Language(language) => std::slice::from_ref(language),
+            StopwordsInterface::Set(set) => &set.languages,
+        }
+    }
+
+    /// User-defined stop words
+    pub fn custom(&self) -> &[String] {
+        match self {
+            StopwordsInterface::Language(_) => &[],
+            StopwordsInterface::Set(set) => &set.custom,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct StopwordsSet {
+    /// Set of languages to use predefined stop words from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<Language>,
+
+    /// Custom stop words.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(untagged)]
+pub enum StemmingAlgorithm {
+    Snowball(SnowballParams),
+}
+
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnowballType {
+    #[default]
+    Snowball,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct SnowballParams {
+    // Required for OpenAPI schema without anonymous types, versus #[serde(tag = "type")]
+    pub r#type: SnowballType,
+
+    /// Language of the stemmer.
+    pub language: Language,
+}
+
 // Bool
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
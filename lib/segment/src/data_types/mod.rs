@@ -1,3 +1,4 @@
+pub mod bm25;
 pub mod facets;
 pub mod groups;
 pub mod index;
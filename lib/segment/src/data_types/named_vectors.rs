@@ -7,11 +7,14 @@ use super::primitive::PrimitiveVectorElement;
 use super::tiny_map;
 use super::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
-    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorInternal, VectorRef,
+    VectorElementType, VectorElementTypeBit, VectorElementTypeByte, VectorElementTypeHalf,
+    VectorElementTypeInt8, VectorInternal, VectorRef, WeightedMultiDenseVector,
 };
 use crate::common::operation_error::OperationError;
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::{Distance, VectorDataConfig, VectorName, VectorNameBuf, VectorStorageDatatype};
 
 type CowKey<'a> = Cow<'a, VectorName>;
@@ -131,7 +134,11 @@ impl From<VectorInternal> for CowVector<'_> {
         match v {
             VectorInternal::Dense(v) => CowVector::Dense(Cow::Owned(v)),
             VectorInternal::Sparse(v) => CowVector::Sparse(Cow::Owned(v)),
-            VectorInternal::MultiDense(v) => CowVector::MultiDense(CowMultiVector::Owned(v)),
+            // Weights are only used for scoring, stored vectors have none
+            VectorInternal::MultiDense(v)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector: v, .. }) => {
+                CowVector::MultiDense(CowMultiVector::Owned(v))
+            }
         }
     }
 }
@@ -367,6 +374,8 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(dense_vector)
                 }
+                // Not allowed for this datatype, nothing to preprocess
+                Distance::Hamming | Distance::Jaccard => dense_vector,
             },
             Some(VectorStorageDatatype::Uint8) => match config.distance {
                 Distance::Cosine => {
@@ -381,6 +390,24 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(dense_vector)
                 }
+                // Not allowed for this datatype, nothing to preprocess
+                Distance::Hamming | Distance::Jaccard => dense_vector,
+            },
+            Some(VectorStorageDatatype::Int8) => match config.distance {
+                Distance::Cosine => {
+                    <CosineMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                Distance::Euclid => {
+                    <EuclidMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                Distance::Dot => {
+                    <DotProductMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                Distance::Manhattan => {
+                    <ManhattanMetric as Metric<VectorElementTypeInt8>>::preprocess(dense_vector)
+                }
+                // Not allowed for this datatype, nothing to preprocess
+                Distance::Hamming | Distance::Jaccard => dense_vector,
             },
             Some(VectorStorageDatatype::Float16) => match config.distance {
                 Distance::Cosine => {
@@ -395,6 +422,20 @@ impl<'a> NamedVectors<'a> {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(dense_vector)
                 }
+                // Not allowed for this datatype, nothing to preprocess
+                Distance::Hamming | Distance::Jaccard => dense_vector,
+            },
+            Some(VectorStorageDatatype::Bit) => match config.distance {
+                Distance::Hamming => {
+                    <HammingMetric as Metric<VectorElementTypeBit>>::preprocess(dense_vector)
+                }
+                Distance::Jaccard => {
+                    <JaccardMetric as Metric<VectorElementTypeBit>>::preprocess(dense_vector)
+                }
+                // Not allowed for this datatype, nothing to preprocess
+                Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => {
+                    dense_vector
+                }
             },
         }
     }
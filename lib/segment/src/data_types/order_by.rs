@@ -55,6 +55,8 @@ pub enum StartFrom {
     Float(FloatPayloadType),
 
     Datetime(DateTimePayloadType),
+
+    Keyword(String),
 }
 
 #[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq)]
@@ -85,6 +87,9 @@ impl OrderBy {
                 StartFrom::Datetime(dt) => {
                     RangeInterface::DateTime(self.direction().as_range_from(*dt))
                 }
+                StartFrom::Keyword(keyword) => {
+                    RangeInterface::Keyword(self.direction().as_range_from(keyword.clone()))
+                }
             })
             .unwrap_or_else(|| RangeInterface::Float(Range::default()))
     }
@@ -100,6 +105,7 @@ impl OrderBy {
                 StartFrom::Integer(i) => OrderValue::Int(*i),
                 StartFrom::Float(f) => OrderValue::Float(*f),
                 StartFrom::Datetime(dt) => OrderValue::Int(dt.timestamp()),
+                StartFrom::Keyword(keyword) => OrderValue::Keyword(keyword.clone()),
             })
             .unwrap_or_else(|| match self.direction() {
                 Direction::Asc => OrderValue::MIN,
@@ -148,13 +154,22 @@ fn order_value_float_example() -> FloatPayloadType {
     42.5
 }
 
-#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+fn order_value_keyword_example() -> String {
+    "keyword".to_string()
+}
+
+/// Value of the ordering field.
+///
+/// Keywords are ordered lexicographically and come after all numbers.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum OrderValue {
     #[schemars(example = "order_value_int_example")]
     Int(IntPayloadType),
     #[schemars(example = "order_value_float_example")]
     Float(FloatPayloadType),
+    #[schemars(example = "order_value_keyword_example")]
+    Keyword(String),
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -163,6 +178,7 @@ impl std::hash::Hash for OrderValue {
         match self {
             OrderValue::Int(i) => i.hash(state),
             OrderValue::Float(f) => f.to_bits().hash(state),
+            OrderValue::Keyword(s) => s.hash(state),
         }
     }
 }
@@ -178,6 +194,7 @@ impl From<OrderValue> for serde_json::Value {
                 .map(serde_json::Value::Number)
                 .unwrap_or(serde_json::Value::Null),
             OrderValue::Int(value) => serde_json::Value::Number(serde_json::Number::from(value)),
+            OrderValue::Keyword(value) => serde_json::Value::String(value),
         }
     }
 }
@@ -190,6 +207,7 @@ impl TryFrom<serde_json::Value> for OrderValue {
             .as_i64()
             .map(Self::from)
             .or_else(|| value.as_f64().map(Self::from))
+            .or_else(|| value.as_str().map(|s| Self::Keyword(s.to_string())))
             .ok_or(())
     }
 }
@@ -215,6 +233,8 @@ impl PartialEq for OrderValue {
             (OrderValue::Int(a), OrderValue::Int(b)) => a == b,
             (OrderValue::Float(a), OrderValue::Int(b)) => a.num_eq(*b),
             (OrderValue::Int(a), OrderValue::Float(b)) => a.num_eq(*b),
+            (OrderValue::Keyword(a), OrderValue::Keyword(b)) => a == b,
+            (OrderValue::Keyword(_), _) | (_, OrderValue::Keyword(_)) => false,
         }
     }
 }
@@ -239,6 +259,16 @@ impl Ord for OrderValue {
                 // Ditto, but the NaN is on the right side of the comparison.
                 a.num_cmp(*b).unwrap_or(std::cmp::Ordering::Less)
             }
+            (OrderValue::Keyword(a), OrderValue::Keyword(b)) => a.cmp(b),
+            // Keywords are greater than any number, except for the NaN, which stays the greatest
+            (OrderValue::Keyword(_), OrderValue::Float(b)) if b.is_nan() => {
+                std::cmp::Ordering::Less
+            }
+            (OrderValue::Float(a), OrderValue::Keyword(_)) if a.is_nan() => {
+                std::cmp::Ordering::Greater
+            }
+            (OrderValue::Keyword(_), _) => std::cmp::Ordering::Greater,
+            (_, OrderValue::Keyword(_)) => std::cmp::Ordering::Less,
         }
     }
 }
@@ -252,17 +282,19 @@ mod tests {
     proptest! {
 
         #[test]
-        fn test_min_ordering_value(a in i64::MIN..0, b in f64::MIN..0.0) {
+        fn test_min_ordering_value(a in i64::MIN..0, b in f64::MIN..0.0, s in ".*") {
             assert!(OrderValue::MIN.cmp(&OrderValue::from(a)).is_le());
             assert!(OrderValue::MIN.cmp(&OrderValue::from(b)).is_le());
             assert!(OrderValue::MIN.cmp(&OrderValue::from(f64::NAN)).is_le());
+            assert!(OrderValue::MIN.cmp(&OrderValue::Keyword(s)).is_le());
         }
 
         #[test]
-        fn test_max_ordering_value(a in 0..i64::MAX, b in 0.0..f64::MAX) {
+        fn test_max_ordering_value(a in 0..i64::MAX, b in 0.0..f64::MAX, s in ".*") {
             assert!(OrderValue::MAX.cmp(&OrderValue::from(a)).is_ge());
             assert!(OrderValue::MAX.cmp(&OrderValue::from(b)).is_ge());
             assert!(OrderValue::MAX.cmp(&OrderValue::from(f64::NAN)).is_ge());
+            assert!(OrderValue::MAX.cmp(&OrderValue::Keyword(s)).is_ge());
         }
     }
 }
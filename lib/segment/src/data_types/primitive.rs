@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use super::named_vectors::CowMultiVector;
 use super::vectors::TypedMultiDenseVector;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
+use crate::data_types::vectors::{
+    PackedBits, VectorElementType, VectorElementTypeBit, VectorElementTypeByte,
+    VectorElementTypeHalf, VectorElementTypeInt8,
+};
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
@@ -159,6 +162,7 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
                 }
+                Distance::Hamming | Distance::Jaccard => vector,
             };
             Cow::from(preprocessed_vector)
         }
@@ -196,3 +200,120 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
         ))
     }
 }
+
+impl PrimitiveVectorElement for VectorElementTypeInt8 {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(vector.iter().map(|&x| x as i8).collect())
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(
+            vector
+                .iter()
+                .map(|&x| VectorElementType::from(x))
+                .collect_vec(),
+        )
+    }
+
+    fn quantization_preprocess<'a>(
+        quantization_config: &QuantizationConfig,
+        distance: Distance,
+        vector: &'a [Self],
+    ) -> Cow<'a, [f32]> {
+        let vector = vector
+            .iter()
+            .map(|&x| VectorElementType::from(x))
+            .collect_vec();
+        // Signed values are already centered around zero, as binary quantization expects
+        if let QuantizationConfig::Binary(_) = quantization_config {
+            return Cow::from(vector);
+        }
+        let preprocessed_vector = match distance {
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Manhattan => {
+                <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector)
+            }
+            Distance::Hamming | Distance::Jaccard => vector,
+        };
+        Cow::from(preprocessed_vector)
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Int8
+    }
+
+    fn from_float_multivector(
+        multivector: CowMultiVector<VectorElementType>,
+    ) -> CowMultiVector<Self> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| x as Self)
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: CowMultiVector<Self>,
+    ) -> CowMultiVector<VectorElementType> {
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .as_vec_ref()
+                .flattened_vectors
+                .iter()
+                .map(|&x| VectorElementType::from(x))
+                .collect_vec(),
+            multivector.as_vec_ref().dim,
+        ))
+    }
+}
+
+impl PrimitiveVectorElement for VectorElementTypeBit {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(PackedBits::pack(&vector))
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(PackedBits::unpack(&vector))
+    }
+
+    fn quantization_preprocess<'a>(
+        _quantization_config: &QuantizationConfig,
+        _distance: Distance,
+        vector: &'a [Self],
+    ) -> Cow<'a, [f32]> {
+        Cow::Owned(PackedBits::unpack(vector))
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Bit
+    }
+
+    fn from_float_multivector(
+        multivector: CowMultiVector<VectorElementType>,
+    ) -> CowMultiVector<Self> {
+        let multivector = multivector.as_vec_ref();
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .multi_vectors()
+                .flat_map(PackedBits::pack)
+                .collect(),
+            multivector.dim.div_ceil(PackedBits::BITS),
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: CowMultiVector<Self>,
+    ) -> CowMultiVector<VectorElementType> {
+        let multivector = multivector.as_vec_ref();
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            PackedBits::unpack(multivector.flattened_vectors),
+            multivector.dim * PackedBits::BITS,
+        ))
+    }
+}
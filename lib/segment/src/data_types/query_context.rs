@@ -6,6 +6,7 @@ use std::sync::Arc;
 use bitvec::prelude::BitSlice;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoreType;
 use sparse::common::types::{DimId, DimWeight};
 
 use crate::data_types::tiny_map;
@@ -93,6 +94,7 @@ impl QueryContext {
         SegmentQueryContext {
             query_context: self,
             deleted_points: None,
+            range_score_threshold: None,
             hardware_counter: self.hardware_usage_accumulator.get_counter_cell(),
         }
     }
@@ -114,6 +116,8 @@ impl Default for QueryContext {
 pub struct SegmentQueryContext<'a> {
     query_context: &'a QueryContext,
     deleted_points: Option<&'a BitSlice>,
+    /// `score_threshold` of the range search requests, bounding the searched range of scores
+    range_score_threshold: Option<ScoreType>,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -129,6 +133,7 @@ impl<'a> SegmentQueryContext<'a> {
             is_stopped: Some(&self.query_context.is_stopped),
             idf: self.query_context.idf.get(vector_name),
             deleted_points: self.deleted_points,
+            range_score_threshold: self.range_score_threshold,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -138,10 +143,16 @@ impl<'a> SegmentQueryContext<'a> {
         self
     }
 
+    pub fn with_range_score_threshold(mut self, threshold: Option<ScoreType>) -> Self {
+        self.range_score_threshold = threshold;
+        self
+    }
+
     pub fn fork(&self) -> Self {
         Self {
             query_context: self.query_context,
             deleted_points: self.deleted_points,
+            range_score_threshold: self.range_score_threshold,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -163,6 +174,9 @@ pub struct VectorQueryContext<'a> {
 
     deleted_points: Option<&'a BitSlice>,
 
+    /// `score_threshold` of the range search requests
+    range_score_threshold: Option<ScoreType>,
+
     hardware_counter: HardwareCounterCell,
 }
 
@@ -199,6 +213,10 @@ impl VectorQueryContext<'_> {
         self.deleted_points
     }
 
+    pub fn range_score_threshold(&self) -> Option<ScoreType> {
+        self.range_score_threshold
+    }
+
     pub fn is_stopped(&self) -> SimpleCow<'_, AtomicBool> {
         self.is_stopped
             .map(SimpleCow::Borrowed)
@@ -241,6 +259,7 @@ impl Default for VectorQueryContext<'_> {
             is_stopped: None,
             idf: None,
             deleted_points: None,
+            range_score_threshold: None,
             hardware_counter: HardwareCounterCell::new(),
         }
     }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::slice::ChunksExactMut;
 
+use common::types::ScoreType;
 use half::f16;
 use itertools::Itertools;
 use schemars::JsonSchema;
@@ -12,7 +13,7 @@ use super::named_vectors::NamedVectors;
 use super::primitive::PrimitiveVectorElement;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::transpose_map_into_named_vector;
-use crate::types::{VectorName, VectorNameBuf};
+use crate::types::{Distance, VectorName, VectorNameBuf};
 use crate::vector_storage::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
 
 #[derive(Clone, Debug, PartialEq)]
@@ -20,6 +21,20 @@ pub enum VectorInternal {
     Dense(DenseVector),
     Sparse(SparseVector),
     MultiDense(MultiDenseVectorInternal),
+    /// Multivector of a query with a weight of each vector, never stored
+    WeightedMultiDense(WeightedMultiDenseVector),
+}
+
+impl VectorInternal {
+    /// Weights of the multivector query, if any
+    pub fn weights(&self) -> Option<&[ScoreType]> {
+        match self {
+            VectorInternal::WeightedMultiDense(v) => Some(&v.weights),
+            VectorInternal::Dense(_)
+            | VectorInternal::Sparse(_)
+            | VectorInternal::MultiDense(_) => None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -74,7 +89,7 @@ impl From<NamedVectorStruct> for VectorInternal {
             NamedVectorStruct::Default(v) => VectorInternal::Dense(v),
             NamedVectorStruct::Dense(v) => VectorInternal::Dense(v.vector),
             NamedVectorStruct::Sparse(v) => VectorInternal::Sparse(v.vector),
-            NamedVectorStruct::MultiDense(v) => VectorInternal::MultiDense(v.vector),
+            NamedVectorStruct::MultiDense(v) => v.into(),
         }
     }
 }
@@ -86,7 +101,9 @@ impl TryFrom<VectorInternal> for DenseVector {
         match value {
             VectorInternal::Dense(v) => Ok(v),
             VectorInternal::Sparse(_) => Err(OperationError::WrongSparse),
-            VectorInternal::MultiDense(_) => Err(OperationError::WrongMulti),
+            VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => {
+                Err(OperationError::WrongMulti)
+            }
         }
     }
 }
@@ -98,7 +115,9 @@ impl TryFrom<VectorInternal> for SparseVector {
         match value {
             VectorInternal::Dense(_) => Err(OperationError::WrongSparse),
             VectorInternal::Sparse(v) => Ok(v),
-            VectorInternal::MultiDense(_) => Err(OperationError::WrongMulti),
+            VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => {
+                Err(OperationError::WrongMulti)
+            }
         }
     }
 }
@@ -114,7 +133,10 @@ impl TryFrom<VectorInternal> for MultiDenseVectorInternal {
                 Ok(MultiDenseVectorInternal::new(v, len))
             }
             VectorInternal::Sparse(_) => Err(OperationError::WrongSparse),
-            VectorInternal::MultiDense(v) => Ok(v),
+            VectorInternal::MultiDense(v)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector: v, .. }) => {
+                Ok(v)
+            }
         }
     }
 }
@@ -167,12 +189,19 @@ impl From<MultiDenseVectorInternal> for VectorInternal {
     }
 }
 
+impl From<WeightedMultiDenseVector> for VectorInternal {
+    fn from(val: WeightedMultiDenseVector) -> Self {
+        VectorInternal::WeightedMultiDense(val)
+    }
+}
+
 impl<'a> From<&'a VectorInternal> for VectorRef<'a> {
     fn from(val: &'a VectorInternal) -> Self {
         match val {
             VectorInternal::Dense(v) => VectorRef::Dense(v.as_slice()),
             VectorInternal::Sparse(v) => VectorRef::Sparse(v),
-            VectorInternal::MultiDense(v) => {
+            VectorInternal::MultiDense(v)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector: v, .. }) => {
                 VectorRef::MultiDense(TypedMultiDenseVectorRef::from(v))
             }
         }
@@ -186,6 +215,53 @@ pub type VectorElementTypeHalf = f16;
 
 pub type VectorElementTypeByte = u8;
 
+pub type VectorElementTypeInt8 = i8;
+
+pub type VectorElementTypeBit = PackedBits;
+
+/// Eight dimensions of a bit vector, packed into a byte.
+///
+/// The first of the eight dimensions is stored in the most significant bit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(transparent)]
+pub struct PackedBits(pub u8);
+
+impl PackedBits {
+    /// Number of dimensions, packed into a single element
+    pub const BITS: usize = u8::BITS as usize;
+
+    /// Pack dimensions into bits, positive values are set bits
+    pub fn pack(vector: &[VectorElementType]) -> Vec<Self> {
+        vector
+            .chunks(Self::BITS)
+            .map(|chunk| {
+                let byte = chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, value)| **value > 0.0)
+                    .fold(0u8, |byte, (i, _)| byte | (0x80 >> i));
+                Self(byte)
+            })
+            .collect()
+    }
+
+    /// Unpack bits into dimensions with values `0.0` and `1.0`
+    pub fn unpack(packed: &[Self]) -> DenseVector {
+        packed
+            .iter()
+            .flat_map(|Self(byte)| {
+                (0..Self::BITS).map(move |i| VectorElementType::from((byte << i) >> 7))
+            })
+            .collect()
+    }
+
+    /// View packed bits as plain bytes
+    pub fn as_bytes(packed: &[Self]) -> &[u8] {
+        // Safety: `PackedBits` is a transparent wrapper around `u8`
+        unsafe { std::slice::from_raw_parts(packed.as_ptr().cast::<u8>(), packed.len()) }
+    }
+}
+
 pub const DEFAULT_VECTOR_NAME: &VectorName = "";
 
 pub type TypedDenseVector<T> = Vec<T>;
@@ -332,6 +408,27 @@ impl<T: PrimitiveVectorElement> TryFrom<Vec<TypedDenseVector<T>>> for TypedMulti
     }
 }
 
+/// Multivector query with a weight of each vector, used by the weighted MaxSim comparator
+///
+/// Weights only affect scoring, so they are kept out of [`MultiDenseVectorInternal`],
+/// which is also used for stored vectors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedMultiDenseVector {
+    pub vector: MultiDenseVectorInternal,
+    pub weights: Vec<ScoreType>,
+}
+
+impl WeightedMultiDenseVector {
+    pub fn new(vector: MultiDenseVectorInternal, weights: Vec<ScoreType>) -> Self {
+        debug_assert_eq!(
+            weights.len(),
+            vector.vectors_count(),
+            "Invalid weights length"
+        );
+        Self { vector, weights }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct TypedMultiDenseVectorRef<'a, T> {
     pub flattened_vectors: &'a [T],
@@ -398,7 +495,9 @@ impl<'a> TryInto<&'a [VectorElementType]> for &'a VectorInternal {
         match self {
             VectorInternal::Dense(v) => Ok(v),
             VectorInternal::Sparse(_) => Err(OperationError::WrongSparse),
-            VectorInternal::MultiDense(_) => Err(OperationError::WrongMulti),
+            VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => {
+                Err(OperationError::WrongMulti)
+            }
         }
     }
 }
@@ -410,7 +509,9 @@ impl<'a> TryInto<&'a SparseVector> for &'a VectorInternal {
         match self {
             VectorInternal::Dense(_) => Err(OperationError::WrongSparse),
             VectorInternal::Sparse(v) => Ok(v),
-            VectorInternal::MultiDense(_) => Err(OperationError::WrongMulti),
+            VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => {
+                Err(OperationError::WrongMulti)
+            }
         }
     }
 }
@@ -422,7 +523,10 @@ impl<'a> TryInto<&'a MultiDenseVectorInternal> for &'a VectorInternal {
         match self {
             VectorInternal::Dense(_) => Err(OperationError::WrongMulti), // &Dense vector cannot be converted to &MultiDense
             VectorInternal::Sparse(_) => Err(OperationError::WrongSparse),
-            VectorInternal::MultiDense(v) => Ok(v),
+            VectorInternal::MultiDense(v)
+            | VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector: v, .. }) => {
+                Ok(v)
+            }
         }
     }
 }
@@ -526,6 +630,24 @@ pub struct NamedMultiDenseVector {
     pub name: VectorNameBuf,
     /// Vector data
     pub vector: MultiDenseVectorInternal,
+    /// Weight of each vector, if the query is weighted
+    pub weights: Option<Vec<ScoreType>>,
+}
+
+impl From<NamedMultiDenseVector> for VectorInternal {
+    fn from(value: NamedMultiDenseVector) -> Self {
+        let NamedMultiDenseVector {
+            name: _,
+            vector,
+            weights,
+        } = value;
+        match weights {
+            Some(weights) => {
+                VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, weights })
+            }
+            None => VectorInternal::MultiDense(vector),
+        }
+    }
 }
 
 /// Sparse vector data with name
@@ -595,7 +717,18 @@ impl NamedVectorStruct {
                 NamedVectorStruct::Sparse(NamedSparseVector { name, vector })
             }
             VectorInternal::MultiDense(vector) => {
-                NamedVectorStruct::MultiDense(NamedMultiDenseVector { name, vector })
+                NamedVectorStruct::MultiDense(NamedMultiDenseVector {
+                    name,
+                    vector,
+                    weights: None,
+                })
+            }
+            VectorInternal::WeightedMultiDense(WeightedMultiDenseVector { vector, weights }) => {
+                NamedVectorStruct::MultiDense(NamedMultiDenseVector {
+                    name,
+                    vector,
+                    weights: Some(weights),
+                })
             }
         }
     }
@@ -614,7 +747,7 @@ impl NamedVectorStruct {
             NamedVectorStruct::Default(v) => v.into(),
             NamedVectorStruct::Dense(v) => v.vector.into(),
             NamedVectorStruct::Sparse(v) => v.vector.into(),
-            NamedVectorStruct::MultiDense(v) => v.vector.into(),
+            NamedVectorStruct::MultiDense(v) => v.into(),
         }
     }
 }
@@ -699,6 +832,20 @@ impl TransformInto<QueryVector, VectorInternal, VectorInternal> for QueryVector
     }
 }
 
+impl QueryVector {
+    /// Bring a displayed score into the scale of the raw scores of this query
+    pub fn internal_score(&self, score: ScoreType, distance: Distance) -> ScoreType {
+        match self {
+            // Scores of the nearest search are displayed as post-processed distances
+            QueryVector::Nearest(_) => distance.preprocess_score(score),
+            // Scores of custom queries are displayed as is
+            QueryVector::Recommend(_) | QueryVector::Discovery(_) | QueryVector::Context(_) => {
+                score
+            }
+        }
+    }
+}
+
 impl From<DenseVector> for QueryVector {
     fn from(vec: DenseVector) -> Self {
         Self::Nearest(VectorInternal::Dense(vec))
@@ -747,3 +894,9 @@ impl From<MultiDenseVectorInternal> for QueryVector {
         Self::Nearest(VectorInternal::MultiDense(vec))
     }
 }
+
+impl From<WeightedMultiDenseVector> for QueryVector {
+    fn from(vec: WeightedMultiDenseVector) -> Self {
+        Self::Nearest(VectorInternal::WeightedMultiDense(vec))
+    }
+}
@@ -4,9 +4,10 @@ use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::tar_ext;
-use common::types::TelemetryDetail;
+use common::types::{ScoreType, TelemetryDetail};
 
 use crate::common::operation_error::{OperationResult, SegmentFailedState};
+use crate::data_types::bm25::Bm25;
 use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
@@ -153,6 +154,20 @@ pub trait SegmentEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Vec<(OrderValue, PointIdType)>>;
 
+    /// Return points which satisfies filtering condition, scored by BM25 relevance
+    /// of the `bm25.key` full-text field to the `bm25.text` query. Highest scores come first.
+    ///
+    /// Will fail if there is no full-text index for the bm25 key.
+    /// Cancelled by `is_stopped` flag.
+    fn read_scored_by_bm25<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        bm25: &'a Bm25,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>>;
+
     /// Return random points which satisfies filtering condition.
     ///
     /// Cancelled by `is_stopped` flag.
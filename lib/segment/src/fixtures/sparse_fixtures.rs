@@ -20,6 +20,7 @@ use crate::index::sparse_index::sparse_vector_index::{
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::VectorIndex;
 use crate::payload_storage::in_memory_payload_storage::InMemoryPayloadStorage;
+use crate::types::SparseDistance;
 use crate::vector_storage::sparse::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
 use crate::vector_storage::VectorStorage;
 
@@ -54,6 +55,7 @@ pub fn fixture_sparse_index_from_iter<I: InvertedIndex>(
     let vector_storage = Arc::new(AtomicRefCell::new(open_simple_sparse_vector_storage(
         db,
         DB_VECTOR_CF,
+        SparseDistance::Dot,
         &stopped,
     )?));
     let mut borrowed_storage = vector_storage.borrow_mut();
@@ -1,4 +1,5 @@
 use std::fmt::Formatter;
+use std::ops::Bound;
 use std::path::PathBuf;
 
 use common::types::PointOffsetType;
@@ -19,12 +20,13 @@ use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
 use crate::data_types::order_by::OrderValue;
 use crate::index::field_index::geo_index::GeoMapIndex;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 use crate::index::field_index::numeric_index::NumericIndexInner;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition};
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchText,
-    PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
+    DateTimePayloadType, FieldCondition, FloatPayloadType, IntPayloadType, Match, MatchPhrase,
+    MatchText, PayloadKeyType, RangeInterface, UuidIntType, UuidPayloadType,
 };
 
 pub trait PayloadFieldIndex {
@@ -156,7 +158,18 @@ impl FieldIndex {
             FieldIndex::IntIndex(_) => None,
             FieldIndex::DatetimeIndex(_) => None,
             FieldIndex::IntMapIndex(_) => None,
-            FieldIndex::KeywordIndex(_) => None,
+            FieldIndex::KeywordIndex(_) => match &condition.r#match {
+                // Keywords are matched as a whole, not word by word
+                Some(Match::Fuzzy(fuzzy)) => {
+                    let automaton =
+                        LevenshteinAutomaton::new(&fuzzy.fuzzy, fuzzy.max_edits(&fuzzy.fuzzy));
+                    let is_match = <MapIndex<str> as ValueIndexer>::get_values(payload_value)
+                        .iter()
+                        .any(|keyword| automaton.is_match(keyword));
+                    Some(is_match)
+                }
+                _ => None,
+            },
             FieldIndex::FloatIndex(_) => None,
             FieldIndex::GeoIndex(_) => None,
             FieldIndex::BoolIndex(_) => None,
@@ -171,6 +184,26 @@ impl FieldIndex {
                     }
                     Some(false)
                 }
+                Some(Match::Fuzzy(fuzzy)) => {
+                    let query = full_text_index.parse_fuzzy_query(fuzzy);
+                    for value in FullTextIndex::get_values(payload_value) {
+                        let document = full_text_index.parse_document(&value);
+                        if query.check_match(&document) {
+                            return Some(true);
+                        }
+                    }
+                    Some(false)
+                }
+                Some(Match::Phrase(MatchPhrase { phrase, slop })) => {
+                    let phrase = full_text_index.parse_phrase(phrase, slop.unwrap_or(0));
+                    for value in FullTextIndex::get_values(payload_value) {
+                        let document = full_text_index.parse_document(&value);
+                        if phrase.check_match(&document) {
+                            return Some(true);
+                        }
+                    }
+                    Some(false)
+                }
                 _ => None,
             },
             FieldIndex::UuidIndex(_) => None,
@@ -354,13 +387,13 @@ impl FieldIndex {
         }
     }
 
-    pub fn as_numeric(&self) -> Option<NumericFieldIndex> {
+    pub fn as_orderable(&self) -> Option<OrderableFieldIndex> {
         match self {
-            FieldIndex::IntIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
-            FieldIndex::DatetimeIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
-            FieldIndex::FloatIndex(index) => Some(NumericFieldIndex::FloatIndex(index.inner())),
+            FieldIndex::IntIndex(index) => Some(OrderableFieldIndex::IntIndex(index.inner())),
+            FieldIndex::DatetimeIndex(index) => Some(OrderableFieldIndex::IntIndex(index.inner())),
+            FieldIndex::FloatIndex(index) => Some(OrderableFieldIndex::FloatIndex(index.inner())),
+            FieldIndex::KeywordIndex(index) => Some(OrderableFieldIndex::KeywordIndex(index)),
             FieldIndex::IntMapIndex(_)
-            | FieldIndex::KeywordIndex(_)
             | FieldIndex::GeoIndex(_)
             | FieldIndex::BoolIndex(_)
             | FieldIndex::UuidMapIndex(_)
@@ -369,6 +402,21 @@ impl FieldIndex {
         }
     }
 
+    pub fn as_full_text(&self) -> Option<&FullTextIndex> {
+        match self {
+            FieldIndex::FullTextIndex(index) => Some(index),
+            FieldIndex::IntIndex(_)
+            | FieldIndex::DatetimeIndex(_)
+            | FieldIndex::IntMapIndex(_)
+            | FieldIndex::KeywordIndex(_)
+            | FieldIndex::FloatIndex(_)
+            | FieldIndex::GeoIndex(_)
+            | FieldIndex::BoolIndex(_)
+            | FieldIndex::UuidMapIndex(_)
+            | FieldIndex::UuidIndex(_) => None,
+        }
+    }
+
     pub fn as_facet_index(&self) -> Option<FacetIndexEnum> {
         match self {
             FieldIndex::KeywordIndex(index) => Some(FacetIndexEnum::Keyword(index)),
@@ -506,51 +554,86 @@ impl FieldIndexBuilderTrait for FieldIndexBuilder {
     }
 }
 
-pub enum NumericFieldIndex<'a> {
+pub enum OrderableFieldIndex<'a> {
     IntIndex(&'a NumericIndexInner<IntPayloadType>),
     FloatIndex(&'a NumericIndexInner<FloatPayloadType>),
+    KeywordIndex(&'a MapIndex<str>),
+}
+
+/// Bounds of the range for ordering by keywords.
+///
+/// Keywords are ordered after all numbers, so a numeric range includes all keywords
+/// only if it is not bounded from above.
+fn keyword_bounds(range: &RangeInterface) -> Option<(Bound<&str>, Bound<&str>)> {
+    let unbounded = (Bound::Unbounded, Bound::Unbounded);
+    match range {
+        RangeInterface::Keyword(range) => Some(range.as_bounds()),
+        RangeInterface::Float(range) => {
+            (range.lt.is_none() && range.lte.is_none()).then_some(unbounded)
+        }
+        RangeInterface::DateTime(range) => {
+            (range.lt.is_none() && range.lte.is_none()).then_some(unbounded)
+        }
+    }
 }
 
-impl<'a> StreamRange<OrderValue> for NumericFieldIndex<'a> {
+impl<'a> StreamRange<OrderValue> for OrderableFieldIndex<'a> {
     fn stream_range(
         &self,
         range: &RangeInterface,
     ) -> Box<dyn DoubleEndedIterator<Item = (OrderValue, PointOffsetType)> + 'a> {
         match self {
-            NumericFieldIndex::IntIndex(index) => Box::new(
+            OrderableFieldIndex::IntIndex(index) => Box::new(
                 index
                     .stream_range(range)
                     .map(|(v, p)| (OrderValue::from(v), p)),
             ),
-            NumericFieldIndex::FloatIndex(index) => Box::new(
+            OrderableFieldIndex::FloatIndex(index) => Box::new(
                 index
                     .stream_range(range)
                     .map(|(v, p)| (OrderValue::from(v), p)),
             ),
+            OrderableFieldIndex::KeywordIndex(index) => {
+                let Some((lower, upper)) = keyword_bounds(range) else {
+                    return Box::new(std::iter::empty());
+                };
+                Box::new(
+                    index
+                        .stream_keywords_in_range(lower, upper)
+                        .map(|(v, p)| (OrderValue::Keyword(v.to_string()), p)),
+                )
+            }
         }
     }
 }
 
-impl<'a> NumericFieldIndex<'a> {
+impl<'a> OrderableFieldIndex<'a> {
     pub fn get_ordering_values(
         &self,
         idx: PointOffsetType,
     ) -> Box<dyn Iterator<Item = OrderValue> + 'a> {
         match self {
-            NumericFieldIndex::IntIndex(index) => Box::new(
+            OrderableFieldIndex::IntIndex(index) => Box::new(
                 index
                     .get_values(idx)
                     .into_iter()
                     .flatten()
                     .map(OrderValue::Int),
             ),
-            NumericFieldIndex::FloatIndex(index) => Box::new(
+            OrderableFieldIndex::FloatIndex(index) => Box::new(
                 index
                     .get_values(idx)
                     .into_iter()
                     .flatten()
                     .map(OrderValue::Float),
             ),
+            OrderableFieldIndex::KeywordIndex(index) => Box::new(
+                index
+                    .get_values(idx)
+                    .into_iter()
+                    .flatten()
+                    .map(|keyword| OrderValue::Keyword(keyword.to_string())),
+            ),
         }
     }
 }
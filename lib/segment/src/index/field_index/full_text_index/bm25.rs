@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::{DimId, DimWeight};
+
+use super::tokenizers::Tokenizer;
+use crate::data_types::bm25::Bm25Params;
+use crate::data_types::index::TextIndexParams;
+
+const DEFAULT_AVG_LEN: f32 = 256.0;
+
+/// Parameters of the BM25 conversion of text into sparse vectors
+///
+/// Inverse document frequency is not part of the conversion,
+/// use `Modifier::Idf` of the sparse vector to apply it on search.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(default, rename_all = "snake_case", deny_unknown_fields)]
+pub struct Bm25Config {
+    /// Term frequency saturation. Default: 1.2
+    pub k1: f32,
+    /// Document length normalization, from 0.0 (none) to 1.0 (full). Default: 0.75
+    pub b: f32,
+    /// Expected average length of the documents, in tokens. Default: 256
+    pub avg_len: f32,
+    /// Tokenization of the text, same as in the full-text payload index
+    pub text_index_params: TextIndexParams,
+}
+
+impl Default for Bm25Config {
+    fn default() -> Self {
+        Self {
+            k1: Bm25Params::DEFAULT_K1,
+            b: Bm25Params::DEFAULT_B,
+            avg_len: DEFAULT_AVG_LEN,
+            text_index_params: TextIndexParams::default(),
+        }
+    }
+}
+
+/// Converts text into sparse vectors, where each token is hashed into a dimension
+pub struct Bm25 {
+    config: Bm25Config,
+    tokenizer: Tokenizer,
+}
+
+impl Bm25 {
+    pub fn new(config: Bm25Config) -> Self {
+        let tokenizer = Tokenizer::new(config.text_index_params.clone());
+        Self { config, tokenizer }
+    }
+
+    /// Sparse vector of a document to be indexed
+    ///
+    /// The weight of each token is its term frequency, saturated by `k1`
+    /// and normalized by the length of the document relative to `avg_len`.
+    pub fn doc_vector(&self, text: &str) -> SparseVector {
+        let mut term_frequencies: BTreeMap<DimId, DimWeight> = BTreeMap::new();
+        let mut doc_len = 0usize;
+        self.tokenizer.tokenize_doc(text, |token| {
+            *term_frequencies.entry(Self::token_dim(token)).or_default() += 1.0;
+            doc_len += 1;
+        });
+
+        let Bm25Config { k1, b, avg_len, .. } = self.config;
+        let norm = k1 * (1.0 - b + b * doc_len as f32 / avg_len);
+
+        let (indices, values) = term_frequencies
+            .into_iter()
+            .map(|(dim, tf)| (dim, tf * (k1 + 1.0) / (tf + norm)))
+            .unzip();
+        SparseVector { indices, values }
+    }
+
+    /// Sparse vector of a search query
+    ///
+    /// Each distinct token gets a weight of 1.0, so that the score is the sum of the
+    /// document weights of the matching tokens.
+    pub fn query_vector(&self, text: &str) -> SparseVector {
+        let mut dims: BTreeMap<DimId, DimWeight> = BTreeMap::new();
+        self.tokenizer.tokenize_query(text, |token| {
+            dims.insert(Self::token_dim(token), 1.0);
+        });
+
+        let (indices, values) = dims.into_iter().unzip();
+        SparseVector { indices, values }
+    }
+
+    fn token_dim(token: &str) -> DimId {
+        // Stable across platforms and versions, dimensions must not change between requests
+        seahash::hash(token.as_bytes()) as DimId
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bm25_doc_vector() {
+        let bm25 = Bm25::new(Bm25Config::default());
+
+        let vector = bm25.doc_vector("The quick brown fox jumps over the lazy dog");
+        assert_eq!(vector.indices.len(), 8);
+        assert!(vector.indices.windows(2).all(|dims| dims[0] < dims[1]));
+
+        // "the" is repeated, tokens are lowercased by default
+        let the = vector
+            .indices
+            .binary_search(&Bm25::token_dim("the"))
+            .unwrap();
+        let fox = vector
+            .indices
+            .binary_search(&Bm25::token_dim("fox"))
+            .unwrap();
+        assert!(vector.values[the] > vector.values[fox]);
+
+        // saturated by k1 + 1
+        let vector = bm25.doc_vector(&"fox ".repeat(1000));
+        assert_eq!(vector.indices.len(), 1);
+        assert!(vector.values[0] < Bm25Params::DEFAULT_K1 + 1.0);
+
+        // longer documents get lower weights
+        let fox_weight = |vector: &SparseVector| {
+            let pos = vector
+                .indices
+                .binary_search(&Bm25::token_dim("fox"))
+                .unwrap();
+            vector.values[pos]
+        };
+        let short = bm25.doc_vector("fox dog");
+        let long = bm25.doc_vector(&format!("fox {}", "dog ".repeat(1000)));
+        assert!(fox_weight(&short) > fox_weight(&long));
+    }
+
+    #[test]
+    fn test_bm25_query_vector() {
+        let bm25 = Bm25::new(Bm25Config::default());
+
+        let query = bm25.query_vector("Fox fox dog");
+        let doc = bm25.doc_vector("the fox and the dog");
+        assert_eq!(query.values, vec![1.0, 1.0]);
+        assert!(query
+            .indices
+            .iter()
+            .all(|dim| doc.indices.binary_search(dim).is_ok()));
+    }
+
+    #[test]
+    fn test_bm25_config_deserialize() {
+        let config: Bm25Config = serde_json::from_value(serde_json::json!({
+            "k1": 1.5,
+            "text_index_params": {
+                "type": "text",
+                "lowercase": false,
+            },
+        }))
+        .unwrap();
+        assert_eq!(config.k1, 1.5);
+        assert_eq!(config.b, Bm25Params::DEFAULT_B);
+        assert_eq!(config.text_index_params.lowercase, Some(false));
+    }
+}
@@ -57,6 +57,28 @@ impl<'a> ChunkReader<'a> {
         }
     }
 
+    /// Offset of the value in the posting list, if the value is present
+    pub fn find_offset(&self, val: PointOffsetType) -> Option<usize> {
+        if !self.is_in_postings_range(val) {
+            return None;
+        }
+        match self.find_chunk(val, None) {
+            Some(chunk_index) => {
+                let mut decompressed = [0u32; BitPackerImpl::BLOCK_LEN];
+                self.decompress_chunk(&BitPackerImpl::new(), chunk_index, &mut decompressed);
+                decompressed
+                    .binary_search(&val)
+                    .ok()
+                    .map(|idx| chunk_index * BitPackerImpl::BLOCK_LEN + idx)
+            }
+            None => self
+                .remainder_postings
+                .binary_search(&val)
+                .ok()
+                .map(|idx| self.chunks.len() * BitPackerImpl::BLOCK_LEN + idx),
+        }
+    }
+
     pub fn find_chunk(&self, doc_id: PointOffsetType, start_chunk: Option<usize>) -> Option<usize> {
         let remainder_postings = self.remainder_postings;
         let chunks = self.chunks;
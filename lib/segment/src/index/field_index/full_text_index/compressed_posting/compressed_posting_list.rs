@@ -7,6 +7,8 @@ use crate::index::field_index::full_text_index::compressed_posting::compressed_c
 };
 use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_iterator::CompressedPostingIterator;
 use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_visitor::CompressedPostingVisitor;
+use crate::index::field_index::full_text_index::compressed_posting::posting_positions::PostingPositions;
+use crate::index::field_index::full_text_index::inverted_index::TokenPosition;
 
 #[derive(Clone, Debug, Default)]
 pub struct CompressedPostingList {
@@ -15,13 +17,19 @@ pub struct CompressedPostingList {
     chunks: Vec<CompressedPostingChunksIndex>,
     // last postings that are not compressed because they are not aligned with the block size
     remainder_postings: Vec<PointOffsetType>,
+    // positions of the token in each document of the posting list
+    positions: PostingPositions,
 }
 
 impl CompressedPostingList {
-    pub fn new(posting_list: &[PointOffsetType]) -> Self {
+    /// Compress the posting list.
+    ///
+    /// `positions` are aligned with `posting_list`, and can be empty if positions are not known.
+    pub fn new(posting_list: &[PointOffsetType], positions: &[Vec<TokenPosition>]) -> Self {
         if posting_list.is_empty() {
             return Self::default();
         }
+        debug_assert!(positions.is_empty() || positions.len() == posting_list.len());
         let (chunks, remainder_postings, data) = compress_posting(posting_list);
 
         Self {
@@ -29,6 +37,7 @@ impl CompressedPostingList {
             data,
             chunks,
             remainder_postings,
+            positions: PostingPositions::new(positions),
         }
     }
 
@@ -45,11 +54,16 @@ impl CompressedPostingList {
         self.reader().contains(val)
     }
 
+    /// Positions of the token in the given document
+    pub fn positions(&self, val: PointOffsetType) -> Option<&[TokenPosition]> {
+        let offset = self.reader().find_offset(val)?;
+        self.positions.reader().get(offset)
+    }
+
     pub fn len(&self) -> usize {
         self.chunks.len() * BitPackerImpl::BLOCK_LEN + self.remainder_postings.len()
     }
 
-    #[allow(dead_code)]
     pub fn iter(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
         let reader = self.reader();
         let visitor = CompressedPostingVisitor::new(reader);
@@ -69,7 +83,7 @@ impl CompressedPostingList {
             set.insert(step * i);
             posting_list.push(step * i);
         }
-        let compressed_posting_list = CompressedPostingList::new(&posting_list);
+        let compressed_posting_list = CompressedPostingList::new(&posting_list, &[]);
         (compressed_posting_list, set)
     }
 
@@ -82,6 +96,10 @@ impl CompressedPostingList {
     pub(crate) fn last_doc_id(&self) -> PointOffsetType {
         self.last_doc_id
     }
+
+    pub(crate) fn posting_positions(&self) -> &PostingPositions {
+        &self.positions
+    }
 }
 
 #[cfg(test)]
@@ -98,4 +116,24 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_compressed_posting_positions() {
+        // Long enough to have both compressed chunks and remainder postings
+        let posting_list: Vec<PointOffsetType> = (0..300).map(|i| i * 3).collect();
+        let positions: Vec<Vec<TokenPosition>> = posting_list
+            .iter()
+            .map(|&doc_id| (0..doc_id % 4).map(|pos| pos * 2).collect())
+            .collect();
+
+        let compressed_posting_list = CompressedPostingList::new(&posting_list, &positions);
+
+        for (doc_id, expected) in posting_list.iter().zip(&positions) {
+            assert_eq!(
+                compressed_posting_list.positions(*doc_id),
+                Some(expected.as_slice())
+            );
+            assert_eq!(compressed_posting_list.positions(*doc_id + 1), None);
+        }
+    }
 }
@@ -3,6 +3,7 @@ pub mod compressed_common;
 pub mod compressed_posting_iterator;
 pub mod compressed_posting_list;
 pub mod compressed_posting_visitor;
+pub mod posting_positions;
 
 //
 //                               CompressedPostingIterator
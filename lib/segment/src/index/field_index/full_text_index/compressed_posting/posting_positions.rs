@@ -0,0 +1,78 @@
+use crate::index::field_index::full_text_index::inverted_index::TokenPosition;
+
+/// Positions of a token in each document of a posting list.
+///
+/// Positions of the document at offset `i` of the posting list are stored in
+/// `positions[offsets[i]..offsets[i + 1]]`.
+/// If positions are not known, `offsets` is empty.
+#[derive(Clone, Debug, Default)]
+pub struct PostingPositions {
+    offsets: Vec<u32>,
+    positions: Vec<TokenPosition>,
+}
+
+impl PostingPositions {
+    pub fn new(posting_positions: &[Vec<TokenPosition>]) -> Self {
+        if posting_positions.is_empty() {
+            return Self::default();
+        }
+
+        let mut offsets = Vec::with_capacity(posting_positions.len() + 1);
+        let mut positions = Vec::with_capacity(posting_positions.iter().map(Vec::len).sum());
+
+        offsets.push(0);
+        for document_positions in posting_positions {
+            positions.extend_from_slice(document_positions);
+            offsets.push(positions.len() as u32);
+        }
+
+        Self { offsets, positions }
+    }
+
+    pub fn reader(&self) -> PostingPositionsReader {
+        PostingPositionsReader::new(&self.offsets, &self.positions)
+    }
+
+    pub(crate) fn internal_structs(&self) -> (&[u32], &[TokenPosition]) {
+        (&self.offsets, &self.positions)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PostingPositionsReader<'a> {
+    offsets: &'a [u32],
+    positions: &'a [TokenPosition],
+}
+
+impl<'a> PostingPositionsReader<'a> {
+    pub fn new(offsets: &'a [u32], positions: &'a [TokenPosition]) -> Self {
+        Self { offsets, positions }
+    }
+
+    /// Positions of the token in the document at the given offset of the posting list
+    pub fn get(&self, offset: usize) -> Option<&'a [TokenPosition]> {
+        let start = *self.offsets.get(offset)? as usize;
+        let end = *self.offsets.get(offset + 1)? as usize;
+        self.positions.get(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_posting_positions() {
+        let posting_positions = vec![vec![0, 5], vec![], vec![3], vec![1, 2, 7]];
+        let positions = PostingPositions::new(&posting_positions);
+        let reader = positions.reader();
+
+        for (offset, expected) in posting_positions.iter().enumerate() {
+            assert_eq!(reader.get(offset), Some(expected.as_slice()));
+        }
+        assert_eq!(reader.get(posting_positions.len()), None);
+
+        let empty = PostingPositions::new(&[]);
+        assert_eq!(empty.reader().get(0), None);
+    }
+}
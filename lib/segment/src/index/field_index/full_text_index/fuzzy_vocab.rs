@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+
+use fst::{IntoStreamer, Streamer};
+
+use super::inverted_index::TokenId;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
+
+/// Vocabulary of an immutable index, compiled into an FST for fuzzy search.
+///
+/// Automatons walk the FST and skip whole branches of tokens which can't match,
+/// instead of checking every token of the vocabulary.
+/// Built on the first fuzzy query, so that indexes without fuzzy queries don't pay for it.
+#[derive(Debug, Default, Clone)]
+pub struct FuzzyVocab {
+    fst: OnceLock<fst::Map<Vec<u8>>>,
+}
+
+impl FuzzyVocab {
+    /// Find vocabulary tokens, accepted by each of the automatons.
+    ///
+    /// `vocab` must provide the same tokens on every call, as the FST is built only once.
+    pub fn fuzzy_tokens<'a, I>(
+        &self,
+        vocab: impl FnOnce() -> I,
+        automatons: &[LevenshteinAutomaton],
+    ) -> Vec<Vec<TokenId>>
+    where
+        I: Iterator<Item = (&'a str, TokenId)>,
+    {
+        let vocab_fst = self.fst.get_or_init(|| {
+            let mut tokens: Vec<_> = vocab().collect();
+            tokens.sort_unstable_by_key(|(token, _)| *token);
+            fst::Map::from_iter(
+                tokens
+                    .into_iter()
+                    .map(|(token, token_id)| (token, u64::from(token_id))),
+            )
+            .expect("vocabulary tokens are sorted and unique")
+        });
+
+        automatons
+            .iter()
+            .map(|automaton| {
+                let mut variants = Vec::new();
+                let mut stream = vocab_fst.search(automaton).into_stream();
+                while let Some((_, token_id)) = stream.next() {
+                    variants.push(token_id as TokenId);
+                }
+                variants.sort_unstable();
+                variants
+            })
+            .collect()
+    }
+}
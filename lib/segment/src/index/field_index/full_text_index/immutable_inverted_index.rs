@@ -2,12 +2,16 @@ use std::collections::HashMap;
 
 use common::types::PointOffsetType;
 
+use super::fuzzy_vocab::FuzzyVocab;
 use super::inverted_index::InvertedIndex;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_list::CompressedPostingList;
-use crate::index::field_index::full_text_index::inverted_index::{ParsedQuery, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{
+    ParsedFuzzyQuery, ParsedPhrase, ParsedQuery, TokenId,
+};
 use crate::index::field_index::full_text_index::mutable_inverted_index::MutableInvertedIndex;
 use crate::index::field_index::full_text_index::postings_iterator::intersect_compressed_postings_iterator;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 
 #[cfg_attr(test, derive(Clone))]
 #[derive(Default, Debug)]
@@ -16,6 +20,9 @@ pub struct ImmutableInvertedIndex {
     pub(in crate::index::field_index::full_text_index) vocab: HashMap<String, TokenId>,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: Vec<Option<usize>>,
     pub(in crate::index::field_index::full_text_index) points_count: usize,
+    /// Sum of the lengths of all non-deleted documents
+    pub(in crate::index::field_index::full_text_index) tokens_count: usize,
+    pub(in crate::index::field_index::full_text_index) fuzzy_vocab: FuzzyVocab,
 }
 
 impl InvertedIndex for ImmutableInvertedIndex {
@@ -37,7 +44,8 @@ impl InvertedIndex for ImmutableInvertedIndex {
         if self.values_is_empty(idx) {
             return false; // Already removed or never actually existed
         }
-        self.point_to_tokens_count[idx as usize] = None;
+        let removed_tokens_count = self.point_to_tokens_count[idx as usize].take();
+        self.tokens_count -= removed_tokens_count.unwrap_or(0);
         self.points_count -= 1;
         true
     }
@@ -72,6 +80,23 @@ impl InvertedIndex for ImmutableInvertedIndex {
         self.postings.get(token_id as usize).map(|p| p.len())
     }
 
+    fn iter_posting(
+        &self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
+        let posting = self.postings.get(token_id as usize)?;
+        // in case of immutable index, deleted documents are still in the postings
+        Some(Box::new(
+            posting
+                .iter()
+                .filter(move |&idx| !self.values_is_empty(idx)),
+        ))
+    }
+
+    fn total_tokens_count(&self) -> usize {
+        self.tokens_count
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
         self.vocab.iter().filter_map(|(token, &posting_idx)| {
             self.postings
@@ -80,6 +105,17 @@ impl InvertedIndex for ImmutableInvertedIndex {
         })
     }
 
+    fn iter_vocab(&self) -> impl Iterator<Item = (&str, TokenId)> + '_ {
+        self.vocab
+            .iter()
+            .map(|(token, &token_id)| (token.as_str(), token_id))
+    }
+
+    fn fuzzy_tokens(&self, automatons: &[LevenshteinAutomaton]) -> Vec<Vec<TokenId>> {
+        self.fuzzy_vocab
+            .fuzzy_tokens(|| self.iter_vocab(), automatons)
+    }
+
     fn check_match(&self, parsed_query: &ParsedQuery, point_id: PointOffsetType) -> bool {
         if parsed_query.tokens.contains(&None) {
             return false;
@@ -96,6 +132,26 @@ impl InvertedIndex for ImmutableInvertedIndex {
             .all(|query_token| self.postings[query_token.unwrap() as usize].contains(point_id))
     }
 
+    fn check_fuzzy(&self, query: &ParsedFuzzyQuery, point_id: PointOffsetType) -> bool {
+        // check presence of the document
+        if self.values_is_empty(point_id) {
+            return false;
+        }
+        query.check(|token| {
+            self.postings
+                .get(token as usize)
+                .is_some_and(|posting| posting.contains(point_id))
+        })
+    }
+
+    fn check_phrase(&self, phrase: &ParsedPhrase, point_id: PointOffsetType) -> bool {
+        // check presence of the document
+        if self.values_is_empty(point_id) {
+            return false;
+        }
+        phrase.check_positions(|token| self.postings.get(token as usize)?.positions(point_id))
+    }
+
     fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
         self.point_to_tokens_count
             .get(point_id as usize)
@@ -148,7 +204,10 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
 
         let postings: Vec<CompressedPostingList> = postings
             .into_iter()
-            .map(|posting| CompressedPostingList::new(&posting.into_vec()))
+            .map(|posting| {
+                let (posting, positions) = posting.into_parts();
+                CompressedPostingList::new(&posting, &positions)
+            })
             .collect();
         vocab.shrink_to_fit();
 
@@ -161,6 +220,8 @@ impl From<MutableInvertedIndex> for ImmutableInvertedIndex {
                 .map(|doc| doc.as_ref().map(|doc| doc.len()))
                 .collect(),
             points_count: index.points_count,
+            tokens_count: index.tokens_count,
+            fuzzy_vocab: FuzzyVocab::default(),
         }
     }
 }
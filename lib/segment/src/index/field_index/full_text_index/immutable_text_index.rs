@@ -4,6 +4,7 @@ use super::immutable_inverted_index::ImmutableInvertedIndex;
 use super::inverted_index::InvertedIndex;
 use super::mutable_inverted_index::MutableInvertedIndex;
 use super::text_index::FullTextIndex;
+use super::tokenizers::Tokenizer;
 use crate::common::operation_error::OperationResult;
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 use crate::data_types::index::TextIndexParams;
@@ -11,7 +12,7 @@ use crate::data_types::index::TextIndexParams;
 pub struct ImmutableFullTextIndex {
     pub(super) inverted_index: ImmutableInvertedIndex,
     pub(super) db_wrapper: DatabaseColumnScheduledDeleteWrapper,
-    pub(super) config: TextIndexParams,
+    pub(super) tokenizer: Tokenizer,
 }
 
 impl ImmutableFullTextIndex {
@@ -19,7 +20,7 @@ impl ImmutableFullTextIndex {
         Self {
             inverted_index: Default::default(),
             db_wrapper,
-            config,
+            tokenizer: Tokenizer::new(config),
         }
     }
 
@@ -33,13 +34,24 @@ impl ImmutableFullTextIndex {
         };
 
         let db = self.db_wrapper.lock_db();
-        let iter = db.iter()?.map(|(key, value)| {
+        let mut missing_positions = false;
+        let iter = db.iter()?.map_while(|(key, value)| {
             let idx = FullTextIndex::restore_key(&key);
-            let tokens = FullTextIndex::deserialize_document(&value)?;
-            Ok((idx, tokens))
+            match FullTextIndex::deserialize_document(&value) {
+                Ok(Some(tokens)) => Some(Ok((idx, tokens))),
+                Ok(None) => {
+                    missing_positions = true;
+                    None
+                }
+                Err(err) => Some(Err(err)),
+            }
         });
 
         let mutable = MutableInvertedIndex::build_index(iter)?;
+        if missing_positions {
+            // Stored without token positions, rebuild from payload to support phrase matching
+            return Ok(false);
+        }
 
         self.inverted_index = ImmutableInvertedIndex::from(mutable);
 
@@ -1,23 +1,33 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, HashMap};
 
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use serde::{Deserialize, Serialize};
 
 use crate::common::operation_error::OperationResult;
+use crate::data_types::bm25::Bm25Params;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 use crate::index::field_index::{CardinalityEstimation, PayloadBlockCondition, PrimaryCondition};
 use crate::types::{FieldCondition, Match, PayloadKeyType};
 
 pub type TokenId = u32;
 
+/// Position of a token within a document, counted in words
+pub type TokenPosition = u32;
+
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 pub struct Document {
     tokens: Vec<TokenId>,
+    /// Positions of each token in the document, aligned with `tokens`.
+    /// Empty if positions were not provided or were moved into posting lists.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    positions: Vec<Vec<TokenPosition>>,
 }
 
 impl Document {
-    pub fn new(mut tokens: Vec<TokenId>) -> Self {
-        tokens.sort_unstable();
-        Self { tokens }
+    pub fn new(mut tokens: Vec<(TokenId, Vec<TokenPosition>)>) -> Self {
+        tokens.sort_unstable_by_key(|(token, _)| *token);
+        let (tokens, positions) = tokens.into_iter().unzip();
+        Self { tokens, positions }
     }
 
     pub fn len(&self) -> usize {
@@ -35,6 +45,17 @@ impl Document {
     pub fn check(&self, token: TokenId) -> bool {
         self.tokens.binary_search(&token).is_ok()
     }
+
+    /// Positions of the given token in the document
+    pub fn positions(&self, token: TokenId) -> Option<&[TokenPosition]> {
+        let idx = self.tokens.binary_search(&token).ok()?;
+        self.positions.get(idx).map(Vec::as_slice)
+    }
+
+    /// Take positions out of the document, aligned with `tokens()`
+    pub fn take_positions(&mut self) -> Vec<Vec<TokenPosition>> {
+        std::mem::take(&mut self.positions)
+    }
 }
 
 #[derive(Debug)]
@@ -55,13 +76,103 @@ impl ParsedQuery {
     }
 }
 
+/// Query, where each token is expanded to the similar tokens of the vocabulary
+#[derive(Debug)]
+pub struct ParsedFuzzyQuery {
+    /// For each query token - sorted ids of the vocabulary tokens within the allowed edit distance
+    pub tokens: Vec<Vec<TokenId>>,
+}
+
+impl ParsedFuzzyQuery {
+    pub fn check_match(&self, document: &Document) -> bool {
+        self.check(|token| document.check(token))
+    }
+
+    /// Check that each query token has a similar token, for which `contains` is true.
+    /// Empty query matches nothing.
+    pub fn check(&self, mut contains: impl FnMut(TokenId) -> bool) -> bool {
+        !self.tokens.is_empty()
+            && self
+                .tokens
+                .iter()
+                .all(|variants| variants.iter().any(|&token| contains(token)))
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsedPhrase {
+    /// Tokens of the phrase, in order of appearance
+    pub tokens: Vec<Option<TokenId>>,
+    /// Maximum number of other tokens allowed between the phrase tokens in total
+    pub slop: u32,
+}
+
+impl ParsedPhrase {
+    /// Query which matches all documents containing every token of the phrase, at any position
+    pub fn to_query(&self) -> ParsedQuery {
+        let mut tokens = self.tokens.clone();
+        tokens.sort_unstable();
+        tokens.dedup();
+        ParsedQuery { tokens }
+    }
+
+    pub fn check_match(&self, document: &Document) -> bool {
+        self.check_positions(|token| document.positions(token))
+    }
+
+    /// Check that the phrase tokens occur in order, with at most `slop` other tokens in between.
+    ///
+    /// `positions` provides the sorted positions of a token in the checked document.
+    pub fn check_positions<'a>(
+        &self,
+        mut positions: impl FnMut(TokenId) -> Option<&'a [TokenPosition]>,
+    ) -> bool {
+        if self.tokens.is_empty() || self.tokens.contains(&None) {
+            return false;
+        }
+
+        // unwrap crash safety: all tokens exist in the vocabulary if it passes the above check
+        let Some(tokens_positions) = self
+            .tokens
+            .iter()
+            .map(|token| positions(token.unwrap()))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+
+        let (first_positions, next_positions) = tokens_positions.split_first().unwrap();
+        let phrase_gaps = next_positions.len() as TokenPosition;
+
+        for &start in first_positions.iter() {
+            // Taking the closest occurrence of each next token gives the shortest span
+            // for the given start position
+            let mut last = start;
+            for token_positions in next_positions {
+                let next_idx = token_positions.partition_point(|&pos| pos <= last);
+                match token_positions.get(next_idx) {
+                    Some(&pos) => last = pos,
+                    // Later start positions can't have the next token after them either
+                    None => return false,
+                }
+            }
+
+            if last - start - phrase_gaps <= self.slop {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
 pub trait InvertedIndex {
     fn get_vocab_mut(&mut self) -> &mut HashMap<String, TokenId>;
 
-    fn document_from_tokens(&mut self, tokens: &BTreeSet<String>) -> Document {
+    fn document_from_tokens(&mut self, tokens: &BTreeMap<String, Vec<TokenPosition>>) -> Document {
         let vocab = self.get_vocab_mut();
         let mut document_tokens = vec![];
-        for token in tokens {
+        for (token, positions) in tokens {
             // check if in vocab
             let vocab_idx = match vocab.get(token) {
                 Some(&idx) => idx,
@@ -71,7 +182,7 @@ pub trait InvertedIndex {
                     next_token_id
                 }
             };
-            document_tokens.push(vocab_idx);
+            document_tokens.push((vocab_idx, positions.clone()));
         }
 
         Document::new(document_tokens)
@@ -85,6 +196,64 @@ pub trait InvertedIndex {
 
     fn get_posting_len(&self, token_id: TokenId) -> Option<usize>;
 
+    /// Iterate over the non-deleted points which contain the given token.
+    fn iter_posting(
+        &self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>>;
+
+    /// Sum of the document lengths of all non-deleted points.
+    fn total_tokens_count(&self) -> usize;
+
+    /// Score all documents, containing at least one of the query tokens, with BM25.
+    ///
+    /// Documents are sets of unique tokens, so term frequency is always 1
+    /// and document length is the number of unique tokens in the document.
+    fn bm25_scores(
+        &self,
+        query: &ParsedQuery,
+        params: Bm25Params,
+    ) -> HashMap<PointOffsetType, ScoreType> {
+        let points_count = self.points_count();
+        let mut scores = HashMap::new();
+        if points_count == 0 {
+            return scores;
+        }
+
+        let avg_doc_len = self.total_tokens_count() as f32 / points_count as f32;
+        let Bm25Params { k1, b } = params;
+
+        let mut query_tokens: Vec<TokenId> = query.tokens.iter().flatten().copied().collect();
+        query_tokens.sort_unstable();
+        query_tokens.dedup();
+
+        for token_id in query_tokens {
+            let Some(posting) = self.iter_posting(token_id) else {
+                continue;
+            };
+            let posting: Vec<PointOffsetType> = posting.collect();
+            if posting.is_empty() {
+                continue;
+            }
+
+            let doc_freq = posting.len().min(points_count) as f32;
+            let idf = (1.0 + (points_count as f32 - doc_freq + 0.5) / (doc_freq + 0.5)).ln();
+
+            for point_id in posting {
+                let doc_len = self.values_count(point_id) as f32;
+                let norm = if avg_doc_len > 0.0 {
+                    1.0 - b + b * doc_len / avg_doc_len
+                } else {
+                    1.0
+                };
+                let score = idf * (k1 + 1.0) / (1.0 + k1 * norm);
+                *scores.entry(point_id).or_insert(0.0) += score;
+            }
+        }
+
+        scores
+    }
+
     fn estimate_cardinality(
         &self,
         query: &ParsedQuery,
@@ -146,6 +315,98 @@ pub trait InvertedIndex {
 
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_;
 
+    fn iter_vocab(&self) -> impl Iterator<Item = (&str, TokenId)> + '_;
+
+    /// Find vocabulary tokens, accepted by each of the automatons.
+    ///
+    /// Vocabulary is scanned once for all the automatons.
+    /// Immutable indexes override it with a search over `FuzzyVocab`,
+    /// mutable vocabulary changes with every update, so it is scanned.
+    fn fuzzy_tokens(&self, automatons: &[LevenshteinAutomaton]) -> Vec<Vec<TokenId>> {
+        let mut tokens = vec![Vec::new(); automatons.len()];
+        for (token, token_id) in self.iter_vocab() {
+            for (automaton, variants) in automatons.iter().zip(tokens.iter_mut()) {
+                if automaton.is_match(token) {
+                    variants.push(token_id);
+                }
+            }
+        }
+        for variants in tokens.iter_mut() {
+            variants.sort_unstable();
+        }
+        tokens
+    }
+
+    fn filter_fuzzy(
+        &self,
+        query: ParsedFuzzyQuery,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        // Candidates are the points with any variant of the rarest query token
+        let rarest = query.tokens.iter().min_by_key(|variants| {
+            variants
+                .iter()
+                .filter_map(|&token| self.get_posting_len(token))
+                .sum::<usize>()
+        });
+        let Some(rarest) = rarest else {
+            // Empty request -> no matches
+            return Box::new(std::iter::empty());
+        };
+
+        let mut candidates: Vec<PointOffsetType> = rarest
+            .iter()
+            .filter_map(|&token| self.iter_posting(token))
+            .flatten()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        Box::new(
+            candidates
+                .into_iter()
+                .filter(move |&point_id| self.check_fuzzy(&query, point_id)),
+        )
+    }
+
+    fn estimate_fuzzy_cardinality(
+        &self,
+        query: &ParsedFuzzyQuery,
+        condition: &FieldCondition,
+    ) -> CardinalityEstimation {
+        let points_count = self.points_count();
+
+        // Points with any variant of a query token, variants may overlap
+        let variants_counts: Vec<usize> = query
+            .tokens
+            .iter()
+            .map(|variants| {
+                variants
+                    .iter()
+                    .filter_map(|&token| self.get_posting_len(token))
+                    .sum::<usize>()
+                    .min(points_count)
+            })
+            .collect();
+
+        let max = variants_counts.iter().copied().min().unwrap_or(0);
+        let exp = if points_count == 0 {
+            0
+        } else {
+            let expected_frac: f64 = variants_counts
+                .iter()
+                .map(|&count| count as f64 / points_count as f64)
+                .product();
+            ((expected_frac * points_count as f64) as usize).min(max)
+        };
+
+        CardinalityEstimation {
+            primary_clauses: vec![PrimaryCondition::Condition(Box::new(condition.clone()))],
+            min: 0,
+            exp,
+            max,
+        }
+    }
+
     fn payload_blocks(
         &self,
         threshold: usize,
@@ -170,6 +431,21 @@ pub trait InvertedIndex {
 
     fn check_match(&self, parsed_query: &ParsedQuery, point_id: PointOffsetType) -> bool;
 
+    /// Check that the document of the point contains the phrase, using token positions
+    fn check_phrase(&self, phrase: &ParsedPhrase, point_id: PointOffsetType) -> bool;
+
+    fn check_fuzzy(&self, query: &ParsedFuzzyQuery, point_id: PointOffsetType) -> bool;
+
+    fn filter_phrase(
+        &self,
+        phrase: ParsedPhrase,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        let query = phrase.to_query();
+        // Candidates contain all the phrase tokens, positions are checked afterwards
+        let candidates = self.filter(&query);
+        Box::new(candidates.filter(move |&point_id| self.check_phrase(&phrase, point_id)))
+    }
+
     fn values_is_empty(&self, point_id: PointOffsetType) -> bool;
 
     fn values_count(&self, point_id: PointOffsetType) -> usize;
@@ -181,13 +457,15 @@ pub trait InvertedIndex {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeSet;
+    use std::collections::BTreeMap;
 
-    use rand::seq::SliceRandom;
+    use common::types::PointOffsetType;
+    use rand::seq::{IndexedRandom, SliceRandom};
     use rand::Rng;
     use rstest::rstest;
 
-    use super::{InvertedIndex, ParsedQuery, TokenId};
+    use super::{InvertedIndex, ParsedPhrase, ParsedQuery, TokenId, TokenPosition};
+    use crate::data_types::bm25::Bm25Params;
     use crate::index::field_index::full_text_index::immutable_inverted_index::ImmutableInvertedIndex;
     use crate::index::field_index::full_text_index::mmap_inverted_index::MmapInvertedIndex;
     use crate::index::field_index::full_text_index::mutable_inverted_index::MutableInvertedIndex;
@@ -209,6 +487,17 @@ mod tests {
         (0..len).map(|_| generate_word()).collect()
     }
 
+    fn to_positioned_tokens(words: &[String]) -> BTreeMap<String, Vec<TokenPosition>> {
+        let mut tokens: BTreeMap<String, Vec<TokenPosition>> = BTreeMap::new();
+        for (position, word) in words.iter().enumerate() {
+            tokens
+                .entry(word.clone())
+                .or_default()
+                .push(position as TokenPosition);
+        }
+        tokens
+    }
+
     fn to_parsed_query(
         query: Vec<String>,
         token_to_id: impl Fn(String) -> Option<TokenId>,
@@ -223,8 +512,8 @@ mod tests {
         for idx in 0..indexed_count {
             // Generate 10 tot 30-word documents
             let doc_len = rand::rng().random_range(10..=30);
-            let tokens: BTreeSet<String> = (0..doc_len).map(|_| generate_word()).collect();
-            let document = index.document_from_tokens(&tokens);
+            let words: Vec<String> = (0..doc_len).map(|_| generate_word()).collect();
+            let document = index.document_from_tokens(&to_positioned_tokens(&words));
             index.index_document(idx, document).unwrap();
         }
 
@@ -384,4 +673,139 @@ mod tests {
             assert_eq!(mut_filtered, imm_filtered);
         }
     }
+
+    #[test]
+    fn test_bm25_congruence() {
+        let indexed_count = 2000;
+        let deleted_count = 200;
+
+        let mut mutable = mutable_inverted_index(indexed_count, deleted_count);
+        let mut immutable = ImmutableInvertedIndex::from(mutable.clone());
+
+        let path = tempfile::tempdir().unwrap().into_path();
+
+        MmapInvertedIndex::create(path.clone(), immutable.clone()).unwrap();
+
+        let mut mmap_index = MmapInvertedIndex::open(path, false).unwrap();
+
+        // Delete random documents from all indexes
+        for _ in 0..deleted_count {
+            let point_id = rand::rng().random_range(0..indexed_count);
+            mutable.remove_document(point_id);
+            immutable.remove_document(point_id);
+            mmap_index.remove_document(point_id);
+        }
+
+        assert_eq!(mutable.total_tokens_count(), immutable.total_tokens_count());
+        assert_eq!(
+            mutable.total_tokens_count(),
+            mmap_index.total_tokens_count()
+        );
+
+        let params = Bm25Params::default();
+
+        for query in (0..100).map(|_| generate_query()) {
+            let mut_query = to_parsed_query(query.clone(), |token| mutable.get_token_id(&token));
+            let imm_query = to_parsed_query(query.clone(), |token| immutable.get_token_id(&token));
+            let mmap_query = to_parsed_query(query, |token| mmap_index.get_token_id(&token));
+
+            let mut_scores = mutable.bm25_scores(&mut_query, params);
+            let imm_scores = immutable.bm25_scores(&imm_query, params);
+            let mmap_scores = mmap_index.bm25_scores(&mmap_query, params);
+
+            assert_eq!(mut_scores.len(), imm_scores.len());
+            assert_eq!(mut_scores.len(), mmap_scores.len());
+
+            for (point_id, score) in mut_scores {
+                assert!((imm_scores[&point_id] - score).abs() < 1e-5);
+                assert!((mmap_scores[&point_id] - score).abs() < 1e-5);
+            }
+        }
+    }
+
+    /// Phrase is a subsequence of the document window, starting with the first phrase word
+    fn naive_phrase_match(words: &[String], phrase: &[String], slop: usize) -> bool {
+        (0..words.len()).any(|start| {
+            let window_end = (start + phrase.len() + slop).min(words.len());
+            let mut window = words[start..window_end].iter();
+            words[start] == phrase[0]
+                && phrase
+                    .iter()
+                    .all(|phrase_word| window.any(|word| word == phrase_word))
+        })
+    }
+
+    #[test]
+    fn test_phrase_congruence() {
+        let indexed_count = 1000;
+        let deleted_count = 100;
+
+        // Small vocabulary, so that phrases have plenty of matches
+        let vocab: Vec<String> = (0..8).map(|i| format!("w{i}")).collect();
+        let random_words = |count: usize| -> Vec<String> {
+            (0..count)
+                .map(|_| vocab.choose(&mut rand::rng()).unwrap().clone())
+                .collect()
+        };
+
+        let mut mutable = MutableInvertedIndex::default();
+        let mut documents: Vec<Option<Vec<String>>> = Vec::new();
+        for idx in 0..indexed_count {
+            let words = random_words(rand::rng().random_range(5..=20));
+            let document = mutable.document_from_tokens(&to_positioned_tokens(&words));
+            mutable.index_document(idx, document).unwrap();
+            documents.push(Some(words));
+        }
+
+        let mut immutable = ImmutableInvertedIndex::from(mutable.clone());
+
+        let path = tempfile::tempdir().unwrap().into_path();
+        MmapInvertedIndex::create(path.clone(), immutable.clone()).unwrap();
+        let mut mmap_index = MmapInvertedIndex::open(path, false).unwrap();
+
+        for _ in 0..deleted_count {
+            let point_id = rand::rng().random_range(0..indexed_count);
+            mutable.remove_document(point_id);
+            immutable.remove_document(point_id);
+            mmap_index.remove_document(point_id);
+            documents[point_id as usize] = None;
+        }
+
+        let to_parsed_phrase =
+            |phrase: &[String], slop: u32, token_to_id: &dyn Fn(&str) -> Option<TokenId>| {
+                ParsedPhrase {
+                    tokens: phrase.iter().map(|word| token_to_id(word)).collect(),
+                    slop,
+                }
+            };
+
+        for _ in 0..200 {
+            let phrase = random_words(rand::rng().random_range(1..=3));
+            let slop = rand::rng().random_range(0..=2);
+
+            let expected: Vec<_> = documents
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, words)| {
+                    let words = words.as_ref()?;
+                    naive_phrase_match(words, &phrase, slop as usize)
+                        .then_some(idx as PointOffsetType)
+                })
+                .collect();
+
+            let mut_phrase = to_parsed_phrase(&phrase, slop, &|token| mutable.get_token_id(token));
+            let imm_phrase =
+                to_parsed_phrase(&phrase, slop, &|token| immutable.get_token_id(token));
+            let mmap_phrase =
+                to_parsed_phrase(&phrase, slop, &|token| mmap_index.get_token_id(token));
+
+            let mut_filtered: Vec<_> = mutable.filter_phrase(mut_phrase).collect();
+            let imm_filtered: Vec<_> = immutable.filter_phrase(imm_phrase).collect();
+            let mmap_filtered: Vec<_> = mmap_index.filter_phrase(mmap_phrase).collect();
+
+            assert_eq!(mut_filtered, expected, "phrase: {phrase:?}, slop: {slop}");
+            assert_eq!(imm_filtered, expected, "phrase: {phrase:?}, slop: {slop}");
+            assert_eq!(mmap_filtered, expected, "phrase: {phrase:?}, slop: {slop}");
+        }
+    }
 }
@@ -0,0 +1,164 @@
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+
+use memmap2::Mmap;
+use memory::madvise::{Advice, AdviceSetting};
+use memory::mmap_ops::open_read_mmap;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_list::CompressedPostingList;
+use crate::index::field_index::full_text_index::compressed_posting::posting_positions::PostingPositionsReader;
+use crate::index::field_index::full_text_index::inverted_index::{TokenId, TokenPosition};
+
+#[derive(Debug, Default, Clone, FromBytes, Immutable, IntoBytes, KnownLayout)]
+#[repr(C)]
+struct PositionsHeader {
+    /// Number of positions lists. One positions list per posting list
+    pub positions_lists_count: usize,
+    _reserved: [u8; 32],
+}
+
+/// This data structure should contain all the necessary information to
+/// construct `PostingPositionsReader` from the mmap file.
+#[derive(Debug, Default, Clone, FromBytes, Immutable, IntoBytes, KnownLayout)]
+#[repr(C)]
+struct PositionsListHeader {
+    /// Offset in bytes from the start of the mmap file
+    /// where the positions list data starts
+    offset: u64,
+    /// Amount of offsets, one per posting entry plus one. Zero if positions are not known
+    offsets_count: u32,
+    /// Amount of positions for all posting entries
+    positions_count: u32,
+}
+
+impl PositionsListHeader {
+    /// Size of the positions list this header represents
+    fn positions_list_size(&self) -> usize {
+        self.offsets_count as usize * size_of::<u32>()
+            + self.positions_count as usize * size_of::<TokenPosition>()
+    }
+}
+
+/// MmapPostingPositions Structure on disk:
+///
+///
+/// `| PositionsHeader |
+/// [ PositionsListHeader, PositionsListHeader, ... ] |
+/// [ offsets, positions, offsets, positions, ... ] |`
+///
+/// Positions lists are in the same order as posting lists in `MmapPostings`.
+pub struct MmapPostingPositions {
+    _path: PathBuf,
+    mmap: Mmap,
+    header: PositionsHeader,
+}
+
+impl MmapPostingPositions {
+    fn get_header(&self, token_id: TokenId) -> Option<&PositionsListHeader> {
+        if self.header.positions_lists_count <= token_id as usize {
+            return None;
+        }
+
+        let header_offset =
+            size_of::<PositionsHeader>() + token_id as usize * size_of::<PositionsListHeader>();
+
+        PositionsListHeader::ref_from_prefix(self.mmap.get(header_offset..)?)
+            .ok()
+            .map(|(header, _)| header)
+    }
+
+    /// Create PostingPositionsReader from the given header
+    ///
+    /// Assume the following layout:
+    ///
+    /// ```ignore
+    /// offsets: &'a [u32],
+    /// positions: &'a [TokenPosition],
+    /// ```
+    fn get_reader(&self, header: &PositionsListHeader) -> Option<PostingPositionsReader<'_>> {
+        let bytes = self.mmap.get(header.offset as usize..)?;
+        let (offsets, bytes) =
+            <[u32]>::ref_from_prefix_with_elems(bytes, header.offsets_count as usize).ok()?;
+        let (positions, _) =
+            <[TokenPosition]>::ref_from_prefix_with_elems(bytes, header.positions_count as usize)
+                .ok()?;
+
+        Some(PostingPositionsReader::new(offsets, positions))
+    }
+
+    pub fn get(&self, token_id: TokenId) -> Option<PostingPositionsReader<'_>> {
+        let header = self.get_header(token_id)?;
+        self.get_reader(header)
+    }
+
+    /// Given a vector of compressed posting lists, this function writes their positions to the `path` file.
+    /// The format of the file is compatible with the `MmapPostingPositions` structure.
+    pub fn create(path: PathBuf, compressed_postings: &[CompressedPostingList]) -> io::Result<()> {
+        // Create a new empty file, where we will write the positions lists and the header
+        let file = tempfile::Builder::new()
+            .prefix(path.file_name().ok_or(io::ErrorKind::InvalidInput)?)
+            .tempfile_in(path.parent().ok_or(io::ErrorKind::InvalidInput)?)?;
+        let mut bufw = io::BufWriter::new(&file);
+
+        let positions_header = PositionsHeader {
+            positions_lists_count: compressed_postings.len(),
+            _reserved: [0; 32],
+        };
+
+        // Write the header to the buffer
+        bufw.write_all(positions_header.as_bytes())?;
+
+        let positions_lists_headers_size =
+            compressed_postings.len() * size_of::<PositionsListHeader>();
+        let mut positions_offset = size_of::<PositionsHeader>() + positions_lists_headers_size;
+
+        for compressed_posting in compressed_postings {
+            let (offsets, positions) = compressed_posting.posting_positions().internal_structs();
+
+            let positions_list_header = PositionsListHeader {
+                offset: positions_offset as u64,
+                offsets_count: offsets.len() as u32,
+                positions_count: positions.len() as u32,
+            };
+
+            // Write the positions list header to the buffer
+            bufw.write_all(positions_list_header.as_bytes())?;
+
+            positions_offset += positions_list_header.positions_list_size();
+        }
+
+        for compressed_posting in compressed_postings {
+            let (offsets, positions) = compressed_posting.posting_positions().internal_structs();
+
+            bufw.write_all(offsets.as_bytes())?;
+            bufw.write_all(positions.as_bytes())?;
+        }
+
+        // Dropping will flush the buffer to the file
+        drop(bufw);
+        file.as_file().sync_all()?;
+        file.persist(path)?;
+
+        Ok(())
+    }
+
+    pub fn open(path: impl Into<PathBuf>, populate: bool) -> io::Result<Self> {
+        let path = path.into();
+        let mmap = open_read_mmap(&path, AdviceSetting::Advice(Advice::Normal), populate)?;
+
+        let (header, _) = PositionsHeader::read_from_prefix(&mmap).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid header deserialization in {}", path.display()),
+            )
+        })?;
+
+        Ok(Self {
+            _path: path,
+            mmap,
+            header,
+        })
+    }
+}
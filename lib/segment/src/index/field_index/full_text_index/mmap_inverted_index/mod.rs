@@ -7,18 +7,25 @@ use common::types::PointOffsetType;
 use memory::madvise::AdviceSetting;
 use memory::mmap_ops;
 use memory::mmap_type::{MmapBitSlice, MmapSlice};
+use mmap_positions::MmapPostingPositions;
 use mmap_postings::MmapPostings;
 
-use super::inverted_index::{InvertedIndex, ParsedQuery};
+use super::fuzzy_vocab::FuzzyVocab;
+use super::inverted_index::{InvertedIndex, ParsedFuzzyQuery, ParsedPhrase, ParsedQuery};
 use super::postings_iterator::intersect_compressed_postings_iterator;
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_iterator::CompressedPostingIterator;
+use crate::index::field_index::full_text_index::compressed_posting::compressed_posting_visitor::CompressedPostingVisitor;
 use crate::index::field_index::full_text_index::immutable_inverted_index::ImmutableInvertedIndex;
 use crate::index::field_index::full_text_index::inverted_index::TokenId;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 
+mod mmap_positions;
 mod mmap_postings;
 
 const POSTINGS_FILE: &str = "postings.dat";
+const POSITIONS_FILE: &str = "positions.dat";
 const VOCAB_FILE: &str = "vocab.dat";
 const POINT_TO_TOKENS_COUNT_FILE: &str = "point_to_tokens_count.dat";
 const DELETED_POINTS_FILE: &str = "deleted_points.dat";
@@ -26,12 +33,17 @@ const DELETED_POINTS_FILE: &str = "deleted_points.dat";
 pub struct MmapInvertedIndex {
     pub(in crate::index::field_index::full_text_index) path: PathBuf,
     pub(in crate::index::field_index::full_text_index) postings: MmapPostings,
+    /// Token positions of the postings. Not present in indexes created without positions
+    pub(in crate::index::field_index::full_text_index) positions: Option<MmapPostingPositions>,
     pub(in crate::index::field_index::full_text_index) vocab: MmapHashMap<str, TokenId>,
     pub(in crate::index::field_index::full_text_index) point_to_tokens_count: MmapSlice<usize>,
     pub(in crate::index::field_index::full_text_index) deleted_points:
         MmapBitSliceBufferedUpdateWrapper,
     /// Number of points which are not deleted
     pub(in crate::index::field_index::full_text_index) active_points_count: usize,
+    /// Sum of the lengths of all non-deleted documents
+    pub(in crate::index::field_index::full_text_index) active_tokens_count: usize,
+    pub(in crate::index::field_index::full_text_index) fuzzy_vocab: FuzzyVocab,
 }
 
 impl MmapInvertedIndex {
@@ -41,16 +53,20 @@ impl MmapInvertedIndex {
             vocab,
             point_to_tokens_count,
             points_count: _,
+            tokens_count: _,
+            fuzzy_vocab: _,
         } = inverted_index;
 
         debug_assert_eq!(vocab.len(), postings.len());
 
         let postings_path = path.join(POSTINGS_FILE);
+        let positions_path = path.join(POSITIONS_FILE);
         let vocab_path = path.join(VOCAB_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
 
         MmapPostings::create(postings_path, &postings)?;
+        MmapPostingPositions::create(positions_path, &postings)?;
 
         // Currently MmapHashMap maps str -> [u32], but we only need to map str -> u32.
         // TODO: Consider making another mmap structure for this case.
@@ -80,11 +96,18 @@ impl MmapInvertedIndex {
 
     pub fn open(path: PathBuf, populate: bool) -> OperationResult<Self> {
         let postings_path = path.join(POSTINGS_FILE);
+        let positions_path = path.join(POSITIONS_FILE);
         let vocab_path = path.join(VOCAB_FILE);
         let point_to_tokens_count_path = path.join(POINT_TO_TOKENS_COUNT_FILE);
         let deleted_points_path = path.join(DELETED_POINTS_FILE);
 
         let postings = MmapPostings::open(&postings_path, populate)?;
+        // Indexes created before positions were introduced don't have the positions file
+        let positions = if positions_path.exists() {
+            Some(MmapPostingPositions::open(&positions_path, populate)?)
+        } else {
+            None
+        };
         let vocab = MmapHashMap::<str, TokenId>::open(&vocab_path)?;
 
         let point_to_tokens_count = unsafe {
@@ -103,21 +126,22 @@ impl MmapInvertedIndex {
         let deleted_points = MmapBitSliceBufferedUpdateWrapper::new(deleted);
         let points_count = point_to_tokens_count.len() - num_deleted_points;
 
+        // Deleted points have zero tokens count, so they don't contribute to the sum
+        let tokens_count: usize = point_to_tokens_count.iter().sum();
+
         Ok(Self {
             path,
             postings,
+            positions,
             vocab,
             point_to_tokens_count,
             deleted_points,
             active_points_count: points_count,
+            active_tokens_count: tokens_count,
+            fuzzy_vocab: FuzzyVocab::default(),
         })
     }
 
-    fn iter_vocab(&self) -> impl Iterator<Item = (&str, &TokenId)> {
-        // unwrap safety: we know that each token points to a token id.
-        self.vocab.iter().map(|(k, v)| (k, v.first().unwrap()))
-    }
-
     /// Returns whether the point id is valid and active.
     fn is_active(&self, point_id: PointOffsetType) -> bool {
         let is_deleted = self.deleted_points.get(point_id as usize).unwrap_or(true);
@@ -126,12 +150,16 @@ impl MmapInvertedIndex {
     }
 
     pub fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             self.path.join(POSTINGS_FILE),
             self.path.join(VOCAB_FILE),
             self.path.join(POINT_TO_TOKENS_COUNT_FILE),
             self.path.join(DELETED_POINTS_FILE),
-        ]
+        ];
+        if self.positions.is_some() {
+            files.push(self.path.join(POSITIONS_FILE));
+        }
+        files
     }
 }
 
@@ -161,6 +189,7 @@ impl InvertedIndex for MmapInvertedIndex {
 
         self.deleted_points.set(idx as usize, true);
         if let Some(count) = self.point_to_tokens_count.get_mut(idx as usize) {
+            self.active_tokens_count -= *count;
             *count = 0;
 
             // `deleted_points`'s length can be larger than `point_to_tokens_count`'s length.
@@ -200,14 +229,38 @@ impl InvertedIndex for MmapInvertedIndex {
         self.postings.get(token_id).map(|p| p.len())
     }
 
+    fn iter_posting(
+        &self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
+        let reader = self.postings.get(token_id)?;
+        let iter = CompressedPostingIterator::new(CompressedPostingVisitor::new(reader));
+        // in case of mmap immutable index, deleted points are still in the postings
+        Some(Box::new(iter.filter(move |&idx| self.is_active(idx))))
+    }
+
+    fn total_tokens_count(&self) -> usize {
+        self.active_tokens_count
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
-        self.iter_vocab().filter_map(|(token, &token_id)| {
+        self.iter_vocab().filter_map(|(token, token_id)| {
             self.postings
                 .get(token_id)
                 .map(|posting| (token, posting.len()))
         })
     }
 
+    fn iter_vocab(&self) -> impl Iterator<Item = (&str, TokenId)> + '_ {
+        // unwrap safety: we know that each token points to a token id.
+        self.vocab.iter().map(|(k, v)| (k, *v.first().unwrap()))
+    }
+
+    fn fuzzy_tokens(&self, automatons: &[LevenshteinAutomaton]) -> Vec<Vec<TokenId>> {
+        self.fuzzy_vocab
+            .fuzzy_tokens(|| self.iter_vocab(), automatons)
+    }
+
     fn check_match(&self, parsed_query: &ParsedQuery, point_id: PointOffsetType) -> bool {
         if parsed_query.tokens.contains(&None) {
             return false;
@@ -229,6 +282,33 @@ impl InvertedIndex for MmapInvertedIndex {
             })
     }
 
+    fn check_fuzzy(&self, query: &ParsedFuzzyQuery, point_id: PointOffsetType) -> bool {
+        // check presence of the document
+        if self.values_is_empty(point_id) {
+            return false;
+        }
+        query.check(|token| {
+            self.postings
+                .get(token)
+                .is_some_and(|posting| posting.contains(point_id))
+        })
+    }
+
+    fn check_phrase(&self, phrase: &ParsedPhrase, point_id: PointOffsetType) -> bool {
+        let Some(positions) = &self.positions else {
+            // Indexes without positions are rebuilt on load, see `FullTextIndex::load`
+            return false;
+        };
+        // check presence of the document
+        if self.values_is_empty(point_id) {
+            return false;
+        }
+        phrase.check_positions(|token| {
+            let offset = self.postings.get(token)?.find_offset(point_id)?;
+            positions.get(token)?.get(offset)
+        })
+    }
+
     fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
         if self.deleted_points.get(point_id as usize).unwrap_or(true) {
             return true;
@@ -1,4 +1,3 @@
-use std::collections::BTreeSet;
 use std::fs::{create_dir_all, remove_dir};
 use std::path::PathBuf;
 
@@ -18,7 +17,7 @@ use crate::index::field_index::{FieldIndexBuilderTrait, ValueIndexer};
 
 pub struct MmapFullTextIndex {
     pub(super) inverted_index: MmapInvertedIndex,
-    pub(super) config: TextIndexParams,
+    pub(super) tokenizer: Tokenizer,
 }
 
 impl MmapFullTextIndex {
@@ -27,7 +26,7 @@ impl MmapFullTextIndex {
 
         Ok(Self {
             inverted_index,
-            config,
+            tokenizer: Tokenizer::new(config),
         })
     }
 
@@ -63,7 +62,7 @@ impl MmapFullTextIndex {
 pub struct FullTextMmapIndexBuilder {
     path: PathBuf,
     mutable_index: MutableInvertedIndex,
-    config: TextIndexParams,
+    tokenizer: Tokenizer,
 }
 
 impl FullTextMmapIndexBuilder {
@@ -71,7 +70,7 @@ impl FullTextMmapIndexBuilder {
         Self {
             path,
             mutable_index: MutableInvertedIndex::default(),
-            config,
+            tokenizer: Tokenizer::new(config),
         }
     }
 }
@@ -95,13 +94,7 @@ impl ValueIndexer for FullTextMmapIndexBuilder {
             return Ok(());
         }
 
-        let mut tokens: BTreeSet<String> = BTreeSet::new();
-
-        for value in values {
-            Tokenizer::tokenize_doc(&value, &self.config, |token| {
-                tokens.insert(token.to_owned());
-            });
-        }
+        let tokens = FullTextIndex::tokenize_values(&values, &self.tokenizer);
 
         let document = self.mutable_index.document_from_tokens(&tokens);
         self.mutable_index.index_document(id, document)?;
@@ -131,7 +124,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
         let Self {
             path,
             mutable_index,
-            config,
+            tokenizer,
         } = self;
 
         let immutable = ImmutableInvertedIndex::from(mutable_index);
@@ -144,7 +137,7 @@ impl FieldIndexBuilderTrait for FullTextMmapIndexBuilder {
 
         let mmap_index = MmapFullTextIndex {
             inverted_index,
-            config,
+            tokenizer,
         };
 
         Ok(FullTextIndex::Mmap(Box::new(mmap_index)))
@@ -1,3 +1,5 @@
+pub mod bm25;
+mod fuzzy_vocab;
 mod immutable_text_index;
 mod inverted_index;
 mod mmap_inverted_index;
@@ -5,6 +7,7 @@ pub mod mmap_text_index;
 mod mutable_text_index;
 mod posting_list;
 mod postings_iterator;
+mod stop_words;
 pub mod text_index;
 mod tokenizers;
 
@@ -1,10 +1,12 @@
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, HashMap};
 
 use common::types::PointOffsetType;
 
 use super::inverted_index::InvertedIndex;
 use crate::common::operation_error::OperationResult;
-use crate::index::field_index::full_text_index::inverted_index::{Document, ParsedQuery, TokenId};
+use crate::index::field_index::full_text_index::inverted_index::{
+    Document, ParsedFuzzyQuery, ParsedPhrase, ParsedQuery, TokenId, TokenPosition,
+};
 use crate::index::field_index::full_text_index::posting_list::PostingList;
 use crate::index::field_index::full_text_index::postings_iterator::intersect_postings_iterator;
 
@@ -15,11 +17,15 @@ pub struct MutableInvertedIndex {
     pub(in crate::index::field_index::full_text_index) vocab: HashMap<String, TokenId>,
     pub(in crate::index::field_index::full_text_index) point_to_docs: Vec<Option<Document>>,
     pub(in crate::index::field_index::full_text_index) points_count: usize,
+    /// Sum of the lengths of all indexed documents
+    pub(in crate::index::field_index::full_text_index) tokens_count: usize,
 }
 
 impl MutableInvertedIndex {
     pub fn build_index(
-        iter: impl Iterator<Item = OperationResult<(PointOffsetType, BTreeSet<String>)>>,
+        iter: impl Iterator<
+            Item = OperationResult<(PointOffsetType, BTreeMap<String, Vec<TokenPosition>>)>,
+        >,
     ) -> OperationResult<Self> {
         let mut index = Self::default();
 
@@ -35,14 +41,18 @@ impl MutableInvertedIndex {
             }
 
             let document = index.document_from_tokens(&tokens);
+            index.tokens_count += document.len();
             index.point_to_docs[idx as usize] = Some(document);
         }
 
         // build postings from point_to_docs
         // build in order to increase document id
-        for (idx, doc) in index.point_to_docs.iter().enumerate() {
+        for (idx, doc) in index.point_to_docs.iter_mut().enumerate() {
             if let Some(doc) = doc {
+                // Positions are kept in the postings only
+                let mut positions = doc.take_positions().into_iter();
                 for token_idx in doc.tokens() {
+                    let token_positions = positions.next().unwrap_or_default();
                     if index.postings.len() <= *token_idx as usize {
                         index
                             .postings
@@ -53,8 +63,11 @@ impl MutableInvertedIndex {
                         .get_mut(*token_idx as usize)
                         .expect("posting must exist even if with None");
                     match posting {
-                        None => *posting = Some(PostingList::new(idx as PointOffsetType)),
-                        Some(vec) => vec.insert(idx as PointOffsetType),
+                        None => {
+                            *posting =
+                                Some(PostingList::new(idx as PointOffsetType, token_positions))
+                        }
+                        Some(vec) => vec.insert(idx as PointOffsetType, token_positions),
                     }
                 }
             }
@@ -76,7 +89,7 @@ impl InvertedIndex for MutableInvertedIndex {
     fn index_document(
         &mut self,
         point_id: PointOffsetType,
-        document: Document,
+        mut document: Document,
     ) -> OperationResult<()> {
         self.points_count += 1;
         if self.point_to_docs.len() <= point_id as usize {
@@ -84,7 +97,10 @@ impl InvertedIndex for MutableInvertedIndex {
                 .resize_with(point_id as usize + 1, Default::default);
         }
 
+        // Positions are kept in the postings only
+        let mut positions = document.take_positions().into_iter();
         for token_idx in document.tokens() {
+            let token_positions = positions.next().unwrap_or_default();
             let token_idx_usize = *token_idx as usize;
             if self.postings.len() <= token_idx_usize {
                 self.postings
@@ -95,10 +111,11 @@ impl InvertedIndex for MutableInvertedIndex {
                 .get_mut(token_idx_usize)
                 .expect("posting must exist even if with None");
             match posting {
-                None => *posting = Some(PostingList::new(point_id)),
-                Some(vec) => vec.insert(point_id),
+                None => *posting = Some(PostingList::new(point_id, token_positions)),
+                Some(vec) => vec.insert(point_id, token_positions),
             }
         }
+        self.tokens_count += document.len();
         self.point_to_docs[point_id as usize] = Some(document);
 
         Ok(())
@@ -114,6 +131,7 @@ impl InvertedIndex for MutableInvertedIndex {
         };
 
         self.points_count -= 1;
+        self.tokens_count -= removed_doc.len();
 
         for removed_token in removed_doc.tokens() {
             // unwrap safety: posting list exists and contains the document id
@@ -156,6 +174,18 @@ impl InvertedIndex for MutableInvertedIndex {
             .map(|x| x.len())
     }
 
+    fn iter_posting(
+        &self,
+        token_id: TokenId,
+    ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
+        let posting = self.postings.get(token_id as usize)?.as_ref()?;
+        Some(Box::new(posting.iter()))
+    }
+
+    fn total_tokens_count(&self) -> usize {
+        self.tokens_count
+    }
+
     fn vocab_with_postings_len_iter(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
         self.vocab.iter().filter_map(|(token, &posting_idx)| {
             if let Some(Some(postings)) = self.postings.get(posting_idx as usize) {
@@ -166,6 +196,12 @@ impl InvertedIndex for MutableInvertedIndex {
         })
     }
 
+    fn iter_vocab(&self) -> impl Iterator<Item = (&str, TokenId)> + '_ {
+        self.vocab
+            .iter()
+            .map(|(token, &token_id)| (token.as_str(), token_id))
+    }
+
     fn check_match(&self, parsed_query: &ParsedQuery, point_id: PointOffsetType) -> bool {
         if let Some(doc) = self.get_doc(point_id) {
             parsed_query.check_match(doc)
@@ -174,6 +210,23 @@ impl InvertedIndex for MutableInvertedIndex {
         }
     }
 
+    fn check_fuzzy(&self, query: &ParsedFuzzyQuery, point_id: PointOffsetType) -> bool {
+        self.get_doc(point_id)
+            .is_some_and(|doc| query.check_match(doc))
+    }
+
+    fn check_phrase(&self, phrase: &ParsedPhrase, point_id: PointOffsetType) -> bool {
+        if self.get_doc(point_id).is_none() {
+            return false;
+        }
+        phrase.check_positions(|token| {
+            self.postings
+                .get(token as usize)?
+                .as_ref()?
+                .positions(point_id)
+        })
+    }
+
     fn values_is_empty(&self, point_id: PointOffsetType) -> bool {
         self.get_doc(point_id).map(|x| x.is_empty()).unwrap_or(true)
     }
@@ -1,5 +1,3 @@
-use std::collections::BTreeSet;
-
 use common::types::PointOffsetType;
 
 use super::inverted_index::InvertedIndex;
@@ -13,7 +11,7 @@ use crate::data_types::index::TextIndexParams;
 pub struct MutableFullTextIndex {
     pub(super) inverted_index: MutableInvertedIndex,
     pub(super) db_wrapper: DatabaseColumnScheduledDeleteWrapper,
-    pub(super) config: TextIndexParams,
+    pub(super) tokenizer: Tokenizer,
 }
 
 impl MutableFullTextIndex {
@@ -21,7 +19,7 @@ impl MutableFullTextIndex {
         Self {
             inverted_index: Default::default(),
             db_wrapper,
-            config,
+            tokenizer: Tokenizer::new(config),
         }
     }
 
@@ -35,13 +33,26 @@ impl MutableFullTextIndex {
         };
 
         let db = self.db_wrapper.lock_db();
-        let iter = db.iter()?.map(|(key, value)| {
+        let mut missing_positions = false;
+        let iter = db.iter()?.map_while(|(key, value)| {
             let idx = FullTextIndex::restore_key(&key);
-            let tokens = FullTextIndex::deserialize_document(&value)?;
-            Ok((idx, tokens))
+            match FullTextIndex::deserialize_document(&value) {
+                Ok(Some(tokens)) => Some(Ok((idx, tokens))),
+                Ok(None) => {
+                    missing_positions = true;
+                    None
+                }
+                Err(err) => Some(Err(err)),
+            }
         });
 
-        self.inverted_index = MutableInvertedIndex::build_index(iter)?;
+        let inverted_index = MutableInvertedIndex::build_index(iter)?;
+        if missing_positions {
+            // Stored without token positions, rebuild from payload to support phrase matching
+            return Ok(false);
+        }
+
+        self.inverted_index = inverted_index;
 
         Ok(true)
     }
@@ -51,13 +62,7 @@ impl MutableFullTextIndex {
             return Ok(());
         }
 
-        let mut tokens: BTreeSet<String> = BTreeSet::new();
-
-        for value in values {
-            Tokenizer::tokenize_doc(&value, &self.config, |token| {
-                tokens.insert(token.to_owned());
-            });
-        }
+        let tokens = FullTextIndex::tokenize_values(&values, &self.tokenizer);
 
         let document = self.inverted_index.document_from_tokens(&tokens);
         self.inverted_index.index_document(idx, document)?;
@@ -125,6 +130,9 @@ mod tests {
             min_token_len: None,
             max_token_len: None,
             lowercase: None,
+            ascii_folding: None,
+            stopwords: None,
+            stemmer: None,
             on_disk: None,
         };
 
@@ -1,18 +1,26 @@
 use common::types::PointOffsetType;
 
+use super::inverted_index::TokenPosition;
+
 #[derive(Clone, Debug, Default)]
 pub struct PostingList {
     list: Vec<PointOffsetType>,
+    /// Positions of the token in each document, aligned with `list`
+    positions: Vec<Vec<TokenPosition>>,
 }
 
 impl PostingList {
-    pub fn new(idx: PointOffsetType) -> Self {
-        Self { list: vec![idx] }
+    pub fn new(idx: PointOffsetType, positions: Vec<TokenPosition>) -> Self {
+        Self {
+            list: vec![idx],
+            positions: vec![positions],
+        }
     }
 
-    pub fn insert(&mut self, idx: PointOffsetType) {
+    pub fn insert(&mut self, idx: PointOffsetType, positions: Vec<TokenPosition>) {
         if self.list.is_empty() || idx > *self.list.last().unwrap() {
             self.list.push(idx);
+            self.positions.push(positions);
         } else if let Err(insertion_idx) = self.list.binary_search(&idx) {
             // Yes, this is O(n) but:
             // 1. That would give us maximal search performance with minimal memory usage
@@ -20,12 +28,14 @@ impl PostingList {
             // 3. Vector indexing is more expensive anyway
             // 4. For loading, insertion is strictly in increasing order
             self.list.insert(insertion_idx, idx);
+            self.positions.insert(insertion_idx, positions);
         }
     }
 
     pub fn remove(&mut self, idx: PointOffsetType) {
         if let Ok(removal_idx) = self.list.binary_search(&idx) {
             self.list.remove(removal_idx);
+            self.positions.remove(removal_idx);
         }
     }
 
@@ -37,11 +47,17 @@ impl PostingList {
         self.list.binary_search(&val).is_ok()
     }
 
+    /// Positions of the token in the given document
+    pub fn positions(&self, val: PointOffsetType) -> Option<&[TokenPosition]> {
+        let idx = self.list.binary_search(&val).ok()?;
+        Some(&self.positions[idx])
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
         self.list.iter().copied()
     }
 
-    pub(crate) fn into_vec(self) -> Vec<PointOffsetType> {
-        self.list
+    pub(crate) fn into_parts(self) -> (Vec<PointOffsetType>, Vec<Vec<TokenPosition>>) {
+        (self.list, self.positions)
     }
 }
@@ -61,22 +61,22 @@ mod tests {
     #[test]
     fn test_postings_iterator() {
         let mut p1 = PostingList::default();
-        p1.insert(1);
-        p1.insert(2);
-        p1.insert(3);
-        p1.insert(4);
-        p1.insert(5);
+        p1.insert(1, vec![]);
+        p1.insert(2, vec![]);
+        p1.insert(3, vec![]);
+        p1.insert(4, vec![]);
+        p1.insert(5, vec![]);
         let mut p2 = PostingList::default();
-        p2.insert(2);
-        p2.insert(4);
-        p2.insert(5);
-        p2.insert(5);
+        p2.insert(2, vec![]);
+        p2.insert(4, vec![]);
+        p2.insert(5, vec![]);
+        p2.insert(5, vec![]);
         let mut p3 = PostingList::default();
-        p3.insert(1);
-        p3.insert(2);
-        p3.insert(5);
-        p3.insert(6);
-        p3.insert(7);
+        p3.insert(1, vec![]);
+        p3.insert(2, vec![]);
+        p3.insert(5, vec![]);
+        p3.insert(6, vec![]);
+        p3.insert(7, vec![]);
 
         let postings = vec![&p1, &p2, &p3];
         let merged = intersect_postings_iterator(postings);
@@ -85,9 +85,9 @@ mod tests {
 
         assert_eq!(res, vec![2, 5]);
 
-        let p1_compressed = CompressedPostingList::new(&p1.into_vec());
-        let p2_compressed = CompressedPostingList::new(&p2.into_vec());
-        let p3_compressed = CompressedPostingList::new(&p3.into_vec());
+        let p1_compressed = CompressedPostingList::new(&p1.into_parts().0, &[]);
+        let p2_compressed = CompressedPostingList::new(&p2.into_parts().0, &[]);
+        let p3_compressed = CompressedPostingList::new(&p3.into_parts().0, &[]);
         let compressed_posting_reades = vec![
             p1_compressed.reader(),
             p2_compressed.reader(),
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use crate::data_types::index::{Language, StopwordsInterface};
+
+mod dutch;
+mod english;
+mod french;
+mod german;
+mod italian;
+mod portuguese;
+mod russian;
+mod spanish;
+
+/// Predefined stop words of the language, sorted by bytes
+fn language_stopwords(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::Dutch => dutch::DUTCH_STOPWORDS,
+        Language::English => english::ENGLISH_STOPWORDS,
+        Language::French => french::FRENCH_STOPWORDS,
+        Language::German => german::GERMAN_STOPWORDS,
+        Language::Italian => italian::ITALIAN_STOPWORDS,
+        Language::Portuguese => portuguese::PORTUGUESE_STOPWORDS,
+        Language::Russian => russian::RUSSIAN_STOPWORDS,
+        Language::Spanish => spanish::SPANISH_STOPWORDS,
+    }
+}
+
+/// Checks whether a token is a stop word.
+///
+/// Comparison is case-insensitive, so that stop words are removed regardless of the
+/// `lowercase` option of the index.
+#[derive(Debug, Default)]
+pub struct StopwordsFilter {
+    predefined: Vec<&'static [&'static str]>,
+    custom: HashSet<String>,
+}
+
+impl StopwordsFilter {
+    pub fn new(stopwords: Option<&StopwordsInterface>) -> Self {
+        let Some(stopwords) = stopwords else {
+            return Self::default();
+        };
+
+        let predefined = stopwords
+            .languages()
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(language_stopwords)
+            .collect();
+
+        let custom = stopwords
+            .custom()
+            .iter()
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        Self { predefined, custom }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.predefined.is_empty() && self.custom.is_empty()
+    }
+
+    /// `token` is expected to be lowercase
+    pub fn is_stopword(&self, token: &str) -> bool {
+        self.custom.contains(token)
+            || self
+                .predefined
+                .iter()
+                .any(|words| words.binary_search(&token).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::index::StopwordsSet;
+
+    #[test]
+    fn test_predefined_stopwords_sorted() {
+        for language in [
+            Language::Dutch,
+            Language::English,
+            Language::French,
+            Language::German,
+            Language::Italian,
+            Language::Portuguese,
+            Language::Russian,
+            Language::Spanish,
+        ] {
+            let words = language_stopwords(language);
+            assert!(
+                words.windows(2).all(|w| w[0] < w[1]),
+                "stop words of {language:?} must be sorted and unique",
+            );
+            assert!(
+                words.iter().all(|word| word.to_lowercase() == *word),
+                "stop words of {language:?} must be lowercase",
+            );
+        }
+    }
+
+    #[test]
+    fn test_stopwords_filter() {
+        let filter = StopwordsFilter::new(Some(&StopwordsInterface::Set(StopwordsSet {
+            languages: vec![Language::English, Language::German, Language::English],
+            custom: vec!["Qdrant".to_string()],
+        })));
+        // Repeated languages are not checked twice
+        assert_eq!(filter.predefined.len(), 2);
+
+        assert!(filter.is_stopword("the"));
+        assert!(filter.is_stopword("über"));
+        assert!(filter.is_stopword("qdrant"));
+        assert!(!filter.is_stopword("vector"));
+
+        let filter = StopwordsFilter::new(Some(&StopwordsInterface::Language(Language::Russian)));
+        assert!(filter.is_stopword("когда"));
+        assert!(!filter.is_stopword("the"));
+
+        assert!(StopwordsFilter::new(None).is_empty());
+    }
+}
@@ -1,11 +1,16 @@
+use std::collections::HashSet;
+
 use common::types::PointOffsetType;
 use rstest::rstest;
 use tempfile::Builder;
 
 use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
+use crate::data_types::bm25::Bm25Params;
 use crate::data_types::index::{TextIndexParams, TextIndexType, TokenizerType};
 use crate::index::field_index::full_text_index::text_index::FullTextIndex;
 use crate::index::field_index::{FieldIndexBuilderTrait as _, PayloadFieldIndex, ValueIndexer};
+use crate::json_path::JsonPath;
+use crate::types::{FieldCondition, Match, MatchFuzzy, MAX_PHRASE_SLOP};
 
 fn get_texts() -> Vec<String> {
     vec![
@@ -161,6 +166,9 @@ fn test_prefix_search(#[case] immutable: bool) {
         min_token_len: None,
         max_token_len: None,
         lowercase: None,
+        ascii_folding: None,
+        stopwords: None,
+        stemmer: None,
         on_disk: None,
     };
 
@@ -202,3 +210,238 @@ fn test_prefix_search(#[case] immutable: bool) {
 
     assert_eq!(res.len(), 0);
 }
+
+#[rstest]
+#[case(true)]
+#[case(false)]
+fn test_bm25_scores(#[case] immutable: bool) {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        ascii_folding: None,
+        stopwords: None,
+        stemmer: None,
+        on_disk: None,
+    };
+
+    let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+    let mut index = FullTextIndex::builder(db.clone(), config.clone(), "text")
+        .make_empty()
+        .unwrap();
+
+    let texts = get_texts();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.to_string()])
+            .unwrap();
+    }
+
+    if immutable {
+        index = FullTextIndex::new_memory(db, config, "text", false);
+        index.load().unwrap();
+    }
+
+    let position = |title: &str| texts.iter().position(|text| text == title).unwrap() as u32;
+
+    let scores = index.bm25_scores("the weapon", Bm25Params::default());
+
+    // Only documents with at least one of the query tokens are scored
+    let expected_matches: HashSet<_> = index.query("the").chain(index.query("weapon")).collect();
+    assert_eq!(
+        scores.keys().copied().collect::<HashSet<_>>(),
+        expected_matches
+    );
+    assert!(scores.values().all(|score| *score > 0.0));
+
+    // Shorter document with both tokens is the most relevant
+    let best = scores
+        .iter()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(idx, _)| *idx)
+        .unwrap();
+    assert_eq!(best, position("The Weapon"));
+
+    // Rare token weights more than a frequent one
+    let weapon_score = scores[&position("The Weapon Too Dreadful to Use")];
+    let the_score = scores[&position("The Dust of Death")];
+    assert!(weapon_score > the_score);
+
+    // Unknown tokens do not match anything
+    let scores = index.bm25_scores("q231", Bm25Params::default());
+    assert!(scores.is_empty());
+}
+
+#[rstest]
+#[case(true)]
+#[case(false)]
+fn test_phrase_search(#[case] immutable: bool) {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        ascii_folding: None,
+        stopwords: None,
+        stemmer: None,
+        on_disk: None,
+    };
+
+    let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+    let mut index = FullTextIndex::builder(db.clone(), config.clone(), "text")
+        .make_empty()
+        .unwrap();
+
+    let texts = get_texts();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.to_string()])
+            .unwrap();
+    }
+
+    // Phrases should not match across values of the same point
+    let multi_value_idx = texts.len() as PointOffsetType;
+    index
+        .add_many(
+            multi_value_idx,
+            vec!["The Last".to_string(), "Question".to_string()],
+        )
+        .unwrap();
+
+    if immutable {
+        index = FullTextIndex::new_memory(db, config, "text", false);
+        index.load().unwrap();
+    }
+
+    let position = |title: &str| texts.iter().position(|text| text == title).unwrap() as u32;
+
+    let phrase_search = |phrase: &str, slop: Option<u32>| {
+        let condition =
+            FieldCondition::new_match(JsonPath::new("text"), Match::new_phrase(phrase, slop));
+        let mut res: Vec<_> = PayloadFieldIndex::filter(&index, &condition)
+            .unwrap()
+            .collect();
+        res.sort_unstable();
+
+        let parsed_phrase = index.parse_phrase(phrase, slop.unwrap_or(0));
+        for idx in 0..=multi_value_idx {
+            assert_eq!(
+                index.check_phrase(&parsed_phrase, idx),
+                res.contains(&idx),
+                "phrase: {phrase}, point: {idx}"
+            );
+        }
+
+        res
+    };
+
+    let mut expected = vec![
+        position("The Last Answer"),
+        position("The Last Question"),
+        position("The Last Trump"),
+    ];
+    expected.sort_unstable();
+    expected.push(multi_value_idx);
+    assert_eq!(phrase_search("the last", None), expected);
+
+    // Order of the tokens matters
+    assert!(phrase_search("last the", Some(3)).is_empty());
+
+    assert!(phrase_search("the question", None).is_empty());
+    assert_eq!(
+        phrase_search("the question", Some(1)),
+        vec![position("The Last Question")]
+    );
+    assert_eq!(
+        phrase_search("last question", Some(10)),
+        vec![position("The Last Question")]
+    );
+    assert_eq!(
+        phrase_search("last question", Some(MAX_PHRASE_SLOP)),
+        vec![position("The Last Question")]
+    );
+
+    // Unknown tokens do not match anything
+    assert!(phrase_search("the q231", Some(5)).is_empty());
+}
+
+#[rstest]
+#[case(true)]
+#[case(false)]
+fn test_fuzzy_search(#[case] immutable: bool) {
+    let temp_dir = Builder::new().prefix("test_dir").tempdir().unwrap();
+    let config = TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::Word,
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        ascii_folding: None,
+        stopwords: None,
+        stemmer: None,
+        on_disk: None,
+    };
+
+    let db = open_db_with_existing_cf(&temp_dir.path().join("test_db")).unwrap();
+    let mut index = FullTextIndex::builder(db.clone(), config.clone(), "text")
+        .make_empty()
+        .unwrap();
+
+    let texts = get_texts();
+
+    for (i, text) in texts.iter().enumerate() {
+        index
+            .add_many(i as PointOffsetType, vec![text.to_string()])
+            .unwrap();
+    }
+
+    if immutable {
+        index = FullTextIndex::new_memory(db, config, "text", false);
+        index.load().unwrap();
+    }
+
+    let position = |title: &str| texts.iter().position(|text| text == title).unwrap() as u32;
+
+    let fuzzy_search = |text: &str, max_edits: Option<u8>| {
+        let condition =
+            FieldCondition::new_match(JsonPath::new("text"), Match::new_fuzzy(text, max_edits));
+        let mut res: Vec<_> = PayloadFieldIndex::filter(&index, &condition)
+            .unwrap()
+            .collect();
+        res.sort_unstable();
+
+        let parsed_query = index.parse_fuzzy_query(&MatchFuzzy {
+            fuzzy: text.to_string(),
+            max_edits,
+        });
+        for idx in 0..texts.len() as PointOffsetType {
+            assert_eq!(
+                index.check_fuzzy(&parsed_query, idx),
+                res.contains(&idx),
+                "text: {text}, point: {idx}"
+            );
+        }
+
+        res
+    };
+
+    assert_eq!(fuzzy_search("Jupyter", None), vec![position("Buy Jupiter")]);
+    assert_eq!(
+        fuzzy_search("lust questian", None),
+        vec![position("The Last Question")]
+    );
+
+    // Explicit limit overrides the default one
+    assert!(fuzzy_search("Jupyter", Some(0)).is_empty());
+    assert!(fuzzy_search("questian", Some(2)).contains(&position("The Last Question")));
+
+    // Every token of the query has to match
+    assert!(fuzzy_search("jupiter q231xyz", None).is_empty());
+}
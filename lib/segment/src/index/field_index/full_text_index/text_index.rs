@@ -1,15 +1,17 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use common::types::PointOffsetType;
+use common::types::{PointOffsetType, ScoreType};
 use parking_lot::RwLock;
 use rocksdb::DB;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::immutable_text_index::ImmutableFullTextIndex;
-use super::inverted_index::{Document, InvertedIndex, ParsedQuery, TokenId};
+use super::inverted_index::{
+    Document, InvertedIndex, ParsedFuzzyQuery, ParsedPhrase, ParsedQuery, TokenId, TokenPosition,
+};
 use super::mmap_text_index::{FullTextMmapIndexBuilder, MmapFullTextIndex};
 use super::mutable_text_index::MutableFullTextIndex;
 use super::tokenizers::Tokenizer;
@@ -17,13 +19,19 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
+use crate::data_types::bm25::Bm25Params;
 use crate::data_types::index::TextIndexParams;
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 use crate::index::field_index::{
     CardinalityEstimation, FieldIndexBuilderTrait, PayloadBlockCondition, PayloadFieldIndex,
     ValueIndexer,
 };
 use crate::telemetry::PayloadIndexTelemetry;
-use crate::types::{FieldCondition, Match, PayloadKeyType};
+use crate::types::{FieldCondition, Match, MatchFuzzy, PayloadKeyType, MAX_PHRASE_SLOP};
+
+/// Gap between positions of consecutive values of a multi-value field,
+/// so that phrases with any allowed slop don't match across the values
+const VALUES_POSITION_GAP: TokenPosition = MAX_PHRASE_SLOP + 1;
 
 pub enum FullTextIndex {
     Mutable(MutableFullTextIndex),
@@ -78,11 +86,11 @@ impl FullTextIndex {
         format!("{field}_fts")
     }
 
-    fn config(&self) -> &TextIndexParams {
+    fn tokenizer(&self) -> &Tokenizer {
         match self {
-            Self::Mutable(index) => &index.config,
-            Self::Immutable(index) => &index.config,
-            Self::Mmap(index) => &index.config,
+            Self::Mutable(index) => &index.tokenizer,
+            Self::Immutable(index) => &index.tokenizer,
+            Self::Mmap(index) => &index.tokenizer,
         }
     }
 
@@ -134,6 +142,62 @@ impl FullTextIndex {
         }
     }
 
+    fn filter_phrase(
+        &self,
+        phrase: ParsedPhrase,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        match self {
+            Self::Mutable(index) => index.inverted_index.filter_phrase(phrase),
+            Self::Immutable(index) => index.inverted_index.filter_phrase(phrase),
+            Self::Mmap(index) => index.inverted_index.filter_phrase(phrase),
+        }
+    }
+
+    pub fn check_phrase(&self, phrase: &ParsedPhrase, point_id: PointOffsetType) -> bool {
+        match self {
+            Self::Mutable(index) => index.inverted_index.check_phrase(phrase, point_id),
+            Self::Immutable(index) => index.inverted_index.check_phrase(phrase, point_id),
+            Self::Mmap(index) => index.inverted_index.check_phrase(phrase, point_id),
+        }
+    }
+
+    fn filter_fuzzy(
+        &self,
+        query: ParsedFuzzyQuery,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + '_> {
+        match self {
+            Self::Mutable(index) => index.inverted_index.filter_fuzzy(query),
+            Self::Immutable(index) => index.inverted_index.filter_fuzzy(query),
+            Self::Mmap(index) => index.inverted_index.filter_fuzzy(query),
+        }
+    }
+
+    fn estimate_fuzzy_cardinality(
+        &self,
+        query: &ParsedFuzzyQuery,
+        condition: &FieldCondition,
+    ) -> CardinalityEstimation {
+        match self {
+            Self::Mutable(index) => index
+                .inverted_index
+                .estimate_fuzzy_cardinality(query, condition),
+            Self::Immutable(index) => index
+                .inverted_index
+                .estimate_fuzzy_cardinality(query, condition),
+            Self::Mmap(index) => index
+                .inverted_index
+                .estimate_fuzzy_cardinality(query, condition),
+        }
+    }
+
+    pub fn check_fuzzy(&self, query: &ParsedFuzzyQuery, point_id: PointOffsetType) -> bool {
+        match self {
+            Self::Mutable(index) => index.inverted_index.check_fuzzy(query, point_id),
+            Self::Immutable(index) => index.inverted_index.check_fuzzy(query, point_id),
+            Self::Mmap(index) => index.inverted_index.check_fuzzy(query, point_id),
+        }
+    }
+
     pub fn check_match(&self, query: &ParsedQuery, point_id: PointOffsetType) -> bool {
         match self {
             Self::Mutable(index) => index.inverted_index.check_match(query, point_id),
@@ -166,27 +230,68 @@ impl FullTextIndex {
         bincode::deserialize(data).unwrap()
     }
 
-    pub(super) fn serialize_document_tokens(tokens: BTreeSet<String>) -> OperationResult<Vec<u8>> {
+    /// Tokenize values of a point into tokens with their positions.
+    ///
+    /// Positions of each next value are shifted by [`VALUES_POSITION_GAP`].
+    pub(super) fn tokenize_values(
+        values: &[String],
+        tokenizer: &Tokenizer,
+    ) -> BTreeMap<String, Vec<TokenPosition>> {
+        let mut tokens: BTreeMap<String, Vec<TokenPosition>> = BTreeMap::new();
+        let mut values_offset: TokenPosition = 0;
+
+        for value in values {
+            let positions_count =
+                tokenizer.tokenize_doc_with_positions(value, |token, position| {
+                    tokens
+                        .entry(token.to_owned())
+                        .or_default()
+                        .push(values_offset + position);
+                });
+            values_offset += positions_count + VALUES_POSITION_GAP;
+        }
+
+        tokens
+    }
+
+    pub(super) fn serialize_document_tokens(
+        tokens: BTreeMap<String, Vec<TokenPosition>>,
+    ) -> OperationResult<Vec<u8>> {
         #[derive(Serialize)]
         struct StoredDocument {
-            tokens: BTreeSet<String>,
+            tokens: Vec<String>,
+            positions: Vec<Vec<TokenPosition>>,
         }
-        let doc = StoredDocument { tokens };
+        let (tokens, positions) = tokens.into_iter().unzip();
+        let doc = StoredDocument { tokens, positions };
         serde_cbor::to_vec(&doc).map_err(|e| {
             OperationError::service_error(format!("Failed to serialize document: {e}"))
         })
     }
 
-    pub(super) fn deserialize_document(data: &[u8]) -> OperationResult<BTreeSet<String>> {
+    /// Returns `None` for documents stored before token positions were introduced.
+    /// Such documents can't match phrases, so the index has to be rebuilt from the payload.
+    pub(super) fn deserialize_document(
+        data: &[u8],
+    ) -> OperationResult<Option<BTreeMap<String, Vec<TokenPosition>>>> {
         #[derive(Deserialize)]
         struct StoredDocument {
-            tokens: BTreeSet<String>,
+            tokens: Vec<String>,
+            /// Aligned with `tokens`
+            #[serde(default)]
+            positions: Option<Vec<Vec<TokenPosition>>>,
         }
-        serde_cbor::from_slice::<StoredDocument>(data)
-            .map_err(|e| {
-                OperationError::service_error(format!("Failed to deserialize document: {e}"))
-            })
-            .map(|doc| doc.tokens)
+        let doc = serde_cbor::from_slice::<StoredDocument>(data).map_err(|e| {
+            OperationError::service_error(format!("Failed to deserialize document: {e}"))
+        })?;
+
+        let tokens = match doc.positions {
+            Some(positions) if positions.len() == doc.tokens.len() => {
+                Some(doc.tokens.into_iter().zip(positions).collect())
+            }
+            _ => None,
+        };
+        Ok(tokens)
     }
 
     pub fn get_telemetry_data(&self) -> PayloadIndexTelemetry {
@@ -200,7 +305,7 @@ impl FullTextIndex {
 
     pub fn parse_query(&self, text: &str) -> ParsedQuery {
         let mut tokens = HashSet::new();
-        Tokenizer::tokenize_query(text, self.config(), |token| {
+        self.tokenizer().tokenize_query(text, |token| {
             tokens.insert(self.get_token(token));
         });
         ParsedQuery {
@@ -208,14 +313,57 @@ impl FullTextIndex {
         }
     }
 
-    pub fn parse_document(&self, text: &str) -> Document {
-        let mut document_tokens = vec![];
-        Tokenizer::tokenize_doc(text, self.config(), |token| {
-            if let Some(token_id) = self.get_token(token) {
-                document_tokens.push(token_id);
-            }
+    pub fn parse_phrase(&self, phrase: &str, slop: u32) -> ParsedPhrase {
+        let mut tokens = Vec::new();
+        self.tokenizer().tokenize_query(phrase, |token| {
+            tokens.push(self.get_token(token));
+        });
+        // Larger slop could match across values of a multi-value field
+        ParsedPhrase {
+            tokens,
+            slop: slop.min(MAX_PHRASE_SLOP),
+        }
+    }
+
+    /// Expand each token of the query to the tokens of the vocabulary within the allowed edit distance
+    pub fn parse_fuzzy_query(&self, fuzzy: &MatchFuzzy) -> ParsedFuzzyQuery {
+        let mut automatons = Vec::new();
+        self.tokenizer().tokenize_query(&fuzzy.fuzzy, |token| {
+            automatons.push(LevenshteinAutomaton::new(token, fuzzy.max_edits(token)));
         });
-        Document::new(document_tokens)
+        let tokens = match self {
+            Self::Mutable(index) => index.inverted_index.fuzzy_tokens(&automatons),
+            Self::Immutable(index) => index.inverted_index.fuzzy_tokens(&automatons),
+            Self::Mmap(index) => index.inverted_index.fuzzy_tokens(&automatons),
+        };
+        ParsedFuzzyQuery { tokens }
+    }
+
+    /// Score points by BM25 relevance of their text to the given query text.
+    ///
+    /// Only points which contain at least one of the query tokens are returned.
+    pub fn bm25_scores(
+        &self,
+        text: &str,
+        params: Bm25Params,
+    ) -> HashMap<PointOffsetType, ScoreType> {
+        let parsed_query = self.parse_query(text);
+        match self {
+            Self::Mutable(index) => index.inverted_index.bm25_scores(&parsed_query, params),
+            Self::Immutable(index) => index.inverted_index.bm25_scores(&parsed_query, params),
+            Self::Mmap(index) => index.inverted_index.bm25_scores(&parsed_query, params),
+        }
+    }
+
+    pub fn parse_document(&self, text: &str) -> Document {
+        let mut document_tokens: BTreeMap<TokenId, Vec<TokenPosition>> = BTreeMap::new();
+        self.tokenizer()
+            .tokenize_doc_with_positions(text, |token, position| {
+                if let Some(token_id) = self.get_token(token) {
+                    document_tokens.entry(token_id).or_default().push(position);
+                }
+            });
+        Document::new(document_tokens.into_iter().collect())
     }
 
     #[cfg(test)]
@@ -283,7 +431,9 @@ impl PayloadFieldIndex for FullTextIndex {
         match self {
             Self::Mutable(index) => index.load_from_db(),
             Self::Immutable(index) => index.load_from_db(),
-            Self::Mmap(_index) => Ok(true), // mmap index is always loaded
+            // mmap index is always loaded, but indexes created without token positions
+            // can't match phrases and have to be rebuilt
+            Self::Mmap(index) => Ok(index.inverted_index.positions.is_some()),
         }
     }
 
@@ -315,19 +465,43 @@ impl PayloadFieldIndex for FullTextIndex {
         &self,
         condition: &FieldCondition,
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + '_>> {
-        if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
-            return Some(self.filter(&parsed_query));
+        match &condition.r#match {
+            Some(Match::Text(text_match)) => {
+                let parsed_query = self.parse_query(&text_match.text);
+                Some(self.filter(&parsed_query))
+            }
+            Some(Match::Phrase(phrase_match)) => {
+                let parsed_phrase = self.parse_phrase(&phrase_match.phrase, phrase_match.slop());
+                Some(self.filter_phrase(parsed_phrase))
+            }
+            Some(Match::Fuzzy(fuzzy_match)) => {
+                let parsed_query = self.parse_fuzzy_query(fuzzy_match);
+                Some(self.filter_fuzzy(parsed_query))
+            }
+            _ => None,
         }
-        None
     }
 
     fn estimate_cardinality(&self, condition: &FieldCondition) -> Option<CardinalityEstimation> {
-        if let Some(Match::Text(text_match)) = &condition.r#match {
-            let parsed_query = self.parse_query(&text_match.text);
-            return Some(self.estimate_cardinality(&parsed_query, condition));
+        match &condition.r#match {
+            Some(Match::Text(text_match)) => {
+                let parsed_query = self.parse_query(&text_match.text);
+                Some(self.estimate_cardinality(&parsed_query, condition))
+            }
+            Some(Match::Phrase(phrase_match)) => {
+                // Documents containing all the phrase tokens are the upper bound of the phrase matches
+                let parsed_phrase = self.parse_phrase(&phrase_match.phrase, phrase_match.slop());
+                let mut estimation =
+                    self.estimate_cardinality(&parsed_phrase.to_query(), condition);
+                estimation.min = 0;
+                Some(estimation)
+            }
+            Some(Match::Fuzzy(fuzzy_match)) => {
+                let parsed_query = self.parse_fuzzy_query(fuzzy_match);
+                Some(self.estimate_fuzzy_cardinality(&parsed_query, condition))
+            }
+            _ => None,
         }
-        None
     }
 
     fn payload_blocks(
@@ -1,6 +1,12 @@
+use std::borrow::Cow;
+
 use charabia::Tokenize;
+use rust_stemmers::{Algorithm, Stemmer};
+use unicode_normalization::char::decompose_canonical;
 
-use crate::data_types::index::{TextIndexParams, TokenizerType};
+use super::inverted_index::TokenPosition;
+use super::stop_words::StopwordsFilter;
+use crate::data_types::index::{Language, StemmingAlgorithm, TextIndexParams, TokenizerType};
 
 struct WhiteSpaceTokenizer;
 
@@ -74,11 +80,149 @@ impl MultilingualTokenizer {
     }
 }
 
-pub struct Tokenizer;
+/// Normalization of tokens, applied consistently at index and query time.
+///
+/// Tokens are lowercased, filtered by stop words, folded to ASCII and stemmed,
+/// according to the index configuration.
+struct TokensProcessor {
+    lowercase: bool,
+    ascii_folding: bool,
+    stopwords: StopwordsFilter,
+    stemmer: Option<Stemmer>,
+}
+
+impl TokensProcessor {
+    fn new(config: &TextIndexParams) -> Self {
+        let stemmer = config.stemmer.as_ref().map(|stemmer| match stemmer {
+            StemmingAlgorithm::Snowball(params) => {
+                Stemmer::create(snowball_algorithm(params.language))
+            }
+        });
+
+        Self {
+            lowercase: config.lowercase.unwrap_or(true),
+            ascii_folding: config.ascii_folding.unwrap_or(false),
+            stopwords: StopwordsFilter::new(config.stopwords.as_ref()),
+            stemmer,
+        }
+    }
+
+    /// Returns `None` if the token is a stop word
+    fn process<'a>(&self, token: &'a str) -> Option<Cow<'a, str>> {
+        let mut token = Cow::Borrowed(token);
+
+        if self.lowercase {
+            token = Cow::Owned(token.to_lowercase());
+        }
+
+        // Stop words are checked before folding, as the predefined lists contain accented words
+        if !self.stopwords.is_empty() {
+            let is_stopword = if self.lowercase {
+                self.stopwords.is_stopword(&token)
+            } else {
+                self.stopwords.is_stopword(&token.to_lowercase())
+            };
+            if is_stopword {
+                return None;
+            }
+        }
+
+        if self.ascii_folding && !token.is_ascii() {
+            token = Cow::Owned(fold_to_ascii(&token).into_owned());
+        }
+
+        if let Some(stemmer) = &self.stemmer {
+            token = Cow::Owned(stemmer.stem(&token).into_owned());
+        }
+
+        Some(token)
+    }
+}
+
+fn snowball_algorithm(language: Language) -> Algorithm {
+    match language {
+        Language::Dutch => Algorithm::Dutch,
+        Language::English => Algorithm::English,
+        Language::French => Algorithm::French,
+        Language::German => Algorithm::German,
+        Language::Italian => Algorithm::Italian,
+        Language::Portuguese => Algorithm::Portuguese,
+        Language::Russian => Algorithm::Russian,
+        Language::Spanish => Algorithm::Spanish,
+    }
+}
+
+/// Replace accented latin characters with their ASCII equivalents, e.g. `"Café"` -> `"Cafe"`.
+///
+/// Characters of other scripts are kept as is.
+fn fold_to_ascii(token: &str) -> Cow<str> {
+    if token.is_ascii() {
+        return Cow::Borrowed(token);
+    }
+
+    let mut folded = String::with_capacity(token.len());
+    for c in token.chars() {
+        if c.is_ascii() {
+            folded.push(c);
+            continue;
+        }
+
+        let replacement = match c {
+            'ß' => "ss",
+            'æ' => "ae",
+            'Æ' => "AE",
+            'œ' => "oe",
+            'Œ' => "OE",
+            'ø' => "o",
+            'Ø' => "O",
+            'đ' | 'ð' => "d",
+            'Đ' | 'Ð' => "D",
+            'ł' => "l",
+            'Ł' => "L",
+            'þ' => "th",
+            'Þ' => "TH",
+            'ı' => "i",
+            _ => "",
+        };
+        if !replacement.is_empty() {
+            folded.push_str(replacement);
+            continue;
+        }
+
+        // Decompose character into base character and combining marks,
+        // keep only the base character if it is ASCII
+        let mut base = None;
+        decompose_canonical(c, |d| {
+            if base.is_none() {
+                base = Some(d);
+            }
+        });
+        match base {
+            Some(base) if base.is_ascii() => folded.push(base),
+            _ => folded.push(c),
+        }
+    }
+
+    Cow::Owned(folded)
+}
+
+/// Tokenizer of a full-text index.
+///
+/// Token normalization is prepared once from the index configuration and reused for every text.
+pub struct Tokenizer {
+    config: TextIndexParams,
+    processor: TokensProcessor,
+}
 
 impl Tokenizer {
+    pub fn new(config: TextIndexParams) -> Self {
+        let processor = TokensProcessor::new(&config);
+        Self { config, processor }
+    }
+
     fn doc_token_filter<'a, C: FnMut(&str) + 'a>(
         config: &'a TextIndexParams,
+        processor: Option<&'a TokensProcessor>,
         mut callback: C,
     ) -> impl FnMut(&str) + 'a {
         move |token: &str| {
@@ -96,40 +240,101 @@ impl Tokenizer {
             {
                 return;
             }
-            if config.lowercase.unwrap_or(true) {
-                callback(&token.to_lowercase());
-            } else {
-                callback(token);
+            match processor {
+                Some(processor) => {
+                    if let Some(token) = processor.process(token) {
+                        callback(&token);
+                    }
+                }
+                None => callback(token),
             }
         }
     }
 
-    pub fn tokenize_doc<C: FnMut(&str)>(text: &str, config: &TextIndexParams, mut callback: C) {
-        let token_filter = Self::doc_token_filter(config, &mut callback);
+    pub fn tokenize_doc<C: FnMut(&str)>(&self, text: &str, mut callback: C) {
+        let Self { config, processor } = self;
         match config.tokenizer {
-            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, token_filter),
-            TokenizerType::Word => WordTokenizer::tokenize(text, token_filter),
-            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, token_filter),
-            TokenizerType::Prefix => PrefixTokenizer::tokenize(
+            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(
                 text,
-                config.min_token_len.unwrap_or(1),
-                config.max_token_len.unwrap_or(usize::MAX),
-                token_filter,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
             ),
+            TokenizerType::Word => WordTokenizer::tokenize(
+                text,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
+            ),
+            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(
+                text,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
+            ),
+            // Words are normalized before splitting into prefixes,
+            // so that the whole word is checked for stop words and stemmed
+            TokenizerType::Prefix => WordTokenizer::tokenize(text, |word| {
+                if let Some(word) = processor.process(word) {
+                    PrefixTokenizer::tokenize(
+                        &word,
+                        config.min_token_len.unwrap_or(1),
+                        config.max_token_len.unwrap_or(usize::MAX),
+                        Self::doc_token_filter(config, None, &mut callback),
+                    );
+                }
+            }),
         }
     }
 
-    pub fn tokenize_query<C: FnMut(&str)>(text: &str, config: &TextIndexParams, mut callback: C) {
-        let token_filter = Self::doc_token_filter(config, &mut callback);
+    /// Tokenize document, providing the position of each token within the text.
+    ///
+    /// Positions are counted in words, so all prefixes of the same word share a position.
+    /// Returns the number of positions in the text.
+    pub fn tokenize_doc_with_positions<C: FnMut(&str, TokenPosition)>(
+        &self,
+        text: &str,
+        mut callback: C,
+    ) -> TokenPosition {
+        let mut position: TokenPosition = 0;
+        match self.config.tokenizer {
+            TokenizerType::Prefix => WordTokenizer::tokenize(text, |word| {
+                let mut has_tokens = false;
+                self.tokenize_doc(word, |token| {
+                    has_tokens = true;
+                    callback(token, position);
+                });
+                if has_tokens {
+                    position += 1;
+                }
+            }),
+            TokenizerType::Whitespace | TokenizerType::Word | TokenizerType::Multilingual => self
+                .tokenize_doc(text, |token| {
+                    callback(token, position);
+                    position += 1;
+                }),
+        }
+        position
+    }
+
+    pub fn tokenize_query<C: FnMut(&str)>(&self, text: &str, mut callback: C) {
+        let Self { config, processor } = self;
         match config.tokenizer {
-            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(text, token_filter),
-            TokenizerType::Word => WordTokenizer::tokenize(text, token_filter),
-            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(text, token_filter),
-            TokenizerType::Prefix => PrefixTokenizer::tokenize_query(
+            TokenizerType::Whitespace => WhiteSpaceTokenizer::tokenize(
+                text,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
+            ),
+            TokenizerType::Word => WordTokenizer::tokenize(
                 text,
-                config.max_token_len.unwrap_or(usize::MAX),
-                token_filter,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
             ),
+            TokenizerType::Multilingual => MultilingualTokenizer::tokenize(
+                text,
+                Self::doc_token_filter(config, Some(processor), &mut callback),
+            ),
+            TokenizerType::Prefix => WordTokenizer::tokenize(text, |word| {
+                if let Some(word) = processor.process(word) {
+                    PrefixTokenizer::tokenize_query(
+                        &word,
+                        config.max_token_len.unwrap_or(usize::MAX),
+                        Self::doc_token_filter(config, None, &mut callback),
+                    );
+                }
+            }),
         }
     }
 }
@@ -137,7 +342,9 @@ impl Tokenizer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data_types::index::TextIndexType;
+    use crate::data_types::index::{
+        SnowballParams, SnowballType, StopwordsInterface, StopwordsSet, TextIndexType,
+    };
 
     #[test]
     fn test_whitespace_tokenizer() {
@@ -188,6 +395,54 @@ mod tests {
         assert_eq!(tokens.get(1), Some(&"мир".to_owned()));
     }
 
+    #[test]
+    fn test_tokenizer_positions() {
+        let text = "Hello, a world!";
+        let mut config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            min_token_len: Some(2),
+            max_token_len: Some(3),
+            lowercase: Some(true),
+            ascii_folding: None,
+            stopwords: None,
+            stemmer: None,
+            on_disk: None,
+        };
+
+        let mut tokens = Vec::new();
+        let positions_count = Tokenizer::new(config.clone())
+            .tokenize_doc_with_positions(text, |token, pos| tokens.push((token.to_owned(), pos)));
+        // Tokens, filtered by length, do not take positions
+        assert_eq!(positions_count, 0);
+        assert!(tokens.is_empty());
+
+        config.max_token_len = None;
+        let positions_count = Tokenizer::new(config.clone())
+            .tokenize_doc_with_positions(text, |token, pos| tokens.push((token.to_owned(), pos)));
+        assert_eq!(positions_count, 2);
+        assert_eq!(
+            tokens,
+            vec![("hello".to_owned(), 0), ("world".to_owned(), 1)]
+        );
+
+        tokens.clear();
+        config.tokenizer = TokenizerType::Prefix;
+        config.max_token_len = Some(3);
+        let positions_count = Tokenizer::new(config.clone())
+            .tokenize_doc_with_positions(text, |token, pos| tokens.push((token.to_owned(), pos)));
+        assert_eq!(positions_count, 2);
+        assert_eq!(
+            tokens,
+            vec![
+                ("he".to_owned(), 0),
+                ("hel".to_owned(), 0),
+                ("wo".to_owned(), 1),
+                ("wor".to_owned(), 1),
+            ]
+        );
+    }
+
     #[cfg(feature = "multiling-japanese")]
     #[test]
     fn test_multilingual_tokenizer_japanese() {
@@ -246,18 +501,18 @@ mod tests {
     fn test_tokenizer() {
         let text = "Hello, Мир!";
         let mut tokens = Vec::new();
-        Tokenizer::tokenize_doc(
-            text,
-            &TextIndexParams {
-                r#type: TextIndexType::Text,
-                tokenizer: TokenizerType::Prefix,
-                min_token_len: Some(1),
-                max_token_len: Some(4),
-                lowercase: Some(true),
-                on_disk: None,
-            },
-            |token| tokens.push(token.to_owned()),
-        );
+        let tokenizer = Tokenizer::new(TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Prefix,
+            min_token_len: Some(1),
+            max_token_len: Some(4),
+            lowercase: Some(true),
+            ascii_folding: None,
+            stopwords: None,
+            stemmer: None,
+            on_disk: None,
+        });
+        tokenizer.tokenize_doc(text, |token| tokens.push(token.to_owned()));
         eprintln!("tokens = {tokens:#?}");
         assert_eq!(tokens.len(), 7);
         assert_eq!(tokens.first(), Some(&"h".to_owned()));
@@ -268,4 +523,109 @@ mod tests {
         assert_eq!(tokens.get(5), Some(&"ми".to_owned()));
         assert_eq!(tokens.get(6), Some(&"мир".to_owned()));
     }
+
+    fn tokenize_doc_to_vec(text: &str, config: &TextIndexParams) -> Vec<String> {
+        let mut tokens = Vec::new();
+        Tokenizer::new(config.clone()).tokenize_doc(text, |token| tokens.push(token.to_owned()));
+        tokens
+    }
+
+    fn tokenize_query_to_vec(text: &str, config: &TextIndexParams) -> Vec<String> {
+        let mut tokens = Vec::new();
+        Tokenizer::new(config.clone()).tokenize_query(text, |token| tokens.push(token.to_owned()));
+        tokens
+    }
+
+    #[test]
+    fn test_ascii_folding() {
+        let mut config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            ascii_folding: Some(true),
+            ..Default::default()
+        };
+
+        let text = "Café Ærø Straße Привет";
+        assert_eq!(
+            tokenize_doc_to_vec(text, &config),
+            vec!["cafe", "aero", "strasse", "привет"],
+        );
+
+        config.lowercase = Some(false);
+        assert_eq!(
+            tokenize_doc_to_vec(text, &config),
+            vec!["Cafe", "AEro", "Strasse", "Привет"],
+        );
+    }
+
+    #[test]
+    fn test_stopwords_and_stemming() {
+        let mut config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            stopwords: Some(StopwordsInterface::Language(Language::English)),
+            stemmer: Some(StemmingAlgorithm::Snowball(SnowballParams {
+                r#type: SnowballType::Snowball,
+                language: Language::English,
+            })),
+            ..Default::default()
+        };
+
+        let text = "The cats are running, and the dog jumped";
+        let expected = vec!["cat", "run", "dog", "jump"];
+        assert_eq!(tokenize_doc_to_vec(text, &config), expected);
+        assert_eq!(tokenize_query_to_vec(text, &config), expected);
+
+        // Stop words do not take positions
+        let mut positions = Vec::new();
+        let positions_count = Tokenizer::new(config.clone())
+            .tokenize_doc_with_positions(text, |_, pos| positions.push(pos));
+        assert_eq!(positions_count, 4);
+        assert_eq!(positions, vec![0, 1, 2, 3]);
+
+        // Custom stop words are case-insensitive
+        config.lowercase = Some(false);
+        config.stemmer = None;
+        config.stopwords = Some(StopwordsInterface::Set(StopwordsSet {
+            languages: vec![],
+            custom: vec!["qdrant".to_string()],
+        }));
+        assert_eq!(tokenize_doc_to_vec("Qdrant rocks", &config), vec!["rocks"]);
+    }
+
+    #[test]
+    fn test_ascii_folding_stopwords() {
+        let config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Word,
+            ascii_folding: Some(true),
+            stopwords: Some(StopwordsInterface::Language(Language::French)),
+            ..Default::default()
+        };
+
+        // Accented stop words are removed before folding
+        assert_eq!(
+            tokenize_doc_to_vec("Vous êtes été café", &config),
+            vec!["cafe"],
+        );
+    }
+
+    #[test]
+    fn test_prefix_tokenizer_stopwords() {
+        let config = TextIndexParams {
+            r#type: TextIndexType::Text,
+            tokenizer: TokenizerType::Prefix,
+            max_token_len: Some(3),
+            stopwords: Some(StopwordsInterface::Language(Language::English)),
+            ..Default::default()
+        };
+
+        // Whole words are checked for stop words, not their prefixes
+        assert_eq!(
+            tokenize_doc_to_vec("The theory", &config),
+            vec!["t", "th", "the"],
+        );
+        assert!(tokenize_query_to_vec("The", &config).is_empty());
+        assert_eq!(tokenize_query_to_vec("Theory", &config), vec!["the"]);
+    }
 }
@@ -0,0 +1,257 @@
+/// Automaton, which accepts strings within a bounded Levenshtein distance of the query.
+///
+/// The state of the automaton is a row of the edit distance matrix. Candidates are consumed
+/// char by char and rejected as soon as no continuation can fit into `max_edits`,
+/// which makes scanning a vocabulary much cheaper than computing full edit distances.
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_edits: u8,
+}
+
+impl LevenshteinAutomaton {
+    pub fn new(query: &str, max_edits: u8) -> Self {
+        Self {
+            query: query.chars().collect(),
+            max_edits,
+        }
+    }
+
+    /// Edit distance between the query and the candidate, if it is at most `max_edits`
+    pub fn distance(&self, candidate: &str) -> Option<u8> {
+        let max_edits = u32::from(self.max_edits);
+        let query_len = self.query.len();
+
+        // Length difference alone requires this many insertions or deletions
+        let candidate_len = candidate.chars().count();
+        if candidate_len.abs_diff(query_len) > max_edits as usize {
+            return None;
+        }
+
+        let mut row = self.start_row();
+        let mut next_row = vec![0; query_len + 1];
+
+        for c in candidate.chars() {
+            // Distances never decrease with more input, so the candidate can't match anymore
+            if self.step(&row, c, &mut next_row) > max_edits {
+                return None;
+            }
+
+            std::mem::swap(&mut row, &mut next_row);
+        }
+
+        let distance = row[query_len];
+        (distance <= max_edits).then_some(distance as u8)
+    }
+
+    pub fn is_match(&self, candidate: &str) -> bool {
+        self.distance(candidate).is_some()
+    }
+
+    fn start_row(&self) -> Vec<u32> {
+        (0..=self.query.len() as u32).collect()
+    }
+
+    /// Fill `next_row` of the edit distance matrix after consuming `c`, returns its minimum
+    fn step(&self, row: &[u32], c: char, next_row: &mut [u32]) -> u32 {
+        next_row[0] = row[0] + 1;
+        let mut row_min = next_row[0];
+        for i in 1..=self.query.len() {
+            let substitution = row[i - 1] + u32::from(self.query[i - 1] != c);
+            let deletion = row[i] + 1;
+            let insertion = next_row[i - 1] + 1;
+            next_row[i] = substitution.min(deletion).min(insertion);
+            row_min = row_min.min(next_row[i]);
+        }
+        row_min
+    }
+}
+
+/// State of [`LevenshteinAutomaton`], consuming the candidate byte by byte.
+#[derive(Debug, Clone)]
+pub struct LevenshteinState {
+    /// Row of the edit distance matrix, `None` if the candidate can't match anymore
+    row: Option<Vec<u32>>,
+    /// Bytes of a char, which is not complete yet
+    pending: Vec<u8>,
+}
+
+/// Allows to search FST sets and maps, visiting only the branches which can still match.
+impl fst::Automaton for LevenshteinAutomaton {
+    type State = LevenshteinState;
+
+    fn start(&self) -> Self::State {
+        LevenshteinState {
+            row: Some(self.start_row()),
+            pending: Vec::new(),
+        }
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        state.pending.is_empty()
+            && state
+                .row
+                .as_ref()
+                .is_some_and(|row| row[self.query.len()] <= u32::from(self.max_edits))
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.row.is_some()
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let Some(row) = &state.row else {
+            return state.clone();
+        };
+
+        let mut pending = state.pending.clone();
+        pending.push(byte);
+        if pending.len() < utf8_char_len(pending[0]) {
+            return LevenshteinState {
+                row: Some(row.clone()),
+                pending,
+            };
+        }
+
+        // Keys are valid UTF-8, but stop matching if they are not
+        let Some(c) = std::str::from_utf8(&pending)
+            .ok()
+            .and_then(|s| s.chars().next())
+        else {
+            return LevenshteinState {
+                row: None,
+                pending: Vec::new(),
+            };
+        };
+
+        let mut next_row = vec![0; row.len()];
+        let row_min = self.step(row, c, &mut next_row);
+        LevenshteinState {
+            row: (row_min <= u32::from(self.max_edits)).then_some(next_row),
+            pending: Vec::new(),
+        }
+    }
+}
+
+/// Length of the UTF-8 encoded char, starting with the given byte
+fn utf8_char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    fn naive_distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        let mut matrix = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cell) in matrix[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let cost = usize::from(a[i - 1] != b[j - 1]);
+                matrix[i][j] = (matrix[i - 1][j - 1] + cost)
+                    .min(matrix[i - 1][j] + 1)
+                    .min(matrix[i][j - 1] + 1);
+            }
+        }
+        matrix[a.len()][b.len()]
+    }
+
+    #[test]
+    fn test_levenshtein_automaton() {
+        let automaton = LevenshteinAutomaton::new("hello", 1);
+        assert_eq!(automaton.distance("hello"), Some(0));
+        assert_eq!(automaton.distance("helo"), Some(1));
+        assert_eq!(automaton.distance("hallo"), Some(1));
+        assert_eq!(automaton.distance("helloo"), Some(1));
+        assert_eq!(automaton.distance("hlelo"), None);
+        assert_eq!(automaton.distance("help"), None);
+
+        let automaton = LevenshteinAutomaton::new("мир", 1);
+        assert!(automaton.is_match("мира"));
+        assert!(!automaton.is_match("миров"));
+
+        let automaton = LevenshteinAutomaton::new("", 1);
+        assert!(automaton.is_match(""));
+        assert!(automaton.is_match("a"));
+        assert!(!automaton.is_match("ab"));
+    }
+
+    #[test]
+    fn test_levenshtein_automaton_congruence() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let random_word = |rng: &mut StdRng| -> String {
+            let len = rng.random_range(0..8);
+            // Small alphabet, so that words are close to each other
+            (0..len)
+                .map(|_| (b'a' + rng.random_range(0..3)) as char)
+                .collect()
+        };
+
+        for _ in 0..1000 {
+            let query = random_word(&mut rng);
+            let candidate = random_word(&mut rng);
+            let max_edits = rng.random_range(0..=3);
+
+            let expected = naive_distance(&query, &candidate);
+            let automaton = LevenshteinAutomaton::new(&query, max_edits);
+            assert_eq!(
+                automaton.distance(&candidate).map(usize::from),
+                (expected <= max_edits as usize).then_some(expected),
+                "query: {query}, candidate: {candidate}, max_edits: {max_edits}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_levenshtein_automaton_fst() {
+        use fst::{IntoStreamer, Streamer};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let alphabet = ['a', 'b', 'é', 'ж'];
+        let random_word = |rng: &mut StdRng| -> String {
+            let len = rng.random_range(0..6);
+            (0..len)
+                .map(|_| alphabet[rng.random_range(0..alphabet.len())])
+                .collect()
+        };
+
+        let mut vocab: Vec<String> = (0..200).map(|_| random_word(&mut rng)).collect();
+        vocab.sort_unstable();
+        vocab.dedup();
+        let set = fst::Set::from_iter(&vocab).unwrap();
+
+        for _ in 0..100 {
+            let query = random_word(&mut rng);
+            let max_edits = rng.random_range(0..=2);
+            let automaton = LevenshteinAutomaton::new(&query, max_edits);
+
+            let mut found = Vec::new();
+            let mut stream = set.search(&automaton).into_stream();
+            while let Some(key) = stream.next() {
+                found.push(String::from_utf8(key.to_vec()).unwrap());
+            }
+
+            let expected: Vec<_> = vocab
+                .iter()
+                .filter(|word| automaton.is_match(word))
+                .cloned()
+                .collect();
+            assert_eq!(found, expected, "query: {query}, max_edits: {max_edits}");
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Borrow as _;
 use std::collections::HashMap;
 use std::iter;
-use std::ops::Range;
+use std::ops::{Bound, Range};
 use std::sync::Arc;
 
 use bitvec::vec::BitVec;
@@ -10,7 +10,7 @@ use parking_lot::RwLock;
 use rocksdb::DB;
 
 use super::mutable_map_index::MutableMapIndex;
-use super::{IdIter, IdRefIter, MapIndex, MapIndexKey};
+use super::{sorted_range_positions, IdIter, IdRefIter, MapIndex, MapIndexKey};
 use crate::common::operation_error::OperationResult;
 use crate::common::rocksdb_buffered_delete_wrapper::DatabaseColumnScheduledDeleteWrapper;
 use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
@@ -18,6 +18,9 @@ use crate::index::field_index::immutable_point_to_values::ImmutablePointToValues
 
 pub struct ImmutableMapIndex<N: MapIndexKey + ?Sized> {
     value_to_points: HashMap<N::Owned, ContainerSegment>,
+    /// All values in ascending order, for iterating values by range.
+    /// May contain values, which have no points left.
+    sorted_values: Vec<N::Owned>,
     /// Container holding a slice of point IDs per value. `value_to_point` holds the range per value.
     /// Each slice MUST be sorted so that we can binary search over it.
     value_to_points_container: Vec<PointOffsetType>,
@@ -45,6 +48,7 @@ impl<N: MapIndexKey + ?Sized> ImmutableMapIndex<N> {
         ));
         Self {
             value_to_points: Default::default(),
+            sorted_values: Default::default(),
             value_to_points_container: Default::default(),
             deleted_value_to_points_container: Default::default(),
             point_to_values: Default::default(),
@@ -207,6 +211,9 @@ impl<N: MapIndexKey + ?Sized> ImmutableMapIndex<N> {
         self.value_to_points_container.reserve_exact(values_count);
         self.deleted_value_to_points_container.clear();
 
+        self.sorted_values = map.keys().cloned().collect();
+        self.sorted_values.sort_unstable();
+
         // flatten values-to-points map
         for (value, points) in map {
             let points = points.into_iter().collect::<Vec<_>>();
@@ -319,4 +326,14 @@ impl<N: MapIndexKey + ?Sized> ImmutableMapIndex<N> {
     pub fn iter_values(&self) -> Box<dyn Iterator<Item = &N> + '_> {
         Box::new(self.value_to_points.keys().map(|v| v.borrow()))
     }
+
+    pub fn iter_values_in_range(
+        &self,
+        lower: Bound<&N>,
+        upper: Bound<&N>,
+    ) -> Box<dyn DoubleEndedIterator<Item = &N> + '_> {
+        let positions =
+            sorted_range_positions(&self.sorted_values, |value| value.borrow(), lower, upper);
+        Box::new(self.sorted_values[positions].iter().map(|v| v.borrow()))
+    }
 }
@@ -0,0 +1,44 @@
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
+use crate::types::{Match, MatchPrefix, MatchRegex, MatchSubstring, RegexPattern};
+
+/// Condition on a keyword, which can't be resolved by a lookup of the exact value.
+///
+/// Such conditions are evaluated over the set of unique keywords of the index,
+/// which is usually much smaller than the number of indexed points.
+#[derive(Debug, Clone)]
+pub enum KeywordPattern {
+    Fuzzy(LevenshteinAutomaton),
+    Prefix(String),
+    Substring(String),
+    Regex(RegexPattern),
+}
+
+impl KeywordPattern {
+    pub fn from_match(r#match: &Match) -> Option<Self> {
+        match r#match {
+            Match::Fuzzy(fuzzy) => Some(Self::Fuzzy(LevenshteinAutomaton::new(
+                &fuzzy.fuzzy,
+                fuzzy.max_edits(&fuzzy.fuzzy),
+            ))),
+            Match::Prefix(MatchPrefix { prefix }) => Some(Self::Prefix(prefix.clone())),
+            Match::Substring(MatchSubstring { substring }) => {
+                Some(Self::Substring(substring.clone()))
+            }
+            Match::Regex(MatchRegex { regex }) => Some(Self::Regex(regex.clone())),
+            Match::Value(_)
+            | Match::Text(_)
+            | Match::Phrase(_)
+            | Match::Any(_)
+            | Match::Except(_) => None,
+        }
+    }
+
+    pub fn is_match(&self, keyword: &str) -> bool {
+        match self {
+            Self::Fuzzy(automaton) => automaton.is_match(keyword),
+            Self::Prefix(prefix) => keyword.starts_with(prefix.as_str()),
+            Self::Substring(substring) => keyword.contains(substring.as_str()),
+            Self::Regex(regex) => regex.is_match(keyword),
+        }
+    }
+}
@@ -2,7 +2,9 @@ use std::borrow::Borrow;
 use std::fs::{create_dir_all, remove_dir};
 use std::iter;
 use std::mem::size_of;
+use std::ops::Bound;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use ahash::HashMap;
 use common::mmap_hashmap::{Key, MmapHashMap};
@@ -12,10 +14,10 @@ use itertools::Itertools;
 use memmap2::MmapMut;
 use memory::madvise::AdviceSetting;
 use memory::mmap_ops::{self, create_and_ensure_length};
-use memory::mmap_type::MmapBitSlice;
+use memory::mmap_type::{MmapBitSlice, MmapSlice};
 use serde::{Deserialize, Serialize};
 
-use super::{IdIter, MapIndexKey};
+use super::{sorted_range_positions, IdIter, MapIndexKey};
 use crate::common::mmap_bitslice_buffered_update_wrapper::MmapBitSliceBufferedUpdateWrapper;
 use crate::common::operation_error::OperationResult;
 use crate::common::Flusher;
@@ -24,10 +26,17 @@ use crate::index::field_index::mmap_point_to_values::MmapPointToValues;
 const DELETED_PATH: &str = "deleted.bin";
 const HASHMAP_PATH: &str = "values_to_points.bin";
 const CONFIG_PATH: &str = "mmap_field_index_config.json";
+const SORTED_VALUES_PATH: &str = "sorted_values.bin";
 
 pub struct MmapMapIndex<N: MapIndexKey + Key + ?Sized> {
     path: PathBuf,
     value_to_points: MmapHashMap<N, PointOffsetType>,
+    /// Positions of the `value_to_points` keys in ascending order of keys, for iterating values by range.
+    /// `None` for indexes created before the order was stored.
+    sorted_values: Option<MmapSlice<u32>>,
+    /// Order of the keys of indexes without stored `sorted_values`.
+    /// Built on the first range iteration, so that indexes without range queries don't pay for it.
+    sorted_values_in_memory: OnceLock<Vec<u32>>,
     point_to_values: MmapPointToValues<N>,
     deleted: MmapBitSliceBufferedUpdateWrapper,
     deleted_count: usize,
@@ -39,15 +48,43 @@ struct MmapMapIndexConfig {
     total_key_value_pairs: usize,
 }
 
+/// Positions of the keys of the `hashmap` in ascending order of keys
+fn sorted_positions<N: MapIndexKey + Key + ?Sized>(
+    hashmap: &MmapHashMap<N, PointOffsetType>,
+) -> Vec<u32> {
+    let mut positions: Vec<u32> = (0..hashmap.keys_count() as u32).collect();
+    positions.sort_unstable_by_key(|&position| hashmap.key_at(position as usize));
+    positions
+}
+
 impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
     pub fn load(path: &Path) -> OperationResult<Self> {
         let hashmap_path = path.join(HASHMAP_PATH);
         let deleted_path = path.join(DELETED_PATH);
         let config_path = path.join(CONFIG_PATH);
+        let sorted_values_path = path.join(SORTED_VALUES_PATH);
 
         let config: MmapMapIndexConfig = read_json(&config_path)?;
 
-        let hashmap = MmapHashMap::open(&hashmap_path)?;
+        let hashmap = MmapHashMap::<N, PointOffsetType>::open(&hashmap_path)?;
+        // Order which doesn't cover all keys is unusable, it is built in memory instead
+        let sorted_values = if sorted_values_path.exists() {
+            let sorted_values: MmapSlice<u32> = unsafe {
+                MmapSlice::try_from(mmap_ops::open_write_mmap(
+                    &sorted_values_path,
+                    AdviceSetting::Global,
+                    false,
+                )?)?
+            };
+            let keys_count = hashmap.keys_count();
+            let is_valid = sorted_values.len() == keys_count
+                && sorted_values
+                    .iter()
+                    .all(|&position| (position as usize) < keys_count);
+            is_valid.then_some(sorted_values)
+        } else {
+            None
+        };
         let point_to_values = MmapPointToValues::open(path)?;
 
         let deleted = mmap_ops::open_write_mmap(&deleted_path, AdviceSetting::Global, false)?;
@@ -57,6 +94,8 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         Ok(Self {
             path: path.to_path_buf(),
             value_to_points: hashmap,
+            sorted_values,
+            sorted_values_in_memory: OnceLock::new(),
             point_to_values,
             deleted: MmapBitSliceBufferedUpdateWrapper::new(deleted),
             deleted_count,
@@ -74,6 +113,7 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         let hashmap_path = path.join(HASHMAP_PATH);
         let deleted_path = path.join(DELETED_PATH);
         let config_path = path.join(CONFIG_PATH);
+        let sorted_values_path = path.join(SORTED_VALUES_PATH);
 
         atomic_save_json(
             &config_path,
@@ -89,6 +129,12 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
                 .map(|(value, ids)| (value.borrow(), ids.iter().copied())),
         )?;
 
+        // Keys are stored in the order of their hashes, so their sorted order is stored separately
+        {
+            let hashmap = MmapHashMap::<N, PointOffsetType>::open(&hashmap_path)?;
+            MmapSlice::create(&sorted_values_path, sorted_positions(&hashmap).into_iter())?;
+        }
+
         MmapPointToValues::<N>::from_iter(
             path,
             point_to_values.iter().enumerate().map(|(idx, values)| {
@@ -140,6 +186,9 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
             self.path.join(DELETED_PATH),
             self.path.join(CONFIG_PATH),
         ];
+        if self.sorted_values.is_some() {
+            files.push(self.path.join(SORTED_VALUES_PATH));
+        }
         files.extend(self.point_to_values.files());
         files
     }
@@ -235,6 +284,28 @@ impl<N: MapIndexKey + Key + ?Sized> MmapMapIndex<N> {
         Box::new(self.value_to_points.keys())
     }
 
+    pub fn iter_values_in_range(
+        &self,
+        lower: Bound<&N>,
+        upper: Bound<&N>,
+    ) -> Box<dyn DoubleEndedIterator<Item = &N> + '_> {
+        let sorted_values = match self.sorted_values.as_deref() {
+            Some(sorted_values) => sorted_values,
+            None => self
+                .sorted_values_in_memory
+                .get_or_init(|| sorted_positions(&self.value_to_points)),
+        };
+
+        // Positions are validated against the stored keys on load
+        let key_at = |position: &u32| {
+            self.value_to_points
+                .key_at(*position as usize)
+                .expect("sorted value position within stored keys")
+        };
+        let range = sorted_range_positions(sorted_values, key_at, lower, upper);
+        Box::new(sorted_values[range].iter().map(key_at))
+    }
+
     pub fn iter_counts_per_value(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
         self.value_to_points.iter().map(|(k, v)| {
             let count = v
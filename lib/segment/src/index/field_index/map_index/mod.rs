@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 use std::fmt::{Debug, Display};
 use std::hash::{BuildHasher, Hash};
 use std::iter;
+use std::ops::{Bound, Range};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -19,6 +20,7 @@ use smol_str::SmolStr;
 use uuid::Uuid;
 
 use self::immutable_map_index::ImmutableMapIndex;
+use self::keyword_pattern::KeywordPattern;
 use self::mutable_map_index::MutableMapIndex;
 use super::facet_index::FacetIndex;
 use super::mmap_point_to_values::MmapValue;
@@ -33,19 +35,20 @@ use crate::index::field_index::{
 use crate::index::query_estimator::combine_should_estimations;
 use crate::telemetry::PayloadIndexTelemetry;
 use crate::types::{
-    AnyVariants, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept, MatchValue,
-    PayloadKeyType, UuidIntType, ValueVariants,
+    AnyVariants, DateTimePayloadType, FieldCondition, IntPayloadType, Match, MatchAny, MatchExcept,
+    MatchValue, PayloadKeyType, RangeInterface, UuidIntType, ValueVariants,
 };
 
 pub mod immutable_map_index;
+pub mod keyword_pattern;
 pub mod mmap_map_index;
 pub mod mutable_map_index;
 
 pub type IdRefIter<'a> = Box<dyn Iterator<Item = &'a PointOffsetType> + 'a>;
 pub type IdIter<'a> = Box<dyn Iterator<Item = PointOffsetType> + 'a>;
 
-pub trait MapIndexKey: Key + MmapValue + Eq + Display + Debug {
-    type Owned: Borrow<Self> + Hash + Eq + Clone + FromStr + Default;
+pub trait MapIndexKey: Key + MmapValue + Eq + Ord + Display + Debug {
+    type Owned: Borrow<Self> + Hash + Eq + Ord + Clone + FromStr + Default;
 
     fn to_owned(&self) -> Self::Owned;
 }
@@ -74,6 +77,29 @@ impl MapIndexKey for UuidIntType {
     }
 }
 
+/// Positions of the values within the bounds in a slice, sorted by `key`
+fn sorted_range_positions<'a, N, V>(
+    values: &'a [V],
+    key: impl Fn(&'a V) -> &'a N,
+    lower: Bound<&N>,
+    upper: Bound<&N>,
+) -> Range<usize>
+where
+    N: Ord + ?Sized + 'a,
+{
+    let start = match lower {
+        Bound::Included(lower) => values.partition_point(|v| key(v) < lower),
+        Bound::Excluded(lower) => values.partition_point(|v| key(v) <= lower),
+        Bound::Unbounded => 0,
+    };
+    let end = match upper {
+        Bound::Included(upper) => values.partition_point(|v| key(v) <= upper),
+        Bound::Excluded(upper) => values.partition_point(|v| key(v) < upper),
+        Bound::Unbounded => values.len(),
+    };
+    start..end.max(start)
+}
+
 pub enum MapIndex<N: MapIndexKey + ?Sized> {
     Mutable(MutableMapIndex<N>),
     Immutable(ImmutableMapIndex<N>),
@@ -145,6 +171,19 @@ impl<N: MapIndexKey + ?Sized> MapIndex<N> {
         }
     }
 
+    /// Stored values within the bounds, in ascending order
+    pub fn iter_values_in_range(
+        &self,
+        lower: Bound<&N>,
+        upper: Bound<&N>,
+    ) -> Box<dyn DoubleEndedIterator<Item = &N> + '_> {
+        match self {
+            MapIndex::Mutable(index) => index.iter_values_in_range(lower, upper),
+            MapIndex::Immutable(index) => index.iter_values_in_range(lower, upper),
+            MapIndex::Mmap(index) => index.iter_values_in_range(lower, upper),
+        }
+    }
+
     fn get_indexed_points(&self) -> usize {
         match self {
             MapIndex::Mutable(index) => index.get_indexed_points(),
@@ -484,6 +523,90 @@ where
     }
 }
 
+impl MapIndex<str> {
+    /// Stored keywords, which match the pattern
+    fn pattern_keywords(&self, pattern: &KeywordPattern) -> Vec<&str> {
+        match pattern {
+            // Keywords with the prefix are adjacent in the sorted order
+            KeywordPattern::Prefix(prefix) => self
+                .iter_values_in_range(Bound::Included(prefix.as_str()), Bound::Unbounded)
+                .take_while(|keyword| keyword.starts_with(prefix.as_str()))
+                .collect(),
+            KeywordPattern::Fuzzy(_) | KeywordPattern::Substring(_) | KeywordPattern::Regex(_) => {
+                self.iter_values()
+                    .filter(|keyword| pattern.is_match(keyword))
+                    .collect()
+            }
+        }
+    }
+
+    /// Stored keywords within the range.
+    ///
+    /// Keyword ranges are lexicographic, even if the bounds look like datetimes.
+    /// Datetime ranges are checked against keywords, which can be parsed as datetime.
+    fn range_keywords(&self, range: &RangeInterface) -> Option<Vec<&str>> {
+        match range {
+            RangeInterface::Keyword(keyword_range) => {
+                let (lower, upper) = keyword_range.as_bounds();
+                Some(self.iter_values_in_range(lower, upper).collect())
+            }
+            RangeInterface::DateTime(datetime_range) => Some(
+                self.iter_values()
+                    .filter(|keyword| {
+                        DateTimePayloadType::from_str(keyword)
+                            .is_ok_and(|datetime| datetime_range.check_range(datetime))
+                    })
+                    .collect(),
+            ),
+            RangeInterface::Float(_) => None,
+        }
+    }
+
+    /// Points with keywords within the bounds, ordered by keyword
+    pub fn stream_keywords_in_range(
+        &self,
+        lower: Bound<&str>,
+        upper: Bound<&str>,
+    ) -> Box<dyn DoubleEndedIterator<Item = (&str, PointOffsetType)> + '_> {
+        Box::new(self.iter_values_in_range(lower, upper).flat_map(|keyword| {
+            // Collected, so that the iterator can be reversed
+            self.get_iterator(keyword)
+                .map(move |idx| (keyword, *idx))
+                .collect::<Vec<_>>()
+        }))
+    }
+
+    /// Points, which have any of the keywords
+    fn filter_keywords<'a>(
+        &'a self,
+        keywords: Vec<&'a str>,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        Box::new(
+            keywords
+                .into_iter()
+                .flat_map(|keyword| self.get_iterator(keyword).copied())
+                .unique(),
+        )
+    }
+
+    fn keywords_cardinality(
+        &self,
+        keywords: &[&str],
+        condition: &FieldCondition,
+    ) -> CardinalityEstimation {
+        let estimations = keywords
+            .iter()
+            .map(|keyword| self.match_cardinality(keyword))
+            .collect::<Vec<_>>();
+        let estimation = if estimations.is_empty() {
+            CardinalityEstimation::exact(0)
+        } else {
+            combine_should_estimations(&estimations, self.get_indexed_points())
+        };
+        estimation.with_primary_clause(PrimaryCondition::Condition(Box::new(condition.clone())))
+    }
+}
+
 impl PayloadFieldIndex for MapIndex<str> {
     fn count_indexed_points(&self) -> usize {
         self.get_indexed_points()
@@ -509,6 +632,11 @@ impl PayloadFieldIndex for MapIndex<str> {
         &'a self,
         condition: &'a FieldCondition,
     ) -> Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> {
+        if let Some(range) = &condition.range {
+            let keywords = self.range_keywords(range)?;
+            return Some(self.filter_keywords(keywords));
+        }
+
         match &condition.r#match {
             Some(Match::Value(MatchValue { value })) => match value {
                 ValueVariants::String(keyword) => {
@@ -542,11 +670,20 @@ impl PayloadFieldIndex for MapIndex<str> {
                     }
                 }
             },
-            _ => None,
+            Some(other) => {
+                let pattern = KeywordPattern::from_match(other)?;
+                Some(self.filter_keywords(self.pattern_keywords(&pattern)))
+            }
+            None => None,
         }
     }
 
     fn estimate_cardinality(&self, condition: &FieldCondition) -> Option<CardinalityEstimation> {
+        if let Some(range) = &condition.range {
+            let keywords = self.range_keywords(range)?;
+            return Some(self.keywords_cardinality(&keywords, condition));
+        }
+
         match &condition.r#match {
             Some(Match::Value(MatchValue { value })) => match value {
                 ValueVariants::String(keyword) => {
@@ -600,7 +737,12 @@ impl PayloadFieldIndex for MapIndex<str> {
                     }
                 }
             },
-            _ => None,
+            Some(other) => {
+                let pattern = KeywordPattern::from_match(other)?;
+                let keywords = self.pattern_keywords(&pattern);
+                Some(self.keywords_cardinality(&keywords, condition))
+            }
+            None => None,
         }
     }
 
@@ -1062,6 +1204,8 @@ mod tests {
 
     use super::*;
     use crate::common::rocksdb_wrapper::open_db_with_existing_cf;
+    use crate::json_path::JsonPath;
+    use crate::types::{Range, RegexPattern};
 
     const FIELD_NAME: &str = "test";
 
@@ -1225,6 +1369,148 @@ mod tests {
             .equals_min_exp_max(&CardinalityEstimation::exact(0)));
     }
 
+    #[rstest]
+    #[case(IndexType::Mutable)]
+    #[case(IndexType::Immutable)]
+    #[case(IndexType::Mmap)]
+    fn test_string_pattern_match(#[case] index_type: IndexType) {
+        let data = vec![
+            vec![SmolStr::from("red-apple"), SmolStr::from("green-pear")],
+            vec![SmolStr::from("red-cherry")],
+            vec![SmolStr::from("yellow-banana")],
+            vec![SmolStr::from("green-apple"), SmolStr::from("red-apple")],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        let check = |r#match: Match, expected: &[PointOffsetType]| {
+            let condition = FieldCondition::new_match(JsonPath::new(FIELD_NAME), r#match);
+            let mut points: Vec<_> = index.filter(&condition).unwrap().collect();
+            points.sort_unstable();
+            assert_eq!(points, expected);
+
+            let estimation = index.estimate_cardinality(&condition).unwrap();
+            assert!(estimation.min <= expected.len());
+            assert!(estimation.max >= expected.len());
+        };
+
+        check(Match::new_prefix("red-"), &[0, 1, 3]);
+        check(Match::new_prefix("blue-"), &[]);
+        check(Match::new_substring("apple"), &[0, 3]);
+        check(Match::new_substring("e-p"), &[0]);
+        check(
+            Match::new_regex(RegexPattern::new("^(green|yellow)-").unwrap()),
+            &[0, 2, 3],
+        );
+        check(Match::new_fuzzy("red-aple", None), &[0, 3]);
+    }
+
+    #[rstest]
+    #[case(IndexType::Mutable)]
+    #[case(IndexType::Immutable)]
+    #[case(IndexType::Mmap)]
+    fn test_keyword_range(#[case] index_type: IndexType) {
+        let data = vec![
+            vec![SmolStr::from("banana")],
+            vec![SmolStr::from("apple"), SmolStr::from("cherry")],
+            vec![SmolStr::from("date")],
+            vec![SmolStr::from("apricot")],
+            vec![SmolStr::from("2024-01-15T00:00:00Z")],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+        let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+        let range =
+            |gt: Option<&str>, gte: Option<&str>, lt: Option<&str>, lte: Option<&str>| Range {
+                gt: gt.map(str::to_string),
+                gte: gte.map(str::to_string),
+                lt: lt.map(str::to_string),
+                lte: lte.map(str::to_string),
+            };
+
+        let check = |range: Range<String>, expected: &[PointOffsetType]| {
+            let condition = FieldCondition::new_keyword_range(JsonPath::new(FIELD_NAME), range);
+            let mut points: Vec<_> = index.filter(&condition).unwrap().collect();
+            points.sort_unstable();
+            assert_eq!(points, expected);
+
+            let estimation = index.estimate_cardinality(&condition).unwrap();
+            assert!(estimation.min <= expected.len());
+            assert!(estimation.max >= expected.len());
+        };
+
+        check(range(None, Some("apple"), Some("b"), None), &[1, 3]);
+        check(range(Some("apple"), None, None, Some("cherry")), &[0, 1, 3]);
+        check(range(Some("b"), None, None, None), &[0, 1, 2]);
+        check(range(None, None, Some("a"), None), &[4]);
+        check(range(Some("d"), None, Some("c"), None), &[]);
+        check(range(Some("date"), None, Some("date"), None), &[]);
+
+        // Keyword bounds, which look like datetimes, are still compared lexicographically
+        check(
+            range(None, Some("2024-01-15"), None, Some("2024-01-15")),
+            &[],
+        );
+        check(
+            range(Some("2024-01-15"), None, Some("2024-01-16"), None),
+            &[4],
+        );
+
+        // Datetime ranges select keywords, which can be parsed as datetime
+        let check_datetime = |json: &str, expected: &[PointOffsetType]| {
+            let RangeInterface::DateTime(range) = serde_json::from_str(json).unwrap() else {
+                panic!("expected datetime range for {json}");
+            };
+            let condition = FieldCondition::new_datetime_range(JsonPath::new(FIELD_NAME), range);
+            let points: Vec<_> = index.filter(&condition).unwrap().collect();
+            assert_eq!(points, expected);
+        };
+        check_datetime(r#"{"gte": "2024-01-15", "lte": "2024-01-15"}"#, &[4]);
+        check_datetime(r#"{"gt": "2024-01-15", "lt": "2024-01-16"}"#, &[]);
+
+        let values: Vec<_> = index
+            .iter_values_in_range(Bound::Excluded("apple"), Bound::Included("date"))
+            .collect();
+        assert_eq!(values, ["apricot", "banana", "cherry", "date"]);
+        let values: Vec<_> = index
+            .iter_values_in_range(Bound::Unbounded, Bound::Excluded("apricot"))
+            .rev()
+            .collect();
+        assert_eq!(values, ["apple", "2024-01-15T00:00:00Z"]);
+
+        let points: Vec<_> = index
+            .stream_keywords_in_range(Bound::Included("cherry"), Bound::Unbounded)
+            .rev()
+            .collect();
+        assert_eq!(points, [("date", 2), ("cherry", 1)]);
+    }
+
+    #[test]
+    fn test_keyword_range_without_stored_order() {
+        let data = vec![
+            vec![SmolStr::from("banana")],
+            vec![SmolStr::from("apple"), SmolStr::from("cherry")],
+            vec![SmolStr::from("date")],
+        ];
+
+        let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+        save_map_index::<str>(&data, temp_dir.path(), IndexType::Mmap, |v| {
+            v.to_string().into()
+        });
+        // Indexes created before the order of values was stored
+        std::fs::remove_file(temp_dir.path().join("sorted_values.bin")).unwrap();
+        let index = load_map_index::<str>(&data, temp_dir.path(), IndexType::Mmap);
+
+        let values: Vec<_> = index
+            .iter_values_in_range(Bound::Excluded("apple"), Bound::Unbounded)
+            .collect();
+        assert_eq!(values, ["banana", "cherry", "date"]);
+    }
+
     #[rstest]
     #[case(IndexType::Mutable)]
     #[case(IndexType::Immutable)]
@@ -1,6 +1,8 @@
 use std::borrow::Borrow;
+use std::collections::hash_map::Entry;
 use std::collections::{BTreeSet, HashMap};
 use std::iter;
+use std::ops::Bound;
 use std::sync::Arc;
 
 use common::types::PointOffsetType;
@@ -14,6 +16,8 @@ use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 
 pub struct MutableMapIndex<N: MapIndexKey + ?Sized> {
     pub(super) map: HashMap<N::Owned, BTreeSet<PointOffsetType>>,
+    /// Keys of the `map` in ascending order, for iterating values by range
+    sorted_values: BTreeSet<N::Owned>,
     pub(super) point_to_values: Vec<Vec<N::Owned>>,
     /// Amount of point which have at least one indexed payload value
     pub(super) indexed_points: usize,
@@ -30,6 +34,7 @@ impl<N: MapIndexKey + ?Sized> MutableMapIndex<N> {
         ));
         Self {
             map: Default::default(),
+            sorted_values: Default::default(),
             point_to_values: Vec::new(),
             indexed_points: 0,
             values_count: 0,
@@ -57,6 +62,9 @@ impl<N: MapIndexKey + ?Sized> MutableMapIndex<N> {
         self.point_to_values[idx as usize] = Vec::with_capacity(values.len());
         for value in values {
             let entry = self.map.entry(value.into());
+            if matches!(entry, Entry::Vacant(_)) {
+                self.sorted_values.insert(entry.key().clone());
+            }
             self.point_to_values[idx as usize].push(entry.key().clone());
             let db_record = MapIndex::encode_db_record(entry.key().borrow(), idx);
             entry.or_default().insert(idx);
@@ -117,6 +125,7 @@ impl<N: MapIndexKey + ?Sized> MutableMapIndex<N> {
             point_values.push(value.clone());
             self.map.entry(value).or_default().insert(idx);
         }
+        self.sorted_values = self.map.keys().cloned().collect();
         Ok(true)
     }
 
@@ -176,4 +185,28 @@ impl<N: MapIndexKey + ?Sized> MutableMapIndex<N> {
     pub fn iter_values(&self) -> Box<dyn Iterator<Item = &N> + '_> {
         Box::new(self.map.keys().map(|v| v.borrow()))
     }
+
+    pub fn iter_values_in_range(
+        &self,
+        lower: Bound<&N>,
+        upper: Bound<&N>,
+    ) -> Box<dyn DoubleEndedIterator<Item = &N> + '_> {
+        // `BTreeSet::range` panics on bounds, which can't contain any value
+        let is_empty = match (lower, upper) {
+            (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+            (
+                Bound::Included(lower) | Bound::Excluded(lower),
+                Bound::Included(upper) | Bound::Excluded(upper),
+            ) => lower >= upper,
+            _ => false,
+        };
+        if is_empty {
+            return Box::new(iter::empty());
+        }
+        Box::new(
+            self.sorted_values
+                .range::<N, _>((lower, upper))
+                .map(|value| value.borrow()),
+        )
+    }
 }
@@ -13,6 +13,7 @@ pub mod geo_index;
 mod histogram;
 mod immutable_point_to_values;
 pub mod index_selector;
+pub mod levenshtein;
 pub mod map_index;
 mod mmap_point_to_values;
 pub mod numeric_index;
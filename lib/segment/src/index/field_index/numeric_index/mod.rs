@@ -158,6 +158,21 @@ impl<T: Encodable + Numericable> Range<T> {
     }
 }
 
+/// Range in terms of the numeric index values.
+///
+/// Keyword ranges are treated as datetime ranges, `None` if bounds are not datetimes.
+fn numeric_range<T: Numericable>(range: &RangeInterface) -> Option<Range<T>> {
+    match range {
+        RangeInterface::Float(float_range) => Some(float_range.map(T::from_f64)),
+        RangeInterface::DateTime(datetime_range) => {
+            Some(datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128)))
+        }
+        RangeInterface::Keyword(keyword_range) => keyword_range
+            .as_datetime_range()
+            .map(|datetime_range| datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))),
+    }
+}
+
 pub enum NumericIndexInner<T: Encodable + Numericable + MmapValue + Default> {
     Mutable(MutableNumericIndex<T>),
     Immutable(ImmutableNumericIndex<T>),
@@ -282,11 +297,8 @@ impl<T: Encodable + Numericable + MmapValue + Default> NumericIndexInner<T> {
             return CardinalityEstimation::exact(0);
         }
 
-        let range = match range {
-            RangeInterface::Float(float_range) => float_range.map(T::from_f64),
-            RangeInterface::DateTime(datetime_range) => {
-                datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-            }
+        let Some(range) = numeric_range::<T>(range) else {
+            return CardinalityEstimation::exact(0);
         };
 
         let lbound = if let Some(lte) = range.lte {
@@ -643,13 +655,10 @@ impl<T: Encodable + Numericable + MmapValue + Default> PayloadFieldIndex for Num
 
         let range_cond = condition.range.as_ref()?;
 
-        let (start_bound, end_bound) = match range_cond {
-            RangeInterface::Float(float_range) => float_range.map(T::from_f64),
-            RangeInterface::DateTime(datetime_range) => {
-                datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-            }
-        }
-        .as_index_key_bounds();
+        let Some(range) = numeric_range::<T>(range_cond) else {
+            return Some(Box::new(std::iter::empty()));
+        };
+        let (start_bound, end_bound) = range.as_index_key_bounds();
 
         // map.range
         // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
@@ -924,11 +933,8 @@ where
         &self,
         range: &RangeInterface,
     ) -> Box<dyn DoubleEndedIterator<Item = (T, PointOffsetType)> + '_> {
-        let range = match range {
-            RangeInterface::Float(float_range) => float_range.map(T::from_f64),
-            RangeInterface::DateTime(datetime_range) => {
-                datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-            }
+        let Some(range) = numeric_range::<T>(range) else {
+            return Box::new(std::iter::empty());
         };
         let (start_bound, end_bound) = range.as_index_key_bounds();
 
@@ -138,7 +138,8 @@ impl GpuBinaryQuantization {
         device: Arc<gpu::Device>,
         quantized_storage: &EncodedVectorsBin<T, TStorage>,
     ) -> Self {
-        let orig_dim = quantized_storage.get_vector_parameters().dim;
+        // Count of meaningful bits, which is larger than dim for multi-bit encodings.
+        let bits_count = quantized_storage.get_bits_count();
         // Bytes count for quantized vector.
         let quantized_vector_len = if quantized_storage.vectors_count() > 0 {
             quantized_storage.get_quantized_vector(0).len()
@@ -149,7 +150,7 @@ impl GpuBinaryQuantization {
         let gpu_bits_count = GpuVectorStorage::gpu_vector_capacity(&device, quantized_vector_len)
             * u8::BITS as usize;
         Self {
-            skip_count: gpu_bits_count - orig_dim,
+            skip_count: gpu_bits_count - bits_count,
         }
     }
 
@@ -94,6 +94,10 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             VectorStorageDatatype::Uint8 => {
                 defines.insert("VECTOR_STORAGE_ELEMENT_UINT8".to_owned(), None);
             }
+            // Rejected on storage creation
+            datatype @ (VectorStorageDatatype::Bit | VectorStorageDatatype::Int8) => {
+                unreachable!("{datatype:?} vectors are not supported on GPU")
+            }
         }
 
         match self.distance {
@@ -109,6 +113,10 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             Distance::Manhattan => {
                 defines.insert("MANHATTAN_DISTANCE".to_owned(), None);
             }
+            // Only used with bit vectors, which are rejected on storage creation
+            Distance::Hamming | Distance::Jaccard => {
+                unreachable!("Bit vectors are not supported on GPU")
+            }
         }
 
         if let Some(quantization) = &self.quantization {
@@ -252,6 +260,14 @@ impl GpuVectorStorage {
                 Some(GpuMultivectors::new_quantized(device, quantized_storage)?),
                 stopped,
             ),
+            QuantizedVectorStorage::ScalarPackedRam(_)
+            | QuantizedVectorStorage::ScalarPackedMmap(_)
+            | QuantizedVectorStorage::ScalarPackedRamMulti(_)
+            | QuantizedVectorStorage::ScalarPackedMmapMulti(_) => {
+                Err(OperationError::from(gpu::GpuError::NotSupported(
+                    "Packed scalar quantization is not supported on GPU".to_string(),
+                )))
+            }
         }
     }
 
@@ -378,6 +394,21 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseAppendableInRamHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseSimpleBit(_)
+            | VectorStorageEnum::DenseMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableInRamBit(_) => Err(OperationError::from(
+                gpu::GpuError::NotSupported("Bit vectors are not supported on GPU".to_string()),
+            )),
+            VectorStorageEnum::DenseSimpleInt8(_)
+            | VectorStorageEnum::DenseMemmapInt8(_)
+            | VectorStorageEnum::DenseAppendableMemmapInt8(_)
+            | VectorStorageEnum::DenseAppendableInRamInt8(_)
+            | VectorStorageEnum::MultiDenseSimpleInt8(_)
+            | VectorStorageEnum::MultiDenseAppendableMemmapInt8(_)
+            | VectorStorageEnum::MultiDenseAppendableInRamInt8(_) => Err(OperationError::from(
+                gpu::GpuError::NotSupported("Int8 vectors are not supported on GPU".to_string()),
+            )),
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::from(
                 gpu::GpuError::NotSupported("Sparse vectors are not supported on GPU".to_string()),
             )),
@@ -132,6 +132,8 @@ fn test_gpu_vector_storage_bq(
     let quantization_config = QuantizationConfig::Binary(BinaryQuantization {
         binary: BinaryQuantizationConfig {
             always_ram: Some(true),
+            encoding: None,
+            query_encoding: None,
         },
     });
 
@@ -178,6 +180,7 @@ fn test_gpu_vector_storage_pq(
         product: ProductQuantizationConfig {
             always_ram: Some(true),
             compression: crate::types::CompressionRatio::X8,
+            opq: None,
         },
     });
 
@@ -328,6 +331,7 @@ fn get_precision(storage_type: TestStorageType, dim: usize, distance: Distance)
         Distance::Dot => 0.01,
         Distance::Euclid => dim as f32 * 0.001,
         Distance::Manhattan => dim as f32 * 0.001,
+        Distance::Hamming | Distance::Jaccard => unreachable!(),
     };
     match storage_type.element_type() {
         TestElementType::Float32 => distance_persision,
@@ -382,6 +386,7 @@ fn create_vector_storage_f32(
             Distance::Euclid => <EuclidMetric as Metric<VectorElementType>>::preprocess(vec),
             Distance::Dot => <DotProductMetric as Metric<VectorElementType>>::preprocess(vec),
             Distance::Manhattan => <ManhattanMetric as Metric<VectorElementType>>::preprocess(vec),
+            Distance::Hamming | Distance::Jaccard => unreachable!(),
         };
         let vec_ref = VectorRef::from(&vec);
         vector_storage
@@ -410,6 +415,7 @@ fn create_vector_storage_f16(
             Distance::Manhattan => {
                 <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(vec)
             }
+            Distance::Hamming | Distance::Jaccard => unreachable!(),
         };
         let vec_ref = VectorRef::from(&vec);
         vector_storage
@@ -438,6 +444,7 @@ fn create_vector_storage_u8(
             Distance::Manhattan => {
                 <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(vec)
             }
+            Distance::Hamming | Distance::Jaccard => unreachable!(),
         };
         let vec_ref = VectorRef::from(&vec);
         vector_storage
@@ -476,6 +483,7 @@ fn create_vector_storage_f32_multi(
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementType>>::preprocess(vec)
                 }
+                Distance::Hamming | Distance::Jaccard => unreachable!(),
             };
             vectors.extend(vec);
         }
@@ -523,6 +531,7 @@ fn create_vector_storage_f16_multi(
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementTypeHalf>>::preprocess(vec)
                 }
+                Distance::Hamming | Distance::Jaccard => unreachable!(),
             };
             vectors.extend(vec);
         }
@@ -570,6 +579,7 @@ fn create_vector_storage_u8_multi(
                 Distance::Manhattan => {
                     <ManhattanMetric as Metric<VectorElementTypeByte>>::preprocess(vec)
                 }
+                Distance::Hamming | Distance::Jaccard => unreachable!(),
             };
             vectors.extend(vec);
         }
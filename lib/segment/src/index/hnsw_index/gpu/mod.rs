@@ -104,7 +104,7 @@ mod tests {
     use crate::data_types::vectors::DenseVector;
     use crate::fixtures::index_fixtures::{FakeFilterContext, TestRawScorerProducer};
     use crate::fixtures::payload_fixtures::random_vector;
-    use crate::index::hnsw_index::graph_layers::GraphLayers;
+    use crate::index::hnsw_index::graph_layers::{GraphLayers, SearchAlgorithm};
     use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
     use crate::index::hnsw_index::graph_links::GraphLinksFormat;
     use crate::index::hnsw_index::point_scorer::FilteredScorer;
@@ -234,7 +234,7 @@ mod tests {
                 .unwrap();
             let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
 
-            let search_result_gpu = graph.search(top, ef, scorer, None);
+            let search_result_gpu = graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None);
 
             let fake_filter_context = FakeFilterContext {};
             let raw_scorer = test
@@ -243,7 +243,7 @@ mod tests {
                 .unwrap();
             let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
 
-            let search_result_cpu = ref_graph.search(top, ef, scorer, None);
+            let search_result_cpu = ref_graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None);
 
             let mut gpu_set = HashSet::default();
             let mut cpu_set = HashSet::default();
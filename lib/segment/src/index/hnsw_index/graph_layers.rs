@@ -11,13 +11,17 @@ use serde::{Deserialize, Serialize};
 
 use super::entry_points::EntryPoint;
 use super::graph_links::{GraphLinks, GraphLinksFormat};
+use super::repaired_links::RepairedLinks;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::score_range::{select_page, ScoreRange};
 use crate::common::utils::rev_range;
+use crate::common::Flusher;
 use crate::index::hnsw_index::entry_points::EntryPoints;
 use crate::index::hnsw_index::graph_links::GraphLinksSerializer;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
 use crate::index::hnsw_index::search_context::SearchContext;
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
+use crate::vector_storage::RawScorer;
 
 pub type LinkContainer = Vec<PointOffsetType>;
 pub type LayersContainer = Vec<LinkContainer>;
@@ -35,11 +39,23 @@ pub(super) struct GraphLayerData<'a> {
     pub(super) entry_points: Cow<'a, EntryPoints>,
 }
 
+/// Traversal of the level 0 during the search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchAlgorithm {
+    /// Expand only the neighbours, which pass the filter
+    Hnsw,
+    /// Also expand the neighbours of filtered-out points (two-hop), so the traversal does not
+    /// get stuck when only a small fraction of the points passes the filter
+    TwoHop,
+}
+
 #[derive(Debug)]
 pub struct GraphLayers {
     pub(super) m: usize,
     pub(super) m0: usize,
     pub(super) links: GraphLinks,
+    /// Links of the points, reconnected after deletion of their neighbours
+    pub(super) repaired_links: RepairedLinks,
     pub(super) entry_points: EntryPoints,
     pub(super) visited_pool: VisitedPool,
 }
@@ -85,6 +101,64 @@ pub trait GraphLayersBase {
         }
     }
 
+    /// Greedy search for closest points within a single graph layer, which also visits
+    /// neighbours of the filtered-out points
+    ///
+    /// Points passing the filter are taken first, the rest of `m` slots are filled with
+    /// the neighbours of the filtered-out points.
+    fn _search_on_level_two_hop(
+        &self,
+        searcher: &mut SearchContext,
+        level: usize,
+        visited_list: &mut VisitedListHandle,
+        points_scorer: &mut FilteredScorer,
+    ) {
+        let limit = self.get_m(level);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(limit);
+        let mut filtered_out: Vec<PointOffsetType> = Vec::with_capacity(limit);
+
+        while let Some(candidate) = searcher.candidates.pop() {
+            if candidate.score < searcher.lower_bound() {
+                break;
+            }
+
+            points_ids.clear();
+            filtered_out.clear();
+            self.links_map(candidate.idx, level, |link| {
+                if visited_list.check_and_update_visited(link) {
+                    return;
+                }
+                if points_scorer.check_vector(link) {
+                    points_ids.push(link);
+                } else {
+                    filtered_out.push(link);
+                }
+            });
+
+            for &hop in &filtered_out {
+                if points_ids.len() >= limit {
+                    break;
+                }
+                self.links_map(hop, level, |link| {
+                    if points_ids.len() < limit
+                        && !visited_list.check(link)
+                        && points_scorer.check_vector(link)
+                    {
+                        visited_list.check_and_update_visited(link);
+                        points_ids.push(link);
+                    }
+                });
+            }
+
+            // Points are already filtered and limited
+            let scores = points_scorer.score_points(&mut points_ids, 0);
+            scores
+                .iter()
+                .copied()
+                .for_each(|score_point| searcher.process_candidate(score_point));
+        }
+    }
+
     fn search_on_level(
         &self,
         level_entry: ScoredPointOffset,
@@ -100,6 +174,21 @@ pub trait GraphLayersBase {
         search_context.nearest
     }
 
+    fn search_on_level_two_hop(
+        &self,
+        level_entry: ScoredPointOffset,
+        level: usize,
+        ef: usize,
+        points_scorer: &mut FilteredScorer,
+    ) -> FixedLengthPriorityQueue<ScoredPointOffset> {
+        let mut visited_list = self.get_visited_list_from_pool();
+        visited_list.check_and_update_visited(level_entry.idx);
+        let mut search_context = SearchContext::new(level_entry, ef);
+
+        self._search_on_level_two_hop(&mut search_context, level, &mut visited_list, points_scorer);
+        search_context.nearest
+    }
+
     /// Greedy searches for entry point of level `target_level`.
     /// Beam size is 1.
     fn search_entry(
@@ -184,7 +273,10 @@ impl GraphLayersBase for GraphLayers {
     where
         F: FnMut(PointOffsetType),
     {
-        self.links.links(point_id, level).for_each(f);
+        match self.repaired_links.get(point_id, level) {
+            Some(links) => links.iter().copied().for_each(f),
+            None => self.links.links(point_id, level).for_each(f),
+        }
     }
 
     fn get_m(&self, level: usize) -> usize {
@@ -233,6 +325,7 @@ impl GraphLayers {
         &self,
         top: usize,
         ef: usize,
+        algorithm: SearchAlgorithm,
         mut points_scorer: FilteredScorer,
         custom_entry_points: Option<&[PointOffsetType]>,
     ) -> Vec<ScoredPointOffset> {
@@ -246,10 +339,84 @@ impl GraphLayers {
             0,
             &mut points_scorer,
         );
-        let nearest = self.search_on_level(zero_level_entry, 0, max(top, ef), &mut points_scorer);
+        let ef = max(top, ef);
+        let nearest = match algorithm {
+            SearchAlgorithm::Hnsw => {
+                self.search_on_level(zero_level_entry, 0, ef, &mut points_scorer)
+            }
+            SearchAlgorithm::TwoHop => {
+                self.search_on_level_two_hop(zero_level_entry, 0, ef, &mut points_scorer)
+            }
+        };
         nearest.into_iter_sorted().take(top).collect_vec()
     }
 
+    /// Find the page of `top` points within the `range`, best first, see [`select_page`].
+    ///
+    /// The regular beam search of size `ef` seeds the traversal, which then expands the
+    /// neighbors on level 0 until all points on the frontier are worse than the range threshold.
+    /// Points of the previous pages are traversed, but not returned.
+    pub fn search_range(
+        &self,
+        range: ScoreRange,
+        top: usize,
+        ef: usize,
+        mut points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+    ) -> Vec<ScoredPointOffset> {
+        if top == 0 {
+            return Vec::default();
+        }
+
+        let Some(entry_point) = self.get_entry_point(&points_scorer, custom_entry_points) else {
+            return Vec::default();
+        };
+
+        let zero_level_entry = self.search_entry(
+            entry_point.point_id,
+            entry_point.level,
+            0,
+            &mut points_scorer,
+        );
+        let seeds = self.search_on_level(zero_level_entry, 0, ef, &mut points_scorer);
+
+        let mut visited_list = self.get_visited_list_from_pool();
+        let mut result = Vec::new();
+        let mut candidates = Vec::new();
+        for seed in seeds.into_iter_sorted() {
+            visited_list.check_and_update_visited(seed.idx);
+            if range.contains(seed.score) {
+                result.push(seed);
+            }
+            if range.is_reachable(seed.score) {
+                candidates.push(seed.idx);
+            }
+        }
+
+        let limit = self.get_m(0);
+        let mut links: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+        while let Some(candidate) = candidates.pop() {
+            links.clear();
+            self.links_map(candidate, 0, |link| {
+                if !visited_list.check_and_update_visited(link) {
+                    links.push(link);
+                }
+            });
+
+            let scores = points_scorer.score_points(&mut links, limit);
+            for &score_point in scores.iter() {
+                if range.contains(score_point.score) {
+                    result.push(score_point);
+                }
+                if range.is_reachable(score_point.score) {
+                    candidates.push(score_point.idx);
+                }
+            }
+        }
+
+        select_page(result, top)
+    }
+
     pub fn get_path(path: &Path) -> PathBuf {
         path.join(HNSW_GRAPH_FILE)
     }
@@ -262,15 +429,114 @@ impl GraphLayers {
     }
 
     pub fn files(&self, path: &Path) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             GraphLayers::get_path(path),
             GraphLayers::get_links_path(path, self.links.format()),
-        ]
+        ];
+        let repaired_links_path = RepairedLinks::get_path(path);
+        if repaired_links_path.exists() {
+            files.push(repaired_links_path);
+        }
+        files
     }
 
     pub fn num_points(&self) -> usize {
         self.links.num_points()
     }
+
+    /// Reconnect the graph around the deleted point
+    ///
+    /// On each level, neighbours of the deleted point, which link back to it, drop their links
+    /// to deleted points and fill the freed slots with the closest of the deleted point's
+    /// neighbours. New links are persisted by [`Self::flusher`].
+    ///
+    /// Links are not symmetric, so there might be other points linking to the deleted one.
+    /// Finding them would require a scan of the whole graph, so they keep their dead links until
+    /// the next optimization. Those links are still counted by [`Self::dead_links_ratio`].
+    ///
+    /// `raw_scorer` is used to check if points are deleted and to score them against each other.
+    pub fn repair_deleted_point(&mut self, point_id: PointOffsetType, raw_scorer: &dyn RawScorer) {
+        let mut repaired = Vec::new();
+        let mut replacements = Vec::new();
+        let mut neighbor_links = Vec::new();
+
+        for level in 0..=self.point_level(point_id) {
+            replacements.clear();
+            self.links_map(point_id, level, |link| {
+                if raw_scorer.check_vector(link) {
+                    replacements.push(link);
+                }
+            });
+
+            for &neighbor in &replacements {
+                neighbor_links.clear();
+                let mut links_count = 0;
+                let mut is_linked = false;
+                self.links_map(neighbor, level, |link| {
+                    links_count += 1;
+                    if link == point_id {
+                        is_linked = true;
+                    } else if raw_scorer.check_vector(link) {
+                        neighbor_links.push(link);
+                    }
+                });
+                if !is_linked {
+                    continue;
+                }
+
+                let free_slots = self.get_m(level).max(links_count) - neighbor_links.len();
+                let mut candidates = replacements
+                    .iter()
+                    .filter(|&&candidate| {
+                        candidate != neighbor && !neighbor_links.contains(&candidate)
+                    })
+                    .map(|&candidate| ScoredPointOffset {
+                        idx: candidate,
+                        score: raw_scorer.score_internal(neighbor, candidate),
+                    })
+                    .collect_vec();
+                candidates.sort_unstable_by(|a, b| b.cmp(a));
+
+                let mut new_links = neighbor_links.clone();
+                new_links.extend(
+                    candidates
+                        .into_iter()
+                        .take(free_slots)
+                        .map(|candidate| candidate.idx),
+                );
+                repaired.push((neighbor, level, new_links));
+            }
+        }
+
+        self.repaired_links.extend(repaired);
+    }
+
+    /// Persist links changed by [`Self::repair_deleted_point`]
+    pub fn flusher(&self, path: &Path) -> Flusher {
+        self.repaired_links.flusher(path)
+    }
+
+    /// Fraction of the links of present points, which lead to deleted points
+    ///
+    /// Returns `None` if there are no links. Scans the whole graph.
+    pub fn dead_links_ratio(&self, is_deleted: impl Fn(PointOffsetType) -> bool) -> Option<f64> {
+        let mut total_links = 0usize;
+        let mut dead_links = 0usize;
+        for point_id in 0..self.num_points() as PointOffsetType {
+            if is_deleted(point_id) {
+                continue;
+            }
+            for level in 0..=self.point_level(point_id) {
+                self.links_map(point_id, level, |link| {
+                    total_links += 1;
+                    if is_deleted(link) {
+                        dead_links += 1;
+                    }
+                });
+            }
+        }
+        (total_links > 0).then(|| dead_links as f64 / total_links as f64)
+    }
 }
 
 impl GraphLayers {
@@ -285,6 +551,7 @@ impl GraphLayers {
             m: graph_data.m,
             m0: graph_data.m0,
             links: Self::load_links(dir, on_disk)?,
+            repaired_links: RepairedLinks::load(dir)?,
             entry_points: graph_data.entry_points.into_owned(),
             visited_pool: VisitedPool::new(),
         })
@@ -367,6 +634,7 @@ mod tests {
     use crate::index::hnsw_index::tests::{
         create_graph_layer_builder_fixture, create_graph_layer_fixture,
     };
+    use crate::payload_storage::FilterContext;
     use crate::spaces::metric::Metric;
     use crate::spaces::simple::{CosineMetric, DotProductMetric};
     use crate::vector_storage::chunked_vector_storage::VectorOffsetType;
@@ -382,7 +650,7 @@ mod tests {
 
         let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
         let ef = 16;
-        graph.search(top, ef, scorer, None)
+        graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None)
     }
 
     const M: usize = 8;
@@ -409,6 +677,7 @@ mod tests {
             m0: 2 * m,
             links: GraphLinksSerializer::new(graph_links.clone(), format, m, 2 * m)
                 .to_graph_links_ram(),
+            repaired_links: Default::default(),
             entry_points: EntryPoints::new(entry_points_num),
             visited_pool: VisitedPool::new(),
         };
@@ -532,4 +801,138 @@ mod tests {
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
+
+    #[test]
+    fn test_search_range() {
+        let num_vectors = 1000;
+        let dim = 8;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        type M = CosineMetric;
+
+        let (vector_holder, graph_layers) = create_graph_layer_fixture::<M, _>(
+            num_vectors,
+            M,
+            dim,
+            GraphLinksFormat::Plain,
+            false,
+            &mut rng,
+        );
+
+        let query = random_vector(&mut rng, dim);
+        let processed_query = <M as Metric<VectorElementType>>::preprocess(query.clone());
+        let mut reference: Vec<_> = (0..num_vectors as PointOffsetType)
+            .map(|idx| ScoredPointOffset {
+                idx,
+                score: M::similarity(
+                    vector_holder.vectors.get(idx as VectorOffsetType),
+                    &processed_query,
+                ),
+            })
+            .collect();
+        reference.sort_unstable_by(|a, b| b.cmp(a));
+
+        // Threshold, which covers many more points than the beam size
+        let threshold = reference[100].score;
+        let reference_in_range = reference.iter().filter(|p| p.score >= threshold).count();
+
+        let fake_filter_context = FakeFilterContext {};
+        let search_range = |range: ScoreRange, top: usize| {
+            let raw_scorer = vector_holder.get_raw_scorer(query.clone()).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            graph_layers.search_range(range, top, 16, scorer, None)
+        };
+
+        let mut range = ScoreRange {
+            threshold,
+            after: None,
+        };
+        let result = search_range(range, num_vectors);
+
+        assert!(result.iter().all(|p| p.score >= threshold));
+        assert!(result.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(result.len() as f64 >= reference_in_range as f64 * 0.9);
+
+        // Pages are limited by `top` and continue after the last score of the previous page
+        let mut pages = Vec::new();
+        loop {
+            let page = search_range(range, 10);
+            let Some(last) = page.last() else {
+                break;
+            };
+            range.after = Some(last.score);
+            pages.extend(page);
+        }
+        assert_eq!(pages, result);
+    }
+
+    /// Passes every `n`-th point
+    struct SparseFilterContext(PointOffsetType);
+
+    impl FilterContext for SparseFilterContext {
+        fn check(&self, point_id: PointOffsetType) -> bool {
+            point_id % self.0 == 0
+        }
+    }
+
+    #[test]
+    fn test_two_hop_search_with_restrictive_filter() {
+        let num_vectors = 2000;
+        let dim = 8;
+        let top = 10;
+        let ef = 32;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        type M = CosineMetric;
+
+        let (vector_holder, graph_layers) = create_graph_layer_fixture::<M, _>(
+            num_vectors,
+            M,
+            dim,
+            GraphLinksFormat::Plain,
+            true,
+            &mut rng,
+        );
+
+        // Only 2% of the points pass the filter
+        let filter_context = SparseFilterContext(50);
+
+        let mut hnsw_found = 0;
+        let mut two_hop_found = 0;
+        for _ in 0..20 {
+            let query = random_vector(&mut rng, dim);
+            let processed_query = <M as Metric<VectorElementType>>::preprocess(query.clone());
+            let mut reference_top = FixedLengthPriorityQueue::new(top);
+            for idx in (0..num_vectors as PointOffsetType).filter(|&idx| filter_context.check(idx))
+            {
+                reference_top.push(ScoredPointOffset {
+                    idx,
+                    score: M::similarity(
+                        vector_holder.vectors.get(idx as VectorOffsetType),
+                        &processed_query,
+                    ),
+                });
+            }
+            let reference_top = reference_top.into_sorted_vec();
+
+            for (algorithm, found) in [
+                (SearchAlgorithm::Hnsw, &mut hnsw_found),
+                (SearchAlgorithm::TwoHop, &mut two_hop_found),
+            ] {
+                let raw_scorer = vector_holder.get_raw_scorer(query.clone()).unwrap();
+                let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&filter_context));
+                let result = graph_layers.search(top, ef, algorithm, scorer, None);
+
+                assert!(result.iter().all(|p| filter_context.check(p.idx)));
+                *found += result.iter().filter(|p| reference_top.contains(p)).count();
+            }
+        }
+
+        assert!(
+            two_hop_found > hnsw_found,
+            "hnsw_found = {hnsw_found}, two_hop_found = {two_hop_found}"
+        );
+    }
 }
@@ -110,6 +110,7 @@ impl GraphLayersBuilder {
             m: self.m,
             m0: self.m0,
             links,
+            repaired_links: Default::default(),
             entry_points,
             visited_pool: self.visited_pool,
         })
@@ -122,6 +123,7 @@ impl GraphLayersBuilder {
             m0: self.m0,
             links: Self::links_layers_to_serializer(self.links_layers, format, self.m, self.m0)
                 .to_graph_links_ram(),
+            repaired_links: Default::default(),
             entry_points: self.entry_points.into_inner(),
             visited_pool: self.visited_pool,
         }
@@ -256,6 +258,79 @@ impl GraphLayersBuilder {
             .merge_from_other(other.entry_points.into_inner());
     }
 
+    /// Copy levels and links of already indexed points from another graph
+    ///
+    /// `old_to_new` maps point offsets of `graph` into offsets of this builder. Links to unmapped
+    /// points are dropped, and the freed slots are refilled with the mapped neighbours of the
+    /// dropped points, so that the copied points stay connected. If a point is left with more
+    /// links than allowed on the level, they are pruned with the same heuristic, as on insertion.
+    ///
+    /// Returns points, which were copied. Only the rest of the points are left to be linked.
+    pub fn reuse_graph<F>(
+        &mut self,
+        graph: &GraphLayers,
+        old_to_new: &[Option<PointOffsetType>],
+        mut score_internal: F,
+    ) -> BitVec
+    where
+        F: FnMut(PointOffsetType, PointOffsetType) -> ScoreType,
+    {
+        let map_link = |link: PointOffsetType| old_to_new.get(link as usize).copied().flatten();
+        let mut reused = BitVec::repeat(false, self.num_points());
+        let mut dropped_links = Vec::new();
+        for (old_id, new_id) in old_to_new.iter().enumerate() {
+            let Some(new_id) = *new_id else {
+                continue;
+            };
+            let old_id = old_id as PointOffsetType;
+            let level = graph.point_level(old_id);
+            self.set_levels(new_id, level);
+
+            for curr_level in 0..=level {
+                let level_m = self.get_m(curr_level);
+                let mut links = Vec::with_capacity(level_m);
+                dropped_links.clear();
+                graph.links_map(old_id, curr_level, |link| match map_link(link) {
+                    Some(new_link) => links.push(new_link),
+                    None => dropped_links.push(link),
+                });
+                // Neighbours of a dropped point are likely close to this point as well
+                for &dropped_link in &dropped_links {
+                    graph.links_map(dropped_link, curr_level, |link| {
+                        if let Some(new_link) = map_link(link) {
+                            if new_link != new_id && !links.contains(&new_link) {
+                                links.push(new_link);
+                            }
+                        }
+                    });
+                }
+                if links.len() > level_m {
+                    let mut candidates: Vec<_> = links
+                        .iter()
+                        .map(|&link| ScoredPointOffset {
+                            idx: link,
+                            score: score_internal(new_id, link),
+                        })
+                        .collect();
+                    candidates.sort_unstable_by(|a, b| b.cmp(a));
+                    links = Self::select_candidate_with_heuristic_from_sorted(
+                        candidates.into_iter(),
+                        level_m,
+                        &mut score_internal,
+                    );
+                }
+                *self.links_layers[new_id as usize][curr_level].get_mut() = links;
+            }
+
+            reused.set(new_id as usize, true);
+            self.ready_list.get_mut().set(new_id as usize, true);
+            self.entry_points
+                .get_mut()
+                .new_point(new_id, level, |_| true);
+        }
+        reused
+    }
+
     fn num_points(&self) -> usize {
         self.links_layers.len()
     }
@@ -557,6 +632,7 @@ mod tests {
     use crate::fixtures::index_fixtures::{
         random_vector, FakeFilterContext, TestRawScorerProducer,
     };
+    use crate::index::hnsw_index::graph_layers::SearchAlgorithm;
     use crate::index::hnsw_index::graph_links::normalize_links;
     use crate::index::hnsw_index::tests::create_graph_layer_fixture;
     use crate::spaces::metric::Metric;
@@ -720,7 +796,7 @@ mod tests {
         let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
         let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
         let ef = 16;
-        let graph_search = graph.search(top, ef, scorer, None);
+        let graph_search = graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None);
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
@@ -813,7 +889,89 @@ mod tests {
         let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
         let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
         let ef = 16;
-        let graph_search = graph.search(top, ef, scorer, None);
+        let graph_search = graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None);
+        assert_eq!(reference_top.into_sorted_vec(), graph_search);
+    }
+
+    #[rstest]
+    #[case::uncompressed(GraphLinksFormat::Plain)]
+    #[case::compressed(GraphLinksFormat::Compressed)]
+    fn test_reuse_graph(#[case] format: GraphLinksFormat) {
+        let num_vectors = 1000;
+        let dim = 8;
+        let m = M / 2;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        type M = CosineMetric;
+
+        let (vector_holder, old_graph) =
+            create_graph_layer::<M, _>(num_vectors, dim, true, &mut rng);
+        let old_graph = old_graph.into_graph_layers_ram(format);
+
+        // Every third point is considered outdated, and has to be linked again
+        let old_to_new: Vec<_> = (0..num_vectors as PointOffsetType)
+            .map(|idx| (idx % 3 != 0).then_some(idx))
+            .collect();
+
+        let mut graph_layers_builder = GraphLayersBuilder::new(num_vectors, m, m * 2, 16, 10, true);
+        let raw_scorer = vector_holder
+            .get_raw_scorer(random_vector(&mut rng, dim))
+            .unwrap();
+        let reused = graph_layers_builder.reuse_graph(&old_graph, &old_to_new, |a, b| {
+            raw_scorer.score_internal(a, b)
+        });
+        assert_eq!(reused.count_ones(), old_to_new.iter().flatten().count());
+
+        for (idx, layers) in graph_layers_builder.links_layers.iter().enumerate() {
+            if !reused[idx] {
+                continue;
+            }
+            assert_eq!(
+                layers.len() - 1,
+                old_graph.point_level(idx as PointOffsetType),
+            );
+            for (level, links) in layers.iter().enumerate() {
+                let links = links.read();
+                assert!(links.len() <= graph_layers_builder.get_m(level));
+                assert!(links.iter().all(|&link| reused[link as usize]));
+                assert!(!links.contains(&(idx as PointOffsetType)));
+                // Links to outdated points are replaced with their neighbours
+                let mut old_links_count = 0;
+                old_graph.links_map(idx as PointOffsetType, level, |_| old_links_count += 1);
+                assert!(old_links_count == 0 || !links.is_empty());
+            }
+        }
+
+        let fake_filter_context = FakeFilterContext {};
+        for idx in (0..num_vectors as PointOffsetType).filter(|&idx| !reused[idx as usize]) {
+            let added_vector = vector_holder.vectors.get(idx as VectorOffsetType).to_vec();
+            let raw_scorer = vector_holder.get_raw_scorer(added_vector).unwrap();
+            let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+            let level = graph_layers_builder.get_random_layer(&mut rng);
+            graph_layers_builder.set_levels(idx, level);
+            graph_layers_builder.link_new_point(idx, scorer);
+        }
+
+        let top = 5;
+        let query = random_vector(&mut rng, dim);
+        let processed_query = <M as Metric<VectorElementType>>::preprocess(query.clone());
+        let mut reference_top = FixedLengthPriorityQueue::new(top);
+        for idx in 0..vector_holder.vectors.len() as PointOffsetType {
+            let vec = &vector_holder.vectors.get(idx as VectorOffsetType);
+            reference_top.push(ScoredPointOffset {
+                idx,
+                score: M::similarity(vec, &processed_query),
+            });
+        }
+
+        let graph = graph_layers_builder.into_graph_layers_ram(format);
+
+        let raw_scorer = vector_holder.get_raw_scorer(query).unwrap();
+        let scorer = FilteredScorer::new(raw_scorer.as_ref(), Some(&fake_filter_context));
+        let ef = 64;
+        let graph_search = graph.search(top, ef, SearchAlgorithm::Hnsw, scorer, None);
+
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
 
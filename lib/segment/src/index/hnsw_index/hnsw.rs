@@ -11,7 +11,7 @@ use bitvec::vec::BitVec;
 use common::counter::hardware_counter::HardwareCounterCell;
 #[cfg(target_os = "linux")]
 use common::cpu::linux_low_thread_priority;
-use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use common::types::{DetailsLevel, PointOffsetType, ScoredPointOffset, TelemetryDetail};
 use log::debug;
 use memory::mmap_ops;
 use parking_lot::Mutex;
@@ -26,7 +26,7 @@ use crate::common::operation_error::{check_process_stopped, OperationError, Oper
 use crate::common::operation_time_statistics::{
     OperationDurationsAggregator, ScopeDurationMeasurer,
 };
-use crate::common::BYTES_IN_KB;
+use crate::common::{Flusher, BYTES_IN_KB};
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
 use crate::id_tracker::IdTrackerSS;
@@ -34,28 +34,26 @@ use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
 use crate::index::hnsw_index::config::HnswGraphConfig;
 #[cfg(feature = "gpu")]
 use crate::index::hnsw_index::gpu::{get_gpu_groups_count, gpu_graph_builder::build_hnsw_on_gpu};
-use crate::index::hnsw_index::graph_layers::GraphLayers;
+use crate::index::hnsw_index::graph_layers::{GraphLayers, SearchAlgorithm};
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
-use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::query_estimator::{adjust_to_available_vectors, filtered_search_algorithm};
 use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vector_index_search_common::{
+    construct_search_scorer, get_oversampled_top, postprocess_search_result, score_range,
+};
 use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
-use crate::index::{PayloadIndex, VectorIndex};
+use crate::index::{PayloadIndex, VectorIndex, VectorIndexEnum};
 #[cfg(feature = "gpu")]
 use crate::payload_storage::FilterContext;
 use crate::segment_constructor::VectorIndexBuildArgs;
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::Condition::Field;
-use crate::types::{
-    default_quantization_ignore_value, default_quantization_oversampling_value, FieldCondition,
-    Filter, HnswConfig, QuantizationSearchParams, SearchParams,
-};
+use crate::types::{FieldCondition, Filter, HnswConfig, QuantizationSearchParams, SearchParams};
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query::DiscoveryQuery;
-use crate::vector_storage::{
-    new_raw_scorer, new_stoppable_raw_scorer, RawScorer, VectorStorage, VectorStorageEnum,
-};
+use crate::vector_storage::{new_raw_scorer, RawScorer, VectorStorage, VectorStorageEnum};
 
 const HNSW_USE_HEURISTIC: bool = true;
 const FINISH_MAIN_GRAPH_LOG_MESSAGE: &str = "Finish main graph in time";
@@ -179,6 +177,54 @@ impl HNSWIndex {
         self.quantized_vectors.clone()
     }
 
+    /// Reconnect neighbours of the deleted point, so the graph stays navigable
+    ///
+    /// Point must be already marked as deleted in the ID tracker or in the vector storage.
+    pub fn repair_deleted_point(&mut self, point_id: PointOffsetType) -> OperationResult<()> {
+        if point_id as usize >= self.graph.num_points() {
+            return Ok(());
+        }
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        // Deleted vector is kept in the storage, so it can be used to create the scorer
+        let vector = vector_storage.get_vector(point_id);
+        let vector = vector.as_vec_ref().into();
+        let stopped = AtomicBool::new(false);
+        // No need to accumulate hardware, since this is an internal operation
+        let internal_hardware_counter = HardwareCounterCell::disposable();
+
+        let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
+            quantized_storage.raw_scorer(
+                vector,
+                id_tracker.deleted_point_bitslice(),
+                vector_storage.deleted_vector_bitslice(),
+                &stopped,
+                internal_hardware_counter,
+            )
+        } else {
+            new_raw_scorer(
+                vector,
+                &vector_storage,
+                id_tracker.deleted_point_bitslice(),
+                &stopped,
+                internal_hardware_counter,
+            )
+        }?;
+        debug_assert!(!raw_scorer.check_vector(point_id));
+
+        self.graph
+            .repair_deleted_point(point_id, raw_scorer.as_ref());
+        Ok(())
+    }
+
+    /// Persist changes of the graph, made after it was built
+    pub fn flusher(&self) -> Flusher {
+        self.graph.flusher(&self.path)
+    }
+
     pub fn build(
         open_args: HnswIndexOpenArgs<'_>,
         build_args: VectorIndexBuildArgs<'_>,
@@ -204,7 +250,7 @@ impl HNSWIndex {
         } = open_args;
         let VectorIndexBuildArgs {
             permit,
-            old_indices: _,
+            old_indices,
             gpu_device,
             stopped,
         } = build_args;
@@ -299,11 +345,32 @@ impl HNSWIndex {
             })
             .build()?;
 
+        let reused_points = if config.m > 0 {
+            Self::reuse_old_graph(
+                old_indices,
+                &config,
+                id_tracker_ref.deref(),
+                &vector_storage_ref,
+                &quantized_vectors_ref,
+                &mut graph_layers_builder,
+                stopped,
+            )?
+        } else {
+            BitVec::new()
+        };
+        let is_reused = |vector_id: PointOffsetType| {
+            reused_points
+                .get(vector_id as usize)
+                .is_some_and(|is_reused| *is_reused)
+        };
+
         let mut indexed_vectors = 0;
         for vector_id in id_tracker_ref.iter_ids_excluding(deleted_bitslice) {
             check_process_stopped(stopped)?;
-            let level = graph_layers_builder.get_random_layer(&mut rng);
-            graph_layers_builder.set_levels(vector_id, level);
+            if !is_reused(vector_id) {
+                let level = graph_layers_builder.get_random_layer(&mut rng);
+                graph_layers_builder.set_levels(vector_id, level);
+            }
             indexed_vectors += 1;
         }
 
@@ -315,6 +382,7 @@ impl HNSWIndex {
 
         // Try to build the main graph on GPU if possible.
         // Store created gpu vectors to reuse them for payload links.
+        // GPU builds the graph from scratch, so a partially reused graph is completed on CPU.
         #[cfg(feature = "gpu")]
         let gpu_vectors = if build_main_graph {
             let timer = std::time::Instant::now();
@@ -324,7 +392,12 @@ impl HNSWIndex {
                 &quantized_vectors_ref,
                 stopped,
             )?;
-            if let Some(gpu_constructed_graph) = Self::build_main_graph_on_gpu(
+            if reused_points.any() {
+                debug!(
+                    "skip building main HNSW graph on GPU, {} points are reused",
+                    reused_points.count_ones(),
+                );
+            } else if let Some(gpu_constructed_graph) = Self::build_main_graph_on_gpu(
                 id_tracker_ref.deref(),
                 &vector_storage_ref,
                 &quantized_vectors_ref,
@@ -348,7 +421,10 @@ impl HNSWIndex {
         if build_main_graph {
             let timer = std::time::Instant::now();
 
-            let mut ids_iterator = id_tracker_ref.iter_ids_excluding(deleted_bitslice);
+            // Points, copied from the old graph, are already linked
+            let mut ids_iterator = id_tracker_ref
+                .iter_ids_excluding(deleted_bitslice)
+                .filter(|&vector_id| !is_reused(vector_id));
 
             let first_few_ids: Vec<_> = ids_iterator
                 .by_ref()
@@ -512,6 +588,101 @@ impl HNSWIndex {
         })
     }
 
+    /// Seed the graph with links of the largest compatible HNSW index among `old_indices`
+    ///
+    /// Points are copied only if they hold the same version of the vector in both segments.
+    /// Returns points, which were copied and do not need to be linked again.
+    fn reuse_old_graph(
+        old_indices: &[Arc<AtomicRefCell<VectorIndexEnum>>],
+        config: &HnswGraphConfig,
+        id_tracker: &IdTrackerSS,
+        vector_storage: &VectorStorageEnum,
+        quantized_vectors: &Option<QuantizedVectors>,
+        graph_layers_builder: &mut GraphLayersBuilder,
+        stopped: &AtomicBool,
+    ) -> OperationResult<BitVec> {
+        let old_indices: Vec<_> = old_indices.iter().map(|index| index.borrow()).collect();
+        let Some(old_index) = old_indices
+            .iter()
+            .filter_map(|index| match index.deref() {
+                VectorIndexEnum::Hnsw(index) => Some(index),
+                _ => None,
+            })
+            .filter(|index| {
+                index.config.m == config.m
+                    && index.config.m0 == config.m0
+                    && index.config.ef_construct == config.ef_construct
+            })
+            .max_by_key(|index| index.graph.num_points())
+        else {
+            return Ok(BitVec::new());
+        };
+
+        let old_id_tracker = old_index.id_tracker.borrow();
+        let old_vector_storage = old_index.vector_storage.borrow();
+        let deleted_bitslice = vector_storage.deleted_vector_bitslice();
+
+        let mut old_to_new = vec![None; old_index.graph.num_points()];
+        for old_id in
+            old_id_tracker.iter_ids_excluding(old_vector_storage.deleted_vector_bitslice())
+        {
+            check_process_stopped(stopped)?;
+            let Some(mapped) = old_to_new.get_mut(old_id as usize) else {
+                continue;
+            };
+            let Some(new_id) = old_id_tracker
+                .external_id(old_id)
+                .and_then(|external_id| id_tracker.internal_id(external_id))
+            else {
+                continue;
+            };
+            let is_same_vector = !deleted_bitslice
+                .get(new_id as usize)
+                .is_some_and(|is_deleted| *is_deleted)
+                && old_id_tracker.internal_version(old_id) == id_tracker.internal_version(new_id);
+            if is_same_vector {
+                *mapped = Some(new_id);
+            }
+        }
+
+        let Some(&Some(first_point)) = old_to_new.iter().find(|new_id| new_id.is_some()) else {
+            return Ok(BitVec::new());
+        };
+
+        // Scorer is only used to compare indexed points with each other, any query fits
+        let vector = vector_storage.get_vector(first_point);
+        let vector = vector.as_vec_ref().into();
+        // No need to accumulate hardware, since this is an internal operation
+        let internal_hardware_counter = HardwareCounterCell::disposable();
+        let raw_scorer = if let Some(quantized_storage) = quantized_vectors.as_ref() {
+            quantized_storage.raw_scorer(
+                vector,
+                id_tracker.deleted_point_bitslice(),
+                deleted_bitslice,
+                stopped,
+                internal_hardware_counter,
+            )
+        } else {
+            new_raw_scorer(
+                vector,
+                vector_storage,
+                id_tracker.deleted_point_bitslice(),
+                stopped,
+                internal_hardware_counter,
+            )
+        }?;
+
+        let reused = graph_layers_builder.reuse_graph(&old_index.graph, &old_to_new, |a, b| {
+            raw_scorer.score_internal(a, b)
+        });
+        debug!(
+            "reused {} points from HNSW graph of {} points",
+            reused.count_ones(),
+            old_index.graph.num_points(),
+        );
+        Ok(reused)
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[allow(unused_variables)]
     #[allow(clippy::needless_pass_by_ref_mut)]
@@ -816,6 +987,7 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        algorithm: SearchAlgorithm,
         custom_entry_points: Option<&[PointOffsetType]>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
@@ -834,7 +1006,7 @@ impl HNSWIndex {
             .deleted_points()
             .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
 
-        let raw_scorer = Self::construct_search_scorer(
+        let raw_scorer = construct_search_scorer(
             vector,
             &vector_storage,
             quantized_vectors.as_ref(),
@@ -843,22 +1015,40 @@ impl HNSWIndex {
             &is_stopped,
             vector_query_context.hardware_counter(),
         )?;
-        let oversampled_top = Self::get_oversampled_top(quantized_vectors.as_ref(), params, top);
+        let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
         let hw_counter = vector_query_context.hardware_counter();
 
         let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
         let points_scorer = FilteredScorer::new(raw_scorer.as_ref(), filter_context.as_deref());
 
-        let search_result =
-            self.graph
-                .search(oversampled_top, ef, points_scorer, custom_entry_points);
+        let range = score_range(vector, params, vector_query_context, &vector_storage);
+        let search_result = match range {
+            Some(range) => self.graph.search_range(
+                range,
+                oversampled_top,
+                ef,
+                points_scorer,
+                custom_entry_points,
+            ),
+            None => self.graph.search(
+                oversampled_top,
+                ef,
+                algorithm,
+                points_scorer,
+                custom_entry_points,
+            ),
+        };
 
-        let res = self.postprocess_search_result(
+        let res = postprocess_search_result(
             search_result,
             vector,
             params,
+            range,
             top,
+            &id_tracker,
+            &vector_storage,
+            quantized_vectors.as_ref(),
             &is_stopped,
             vector_query_context.hardware_counter(),
         )?;
@@ -872,6 +1062,7 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        algorithm: SearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
         vectors
@@ -882,11 +1073,18 @@ impl HNSWIndex {
                     filter,
                     top,
                     params,
+                    algorithm,
+                    vector_query_context,
+                ),
+                other => self.search_with_graph(
+                    other,
+                    filter,
+                    top,
+                    params,
+                    algorithm,
+                    None,
                     vector_query_context,
                 ),
-                other => {
-                    self.search_with_graph(other, filter, top, params, None, vector_query_context)
-                }
             })
             .collect()
     }
@@ -909,7 +1107,7 @@ impl HNSWIndex {
 
         let is_stopped = vector_query_context.is_stopped();
 
-        let raw_scorer = Self::construct_search_scorer(
+        let raw_scorer = construct_search_scorer(
             vector,
             &vector_storage,
             quantized_vectors.as_ref(),
@@ -918,15 +1116,23 @@ impl HNSWIndex {
             &is_stopped,
             vector_query_context.hardware_counter(),
         )?;
-        let oversampled_top = Self::get_oversampled_top(quantized_vectors.as_ref(), params, top);
+        let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
-        let search_result = raw_scorer.peek_top_iter(points, oversampled_top);
+        let range = score_range(vector, params, vector_query_context, &vector_storage);
+        let search_result = match range {
+            Some(range) => raw_scorer.peek_range_iter(points, range, oversampled_top),
+            None => raw_scorer.peek_top_iter(points, oversampled_top),
+        };
 
-        let res = self.postprocess_search_result(
+        let res = postprocess_search_result(
             search_result,
             vector,
             params,
+            range,
             top,
+            &id_tracker,
+            &vector_storage,
+            quantized_vectors.as_ref(),
             &is_stopped,
             vector_query_context.hardware_counter(),
         )?;
@@ -988,10 +1194,16 @@ impl HNSWIndex {
         filter: Option<&Filter>,
         top: usize,
         params: Option<&SearchParams>,
+        algorithm: SearchAlgorithm,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
         // Stage 1: Find best entry points using Context search
         let query_vector = QueryVector::Context(discovery_query.pairs.clone().into());
+        // Range only applies to the final discovery scores
+        let context_params = params.map(|params| SearchParams {
+            range: None,
+            ..*params
+        });
 
         const DISCOVERY_ENTRY_POINT_COUNT: usize = 10;
 
@@ -1000,7 +1212,8 @@ impl HNSWIndex {
                 &query_vector,
                 filter,
                 DISCOVERY_ENTRY_POINT_COUNT,
-                params,
+                context_params.as_ref(),
+                algorithm,
                 None,
                 vector_query_context,
             )
@@ -1014,117 +1227,12 @@ impl HNSWIndex {
             filter,
             top,
             params,
+            algorithm,
             Some(&custom_entry_points),
             vector_query_context,
         )
     }
 
-    fn is_quantized_search(
-        quantized_storage: Option<&QuantizedVectors>,
-        params: Option<&SearchParams>,
-    ) -> bool {
-        let ignore_quantization = params
-            .and_then(|p| p.quantization)
-            .map(|q| q.ignore)
-            .unwrap_or(default_quantization_ignore_value());
-        quantized_storage.is_some() && !ignore_quantization
-    }
-
-    fn construct_search_scorer<'a>(
-        vector: &QueryVector,
-        vector_storage: &'a VectorStorageEnum,
-        quantized_storage: Option<&'a QuantizedVectors>,
-        deleted_points: &'a BitSlice,
-        params: Option<&SearchParams>,
-        is_stopped: &'a AtomicBool,
-        hardware_counter: HardwareCounterCell,
-    ) -> OperationResult<Box<dyn RawScorer + 'a>> {
-        let quantization_enabled = Self::is_quantized_search(quantized_storage, params);
-        match quantized_storage {
-            Some(quantized_storage) if quantization_enabled => quantized_storage.raw_scorer(
-                vector.to_owned(),
-                deleted_points,
-                vector_storage.deleted_vector_bitslice(),
-                is_stopped,
-                hardware_counter,
-            ),
-            _ => new_stoppable_raw_scorer(
-                vector.to_owned(),
-                vector_storage,
-                deleted_points,
-                is_stopped,
-                hardware_counter,
-            ),
-        }
-    }
-
-    fn get_oversampled_top(
-        quantized_storage: Option<&QuantizedVectors>,
-        params: Option<&SearchParams>,
-        top: usize,
-    ) -> usize {
-        let quantization_enabled = Self::is_quantized_search(quantized_storage, params);
-
-        let oversampling_value = params
-            .and_then(|p| p.quantization)
-            .map(|q| q.oversampling)
-            .unwrap_or(default_quantization_oversampling_value());
-
-        match oversampling_value {
-            Some(oversampling) if quantization_enabled && oversampling > 1.0 => {
-                (oversampling * top as f64) as usize
-            }
-            _ => top,
-        }
-    }
-
-    fn postprocess_search_result(
-        &self,
-        search_result: Vec<ScoredPointOffset>,
-        vector: &QueryVector,
-        params: Option<&SearchParams>,
-        top: usize,
-        is_stopped: &AtomicBool,
-        hardware_counter: HardwareCounterCell,
-    ) -> OperationResult<Vec<ScoredPointOffset>> {
-        let id_tracker = self.id_tracker.borrow();
-        let vector_storage = self.vector_storage.borrow();
-        let quantized_vectors = self.quantized_vectors.borrow();
-
-        let quantization_enabled = Self::is_quantized_search(quantized_vectors.as_ref(), params);
-
-        let default_rescoring = quantized_vectors
-            .as_ref()
-            .map(|q| q.default_rescoring())
-            .unwrap_or(false);
-        let rescore = quantization_enabled
-            && params
-                .and_then(|p| p.quantization)
-                .and_then(|q| q.rescore)
-                .unwrap_or(default_rescoring);
-
-        let mut postprocess_result = if rescore {
-            let raw_scorer = new_stoppable_raw_scorer(
-                vector.to_owned(),
-                &vector_storage,
-                id_tracker.deleted_point_bitslice(),
-                is_stopped,
-                hardware_counter,
-            )?;
-
-            let mut ids_iterator = search_result.iter().map(|x| x.idx);
-            let mut re_scored = raw_scorer.score_points_unfiltered(&mut ids_iterator);
-
-            re_scored.sort_unstable();
-            re_scored.reverse();
-            re_scored
-        } else {
-            search_result
-        };
-        postprocess_result.truncate(top);
-        Ok(postprocess_result)
-    }
-
     pub fn prefault_mmap_pages(&self) -> Option<mmap_ops::PrefaultMmapPages> {
         self.graph.prefault_mmap_pages(&self.path)
     }
@@ -1190,7 +1298,14 @@ impl VectorIndex for HNSWIndex {
                 } else {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_hnsw);
-                    self.search_vectors_with_graph(vectors, None, top, params, query_context)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        None,
+                        top,
+                        params,
+                        SearchAlgorithm::Hnsw,
+                        query_context,
+                    )
                 }
             }
             Some(query_filter) => {
@@ -1228,6 +1343,11 @@ impl VectorIndex for HNSWIndex {
                     id_tracker.available_point_count(),
                 );
 
+                // Restrictive filters disconnect the graph, so the traversal has to go through
+                // filtered-out points
+                let algorithm =
+                    filtered_search_algorithm(&query_cardinality, available_vector_count);
+
                 if query_cardinality.max < self.config.full_scan_threshold {
                     // if cardinality is small - use plain index
                     let _timer =
@@ -1250,6 +1370,7 @@ impl VectorIndex for HNSWIndex {
                         filter,
                         top,
                         params,
+                        algorithm,
                         query_context,
                     );
                 }
@@ -1268,7 +1389,14 @@ impl VectorIndex for HNSWIndex {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    self.search_vectors_with_graph(vectors, filter, top, params, query_context)
+                    self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        params,
+                        algorithm,
+                        query_context,
+                    )
                 } else {
                     // if cardinality is small - use plain index
                     let _timer =
@@ -1292,6 +1420,22 @@ impl VectorIndex for HNSWIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
             unfiltered_sparse: Default::default(),
+            unfiltered_ivf: Default::default(),
+            filtered_ivf: Default::default(),
+            unfiltered_vamana: Default::default(),
+            filtered_vamana: Default::default(),
+            // Requires a scan of the whole graph, so only computed for the detailed telemetry
+            dead_links_ratio: if detail.level >= DetailsLevel::Level2 {
+                let id_tracker = self.id_tracker.borrow();
+                let vector_storage = self.vector_storage.borrow();
+                self.graph.dead_links_ratio(|point_id| {
+                    id_tracker.is_deleted_point(point_id)
+                        || vector_storage.is_deleted_vector(point_id)
+                })
+            } else {
+                None
+            },
+            pruned_postings_ratio: None,
         }
     }
 
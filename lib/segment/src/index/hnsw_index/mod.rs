@@ -9,6 +9,7 @@ pub mod graph_layers_builder;
 pub mod graph_links;
 pub mod hnsw;
 pub mod point_scorer;
+mod repaired_links;
 mod search_context;
 
 #[cfg(feature = "gpu")]
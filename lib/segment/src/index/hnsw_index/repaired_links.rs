@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bitvec::vec::BitVec;
+use common::types::PointOffsetType;
+use io::file_operations::{atomic_save_bin, read_bin};
+
+use super::graph_layers::LinkContainer;
+use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
+
+pub const HNSW_REPAIRED_LINKS_FILE: &str = "links_repaired.bin";
+
+/// Links, which were changed after the graph was built
+///
+/// When a point is deleted, its neighbours are reconnected to each other. Their new links take
+/// precedence over the immutable [`GraphLinks`](super::graph_links::GraphLinks).
+///
+/// Only the latest links of each point and level are kept, so the size is bounded by the size of
+/// the graph. Changes are kept in memory and saved as a whole on flush, replacing the previous
+/// file. Once the segment is optimized, the graph is rebuilt and repaired links are dropped.
+///
+/// Losing unflushed changes is fine: the graph only loses some shortcuts around deleted points,
+/// and deletions replayed from WAL repair it again.
+#[derive(Debug, Default)]
+pub struct RepairedLinks {
+    /// Points, which have repaired links on any level. Allows to skip the map lookup for the rest
+    repaired_points: BitVec,
+    links: HashMap<(PointOffsetType, usize), LinkContainer>,
+    /// Whether there are changes, which are not saved yet
+    is_dirty: Arc<AtomicBool>,
+}
+
+impl RepairedLinks {
+    pub fn get_path(dir: &Path) -> PathBuf {
+        dir.join(HNSW_REPAIRED_LINKS_FILE)
+    }
+
+    pub fn load(dir: &Path) -> OperationResult<Self> {
+        let path = Self::get_path(dir);
+        let mut repaired_links = Self::default();
+        if !path.exists() {
+            return Ok(repaired_links);
+        }
+
+        let links: Vec<(PointOffsetType, usize, LinkContainer)> = read_bin(&path)?;
+        for (point_id, level, links) in links {
+            repaired_links.insert(point_id, level, links);
+        }
+        Ok(repaired_links)
+    }
+
+    /// Links of the point on the given level, if they were repaired
+    pub fn get(&self, point_id: PointOffsetType, level: usize) -> Option<&[PointOffsetType]> {
+        if !self
+            .repaired_points
+            .get(point_id as usize)
+            .is_some_and(|is_repaired| *is_repaired)
+        {
+            return None;
+        }
+        self.links.get(&(point_id, level)).map(Vec::as_slice)
+    }
+
+    /// Use new links of the points instead of the previous ones
+    ///
+    /// Changes are persisted by the next [`flusher`](Self::flusher).
+    pub fn extend(&mut self, repaired: Vec<(PointOffsetType, usize, LinkContainer)>) {
+        if repaired.is_empty() {
+            return;
+        }
+        for (point_id, level, links) in repaired {
+            self.insert(point_id, level, links);
+        }
+        self.is_dirty.store(true, Ordering::Relaxed);
+    }
+
+    /// Save a snapshot of all repaired links into `dir`, if there are unsaved changes
+    pub fn flusher(&self, dir: &Path) -> Flusher {
+        if !self.is_dirty.swap(false, Ordering::Relaxed) {
+            return Box::new(|| Ok(()));
+        }
+
+        let links = self
+            .links
+            .iter()
+            .map(|(&(point_id, level), links)| (point_id, level, links.clone()))
+            .collect::<Vec<_>>();
+        let path = Self::get_path(dir);
+        let is_dirty = self.is_dirty.clone();
+        Box::new(move || {
+            atomic_save_bin(&path, &links).inspect_err(|_| {
+                // Try again on the next flush
+                is_dirty.store(true, Ordering::Relaxed);
+            })?;
+            Ok(())
+        })
+    }
+
+    fn insert(&mut self, point_id: PointOffsetType, level: usize, links: LinkContainer) {
+        let idx = point_id as usize;
+        if self.repaired_points.len() <= idx {
+            self.repaired_points.resize(idx + 1, false);
+        }
+        self.repaired_points.set(idx, true);
+        self.links.insert((point_id, level), links);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    #[test]
+    fn test_repaired_links_persistence() {
+        let dir = Builder::new().prefix("repaired_links").tempdir().unwrap();
+        let path = RepairedLinks::get_path(dir.path());
+
+        let mut repaired_links = RepairedLinks::load(dir.path()).unwrap();
+        assert_eq!(repaired_links.get(3, 0), None);
+
+        // Nothing to save
+        repaired_links.flusher(dir.path())().unwrap();
+        assert!(!path.exists());
+
+        repaired_links.extend(vec![(3, 0, vec![1, 2]), (3, 1, vec![5])]);
+        repaired_links.extend(vec![(3, 0, vec![2, 4, 6])]);
+        assert_eq!(repaired_links.get(3, 0), Some([2, 4, 6].as_slice()));
+        assert_eq!(repaired_links.get(3, 1), Some([5].as_slice()));
+        assert_eq!(repaired_links.get(3, 2), None);
+        assert_eq!(repaired_links.get(1, 0), None);
+        assert_eq!(repaired_links.get(100, 0), None);
+
+        // Not flushed yet
+        assert!(!path.exists());
+        assert_eq!(RepairedLinks::load(dir.path()).unwrap().get(3, 0), None);
+
+        repaired_links.flusher(dir.path())().unwrap();
+        let loaded = RepairedLinks::load(dir.path()).unwrap();
+        assert_eq!(loaded.get(3, 0), Some([2, 4, 6].as_slice()));
+        assert_eq!(loaded.get(3, 1), Some([5].as_slice()));
+        assert_eq!(loaded.get(7, 0), None);
+
+        // Overwritten links are not accumulated in the file
+        let saved_len = std::fs::metadata(&path).unwrap().len();
+        for _ in 0..10 {
+            repaired_links.extend(vec![(3, 0, vec![2, 4, 6])]);
+            repaired_links.flusher(dir.path())().unwrap();
+        }
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), saved_len);
+
+        repaired_links.extend(vec![(7, 0, vec![8])]);
+        repaired_links.flusher(dir.path())().unwrap();
+        let loaded = RepairedLinks::load(dir.path()).unwrap();
+        assert_eq!(loaded.get(7, 0), Some([8].as_slice()));
+        assert_eq!(loaded.get(3, 0), Some([2, 4, 6].as_slice()));
+    }
+}
@@ -7,7 +7,7 @@ use rand::SeedableRng;
 use rstest::rstest;
 
 use crate::fixtures::index_fixtures::random_vector;
-use crate::index::hnsw_index::graph_layers::GraphLayersBase;
+use crate::index::hnsw_index::graph_layers::{GraphLayersBase, SearchAlgorithm};
 use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
 use crate::index::hnsw_index::graph_links::GraphLinksFormat;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
@@ -76,7 +76,7 @@ fn test_compact_graph_layers(#[case] format: GraphLinksFormat) {
         .map(|query| {
             let raw_scorer = vector_holder.get_raw_scorer(query.clone()).unwrap();
             let scorer = FilteredScorer::new(raw_scorer.as_ref(), None);
-            graph_layers.search(top, ef, scorer, None)
+            graph_layers.search(top, ef, SearchAlgorithm::Hnsw, scorer, None)
         })
         .collect_vec();
 
@@ -0,0 +1,242 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::types::{PointOffsetType, ScoreType};
+use io::file_operations::{atomic_save_bin, read_bin};
+use quantization::kmeans::kmeans;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::{check_process_stopped, OperationResult};
+use crate::data_types::vectors::{DenseVector, QueryVector, VectorElementType, VectorInternal};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::types::Distance;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+pub const IVF_CLUSTERS_FILE: &str = "ivf_clusters.bin";
+
+/// Number of sampled vectors per cluster, used to train centroids
+const IVF_SAMPLES_PER_CLUSTER: usize = 64;
+const IVF_KMEANS_ITERATIONS: usize = 20;
+const IVF_KMEANS_ACCURACY: f32 = 1e-5;
+
+/// Partition of the vectors into clusters.
+/// Each cluster is represented by its centroid and the posting list of the assigned points.
+/// Posting lists hold point ids only, residuals to the centroids are not encoded.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct IvfClusters {
+    dim: usize,
+    /// Centroids, stored one after another
+    centroids: Vec<VectorElementType>,
+    postings: Vec<Vec<PointOffsetType>>,
+}
+
+impl IvfClusters {
+    pub fn get_path(path: &Path) -> PathBuf {
+        path.join(IVF_CLUSTERS_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_bin(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_bin(path, self)?)
+    }
+
+    /// Train centroids on a random sample of the vectors and assign each point to the most similar one
+    ///
+    /// If `nlist` is 0, the number of clusters is the square root of the number of points.
+    pub fn build(
+        point_ids: &[PointOffsetType],
+        vector_storage: &VectorStorageEnum,
+        nlist: usize,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let Some(&first_id) = point_ids.first() else {
+            return Ok(Self::default());
+        };
+        let dim = get_dense(vector_storage, first_id)?.len();
+        let distance = vector_storage.distance();
+
+        let nlist = if nlist == 0 {
+            (point_ids.len() as f64).sqrt().ceil() as usize
+        } else {
+            nlist
+        }
+        .clamp(1, point_ids.len());
+
+        // Sample is taken in random order, because k-means uses the first vectors as initial centroids
+        let sample_size = nlist
+            .saturating_mul(IVF_SAMPLES_PER_CLUSTER)
+            .min(point_ids.len());
+        let mut sample = Vec::with_capacity(sample_size * dim);
+        for idx in rand::seq::index::sample(&mut rand::rng(), point_ids.len(), sample_size) {
+            check_process_stopped(stopped)?;
+            sample.extend_from_slice(&get_dense(vector_storage, point_ids[idx])?);
+        }
+
+        let mut centroids = kmeans(
+            &sample,
+            nlist,
+            dim,
+            IVF_KMEANS_ITERATIONS,
+            max_threads,
+            IVF_KMEANS_ACCURACY,
+            stopped,
+        )?;
+        normalize_centroids(distance, &mut centroids, dim);
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .thread_name(|idx| format!("ivf-build-{idx}"))
+            .num_threads(max_threads)
+            .build()?;
+
+        let assignments: Vec<usize> = pool.install(|| {
+            point_ids
+                .par_iter()
+                .map(|&point_id| {
+                    check_process_stopped(stopped)?;
+                    let vector = get_dense(vector_storage, point_id)?;
+                    Ok(nearest_centroid(distance, &vector, &centroids, dim))
+                })
+                .collect::<OperationResult<_>>()
+        })?;
+
+        let mut postings = vec![Vec::new(); nlist];
+        for (&point_id, cluster) in point_ids.iter().zip(assignments) {
+            postings[cluster].push(point_id);
+        }
+
+        Ok(Self {
+            dim,
+            centroids,
+            postings,
+        })
+    }
+
+    pub fn nlist(&self) -> usize {
+        self.postings.len()
+    }
+
+    pub fn posting(&self, cluster: usize) -> &[PointOffsetType] {
+        &self.postings[cluster]
+    }
+
+    /// Number of points in all posting lists
+    pub fn points_count(&self) -> usize {
+        self.postings.iter().map(Vec::len).sum()
+    }
+
+    /// Clusters, ordered from the most to the least promising for the query
+    pub fn rank(&self, query: &QueryVector, distance: Distance) -> OperationResult<Vec<usize>> {
+        let query = query
+            .clone()
+            .transform(|vector| Ok(preprocess(vector, distance)))?;
+
+        let mut scores: Vec<(ScoreType, usize)> = self
+            .centroids
+            .chunks_exact(self.dim.max(1))
+            .enumerate()
+            .map(|(cluster, centroid)| {
+                let similarity = |vector: &VectorInternal| match vector {
+                    VectorInternal::Dense(vector) => similarity(distance, vector, centroid),
+                    // Only dense vectors are clustered
+                    VectorInternal::Sparse(_)
+                    | VectorInternal::MultiDense(_)
+                    | VectorInternal::WeightedMultiDense(_) => 0.0,
+                };
+                let score = match &query {
+                    QueryVector::Nearest(vector) => similarity(vector),
+                    QueryVector::Recommend(query) => query.score_by(similarity),
+                    QueryVector::Discovery(query) => query.score_by(similarity),
+                    QueryVector::Context(query) => query.score_by(similarity),
+                };
+                (score, cluster)
+            })
+            .collect();
+
+        scores.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+        Ok(scores.into_iter().map(|(_, cluster)| cluster).collect())
+    }
+}
+
+fn get_dense(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+) -> OperationResult<DenseVector> {
+    let vector = vector_storage.get_vector(point_id);
+    let dense: &[VectorElementType] = vector.as_vec_ref().try_into()?;
+    Ok(dense.to_vec())
+}
+
+fn nearest_centroid(
+    distance: Distance,
+    vector: &[VectorElementType],
+    centroids: &[VectorElementType],
+    dim: usize,
+) -> usize {
+    centroids
+        .chunks_exact(dim.max(1))
+        .map(|centroid| similarity(distance, vector, centroid))
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(cluster, _)| cluster)
+}
+
+/// Averages of the vectors are shorter than the vectors themselves, and the more spread
+/// a cluster is, the shorter its centroid. Dot product would rank clusters by their spread
+/// rather than by direction, so centroids are compared with the query as unit vectors.
+fn normalize_centroids(distance: Distance, centroids: &mut [VectorElementType], dim: usize) {
+    match distance {
+        Distance::Cosine | Distance::Dot => {
+            for centroid in centroids.chunks_exact_mut(dim.max(1)) {
+                let normalized =
+                    <CosineMetric as Metric<VectorElementType>>::preprocess(centroid.to_vec());
+                centroid.copy_from_slice(&normalized);
+            }
+        }
+        Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {}
+    }
+}
+
+/// Bring the query vector into the form of the stored vectors
+fn preprocess(vector: VectorInternal, distance: Distance) -> VectorInternal {
+    match vector {
+        VectorInternal::Dense(vector) => VectorInternal::Dense(match distance {
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Euclid | Distance::Dot | Distance::Manhattan => vector,
+            // Stored bits are either 0 or 1
+            Distance::Hamming | Distance::Jaccard => vector
+                .into_iter()
+                .map(|value| if value > 0.0 { 1.0 } else { 0.0 })
+                .collect(),
+        }),
+        other => other,
+    }
+}
+
+/// Similarity of the vector to the centroid, larger is more similar
+///
+/// Centroids are averages of the stored vectors, so bit vectors are compared
+/// with them by the euclidean distance.
+fn similarity(
+    distance: Distance,
+    vector: &[VectorElementType],
+    centroid: &[VectorElementType],
+) -> ScoreType {
+    match distance {
+        Distance::Cosine | Distance::Dot => {
+            <DotProductMetric as Metric<VectorElementType>>::similarity(vector, centroid)
+        }
+        Distance::Euclid | Distance::Hamming | Distance::Jaccard => {
+            <EuclidMetric as Metric<VectorElementType>>::similarity(vector, centroid)
+        }
+        Distance::Manhattan => {
+            <ManhattanMetric as Metric<VectorElementType>>::similarity(vector, centroid)
+        }
+    }
+}
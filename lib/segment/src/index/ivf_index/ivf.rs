@@ -0,0 +1,502 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::types::{PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use log::debug;
+use parking_lot::Mutex;
+
+use super::clusters::IvfClusters;
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{QueryVector, VectorRef};
+use crate::id_tracker::IdTrackerSS;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vector_index_search_common::{
+    construct_search_scorer, get_oversampled_top, postprocess_search_result, score_range,
+};
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{Filter, IvfConfig, QuantizationSearchParams, SearchParams};
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+/// Inverted file index: vectors are partitioned into clusters by k-means,
+/// and the search scans only the clusters, closest to the query.
+///
+/// There is no separate PQ coding of residuals in posting lists: scanned points are scored
+/// with the segment-level quantization (including PQ) if it is configured,
+/// and with the original vectors otherwise.
+#[derive(Debug)]
+pub struct IVFIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    config: IvfConfig,
+    path: PathBuf,
+    clusters: IvfClusters,
+    searches_telemetry: IVFSearchesTelemetry,
+}
+
+#[derive(Debug)]
+struct IVFSearchesTelemetry {
+    unfiltered_ivf: Arc<Mutex<OperationDurationsAggregator>>,
+    filtered_ivf: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl IVFSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_ivf: OperationDurationsAggregator::new(),
+            filtered_ivf: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+pub struct IvfIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub ivf_config: IvfConfig,
+}
+
+impl IVFIndex {
+    pub fn open(args: IvfIndexOpenArgs<'_>) -> OperationResult<Self> {
+        let IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            ivf_config,
+        } = args;
+
+        let clusters = IvfClusters::load(&IvfClusters::get_path(path))?;
+
+        Ok(IVFIndex {
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            config: ivf_config,
+            path: path.to_owned(),
+            clusters,
+            searches_telemetry: IVFSearchesTelemetry::new(),
+        })
+    }
+
+    pub fn build(
+        open_args: IvfIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_>,
+    ) -> OperationResult<Self> {
+        if IvfClusters::get_path(open_args.path).exists() {
+            log::warn!(
+                "IVF index already exists at {:?}, skipping building",
+                open_args.path
+            );
+            debug_assert!(false);
+            return Self::open(open_args);
+        }
+
+        let IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            ivf_config,
+        } = open_args;
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            stopped,
+        } = build_args;
+
+        create_dir_all(path)?;
+
+        let clusters = {
+            let id_tracker_ref = id_tracker.borrow();
+            let vector_storage_ref = vector_storage.borrow();
+
+            let point_ids: Vec<_> = id_tracker_ref
+                .iter_ids_excluding(vector_storage_ref.deleted_vector_bitslice())
+                .collect();
+            debug!(
+                "building IVF for {} vectors with {} CPUs",
+                point_ids.len(),
+                permit.num_cpus,
+            );
+
+            IvfClusters::build(
+                &point_ids,
+                &vector_storage_ref,
+                ivf_config.nlist,
+                permit.num_cpus as usize,
+                stopped,
+            )?
+        };
+        clusters.save(&IvfClusters::get_path(path))?;
+
+        Ok(IVFIndex {
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            config: ivf_config,
+            path: path.to_owned(),
+            clusters,
+            searches_telemetry: IVFSearchesTelemetry::new(),
+        })
+    }
+
+    fn nprobe(&self, params: Option<&SearchParams>) -> usize {
+        params
+            .and_then(|params| params.ivf_nprobe)
+            .unwrap_or(self.config.nprobe)
+    }
+
+    /// Score the points of the clusters, closest to the query
+    ///
+    /// At least `nprobe` clusters are scanned, and more clusters are scanned
+    /// until there are enough points, which pass the filter.
+    fn search_with_clusters(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let nprobe = self.nprobe(params);
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        let deleted_points = vector_query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+
+        let raw_scorer = construct_search_scorer(
+            vector,
+            &vector_storage,
+            quantized_vectors.as_ref(),
+            deleted_points,
+            params,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )?;
+        let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
+
+        let hw_counter = vector_query_context.hardware_counter();
+
+        let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
+
+        let mut candidates = Vec::new();
+        for (probed, cluster) in self
+            .clusters
+            .rank(vector, vector_storage.distance())?
+            .into_iter()
+            .enumerate()
+        {
+            if probed >= nprobe && candidates.len() >= oversampled_top {
+                break;
+            }
+            check_process_stopped(&is_stopped)?;
+            candidates.extend(
+                self.clusters
+                    .posting(cluster)
+                    .iter()
+                    .copied()
+                    .filter(|&point_id| {
+                        raw_scorer.check_vector(point_id)
+                            && filter_context
+                                .as_ref()
+                                .map_or(true, |filter_context| filter_context.check(point_id))
+                    }),
+            );
+        }
+
+        let mut candidates_iter = candidates.into_iter();
+        let range = score_range(vector, params, vector_query_context, &vector_storage);
+        let search_result = match range {
+            Some(range) => raw_scorer.peek_range_iter(&mut candidates_iter, range, oversampled_top),
+            None => raw_scorer.peek_top_iter(&mut candidates_iter, oversampled_top),
+        };
+
+        postprocess_search_result(
+            search_result,
+            vector,
+            params,
+            range,
+            top,
+            &id_tracker,
+            &vector_storage,
+            quantized_vectors.as_ref(),
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )
+    }
+
+    fn search_vectors_with_clusters(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vectors
+            .iter()
+            .map(|&vector| {
+                self.search_with_clusters(vector, filter, top, params, vector_query_context)
+            })
+            .collect()
+    }
+
+    fn search_plain_iterator(
+        &self,
+        vector: &QueryVector,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        let deleted_points = vector_query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        let raw_scorer = construct_search_scorer(
+            vector,
+            &vector_storage,
+            quantized_vectors.as_ref(),
+            deleted_points,
+            params,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )?;
+        let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
+
+        let range = score_range(vector, params, vector_query_context, &vector_storage);
+        let search_result = match range {
+            Some(range) => raw_scorer.peek_range_iter(points, range, oversampled_top),
+            None => raw_scorer.peek_top_iter(points, oversampled_top),
+        };
+
+        postprocess_search_result(
+            search_result,
+            vector,
+            params,
+            range,
+            top,
+            &id_tracker,
+            &vector_storage,
+            quantized_vectors.as_ref(),
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )
+    }
+
+    fn search_vectors_plain_unfiltered(
+        &self,
+        vectors: &[&QueryVector],
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let id_tracker = self.id_tracker.borrow();
+        vectors
+            .iter()
+            .map(|&vector| {
+                let mut ids_iterator = id_tracker.iter_internal();
+                self.search_plain_iterator(
+                    vector,
+                    &mut ids_iterator,
+                    top,
+                    params,
+                    vector_query_context,
+                )
+            })
+            .collect()
+    }
+
+    fn search_vectors_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: &Filter,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let payload_index = self.payload_index.borrow();
+        // share filtered points for all query vectors
+        let filtered_points =
+            payload_index.query_points(filter, &vector_query_context.hardware_counter());
+        vectors
+            .iter()
+            .map(|&vector| {
+                self.search_plain_iterator(
+                    vector,
+                    &mut filtered_points.iter().copied(),
+                    top,
+                    params,
+                    vector_query_context,
+                )
+            })
+            .collect()
+    }
+}
+
+impl VectorIndex for IVFIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let exact = params.map(|params| params.exact).unwrap_or(false);
+
+        if exact {
+            let exact_params = params.map(|params| {
+                let mut params = *params;
+                params.quantization = Some(QuantizationSearchParams {
+                    ignore: true,
+                    rescore: Some(false),
+                    oversampling: None,
+                }); // disable quantization for exact search
+                params
+            });
+
+            return match filter {
+                None => {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.exact_unfiltered);
+                    self.search_vectors_plain_unfiltered(
+                        vectors,
+                        top,
+                        exact_params.as_ref(),
+                        query_context,
+                    )
+                }
+                Some(query_filter) => {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.exact_filtered);
+                    self.search_vectors_plain(
+                        vectors,
+                        query_filter,
+                        top,
+                        exact_params.as_ref(),
+                        query_context,
+                    )
+                }
+            };
+        }
+
+        match filter {
+            None => {
+                let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_ivf);
+                self.search_vectors_with_clusters(vectors, None, top, params, query_context)
+            }
+            Some(query_filter) => {
+                let payload_index = self.payload_index.borrow();
+                let vector_storage = self.vector_storage.borrow();
+                let id_tracker = self.id_tracker.borrow();
+                let query_point_cardinality = payload_index.estimate_cardinality(query_filter);
+                let query_cardinality = adjust_to_available_vectors(
+                    query_point_cardinality,
+                    vector_storage.available_vector_count(),
+                    id_tracker.available_point_count(),
+                );
+
+                // Expected number of points in the probed clusters
+                let probe_size = self
+                    .clusters
+                    .points_count()
+                    .saturating_mul(self.nprobe(params))
+                    .checked_div(self.clusters.nlist())
+                    .unwrap_or(0);
+
+                if query_cardinality.max < probe_size {
+                    // if cardinality is small - it is cheaper to score all the filtered points
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
+                    self.search_vectors_plain(vectors, query_filter, top, params, query_context)
+                } else {
+                    let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_ivf);
+                    self.search_vectors_with_clusters(vectors, filter, top, params, query_context)
+                }
+            }
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: Default::default(),
+            filtered_plain: Default::default(),
+            unfiltered_hnsw: Default::default(),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: Default::default(),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+            unfiltered_ivf: tm.unfiltered_ivf.lock().get_statistics(detail),
+            filtered_ivf: tm.filtered_ivf.lock().get_statistics(detail),
+            unfiltered_vamana: Default::default(),
+            filtered_vamana: Default::default(),
+            dead_links_ratio: None,
+            pruned_postings_ratio: None,
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        vec![IvfClusters::get_path(&self.path)]
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.clusters.points_count()
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update IVF index"))
+    }
+}
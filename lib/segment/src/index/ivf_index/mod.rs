@@ -0,0 +1,2 @@
+pub mod clusters;
+pub mod ivf;
@@ -1,5 +1,6 @@
 pub mod field_index;
 pub mod hnsw_index;
+pub mod ivf_index;
 mod key_encoding;
 mod payload_config;
 mod payload_index_base;
@@ -11,7 +12,9 @@ mod sample_estimation;
 pub mod sparse_index;
 mod struct_filter_context;
 pub mod struct_payload_index;
+pub mod vamana_index;
 pub mod vector_index_base;
+mod vector_index_search_common;
 mod visited_pool;
 
 pub use payload_index_base::*;
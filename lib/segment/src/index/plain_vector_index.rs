@@ -14,6 +14,7 @@ use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::id_tracker::IdTrackerSS;
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vector_index_search_common::score_range;
 use crate::index::{PayloadIndex, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::{Filter, SearchParams};
@@ -114,9 +115,13 @@ impl VectorIndex for PlainVectorIndex {
                             query_context.hardware_counter(),
                         )
                         .map(|scorer| {
-                            let res =
-                                scorer.peek_top_iter(&mut filtered_ids_vec.iter().copied(), top);
-                            res
+                            let mut filtered_ids = filtered_ids_vec.iter().copied();
+                            match score_range(vector, params, query_context, &vector_storage) {
+                                Some(range) => {
+                                    scorer.peek_range_iter(&mut filtered_ids, range, top)
+                                }
+                                None => scorer.peek_top_iter(&mut filtered_ids, top),
+                            }
                         })
                     })
                     .collect()
@@ -138,7 +143,12 @@ impl VectorIndex for PlainVectorIndex {
                             &is_stopped,
                             query_context.hardware_counter(),
                         )
-                        .map(|scorer| scorer.peek_top_all(top))
+                        .map(|scorer| {
+                            match score_range(vector, params, query_context, &vector_storage) {
+                                Some(range) => scorer.peek_range_all(range, top),
+                                None => scorer.peek_top_all(top),
+                            }
+                        })
                     })
                     .collect()
             }
@@ -163,6 +173,12 @@ impl VectorIndex for PlainVectorIndex {
             filtered_sparse: Default::default(),
             unfiltered_exact: OperationDurationStatistics::default(),
             unfiltered_sparse: OperationDurationStatistics::default(),
+            unfiltered_ivf: OperationDurationStatistics::default(),
+            filtered_ivf: OperationDurationStatistics::default(),
+            unfiltered_vamana: OperationDurationStatistics::default(),
+            filtered_vamana: OperationDurationStatistics::default(),
+            dead_links_ratio: None,
+            pruned_postings_ratio: None,
         }
     }
 
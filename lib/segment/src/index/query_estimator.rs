@@ -8,6 +8,7 @@ use std::cmp::{max, min};
 use itertools::Itertools;
 
 use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
+use crate::index::hnsw_index::graph_layers::SearchAlgorithm;
 use crate::types::{Condition, Filter, MinShould};
 
 /// Re-estimate cardinality based on number of available vectors
@@ -64,6 +65,35 @@ pub fn adjust_to_available_vectors(
     }
 }
 
+/// Fraction of vectors passing the filter, below which the graph search also expands
+/// neighbours of filtered-out points
+const TWO_HOP_SEARCH_MAX_SELECTIVITY: f64 = 0.1;
+
+/// Choose graph traversal for a filtered search, based on the expected selectivity of the filter
+///
+/// With a restrictive filter most of the links lead to filtered-out points, and the regular
+/// traversal gets stuck in a small part of the graph.
+///
+/// # Arguments
+///
+/// * `estimation` - cardinality estimation of the filter, adjusted to available vectors
+/// * `available_vectors` - number of available vectors for the named vector storage
+pub fn filtered_search_algorithm(
+    estimation: &CardinalityEstimation,
+    available_vectors: usize,
+) -> SearchAlgorithm {
+    if available_vectors == 0 {
+        return SearchAlgorithm::Hnsw;
+    }
+
+    let selectivity = estimation.exp as f64 / available_vectors as f64;
+    if selectivity < TWO_HOP_SEARCH_MAX_SELECTIVITY {
+        SearchAlgorithm::TwoHop
+    } else {
+        SearchAlgorithm::Hnsw
+    }
+}
+
 pub fn combine_should_estimations(
     estimations: &[CardinalityEstimation],
     total: usize,
@@ -574,4 +604,31 @@ mod tests {
         assert_eq!(new_estimation.exp, 16);
         assert_eq!(new_estimation.max, 50);
     }
+
+    #[test]
+    fn test_filtered_search_algorithm() {
+        let estimation = |exp| CardinalityEstimation {
+            primary_clauses: vec![],
+            min: 0,
+            exp,
+            max: TOTAL,
+        };
+
+        assert_eq!(
+            filtered_search_algorithm(&estimation(500), TOTAL),
+            SearchAlgorithm::Hnsw,
+        );
+        assert_eq!(
+            filtered_search_algorithm(&estimation(100), TOTAL),
+            SearchAlgorithm::Hnsw,
+        );
+        assert_eq!(
+            filtered_search_algorithm(&estimation(20), TOTAL),
+            SearchAlgorithm::TwoHop,
+        );
+        assert_eq!(
+            filtered_search_algorithm(&estimation(0), 0),
+            SearchAlgorithm::Hnsw,
+        );
+    }
 }
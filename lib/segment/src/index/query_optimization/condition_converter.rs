@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
@@ -279,6 +280,7 @@ pub fn get_range_checkers(index: &FieldIndex, range: RangeInterface) -> Option<C
     match range {
         RangeInterface::Float(range) => get_float_range_checkers(index, range),
         RangeInterface::DateTime(range) => get_datetime_range_checkers(index, range),
+        RangeInterface::Keyword(range) => get_keyword_range_checkers(index, range),
     }
 }
 
@@ -318,13 +320,40 @@ pub fn get_datetime_range_checkers(
                 num_index.check_values_any(point_id, |value| range.check_range(*value))
             }))
         }
+        // Same as for non-indexed payload: keywords, which are not valid datetimes, never match
+        FieldIndex::KeywordIndex(index) => Some(Box::new(move |point_id: PointOffsetType| {
+            index.check_values_any(point_id, |keyword| {
+                DateTimePayloadType::from_str(keyword).is_ok_and(|dt| range.check_range(dt))
+            })
+        })),
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+pub fn get_keyword_range_checkers(
+    index: &FieldIndex,
+    range: Range<String>,
+) -> Option<ConditionCheckerFn> {
+    match index {
+        FieldIndex::KeywordIndex(index) => Some(Box::new(move |point_id: PointOffsetType| {
+            index.check_values_any(point_id, |keyword| range.check_keyword(keyword))
+        })),
+        FieldIndex::DatetimeIndex(_) => {
+            get_datetime_range_checkers(index, range.as_datetime_range()?)
+        }
         FieldIndex::BoolIndex(_)
         | FieldIndex::FloatIndex(_)
         | FieldIndex::FullTextIndex(_)
         | FieldIndex::GeoIndex(_)
         | FieldIndex::IntIndex(_)
         | FieldIndex::IntMapIndex(_)
-        | FieldIndex::KeywordIndex(_)
         | FieldIndex::UuidIndex(_)
         | FieldIndex::UuidMapIndex(_) => None,
     }
@@ -2,17 +2,27 @@ use common::types::PointOffsetType;
 use indexmap::IndexSet;
 use uuid::Uuid;
 
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
+use crate::index::field_index::map_index::keyword_pattern::KeywordPattern;
 use crate::index::field_index::FieldIndex;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::types::{
-    AnyVariants, Match, MatchAny, MatchExcept, MatchText, MatchValue, ValueVariants,
+    AnyVariants, Match, MatchAny, MatchExcept, MatchFuzzy, MatchPhrase, MatchText, MatchValue,
+    ValueVariants,
 };
 
 pub fn get_match_checkers(index: &FieldIndex, cond_match: Match) -> Option<ConditionCheckerFn> {
     match cond_match {
         Match::Value(MatchValue { value }) => get_match_value_checker(value, index),
         Match::Text(MatchText { text }) => get_match_text_checker(text, index),
+        Match::Phrase(MatchPhrase { phrase, slop }) => {
+            get_match_phrase_checker(phrase, slop.unwrap_or(0), index)
+        }
+        Match::Fuzzy(fuzzy) => get_match_fuzzy_checker(fuzzy, index),
+        Match::Prefix(_) | Match::Substring(_) | Match::Regex(_) => {
+            get_match_pattern_checker(&cond_match, index)
+        }
         Match::Any(MatchAny { any }) => get_match_any_checker(any, index),
         Match::Except(MatchExcept { except }) => get_match_except_checker(except, index),
     }
@@ -228,3 +238,70 @@ fn get_match_text_checker(text: String, index: &FieldIndex) -> Option<ConditionC
         | FieldIndex::UuidMapIndex(_) => None,
     }
 }
+
+fn get_match_phrase_checker(
+    phrase: String,
+    slop: u32,
+    index: &FieldIndex,
+) -> Option<ConditionCheckerFn> {
+    match index {
+        FieldIndex::FullTextIndex(full_text_index) => {
+            let parsed_phrase = full_text_index.parse_phrase(&phrase, slop);
+            Some(Box::new(move |point_id: PointOffsetType| {
+                full_text_index.check_phrase(&parsed_phrase, point_id)
+            }))
+        }
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::KeywordIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+fn get_match_fuzzy_checker(fuzzy: MatchFuzzy, index: &FieldIndex) -> Option<ConditionCheckerFn> {
+    match index {
+        FieldIndex::FullTextIndex(full_text_index) => {
+            let parsed_query = full_text_index.parse_fuzzy_query(&fuzzy);
+            Some(Box::new(move |point_id: PointOffsetType| {
+                full_text_index.check_fuzzy(&parsed_query, point_id)
+            }))
+        }
+        FieldIndex::KeywordIndex(index) => {
+            let automaton = LevenshteinAutomaton::new(&fuzzy.fuzzy, fuzzy.max_edits(&fuzzy.fuzzy));
+            Some(Box::new(move |point_id: PointOffsetType| {
+                index.check_values_any(point_id, |keyword| automaton.is_match(keyword))
+            }))
+        }
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
+
+fn get_match_pattern_checker(cond_match: &Match, index: &FieldIndex) -> Option<ConditionCheckerFn> {
+    let pattern = KeywordPattern::from_match(cond_match)?;
+    match index {
+        FieldIndex::KeywordIndex(index) => Some(Box::new(move |point_id: PointOffsetType| {
+            index.check_values_any(point_id, |keyword| pattern.is_match(keyword))
+        })),
+        FieldIndex::BoolIndex(_)
+        | FieldIndex::DatetimeIndex(_)
+        | FieldIndex::FloatIndex(_)
+        | FieldIndex::FullTextIndex(_)
+        | FieldIndex::GeoIndex(_)
+        | FieldIndex::IntIndex(_)
+        | FieldIndex::IntMapIndex(_)
+        | FieldIndex::UuidIndex(_)
+        | FieldIndex::UuidMapIndex(_) => None,
+    }
+}
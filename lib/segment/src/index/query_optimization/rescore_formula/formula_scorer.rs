@@ -1,16 +1,22 @@
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoreType};
+use geo::{Distance, Haversine, Point};
 use serde_json::Value;
 
-use super::parsed_formula::{Expression, ParsedFormula, VariableId};
+use super::parsed_formula::{
+    DatetimeExpression, DecayKind, DecayParams, Expression, Operation, ParsedFormula, PreciseScore,
+    VariableId,
+};
 use super::value_retriever::VariableRetrieverFn;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::index::query_optimization::optimized_filter::{check_condition, OptimizedCondition};
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::json_path::JsonPath;
+use crate::types::{DateTimePayloadType, DateTimeWrapper, GeoPoint};
 
 const DEFAULT_SCORE: ScoreType = 0.0;
 
@@ -26,6 +32,8 @@ pub struct FormulaScorer<'a> {
     condition_checkers: Vec<OptimizedCondition<'a>>,
     /// Default values for all variables
     defaults: HashMap<VariableId, Value>,
+    /// Value of the `now` variable, fixed for all points
+    now: DateTimePayloadType,
 }
 
 /// Ephemeral type to hold all formula variables for a single point
@@ -38,6 +46,8 @@ struct PointVariables<'a> {
     conditions: Vec<bool>,
     /// The default values for all variables
     defaults: &'a HashMap<VariableId, Value>,
+    /// Value of the `now` variable
+    now: DateTimePayloadType,
 }
 
 impl StructPayloadIndex {
@@ -73,6 +83,7 @@ impl StructPayloadIndex {
             payload_retrievers,
             condition_checkers,
             defaults: defaults.clone(),
+            now: DateTimeWrapper(chrono::Utc::now()),
         }
     }
 }
@@ -102,13 +113,336 @@ impl FormulaScorer<'_> {
             prefetch_scores.push(score);
         }
 
-        let _vars = PointVariables {
+        let vars = PointVariables {
             prefetch_scores,
             payload_values,
             conditions,
             defaults: &self.defaults,
+            now: self.now,
         };
 
-        todo!() // self.formula.evaluate(&vars)
+        self.formula.evaluate(&vars).map(|score| score as ScoreType)
+    }
+}
+
+impl Expression {
+    fn evaluate(&self, vars: &PointVariables) -> OperationResult<PreciseScore> {
+        match self {
+            Expression::Constant(value) => Ok(*value),
+            Expression::Variable(variable) => vars.number(variable),
+            Expression::Operation(operation) => operation.evaluate(vars),
+            Expression::Datetime(datetime) => vars.datetime(datetime).map(datetime_to_seconds),
+        }
+    }
+}
+
+impl Operation {
+    fn evaluate(&self, vars: &PointVariables) -> OperationResult<PreciseScore> {
+        let value = match self {
+            Operation::Mult(expressions) => expressions
+                .iter()
+                .try_fold(1.0, |acc, expression| -> OperationResult<_> {
+                    Ok(acc * expression.evaluate(vars)?)
+                })?,
+            Operation::Sum(expressions) => expressions
+                .iter()
+                .try_fold(0.0, |acc, expression| -> OperationResult<_> {
+                    Ok(acc + expression.evaluate(vars)?)
+                })?,
+            Operation::Div {
+                left,
+                right,
+                by_zero_default,
+            } => {
+                let right = right.evaluate(vars)?;
+                if right == 0.0 {
+                    *by_zero_default
+                } else {
+                    left.evaluate(vars)? / right
+                }
+            }
+            Operation::Neg(expression) => -expression.evaluate(vars)?,
+            Operation::Sqrt(expression) => expression.evaluate(vars)?.sqrt(),
+            Operation::Pow { base, exponent } => {
+                base.evaluate(vars)?.powf(exponent.evaluate(vars)?)
+            }
+            Operation::Exp(expression) => expression.evaluate(vars)?.exp(),
+            Operation::Ln(expression) => expression.evaluate(vars)?.ln(),
+            Operation::Abs(expression) => expression.evaluate(vars)?.abs(),
+            // Empty min and max evaluate to infinity, and are reported as non-finite below
+            Operation::Min(expressions) => expressions.iter().try_fold(
+                PreciseScore::INFINITY,
+                |acc, expression| -> OperationResult<_> { Ok(acc.min(expression.evaluate(vars)?)) },
+            )?,
+            Operation::Max(expressions) => expressions.iter().try_fold(
+                PreciseScore::NEG_INFINITY,
+                |acc, expression| -> OperationResult<_> { Ok(acc.max(expression.evaluate(vars)?)) },
+            )?,
+            Operation::GeoDistance { origin, key } => {
+                let point = vars.geo_point(key)?;
+                Haversine::distance(
+                    Point::new(origin.lon, origin.lat),
+                    Point::new(point.lon, point.lat),
+                )
+            }
+            Operation::Decay {
+                kind,
+                x,
+                target,
+                params,
+            } => {
+                let x = x.evaluate(vars)?;
+                let target = match target {
+                    Some(target) => target.evaluate(vars)?,
+                    None => 0.0,
+                };
+                decay(*kind, params, x, target)
+            }
+        };
+
+        if !value.is_finite() {
+            return Err(OperationError::NonFiniteNumber {
+                operation: self.name().to_string(),
+            });
+        }
+
+        Ok(value)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Operation::Mult(_) => "mult",
+            Operation::Sum(_) => "sum",
+            Operation::Div { .. } => "div",
+            Operation::Neg(_) => "neg",
+            Operation::Sqrt(_) => "sqrt",
+            Operation::Pow { .. } => "pow",
+            Operation::Exp(_) => "exp",
+            Operation::Ln(_) => "ln",
+            Operation::Abs(_) => "abs",
+            Operation::Min(_) => "min",
+            Operation::Max(_) => "max",
+            Operation::GeoDistance { .. } => "geo_distance",
+            Operation::Decay { kind, .. } => match kind {
+                DecayKind::Lin => "lin_decay",
+                DecayKind::Exp => "exp_decay",
+                DecayKind::Gauss => "gauss_decay",
+            },
+        }
+    }
+}
+
+/// Elasticsearch-style decay of the distance between `x` and `target`
+fn decay(
+    kind: DecayKind,
+    params: &DecayParams,
+    x: PreciseScore,
+    target: PreciseScore,
+) -> PreciseScore {
+    let DecayParams {
+        scale,
+        offset,
+        decay,
+    } = *params;
+
+    let distance = ((x - target).abs() - offset).max(0.0);
+
+    match kind {
+        DecayKind::Lin => {
+            // Reaches zero at this distance
+            let zero_at = scale / (1.0 - decay);
+            ((zero_at - distance) / zero_at).max(0.0)
+        }
+        DecayKind::Exp => (decay.ln() * distance / scale).exp(),
+        DecayKind::Gauss => (decay.ln() * (distance / scale).powi(2)).exp(),
+    }
+}
+
+fn datetime_to_seconds(datetime: DateTimePayloadType) -> PreciseScore {
+    // Timestamp is in microseconds
+    datetime.timestamp() as PreciseScore / 1_000_000.0
+}
+
+impl PointVariables<'_> {
+    /// Value of the payload variable, or its default
+    fn payload_value(&self, key: &JsonPath) -> Option<&Value> {
+        self.payload_values
+            .get(key)
+            .or_else(|| self.defaults.get(&VariableId::Payload(key.clone())))
+    }
+
+    fn number(&self, variable: &VariableId) -> OperationResult<PreciseScore> {
+        match variable {
+            VariableId::Score(index) => Ok(self
+                .prefetch_scores
+                .get(*index)
+                .copied()
+                .flatten()
+                .map(PreciseScore::from)
+                .or_else(|| self.defaults.get(variable).and_then(Value::as_f64))
+                .unwrap_or(PreciseScore::from(DEFAULT_SCORE))),
+            VariableId::Payload(key) => match self.payload_value(key) {
+                Some(value) => value
+                    .as_f64()
+                    .ok_or_else(|| OperationError::FormulaVariableError {
+                        field_name: key.clone(),
+                        description: format!("expected a number, got {value}"),
+                    }),
+                None => Ok(PreciseScore::from(DEFAULT_SCORE)),
+            },
+            VariableId::Condition(id) => Ok(if self.conditions[*id] { 1.0 } else { 0.0 }),
+        }
+    }
+
+    fn geo_point(&self, key: &JsonPath) -> OperationResult<GeoPoint> {
+        let value =
+            self.payload_value(key)
+                .ok_or_else(|| OperationError::FormulaVariableError {
+                    field_name: key.clone(),
+                    description: "no geo point in the payload nor in the defaults".to_string(),
+                })?;
+
+        serde_json::from_value(value.clone()).map_err(|err| OperationError::FormulaVariableError {
+            field_name: key.clone(),
+            description: format!("expected a geo point, got {value}: {err}"),
+        })
+    }
+
+    fn datetime(&self, datetime: &DatetimeExpression) -> OperationResult<DateTimePayloadType> {
+        match datetime {
+            DatetimeExpression::Constant(datetime) => Ok(*datetime),
+            DatetimeExpression::Now => Ok(self.now),
+            DatetimeExpression::PayloadVariable(key) => {
+                let value = self.payload_value(key).ok_or_else(|| {
+                    OperationError::FormulaVariableError {
+                        field_name: key.clone(),
+                        description: "no datetime in the payload nor in the defaults".to_string(),
+                    }
+                })?;
+
+                value
+                    .as_str()
+                    .and_then(|value| DateTimePayloadType::from_str(value).ok())
+                    .ok_or_else(|| OperationError::FormulaVariableError {
+                        field_name: key.clone(),
+                        description: format!("expected a datetime string, got {value}"),
+                    })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn evaluate(expression: &Expression, payload: Value) -> OperationResult<PreciseScore> {
+        let payload_values = payload
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(key, value)| (JsonPath::new(key), value.clone()))
+            .collect();
+        let defaults = HashMap::new();
+        let vars = PointVariables {
+            prefetch_scores: vec![Some(2.0)],
+            payload_values,
+            conditions: vec![true],
+            defaults: &defaults,
+            now: DateTimePayloadType::from_str("2025-01-02T00:00:00Z").unwrap(),
+        };
+        expression.evaluate(&vars)
+    }
+
+    fn var(key: &str) -> Expression {
+        Expression::Variable(VariableId::Payload(JsonPath::new(key)))
+    }
+
+    #[test]
+    fn test_math_functions() {
+        let payload = json!({ "a": 4.0, "b": -2.5, "s": "text" });
+        let check = |expression: Expression, expected: PreciseScore| {
+            let value = evaluate(&expression, payload.clone()).unwrap();
+            assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+        };
+
+        check(Expression::new_sqrt(var("a")), 2.0);
+        check(
+            Expression::new_pow(var("a"), Expression::Constant(1.5)),
+            8.0,
+        );
+        check(Expression::new_abs(var("b")), 2.5);
+        check(Expression::new_ln(Expression::new_exp(var("b"))), -2.5);
+        check(Expression::new_min(vec![var("a"), var("b")]), -2.5);
+        check(
+            Expression::new_max(vec![var("a"), Expression::new_score_id(0)]),
+            4.0,
+        );
+        check(
+            Expression::new_sum(vec![var("missing"), Expression::new_condition_id(0)]),
+            1.0,
+        );
+
+        assert!(matches!(
+            evaluate(&Expression::new_ln(var("b")), payload.clone()),
+            Err(OperationError::NonFiniteNumber { .. }),
+        ));
+        assert!(matches!(
+            evaluate(&Expression::new_sqrt(var("s")), payload),
+            Err(OperationError::FormulaVariableError { .. }),
+        ));
+    }
+
+    #[test]
+    fn test_decay_functions() {
+        let params = DecayParams::new(10.0, Some(2.0), Some(0.25)).unwrap();
+        for kind in [DecayKind::Lin, DecayKind::Exp, DecayKind::Gauss] {
+            // Within the offset
+            assert_eq!(decay(kind, &params, 6.0, 5.0), 1.0);
+            assert_eq!(decay(kind, &params, 3.0, 5.0), 1.0);
+            // At the scale from the offset, the decay is reached
+            assert!((decay(kind, &params, 17.0, 5.0) - 0.25).abs() < 1e-9);
+            assert!((decay(kind, &params, -7.0, 5.0) - 0.25).abs() < 1e-9);
+            // Decreases with distance
+            assert!(decay(kind, &params, 20.0, 5.0) < decay(kind, &params, 10.0, 5.0));
+        }
+        // Linear decay stops at zero
+        assert_eq!(decay(DecayKind::Lin, &params, 100.0, 5.0), 0.0);
+
+        assert!(DecayParams::new(0.0, None, None).is_err());
+        assert!(DecayParams::new(1.0, Some(-1.0), None).is_err());
+        assert!(DecayParams::new(1.0, None, Some(1.0)).is_err());
+    }
+
+    #[test]
+    fn test_datetime_decay() {
+        let day = 24.0 * 60.0 * 60.0;
+        let freshness = Expression::new_decay(
+            DecayKind::Exp,
+            Expression::new_datetime_key(JsonPath::new("published")),
+            Some(Expression::new_now()),
+            DecayParams::new(day, None, Some(0.5)).unwrap(),
+        );
+
+        let value = evaluate(&freshness, json!({ "published": "2025-01-01T00:00:00Z" })).unwrap();
+        assert!((value - 0.5).abs() < 1e-9);
+
+        let value = evaluate(&freshness, json!({ "published": "2025-01-02T00:00:00Z" })).unwrap();
+        assert_eq!(value, 1.0);
+
+        let elapsed = Expression::new_sum(vec![
+            Expression::new_now(),
+            Expression::new_neg(Expression::new_datetime(
+                DateTimePayloadType::from_str("2025-01-01T12:00:00Z").unwrap(),
+            )),
+        ]);
+        assert_eq!(evaluate(&elapsed, json!({})).unwrap(), day / 2.0);
+
+        assert!(matches!(
+            evaluate(&freshness, json!({ "published": 42 })),
+            Err(OperationError::FormulaVariableError { .. }),
+        ));
     }
 }
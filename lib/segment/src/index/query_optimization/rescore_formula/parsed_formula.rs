@@ -1,13 +1,19 @@
 use std::collections::{HashMap, HashSet};
 
-use common::types::ScoreType;
 use serde_json::Value;
 
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::json_path::JsonPath;
-use crate::types::{Condition, GeoPoint};
+use crate::types::{Condition, DateTimePayloadType, GeoPoint};
 
 pub type ConditionId = usize;
 
+/// Formulas are evaluated in double precision, so that datetimes can be operated on
+pub type PreciseScore = f64;
+
+/// Default `decay` parameter of decay functions
+pub const DEFAULT_DECAY: PreciseScore = 0.5;
+
 pub struct ParsedFormula {
     /// Variables used in the formula
     pub(super) payload_vars: HashSet<JsonPath>,
@@ -24,9 +30,20 @@ pub struct ParsedFormula {
 
 #[derive(Clone)]
 pub enum Expression {
-    Constant(ScoreType),
+    Constant(PreciseScore),
     Variable(VariableId),
     Operation(Operation),
+    Datetime(DatetimeExpression),
+}
+
+/// Datetime, which evaluates to seconds since the Unix epoch
+#[derive(Clone)]
+pub enum DatetimeExpression {
+    Constant(DateTimePayloadType),
+    /// Time of creation of the scorer, same for all points
+    Now,
+    /// Payload field with a datetime string
+    PayloadVariable(JsonPath),
 }
 
 #[derive(Clone, Hash, Eq, PartialEq)]
@@ -46,13 +63,85 @@ pub enum Operation {
     Div {
         left: Box<Expression>,
         right: Box<Expression>,
-        by_zero_default: ScoreType,
+        by_zero_default: PreciseScore,
     },
     Neg(Box<Expression>),
+    Sqrt(Box<Expression>),
+    Pow {
+        base: Box<Expression>,
+        exponent: Box<Expression>,
+    },
+    Exp(Box<Expression>),
+    Ln(Box<Expression>),
+    Abs(Box<Expression>),
+    Min(Vec<Expression>),
+    Max(Vec<Expression>),
     GeoDistance {
         origin: GeoPoint,
         key: JsonPath,
     },
+    Decay {
+        kind: DecayKind,
+        /// Value to compute the decay for
+        x: Box<Expression>,
+        /// Value at which the decay is the highest. Zero, if not set
+        target: Option<Box<Expression>>,
+        params: DecayParams,
+    },
+}
+
+/// Shape of the decay curve, same as in Elasticsearch function score
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecayKind {
+    /// Linear decay down to zero
+    Lin,
+    /// Exponential decay
+    Exp,
+    /// Gaussian decay
+    Gauss,
+}
+
+/// Parameters of a decay function.
+///
+/// Within `offset` from the target the decay is `1.0`, and at `offset + scale` it is `decay`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecayParams {
+    pub(super) scale: PreciseScore,
+    pub(super) offset: PreciseScore,
+    pub(super) decay: PreciseScore,
+}
+
+impl DecayParams {
+    pub fn new(
+        scale: PreciseScore,
+        offset: Option<PreciseScore>,
+        decay: Option<PreciseScore>,
+    ) -> OperationResult<Self> {
+        let offset = offset.unwrap_or(0.0);
+        let decay = decay.unwrap_or(DEFAULT_DECAY);
+
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(OperationError::ValidationError {
+                description: format!("Decay scale must be positive, got {scale}"),
+            });
+        }
+        if !(offset.is_finite() && offset >= 0.0) {
+            return Err(OperationError::ValidationError {
+                description: format!("Decay offset must be non-negative, got {offset}"),
+            });
+        }
+        if !(decay > 0.0 && decay < 1.0) {
+            return Err(OperationError::ValidationError {
+                description: format!("Decay must be between 0 and 1 exclusive, got {decay}"),
+            });
+        }
+
+        Ok(Self {
+            scale,
+            offset,
+            decay,
+        })
+    }
 }
 
 impl Expression {
@@ -64,7 +153,7 @@ impl Expression {
         Expression::Operation(Operation::Mult(expressions))
     }
 
-    pub fn new_div(left: Expression, right: Expression, by_zero_default: ScoreType) -> Self {
+    pub fn new_div(left: Expression, right: Expression, by_zero_default: PreciseScore) -> Self {
         Expression::Operation(Operation::Div {
             left: Box::new(left),
             right: Box::new(right),
@@ -76,10 +165,67 @@ impl Expression {
         Expression::Operation(Operation::Neg(Box::new(expression)))
     }
 
+    pub fn new_sqrt(expression: Expression) -> Self {
+        Expression::Operation(Operation::Sqrt(Box::new(expression)))
+    }
+
+    pub fn new_pow(base: Expression, exponent: Expression) -> Self {
+        Expression::Operation(Operation::Pow {
+            base: Box::new(base),
+            exponent: Box::new(exponent),
+        })
+    }
+
+    pub fn new_exp(expression: Expression) -> Self {
+        Expression::Operation(Operation::Exp(Box::new(expression)))
+    }
+
+    pub fn new_ln(expression: Expression) -> Self {
+        Expression::Operation(Operation::Ln(Box::new(expression)))
+    }
+
+    pub fn new_abs(expression: Expression) -> Self {
+        Expression::Operation(Operation::Abs(Box::new(expression)))
+    }
+
+    pub fn new_min(expressions: Vec<Expression>) -> Self {
+        Expression::Operation(Operation::Min(expressions))
+    }
+
+    pub fn new_max(expressions: Vec<Expression>) -> Self {
+        Expression::Operation(Operation::Max(expressions))
+    }
+
     pub fn new_geo_distance(origin: GeoPoint, key: JsonPath) -> Self {
         Expression::Operation(Operation::GeoDistance { origin, key })
     }
 
+    pub fn new_decay(
+        kind: DecayKind,
+        x: Expression,
+        target: Option<Expression>,
+        params: DecayParams,
+    ) -> Self {
+        Expression::Operation(Operation::Decay {
+            kind,
+            x: Box::new(x),
+            target: target.map(Box::new),
+            params,
+        })
+    }
+
+    pub fn new_datetime(datetime: DateTimePayloadType) -> Self {
+        Expression::Datetime(DatetimeExpression::Constant(datetime))
+    }
+
+    pub fn new_now() -> Self {
+        Expression::Datetime(DatetimeExpression::Now)
+    }
+
+    pub fn new_datetime_key(key: JsonPath) -> Self {
+        Expression::Datetime(DatetimeExpression::PayloadVariable(key))
+    }
+
     #[cfg(feature = "testing")]
     pub fn new_payload_id(path: &str) -> Self {
         Expression::Variable(VariableId::Payload(JsonPath::new(path)))
@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+use io::file_operations::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+
+const PRUNING_STATS_FILE_NAME: &str = "pruning_stats.json";
+
+/// Number of postings dropped by static pruning, when the index was built
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PruningStats {
+    /// Number of postings before pruning
+    pub total_postings: usize,
+    /// Number of dropped postings
+    pub pruned_postings: usize,
+}
+
+impl PruningStats {
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let path = Self::file_path(path);
+        Ok(read_json(&path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        let path = Self::file_path(path);
+        Ok(atomic_save_json(&path, self)?)
+    }
+
+    pub fn file_path(path: &Path) -> PathBuf {
+        path.join(PRUNING_STATS_FILE_NAME)
+    }
+
+    /// Fraction of the postings, which were dropped
+    pub fn pruned_ratio(&self) -> f64 {
+        if self.total_postings == 0 {
+            return 0.0;
+        }
+        self.pruned_postings as f64 / self.total_postings as f64
+    }
+}
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use io::file_operations::{atomic_save_json, read_json};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_error::OperationResult;
@@ -40,6 +41,32 @@ impl SparseIndexType {
     }
 }
 
+/// Static pruning of the posting lists, applied when building immutable sparse indexes.
+///
+/// Drops the postings with the smallest absolute weights of each dimension. Pruned postings are
+/// not found by the index search, and are not counted in the statistics of the `idf` modifier.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Copy, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct SparsePruningConfig {
+    /// Keep at most this number of postings with the largest weights in each dimension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub max_postings: Option<usize>,
+    /// Drop postings with weight below this fraction of the largest weight in the dimension.
+    /// Expected value range in [0.0, 1.0]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub min_weight_ratio: Option<f32>,
+}
+
+impl std::hash::Hash for SparsePruningConfig {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.max_postings.hash(state);
+    }
+}
+
+impl Eq for SparsePruningConfig {}
+
 /// Configuration for sparse inverted index.
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
@@ -54,6 +81,10 @@ pub struct SparseIndexConfig {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Static pruning of the posting lists, applied to immutable indexes only.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pruning: Option<SparsePruningConfig>,
 }
 
 impl Anonymize for SparseIndexConfig {
@@ -62,6 +93,7 @@ impl Anonymize for SparseIndexConfig {
             full_scan_threshold: self.full_scan_threshold,
             index_type: self.index_type,
             datatype: self.datatype,
+            pruning: self.pruning,
         }
     }
 }
@@ -76,6 +108,7 @@ impl SparseIndexConfig {
             full_scan_threshold,
             index_type,
             datatype,
+            pruning: None,
         }
     }
 
@@ -37,6 +37,12 @@ impl SparseSearchesTelemetry {
             filtered_exact: Default::default(),
             filtered_sparse: self.filtered_sparse.lock().get_statistics(detail),
             unfiltered_sparse: self.unfiltered_sparse.lock().get_statistics(detail),
+            unfiltered_ivf: Default::default(),
+            filtered_ivf: Default::default(),
+            unfiltered_vamana: Default::default(),
+            filtered_vamana: Default::default(),
+            dead_links_ratio: None,
+            pruned_postings_ratio: None,
             unfiltered_exact: Default::default(),
         }
     }
@@ -15,12 +15,15 @@ use sparse::common::sparse_vector::SparseVector;
 use sparse::common::types::DimId;
 use sparse::index::inverted_index::inverted_index_ram_builder::InvertedIndexBuilder;
 use sparse::index::inverted_index::{InvertedIndex, INDEX_FILE_NAME, OLD_INDEX_FILE_NAME};
+use sparse::index::posting_list_common::PostingListIter as _;
 use sparse::index::search_context::SearchContext;
 
 use super::indices_tracker::IndicesTracker;
-use super::sparse_index_config::SparseIndexType;
+use super::pruning_stats::PruningStats;
+use super::sparse_index_config::{SparseIndexType, SparsePruningConfig};
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
+use crate::common::score_range::{select_page, ScoreRange};
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
@@ -32,7 +35,7 @@ use crate::index::sparse_index::sparse_search_telemetry::SparseSearchesTelemetry
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::{PayloadIndex, VectorIndex};
 use crate::telemetry::VectorIndexSearchesTelemetry;
-use crate::types::{Filter, SearchParams, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD};
+use crate::types::{Distance, Filter, SearchParams, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD};
 use crate::vector_storage::query::TransformInto;
 use crate::vector_storage::{
     check_deleted_condition, new_stoppable_raw_scorer, VectorStorage, VectorStorageEnum,
@@ -52,6 +55,8 @@ pub struct SparseVectorIndex<TInvertedIndex: InvertedIndex> {
     searches_telemetry: SparseSearchesTelemetry,
     indices_tracker: IndicesTracker,
     scores_memory_pool: ScoresMemoryPool,
+    /// Postings dropped by static pruning, if it was applied
+    pruning_stats: Option<PruningStats>,
 }
 
 /// Getters for internals, used for testing.
@@ -110,10 +115,11 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         let (config, inverted_index, indices_tracker) = if !config.index_type.is_persisted() {
             // RAM mutable case - build inverted index from scratch and use provided config
             create_dir_all(path)?;
-            let (inverted_index, indices_tracker) = Self::build_inverted_index(
+            let (inverted_index, indices_tracker, _) = Self::build_inverted_index(
                 &id_tracker,
                 &vector_storage,
                 path,
+                None,
                 stopped,
                 tick_progress,
             )?;
@@ -129,10 +135,11 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
 
                 create_dir_all(path)?;
 
-                let (inverted_index, indices_tracker) = Self::build_inverted_index(
+                let (inverted_index, indices_tracker, pruning_stats) = Self::build_inverted_index(
                     &id_tracker,
                     &vector_storage,
                     path,
+                    config.pruning,
                     stopped,
                     tick_progress,
                 )?;
@@ -140,6 +147,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                 config.save(&config_path)?;
                 inverted_index.save(path)?;
                 indices_tracker.save(path)?;
+                if let Some(pruning_stats) = pruning_stats {
+                    pruning_stats.save(path)?;
+                }
 
                 // Save the version as the last step to mark a successful rebuild.
                 // NOTE: index in the original format (Qdrant <=v1.9 / sparse <=v0.1.0) lacks of the
@@ -152,6 +162,12 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             })?
         };
 
+        // Saved only by the build of a pruned immutable index
+        let pruning_stats = PruningStats::file_path(path)
+            .exists()
+            .then(|| PruningStats::open(path))
+            .transpose()?;
+
         let searches_telemetry = SparseSearchesTelemetry::new();
         let path = path.to_path_buf();
         let scores_memory_pool = ScoresMemoryPool::new();
@@ -165,6 +181,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             searches_telemetry,
             indices_tracker,
             scores_memory_pool,
+            pruning_stats,
         })
     }
 
@@ -200,15 +217,18 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         id_tracker: &AtomicRefCell<IdTrackerSS>,
         vector_storage: &AtomicRefCell<VectorStorageEnum>,
         path: &Path,
+        pruning: Option<SparsePruningConfig>,
         stopped: &AtomicBool,
         mut tick_progress: impl FnMut(),
-    ) -> OperationResult<(TInvertedIndex, IndicesTracker)> {
+    ) -> OperationResult<(TInvertedIndex, IndicesTracker, Option<PruningStats>)> {
         let borrowed_vector_storage = vector_storage.borrow();
         let borrowed_id_tracker = id_tracker.borrow();
         let deleted_bitslice = borrowed_vector_storage.deleted_vector_bitslice();
+        let is_cosine = borrowed_vector_storage.distance() == Distance::Cosine;
 
         let mut ram_index_builder = InvertedIndexBuilder::new();
         let mut indices_tracker = IndicesTracker::default();
+        let mut total_postings = 0;
         for id in borrowed_id_tracker.iter_ids_excluding(deleted_bitslice) {
             check_process_stopped(stopped)?;
             // It is possible that the vector is not present in the storage in case of crash.
@@ -229,15 +249,28 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
                         continue;
                     }
                     indices_tracker.register_indices(vector);
-                    let vector = indices_tracker.remap_vector(vector.to_owned());
+                    total_postings += vector.len();
+                    let mut vector = vector.to_owned();
+                    if is_cosine {
+                        vector.normalize_by(vector.norm());
+                    }
+                    let vector = indices_tracker.remap_vector(vector);
                     ram_index_builder.add(id, vector);
                 }
             }
             tick_progress();
         }
+
+        let pruning_stats = pruning.map(|pruning| PruningStats {
+            total_postings,
+            pruned_postings: ram_index_builder
+                .prune(pruning.max_postings, pruning.min_weight_ratio),
+        });
+
         Ok((
             TInvertedIndex::from_ram_index(Cow::Owned(ram_index_builder.build()), path)?,
             indices_tracker,
+            pruning_stats,
         ))
     }
 
@@ -245,8 +278,6 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
     /// Warning: the cost of this function grows with the number of dimensions in the query vector
     #[cfg(feature = "testing")]
     pub fn max_result_count(&self, query_vector: &SparseVector) -> usize {
-        use sparse::index::posting_list_common::PostingListIter as _;
-
         let mut unique_record_ids = std::collections::HashSet::new();
         for dim_id in query_vector.indices.iter() {
             if let Some(dim_id) = self.indices_tracker.remap_index(*dim_id) {
@@ -260,6 +291,22 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         unique_record_ids.len()
     }
 
+    /// With cosine distance the index holds vectors normalized to unit length,
+    /// so the query vectors have to be normalized as well.
+    fn is_cosine(&self) -> bool {
+        self.vector_storage.borrow().distance() == Distance::Cosine
+    }
+
+    fn preprocess_query<'a>(&self, vector: &'a SparseVector) -> Cow<'a, SparseVector> {
+        if self.is_cosine() {
+            let mut vector = vector.clone();
+            vector.normalize_by(vector.norm());
+            Cow::Owned(vector)
+        } else {
+            Cow::Borrowed(vector)
+        }
+    }
+
     fn get_query_cardinality(&self, filter: &Filter) -> CardinalityEstimation {
         let vector_storage = self.vector_storage.borrow();
         let id_tracker = self.id_tracker.borrow();
@@ -425,6 +472,9 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             return Ok(vec![]);
         }
 
+        let vector = self.preprocess_query(vector);
+        let vector = vector.as_ref();
+
         match filter {
             Some(filter) => {
                 // if cardinality is small - use plain search
@@ -493,6 +543,125 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         }
     }
 
+    /// Range search: the page of `top` points within the `range`, best first.
+    ///
+    /// Nearest queries only score the points, which share at least one dimension with the query.
+    /// Other points are never returned, even if their zero score is within the range.
+    pub fn search_range_query(
+        &self,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        range: ScoreRange,
+        top: usize,
+        prefiltered_points: &mut Option<Vec<PointOffsetType>>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        if top == 0 {
+            return Ok(vec![]);
+        }
+
+        let vector: &SparseVector = match query_vector {
+            QueryVector::Nearest(vector) => vector.try_into()?,
+            QueryVector::Recommend(_) | QueryVector::Discovery(_) | QueryVector::Context(_) => {
+                return self.search_range_scored(
+                    query_vector,
+                    filter,
+                    range,
+                    top,
+                    prefiltered_points,
+                    vector_query_context,
+                );
+            }
+        };
+        if vector.is_empty() {
+            return Ok(vec![]);
+        }
+        let vector = self.preprocess_query(vector);
+
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        let deleted_point_bitslice = vector_query_context
+            .deleted_points()
+            .unwrap_or(id_tracker.deleted_point_bitslice());
+        let deleted_vectors = vector_storage.deleted_vector_bitslice();
+
+        let hw_counter = vector_query_context.hardware_counter();
+        let filter_context = filter.map(|filter| payload_index.filter_context(filter, &hw_counter));
+
+        // All points, which have a non-zero score
+        let sparse_vector = self.indices_tracker.remap_vector(vector.into_owned());
+        let candidates = sparse_vector
+            .indices
+            .iter()
+            .filter_map(|dim_id| self.inverted_index.get(dim_id))
+            .flat_map(|posting_list_iter| posting_list_iter.into_std_iter())
+            .map(|element| element.record_id)
+            .filter(|&idx| {
+                check_deleted_condition(idx, deleted_vectors, deleted_point_bitslice)
+                    && filter_context
+                        .as_ref()
+                        .map_or(true, |filter_context| filter_context.check(idx))
+            })
+            .sorted_unstable()
+            .dedup()
+            .collect_vec();
+        if candidates.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let is_stopped = vector_query_context.is_stopped();
+        let memory_handle = self.scores_memory_pool.get();
+        let mut search_context = SearchContext::new(
+            sparse_vector,
+            candidates.len(),
+            &self.inverted_index,
+            memory_handle,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        );
+        let mut in_range = search_context.plain_search(&candidates);
+        in_range.retain(|scored| range.contains(scored.score));
+        Ok(select_page(in_range, top))
+    }
+
+    // Range search using raw scorer
+    fn search_range_scored(
+        &self,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        range: ScoreRange,
+        top: usize,
+        prefiltered_points: &mut Option<Vec<PointOffsetType>>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let deleted_point_bitslice = vector_query_context
+            .deleted_points()
+            .unwrap_or(id_tracker.deleted_point_bitslice());
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        let raw_scorer = new_stoppable_raw_scorer(
+            query_vector.clone(),
+            &vector_storage,
+            deleted_point_bitslice,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )?;
+        let hw_counter = vector_query_context.hardware_counter();
+        match filter {
+            Some(filter) => {
+                let payload_index = self.payload_index.borrow();
+                let filtered_points = prefiltered_points
+                    .get_or_insert_with(|| payload_index.query_points(filter, &hw_counter));
+                Ok(raw_scorer.peek_range_iter(&mut filtered_points.iter().copied(), range, top))
+            }
+            None => Ok(raw_scorer.peek_range_all(range, top)),
+        }
+    }
+
     // Update statistics for idf-dot similarity
     pub fn fill_idf_statistics(&self, idf: &mut HashMap<DimId, usize>) {
         for (dim_id, count) in idf.iter_mut() {
@@ -513,18 +682,47 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
         vectors: &[&QueryVector],
         filter: Option<&Filter>,
         top: usize,
-        _params: Option<&SearchParams>,
+        params: Option<&SearchParams>,
         query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let distance = self.vector_storage.borrow().distance();
         let mut results = Vec::with_capacity(vectors.len());
         let mut prefiltered_points = None;
         for vector in vectors {
             check_process_stopped(&query_context.is_stopped())?;
 
+            let search_query =
+                |vector: &QueryVector, prefiltered_points: &mut Option<Vec<PointOffsetType>>| {
+                    match ScoreRange::from_params(
+                        vector,
+                        params,
+                        query_context.range_score_threshold(),
+                        distance,
+                    ) {
+                        Some(range) => self.search_range_query(
+                            vector,
+                            filter,
+                            range,
+                            top,
+                            prefiltered_points,
+                            query_context,
+                        ),
+                        None => self.search_query(
+                            vector,
+                            filter,
+                            top,
+                            prefiltered_points,
+                            query_context,
+                        ),
+                    }
+                };
+
             let search_results = if query_context.is_require_idf() {
                 let vector = (*vector).clone().transform(|mut vector| {
                     match &mut vector {
-                        VectorInternal::Dense(_) | VectorInternal::MultiDense(_) => {
+                        VectorInternal::Dense(_)
+                        | VectorInternal::MultiDense(_)
+                        | VectorInternal::WeightedMultiDense(_) => {
                             return Err(OperationError::WrongSparse);
                         }
                         VectorInternal::Sparse(sparse) => {
@@ -535,9 +733,9 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
                     Ok(vector)
                 })?;
 
-                self.search_query(&vector, filter, top, &mut prefiltered_points, query_context)?
+                search_query(&vector, &mut prefiltered_points)?
             } else {
-                self.search_query(vector, filter, top, &mut prefiltered_points, query_context)?
+                search_query(vector, &mut prefiltered_points)?
             };
 
             results.push(search_results);
@@ -546,7 +744,10 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
     }
 
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
-        self.searches_telemetry.get_telemetry_data(detail)
+        VectorIndexSearchesTelemetry {
+            pruned_postings_ratio: self.pruning_stats.map(|stats| stats.pruned_ratio()),
+            ..self.searches_telemetry.get_telemetry_data(detail)
+        }
     }
 
     fn files(&self) -> Vec<PathBuf> {
@@ -557,6 +758,7 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
 
         let mut all_files = vec![
             IndicesTracker::file_path(&self.path),
+            PruningStats::file_path(&self.path),
             self.path.join(VERSION_FILE),
         ];
         all_files.retain(|f| f.exists());
@@ -604,7 +806,10 @@ impl<TInvertedIndex: InvertedIndex> VectorIndex for SparseVectorIndex<TInvertedI
             ));
         }
 
-        let vector = SparseVector::try_from(new_vector)?;
+        let mut vector = SparseVector::try_from(new_vector)?;
+        if self.is_cosine() {
+            vector.normalize_by(vector.norm());
+        }
         let old_vector: Option<SparseVector> =
             old_vector.map(SparseVector::try_from).transpose()?;
 
@@ -0,0 +1,251 @@
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::types::PointOffsetType;
+use memmap2::Mmap;
+use memory::madvise::{Advice, AdviceSetting};
+use memory::mmap_ops;
+use parking_lot::Mutex;
+use zerocopy::little_endian::U64 as LittleU64;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::data_types::vectors::{DenseVector, VectorElementType};
+#[cfg(target_os = "linux")]
+use crate::vector_storage::async_io::UringReader;
+#[cfg(not(target_os = "linux"))]
+use crate::vector_storage::async_io_mock::UringReader;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum};
+
+pub const VAMANA_GRAPH_FILE: &str = "vamana_graph.bin";
+
+const NO_ENTRY_POINT: u64 = u64::MAX;
+
+#[derive(FromBytes, Immutable, IntoBytes, KnownLayout)]
+#[repr(C)]
+struct VamanaGraphHeader {
+    point_count: LittleU64,
+    /// Number of points, linked into the graph
+    indexed_count: LittleU64,
+    dim: LittleU64,
+    max_degree: LittleU64,
+    /// [`NO_ENTRY_POINT`] if the graph is empty
+    entry_point: LittleU64,
+}
+
+const HEADER_SIZE: usize = size_of::<VamanaGraphHeader>();
+
+/// Vamana graph, stored on disk
+///
+/// Each point has a fixed-size block, which holds its full-precision vector and its links:
+///
+/// ```text
+/// [vector: dim x f32][links count: u32][links: max_degree x u32]
+/// ```
+///
+/// So a single read of the block is enough to rescore the node and to follow its links.
+/// Blocks are read with io_uring, if it is available, and through mmap otherwise.
+#[derive(Debug)]
+pub struct DiskGraph {
+    point_count: usize,
+    indexed_count: usize,
+    dim: usize,
+    max_degree: usize,
+    entry_point: Option<PointOffsetType>,
+    mmap: Mmap,
+    /// Context for io_uring-base async IO
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    uring_reader: Mutex<Option<UringReader<u8>>>,
+}
+
+/// View of the node block
+pub struct NodeView<'a> {
+    dim: usize,
+    block: &'a [u8],
+}
+
+impl NodeView<'_> {
+    pub fn vector(&self) -> DenseVector {
+        self.block[..self.dim * size_of::<VectorElementType>()]
+            .chunks_exact(size_of::<VectorElementType>())
+            .map(|bytes| VectorElementType::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect()
+    }
+
+    pub fn links(&self) -> impl Iterator<Item = PointOffsetType> + '_ {
+        let links = &self.block[self.dim * size_of::<VectorElementType>()..];
+        let (count, links) = links.split_at(size_of::<u32>());
+        let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+        links
+            .chunks_exact(size_of::<PointOffsetType>())
+            .take(count)
+            .map(|bytes| PointOffsetType::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+fn node_size(dim: usize, max_degree: usize) -> usize {
+    dim * size_of::<VectorElementType>()
+        + size_of::<u32>()
+        + max_degree * size_of::<PointOffsetType>()
+}
+
+impl DiskGraph {
+    pub fn get_path(path: &Path) -> PathBuf {
+        path.join(VAMANA_GRAPH_FILE)
+    }
+
+    /// Write node blocks of the graph
+    ///
+    /// Blocks of the points without vector are filled with zeros.
+    #[allow(clippy::too_many_arguments)]
+    pub fn save(
+        path: &Path,
+        links: &[Vec<PointOffsetType>],
+        indexed_count: usize,
+        entry_point: Option<PointOffsetType>,
+        dim: usize,
+        max_degree: usize,
+        vector_storage: &VectorStorageEnum,
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let header = VamanaGraphHeader {
+            point_count: LittleU64::new(links.len() as u64),
+            indexed_count: LittleU64::new(indexed_count as u64),
+            dim: LittleU64::new(dim as u64),
+            max_degree: LittleU64::new(max_degree as u64),
+            entry_point: LittleU64::new(entry_point.map_or(NO_ENTRY_POINT, u64::from)),
+        };
+
+        let tmp_path = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        writer.write_all(header.as_bytes())?;
+
+        let zero_vector = vec![0.0; dim];
+        for (point_id, point_links) in links.iter().enumerate() {
+            check_process_stopped(stopped)?;
+            let point_id = point_id as PointOffsetType;
+            let vector = if vector_storage.is_deleted_vector(point_id) {
+                None
+            } else {
+                vector_storage.get_vector_opt(point_id)
+            };
+            let vector: &[VectorElementType] = match &vector {
+                Some(vector) => vector.as_vec_ref().try_into()?,
+                None => &zero_vector,
+            };
+            debug_assert!(point_links.len() <= max_degree);
+
+            for value in vector {
+                writer.write_all(&value.to_le_bytes())?;
+            }
+            writer.write_all(&(point_links.len() as u32).to_le_bytes())?;
+            for link in point_links {
+                writer.write_all(&link.to_le_bytes())?;
+            }
+            for _ in point_links.len()..max_degree {
+                writer.write_all(&PointOffsetType::MAX.to_le_bytes())?;
+            }
+        }
+
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn open(path: &Path) -> OperationResult<Self> {
+        let mmap = mmap_ops::open_read_mmap(path, AdviceSetting::Advice(Advice::Random), false)?;
+
+        let (header, _) = VamanaGraphHeader::read_from_prefix(&mmap).map_err(|_| {
+            OperationError::service_error(format!("Vamana graph file {path:?} is too small"))
+        })?;
+        let point_count = header.point_count.get() as usize;
+        let indexed_count = header.indexed_count.get() as usize;
+        let dim = header.dim.get() as usize;
+        let max_degree = header.max_degree.get() as usize;
+        let entry_point = match header.entry_point.get() {
+            NO_ENTRY_POINT => None,
+            entry_point => Some(entry_point as PointOffsetType),
+        };
+
+        let node_size = node_size(dim, max_degree);
+        if mmap.len() != HEADER_SIZE + point_count * node_size {
+            return Err(OperationError::service_error(format!(
+                "Vamana graph file {path:?} has unexpected size {}",
+                mmap.len(),
+            )));
+        }
+
+        #[cfg(target_os = "linux")]
+        let uring_reader = match UringReader::new(File::open(path)?, node_size, HEADER_SIZE) {
+            Ok(uring_reader) => Some(uring_reader),
+            Err(err) => {
+                log::warn!("Failed to initialize io_uring for Vamana graph, using mmap: {err}");
+                None
+            }
+        };
+        #[cfg(not(target_os = "linux"))]
+        let uring_reader = None;
+
+        Ok(Self {
+            point_count,
+            indexed_count,
+            dim,
+            max_degree,
+            entry_point,
+            mmap,
+            uring_reader: Mutex::new(uring_reader),
+        })
+    }
+
+    pub fn point_count(&self) -> usize {
+        self.point_count
+    }
+
+    pub fn indexed_count(&self) -> usize {
+        self.indexed_count
+    }
+
+    pub fn entry_point(&self) -> Option<PointOffsetType> {
+        self.entry_point
+    }
+
+    pub fn node_size(&self) -> usize {
+        node_size(self.dim, self.max_degree)
+    }
+
+    fn node_view<'a>(&self, block: &'a [u8]) -> NodeView<'a> {
+        NodeView {
+            dim: self.dim,
+            block,
+        }
+    }
+
+    /// Read blocks of the given nodes, and call the callback for each of them
+    ///
+    /// Nodes are not necessarily processed in the given order.
+    pub fn read_nodes(
+        &self,
+        points: &[PointOffsetType],
+        mut callback: impl FnMut(PointOffsetType, NodeView<'_>),
+    ) -> OperationResult<()> {
+        #[cfg(target_os = "linux")]
+        if let Some(uring_reader) = self.uring_reader.lock().as_mut() {
+            return uring_reader.read_stream(points.iter().copied(), |_, point_id, block| {
+                callback(point_id, self.node_view(block))
+            });
+        }
+
+        let node_size = self.node_size();
+        for &point_id in points {
+            let offset = HEADER_SIZE + point_id as usize * node_size;
+            let block = &self.mmap[offset..offset + node_size];
+            callback(point_id, self.node_view(block));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+use parking_lot::RwLock;
+
+use super::search_list::SearchList;
+use crate::index::visited_pool::VisitedPool;
+use crate::types::Distance;
+use crate::vector_storage::RawScorer;
+
+/// In-memory single-layer graph, which is linked by Vamana algorithm
+///
+/// Points are inserted one by one: the graph is searched for the point, and the visited nodes
+/// are pruned to the point links. Back links are added to the neighbours, which are pruned as well
+/// if they exceed the maximal degree.
+pub struct VamanaGraphBuilder {
+    max_degree: usize,
+    build_search_list: usize,
+    distance: Distance,
+    entry_point: Option<PointOffsetType>,
+    links: Vec<RwLock<Vec<PointOffsetType>>>,
+    visited_pool: VisitedPool,
+}
+
+impl VamanaGraphBuilder {
+    pub fn new(
+        num_vectors: usize,
+        max_degree: usize,
+        build_search_list: usize,
+        distance: Distance,
+        entry_point: Option<PointOffsetType>,
+    ) -> Self {
+        Self {
+            max_degree,
+            build_search_list,
+            distance,
+            entry_point,
+            links: (0..num_vectors).map(|_| RwLock::new(Vec::new())).collect(),
+            visited_pool: VisitedPool::new(),
+        }
+    }
+
+    pub fn into_links(self) -> Vec<Vec<PointOffsetType>> {
+        self.links.into_iter().map(RwLock::into_inner).collect()
+    }
+
+    /// Link the point into the graph
+    ///
+    /// `raw_scorer` must be created for the vector of the point.
+    /// Larger `alpha` keeps more long-range links, which shortens the search paths.
+    pub fn link_point(&self, point_id: PointOffsetType, raw_scorer: &dyn RawScorer, alpha: f32) {
+        let mut candidates = self.search(raw_scorer);
+        candidates.extend(self.links[point_id as usize].read().iter().map(|&link| {
+            ScoredPointOffset {
+                idx: link,
+                score: raw_scorer.score_point(link),
+            }
+        }));
+
+        let point_links = self.prune(point_id, candidates, raw_scorer, alpha);
+        *self.links[point_id as usize].write() = point_links.clone();
+
+        for neighbor in point_links {
+            let mut neighbor_links = self.links[neighbor as usize].write();
+            if neighbor_links.contains(&point_id) {
+                continue;
+            }
+            if neighbor_links.len() < self.max_degree {
+                neighbor_links.push(point_id);
+                continue;
+            }
+            let candidates = neighbor_links
+                .iter()
+                .copied()
+                .chain(std::iter::once(point_id))
+                .map(|link| ScoredPointOffset {
+                    idx: link,
+                    score: raw_scorer.score_internal(neighbor, link),
+                })
+                .collect();
+            *neighbor_links = self.prune(neighbor, candidates, raw_scorer, alpha);
+        }
+    }
+
+    /// Greedy search from the entry point, returns all expanded nodes
+    fn search(&self, raw_scorer: &dyn RawScorer) -> Vec<ScoredPointOffset> {
+        let Some(entry_point) = self.entry_point else {
+            return Vec::new();
+        };
+
+        let mut visited = self.visited_pool.get(self.links.len());
+        let mut search_list = SearchList::new(self.build_search_list);
+        let mut expanded = Vec::new();
+
+        visited.check_and_update_visited(entry_point);
+        search_list.push(ScoredPointOffset {
+            idx: entry_point,
+            score: raw_scorer.score_point(entry_point),
+        });
+
+        while let Some(&candidate) = search_list.next_unexpanded(1).first() {
+            expanded.push(candidate);
+            let links = self.links[candidate.idx as usize].read().clone();
+            for link in links {
+                if !visited.check_and_update_visited(link) {
+                    search_list.push(ScoredPointOffset {
+                        idx: link,
+                        score: raw_scorer.score_point(link),
+                    });
+                }
+            }
+        }
+        expanded
+    }
+
+    /// Select up to `max_degree` links from the candidates, skipping the ones, which are
+    /// reachable through already selected links
+    fn prune(
+        &self,
+        point_id: PointOffsetType,
+        mut candidates: Vec<ScoredPointOffset>,
+        raw_scorer: &dyn RawScorer,
+        alpha: f32,
+    ) -> Vec<PointOffsetType> {
+        candidates.retain(|candidate| candidate.idx != point_id);
+        candidates.sort_unstable_by_key(|candidate| candidate.idx);
+        candidates.dedup_by_key(|candidate| candidate.idx);
+        candidates.sort_unstable_by(|a, b| b.score.total_cmp(&a.score));
+
+        let mut selected: Vec<PointOffsetType> = Vec::with_capacity(self.max_degree);
+        for candidate in candidates {
+            if selected.len() >= self.max_degree {
+                break;
+            }
+            let is_occluded = selected.iter().any(|&selected_id| {
+                is_occluded(
+                    self.distance,
+                    raw_scorer.score_internal(selected_id, candidate.idx),
+                    candidate.score,
+                    alpha,
+                )
+            });
+            if !is_occluded {
+                selected.push(candidate.idx);
+            }
+        }
+        selected
+    }
+}
+
+/// Candidate is occluded by the selected link, if it is `alpha` times closer to the link than to the point
+fn is_occluded(
+    distance: Distance,
+    score_to_selected: ScoreType,
+    score_to_point: ScoreType,
+    alpha: f32,
+) -> bool {
+    match distance {
+        // Similarities are not larger than 1
+        Distance::Cosine | Distance::Jaccard => {
+            alpha * (1.0 - score_to_selected) <= 1.0 - score_to_point
+        }
+        // Scores are negated distances
+        Distance::Euclid | Distance::Manhattan | Distance::Hamming => {
+            alpha * -score_to_selected <= -score_to_point
+        }
+        // Dot product is unbounded, so it can't be scaled
+        Distance::Dot => score_to_selected >= score_to_point,
+    }
+}
@@ -0,0 +1,4 @@
+pub mod disk_graph;
+mod graph_builder;
+mod search_list;
+pub mod vamana;
@@ -0,0 +1,61 @@
+use common::types::ScoredPointOffset;
+
+/// Bounded list of the best candidates of a beam search
+///
+/// Candidates are kept sorted from the best to the worst score,
+/// each of them is expanded (its links are followed) at most once.
+#[derive(Debug)]
+pub struct SearchList {
+    capacity: usize,
+    /// Candidates with the expanded flag
+    items: Vec<(ScoredPointOffset, bool)>,
+}
+
+impl SearchList {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            items: Vec::with_capacity(capacity.saturating_add(1)),
+        }
+    }
+
+    /// Insert candidate, if it is better than the worst one in the full list
+    pub fn push(&mut self, candidate: ScoredPointOffset) {
+        if self.items.len() >= self.capacity
+            && self
+                .items
+                .last()
+                .is_some_and(|(worst, _)| worst.score >= candidate.score)
+        {
+            return;
+        }
+        self.insert(candidate);
+        self.items.truncate(self.capacity);
+    }
+
+    /// Insert candidate even if the list is full, extending its capacity
+    pub fn push_unbounded(&mut self, candidate: ScoredPointOffset) {
+        self.insert(candidate);
+        self.capacity = self.capacity.max(self.items.len());
+    }
+
+    fn insert(&mut self, candidate: ScoredPointOffset) {
+        let position = self
+            .items
+            .partition_point(|(item, _)| item.score >= candidate.score);
+        self.items.insert(position, (candidate, false));
+    }
+
+    /// Take up to `count` best candidates, which are not expanded yet, and mark them as expanded
+    pub fn next_unexpanded(&mut self, count: usize) -> Vec<ScoredPointOffset> {
+        self.items
+            .iter_mut()
+            .filter(|(_, expanded)| !*expanded)
+            .take(count)
+            .map(|(candidate, expanded)| {
+                *expanded = true;
+                *candidate
+            })
+            .collect()
+    }
+}
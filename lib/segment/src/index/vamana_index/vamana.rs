@@ -0,0 +1,740 @@
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset, TelemetryDetail};
+use log::debug;
+use parking_lot::Mutex;
+use rand::seq::SliceRandom;
+use rayon::prelude::*;
+
+use super::disk_graph::DiskGraph;
+use super::graph_builder::VamanaGraphBuilder;
+use super::search_list::SearchList;
+use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::common::score_range::select_page;
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{
+    DenseVector, QueryVector, VectorElementType, VectorInternal, VectorRef,
+};
+use crate::id_tracker::IdTrackerSS;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vector_index_search_common::{
+    construct_search_scorer, get_oversampled_top, score_range,
+};
+use crate::index::visited_pool::VisitedPool;
+use crate::index::{PayloadIndex, VectorIndex};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{Distance, Filter, QuantizationSearchParams, SearchParams, VamanaConfig};
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::query::{Query, TransformInto};
+use crate::vector_storage::{new_raw_scorer, VectorStorage, VectorStorageEnum};
+
+/// Pruning slack of the second linking pass, which keeps long-range links in the graph
+const VAMANA_ALPHA: f32 = 1.2;
+/// Number of sampled vectors, used to find the entry point of the graph
+const VAMANA_MEDOID_SAMPLE_SIZE: usize = 1000;
+const SINGLE_THREADED_VAMANA_BUILD_THRESHOLD: usize = 32;
+
+/// Disk-resident graph index
+///
+/// Single-layer Vamana graph is stored on disk together with full-precision vectors,
+/// see [`DiskGraph`]. Search navigates the graph with the in-memory vectors, which are
+/// quantized if quantization is configured, and rescores the visited nodes with
+/// the vectors, read from disk in the same blocks as the links.
+#[derive(Debug)]
+pub struct VamanaIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    config: VamanaConfig,
+    path: PathBuf,
+    graph: DiskGraph,
+    visited_pool: VisitedPool,
+    searches_telemetry: VamanaSearchesTelemetry,
+}
+
+#[derive(Debug)]
+struct VamanaSearchesTelemetry {
+    unfiltered_vamana: Arc<Mutex<OperationDurationsAggregator>>,
+    filtered_vamana: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl VamanaSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_vamana: OperationDurationsAggregator::new(),
+            filtered_vamana: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+pub struct VamanaIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerSS>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub vamana_config: VamanaConfig,
+}
+
+impl VamanaIndex {
+    pub fn open(args: VamanaIndexOpenArgs<'_>) -> OperationResult<Self> {
+        let VamanaIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            vamana_config,
+        } = args;
+
+        let graph = DiskGraph::open(&DiskGraph::get_path(path))?;
+
+        Ok(VamanaIndex {
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            config: vamana_config,
+            path: path.to_owned(),
+            graph,
+            visited_pool: VisitedPool::new(),
+            searches_telemetry: VamanaSearchesTelemetry::new(),
+        })
+    }
+
+    pub fn build(
+        open_args: VamanaIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_>,
+    ) -> OperationResult<Self> {
+        if DiskGraph::get_path(open_args.path).exists() {
+            log::warn!(
+                "Vamana index already exists at {:?}, skipping building",
+                open_args.path
+            );
+            debug_assert!(false);
+            return Self::open(open_args);
+        }
+
+        let VamanaIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            vamana_config,
+        } = open_args;
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            stopped,
+        } = build_args;
+
+        create_dir_all(path)?;
+
+        {
+            let id_tracker_ref = id_tracker.borrow();
+            let vector_storage_ref = vector_storage.borrow();
+            let quantized_vectors_ref = quantized_vectors.borrow();
+
+            let distance = vector_storage_ref.distance();
+            if distance.is_bitwise() {
+                return Err(OperationError::service_error(format!(
+                    "Vamana index is not supported for {distance:?} distance"
+                )));
+            }
+            if quantized_vectors_ref.is_none() {
+                log::warn!(
+                    "Vamana index at {path:?} is built without quantized vectors, \
+                     search will navigate the graph with full-precision vectors from disk"
+                );
+            }
+
+            let total_vector_count = vector_storage_ref.total_vector_count();
+            let deleted_bitslice = vector_storage_ref.deleted_vector_bitslice();
+
+            let mut point_ids: Vec<_> = id_tracker_ref
+                .iter_ids_excluding(deleted_bitslice)
+                .collect();
+            debug!(
+                "building Vamana graph for {} vectors with {} CPUs",
+                point_ids.len(),
+                permit.num_cpus,
+            );
+
+            let (entry_point, dim) = find_medoid(&point_ids, &vector_storage_ref, stopped)?;
+            let builder = VamanaGraphBuilder::new(
+                total_vector_count,
+                vamana_config.max_degree,
+                vamana_config.build_search_list,
+                distance,
+                entry_point,
+            );
+
+            let pool = rayon::ThreadPoolBuilder::new()
+                .thread_name(|idx| format!("vamana-build-{idx}"))
+                .num_threads(permit.num_cpus as usize)
+                .build()?;
+
+            let link_point = |point_id, alpha| {
+                check_process_stopped(stopped)?;
+                let vector = vector_storage_ref.get_vector(point_id);
+                let vector = vector.as_vec_ref().into();
+                // No need to accumulate hardware, since this is an internal operation
+                let internal_hardware_counter = HardwareCounterCell::disposable();
+
+                let raw_scorer = if let Some(quantized_storage) = quantized_vectors_ref.as_ref() {
+                    quantized_storage.raw_scorer(
+                        vector,
+                        id_tracker_ref.deleted_point_bitslice(),
+                        deleted_bitslice,
+                        stopped,
+                        internal_hardware_counter,
+                    )
+                } else {
+                    new_raw_scorer(
+                        vector,
+                        &vector_storage_ref,
+                        id_tracker_ref.deleted_point_bitslice(),
+                        stopped,
+                        internal_hardware_counter,
+                    )
+                }?;
+
+                builder.link_point(point_id, raw_scorer.as_ref(), alpha);
+
+                Ok::<_, OperationError>(())
+            };
+
+            // The first pass links the closest neighbours,
+            // the second one adds long-range links and makes the graph navigable
+            point_ids.shuffle(&mut rand::rng());
+            let (first_few_ids, ids) =
+                point_ids.split_at(point_ids.len().min(SINGLE_THREADED_VAMANA_BUILD_THRESHOLD));
+            for alpha in [1.0, VAMANA_ALPHA] {
+                for &point_id in first_few_ids {
+                    link_point(point_id, alpha)?;
+                }
+                pool.install(|| {
+                    ids.par_iter()
+                        .try_for_each(|&point_id| link_point(point_id, alpha))
+                })?;
+            }
+
+            DiskGraph::save(
+                &DiskGraph::get_path(path),
+                &builder.into_links(),
+                point_ids.len(),
+                entry_point,
+                dim,
+                vamana_config.max_degree,
+                &vector_storage_ref,
+                stopped,
+            )?;
+        }
+
+        Self::open(VamanaIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            vamana_config,
+        })
+    }
+
+    fn search_list_size(&self, params: Option<&SearchParams>) -> usize {
+        params
+            .and_then(|params| params.vamana_search_list)
+            .unwrap_or(self.config.search_list)
+    }
+
+    /// Beam search over the disk graph
+    ///
+    /// Up to `beam_width` best candidates are read from disk at once. Their links are scored
+    /// with the in-memory vectors, while the vectors from the read blocks give the final scores.
+    fn search_with_graph(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        search_list_size: usize,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let Some(entry_point) = self.graph.entry_point() else {
+            return Ok(Vec::new());
+        };
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        let id_tracker = self.id_tracker.borrow();
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_vectors = self.quantized_vectors.borrow();
+
+        let deleted_points = vector_query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+
+        let raw_scorer = construct_search_scorer(
+            vector,
+            &vector_storage,
+            quantized_vectors.as_ref(),
+            deleted_points,
+            params,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )?;
+        let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
+
+        let hw_counter = vector_query_context.hardware_counter();
+
+        let filter_context = filter.map(|f| payload_index.filter_context(f, &hw_counter));
+
+        let distance = vector_storage.distance();
+        let query = vector
+            .clone()
+            .transform(|vector| Ok(preprocess(vector, distance)))?;
+
+        // Points within the range are always kept in the search list, so that the traversal
+        // reaches all of them, not only the best ones
+        let range = score_range(vector, params, vector_query_context, &vector_storage);
+        let mut search_list = SearchList::new(search_list_size.max(oversampled_top));
+        let mut visited = self.visited_pool.get(self.graph.point_count());
+        let mut links = Vec::new();
+        let mut result = Vec::new();
+
+        visited.check_and_update_visited(entry_point);
+        search_list.push(ScoredPointOffset {
+            idx: entry_point,
+            score: raw_scorer.score_point(entry_point),
+        });
+
+        loop {
+            check_process_stopped(&is_stopped)?;
+            let beam: Vec<_> = search_list
+                .next_unexpanded(self.config.beam_width)
+                .into_iter()
+                .map(|candidate| candidate.idx)
+                .collect();
+            if beam.is_empty() {
+                break;
+            }
+
+            hw_counter
+                .vector_io_read()
+                .incr_delta(beam.len() * self.graph.node_size());
+            self.graph.read_nodes(&beam, |point_id, node| {
+                if raw_scorer.check_vector(point_id)
+                    && filter_context
+                        .as_ref()
+                        .map_or(true, |filter_context| filter_context.check(point_id))
+                {
+                    result.push(ScoredPointOffset {
+                        idx: point_id,
+                        score: score_vector(&query, &node.vector(), distance),
+                    });
+                }
+                links.extend(
+                    node.links()
+                        .filter(|&link| !visited.check_and_update_visited(link)),
+                );
+            })?;
+
+            for link in links.drain(..) {
+                let candidate = ScoredPointOffset {
+                    idx: link,
+                    score: raw_scorer.score_point(link),
+                };
+                if range.is_some_and(|range| range.is_reachable(candidate.score)) {
+                    search_list.push_unbounded(candidate);
+                } else {
+                    search_list.push(candidate);
+                }
+            }
+        }
+
+        if let Some(range) = range {
+            result.retain(|scored| range.contains(scored.score));
+            return Ok(select_page(result, top));
+        }
+        result.sort_unstable_by(|a, b| b.score.total_cmp(&a.score));
+        result.truncate(top);
+        Ok(result)
+    }
+
+    fn search_vectors_with_graph(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        search_list_size: usize,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vectors
+            .iter()
+            .map(|&vector| {
+                self.search_with_graph(
+                    vector,
+                    filter,
+                    top,
+                    params,
+                    search_list_size,
+                    vector_query_context,
+                )
+            })
+            .collect()
+    }
+
+    fn search_plain_iterator(
+        &self,
+        vector: &QueryVector,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+
+        let deleted_points = vector_query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+
+        let is_stopped = vector_query_context.is_stopped();
+
+        // Vectors in the storage are full-precision, so no rescoring is required
+        let raw_scorer = construct_search_scorer(
+            vector,
+            &vector_storage,
+            None,
+            deleted_points,
+            params,
+            &is_stopped,
+            vector_query_context.hardware_counter(),
+        )?;
+
+        let search_result = match score_range(vector, params, vector_query_context, &vector_storage)
+        {
+            Some(range) => raw_scorer.peek_range_iter(points, range, top),
+            None => raw_scorer.peek_top_iter(points, top),
+        };
+        Ok(search_result)
+    }
+
+    fn search_vectors_plain_unfiltered(
+        &self,
+        vectors: &[&QueryVector],
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let id_tracker = self.id_tracker.borrow();
+        vectors
+            .iter()
+            .map(|&vector| {
+                let mut ids_iterator = id_tracker.iter_internal();
+                self.search_plain_iterator(
+                    vector,
+                    &mut ids_iterator,
+                    top,
+                    params,
+                    vector_query_context,
+                )
+            })
+            .collect()
+    }
+
+    fn search_vectors_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: &Filter,
+        top: usize,
+        params: Option<&SearchParams>,
+        vector_query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let payload_index = self.payload_index.borrow();
+        // share filtered points for all query vectors
+        let filtered_points =
+            payload_index.query_points(filter, &vector_query_context.hardware_counter());
+        vectors
+            .iter()
+            .map(|&vector| {
+                self.search_plain_iterator(
+                    vector,
+                    &mut filtered_points.iter().copied(),
+                    top,
+                    params,
+                    vector_query_context,
+                )
+            })
+            .collect()
+    }
+}
+
+impl VectorIndex for VamanaIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let exact = params.map(|params| params.exact).unwrap_or(false);
+
+        if exact {
+            let exact_params = params.map(|params| {
+                let mut params = *params;
+                params.quantization = Some(QuantizationSearchParams {
+                    ignore: true,
+                    rescore: Some(false),
+                    oversampling: None,
+                }); // disable quantization for exact search
+                params
+            });
+
+            return match filter {
+                None => {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.exact_unfiltered);
+                    self.search_vectors_plain_unfiltered(
+                        vectors,
+                        top,
+                        exact_params.as_ref(),
+                        query_context,
+                    )
+                }
+                Some(query_filter) => {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.exact_filtered);
+                    self.search_vectors_plain(
+                        vectors,
+                        query_filter,
+                        top,
+                        exact_params.as_ref(),
+                        query_context,
+                    )
+                }
+            };
+        }
+
+        let search_list_size = self.search_list_size(params);
+
+        match filter {
+            None => {
+                let _timer = ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_vamana);
+                self.search_vectors_with_graph(
+                    vectors,
+                    None,
+                    top,
+                    params,
+                    search_list_size,
+                    query_context,
+                )
+            }
+            Some(query_filter) => {
+                let payload_index = self.payload_index.borrow();
+                let vector_storage = self.vector_storage.borrow();
+                let id_tracker = self.id_tracker.borrow();
+                let available_vector_count = vector_storage.available_vector_count();
+                let query_point_cardinality = payload_index.estimate_cardinality(query_filter);
+                let query_cardinality = adjust_to_available_vectors(
+                    query_point_cardinality,
+                    available_vector_count,
+                    id_tracker.available_point_count(),
+                );
+
+                // Only a fraction of the visited nodes passes the filter,
+                // so the search list is extended proportionally
+                let filtered_search_list_size = search_list_size
+                    .saturating_mul(available_vector_count)
+                    .checked_div(query_cardinality.exp)
+                    .unwrap_or(usize::MAX);
+
+                if query_cardinality.max <= filtered_search_list_size {
+                    // if cardinality is small - it is cheaper to score all the filtered points
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
+                    self.search_vectors_plain(vectors, query_filter, top, params, query_context)
+                } else {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_vamana);
+                    self.search_vectors_with_graph(
+                        vectors,
+                        filter,
+                        top,
+                        params,
+                        filtered_search_list_size,
+                        query_context,
+                    )
+                }
+            }
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: Default::default(),
+            filtered_plain: Default::default(),
+            unfiltered_hnsw: Default::default(),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: Default::default(),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+            unfiltered_ivf: Default::default(),
+            filtered_ivf: Default::default(),
+            unfiltered_vamana: tm.unfiltered_vamana.lock().get_statistics(detail),
+            filtered_vamana: tm.filtered_vamana.lock().get_statistics(detail),
+            dead_links_ratio: None,
+            pruned_postings_ratio: None,
+        }
+    }
+
+    fn files(&self) -> Vec<PathBuf> {
+        vec![DiskGraph::get_path(&self.path)]
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.graph.indexed_count()
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update Vamana index"))
+    }
+}
+
+/// Entry point of the graph: the point, closest to the mean of the sampled vectors
+///
+/// Returns the entry point and the dimensionality of the vectors.
+fn find_medoid(
+    point_ids: &[PointOffsetType],
+    vector_storage: &VectorStorageEnum,
+    stopped: &AtomicBool,
+) -> OperationResult<(Option<PointOffsetType>, usize)> {
+    let Some(&first_id) = point_ids.first() else {
+        return Ok((None, 0));
+    };
+    let dim = get_dense(vector_storage, first_id)?.len();
+
+    let sample_size = point_ids.len().min(VAMANA_MEDOID_SAMPLE_SIZE);
+    let mut mean = vec![0.0; dim];
+    for idx in rand::seq::index::sample(&mut rand::rng(), point_ids.len(), sample_size) {
+        check_process_stopped(stopped)?;
+        let vector = get_dense(vector_storage, point_ids[idx])?;
+        for (mean, value) in mean.iter_mut().zip(vector) {
+            *mean += value / sample_size as VectorElementType;
+        }
+    }
+
+    let raw_scorer = new_raw_scorer(
+        mean.into(),
+        vector_storage,
+        vector_storage.deleted_vector_bitslice(),
+        stopped,
+        HardwareCounterCell::disposable(),
+    )?;
+    let medoid = raw_scorer
+        .peek_top_iter(&mut point_ids.iter().copied(), 1)
+        .first()
+        .map_or(first_id, |scored| scored.idx);
+    check_process_stopped(stopped)?;
+
+    Ok((Some(medoid), dim))
+}
+
+fn get_dense(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+) -> OperationResult<DenseVector> {
+    let vector = vector_storage.get_vector(point_id);
+    let dense: &[VectorElementType] = vector.as_vec_ref().try_into()?;
+    Ok(dense.to_vec())
+}
+
+/// Bring the query vector into the form of the stored vectors
+fn preprocess(vector: VectorInternal, distance: Distance) -> VectorInternal {
+    match vector {
+        VectorInternal::Dense(vector) => VectorInternal::Dense(match distance {
+            Distance::Cosine => <CosineMetric as Metric<VectorElementType>>::preprocess(vector),
+            Distance::Euclid
+            | Distance::Dot
+            | Distance::Manhattan
+            | Distance::Hamming
+            | Distance::Jaccard => vector,
+        }),
+        other => other,
+    }
+}
+
+/// Score the full-precision vector, read from disk, against the preprocessed query
+fn score_vector(
+    query: &QueryVector,
+    vector: &[VectorElementType],
+    distance: Distance,
+) -> ScoreType {
+    let similarity = |example: &VectorInternal| match example {
+        VectorInternal::Dense(example) => match distance {
+            Distance::Cosine => {
+                <CosineMetric as Metric<VectorElementType>>::similarity(example, vector)
+            }
+            Distance::Euclid => {
+                <EuclidMetric as Metric<VectorElementType>>::similarity(example, vector)
+            }
+            Distance::Dot => {
+                <DotProductMetric as Metric<VectorElementType>>::similarity(example, vector)
+            }
+            Distance::Manhattan => {
+                <ManhattanMetric as Metric<VectorElementType>>::similarity(example, vector)
+            }
+            Distance::Hamming | Distance::Jaccard => {
+                unreachable!("Vamana index is not built for bitwise distances")
+            }
+        },
+        // Only dense vectors are indexed
+        VectorInternal::Sparse(_)
+        | VectorInternal::MultiDense(_)
+        | VectorInternal::WeightedMultiDense(_) => 0.0,
+    };
+    match query {
+        QueryVector::Nearest(example) => similarity(example),
+        QueryVector::Recommend(query) => query.score_by(similarity),
+        QueryVector::Discovery(query) => query.score_by(similarity),
+        QueryVector::Context(query) => query.score_by(similarity),
+    }
+}
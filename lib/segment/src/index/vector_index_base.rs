@@ -11,9 +11,12 @@ use sparse::index::inverted_index::inverted_index_mmap::InvertedIndexMmap;
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
 use super::hnsw_index::hnsw::HNSWIndex;
+use super::ivf_index::ivf::IVFIndex;
 use super::plain_vector_index::PlainVectorIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
+use super::vamana_index::vamana::VamanaIndex;
 use crate::common::operation_error::OperationResult;
+use crate::common::Flusher;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::telemetry::VectorIndexSearchesTelemetry;
@@ -61,6 +64,8 @@ pub trait VectorIndex {
 pub enum VectorIndexEnum {
     Plain(PlainVectorIndex),
     Hnsw(HNSWIndex),
+    Ivf(IVFIndex),
+    Vamana(VamanaIndex),
     SparseRam(SparseVectorIndex<InvertedIndexRam>),
     SparseImmutableRam(SparseVectorIndex<InvertedIndexImmutableRam>),
     SparseMmap(SparseVectorIndex<InvertedIndexMmap>),
@@ -79,6 +84,8 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => false,
             Self::Hnsw(_) => true,
+            Self::Ivf(_) => true,
+            Self::Vamana(_) => true,
             Self::SparseRam(_) => true,
             Self::SparseImmutableRam(_) => true,
             Self::SparseMmap(_) => true,
@@ -91,9 +98,47 @@ impl VectorIndexEnum {
         }
     }
 
+    /// Update index after the point was deleted in the ID tracker or in the vector storage
+    pub fn repair_deleted_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        match self {
+            Self::Hnsw(index) => index.repair_deleted_point(id),
+            Self::Plain(_)
+            | Self::Ivf(_)
+            | Self::Vamana(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_)
+            | Self::SparseCompressedImmutableRamF32(_)
+            | Self::SparseCompressedImmutableRamF16(_)
+            | Self::SparseCompressedImmutableRamU8(_)
+            | Self::SparseCompressedMmapF32(_)
+            | Self::SparseCompressedMmapF16(_)
+            | Self::SparseCompressedMmapU8(_) => Ok(()),
+        }
+    }
+
+    /// Persist changes made by [`Self::repair_deleted_point`]
+    pub fn flusher(&self) -> Flusher {
+        match self {
+            Self::Hnsw(index) => index.flusher(),
+            Self::Plain(_)
+            | Self::Ivf(_)
+            | Self::Vamana(_)
+            | Self::SparseRam(_)
+            | Self::SparseImmutableRam(_)
+            | Self::SparseMmap(_)
+            | Self::SparseCompressedImmutableRamF32(_)
+            | Self::SparseCompressedImmutableRamF16(_)
+            | Self::SparseCompressedImmutableRamU8(_)
+            | Self::SparseCompressedMmapF32(_)
+            | Self::SparseCompressedMmapF16(_)
+            | Self::SparseCompressedMmapU8(_) => Box::new(|| Ok(())),
+        }
+    }
+
     pub fn fill_idf_statistics(&self, idf: &mut HashMap<DimId, usize>) {
         match self {
-            Self::Plain(_) | Self::Hnsw(_) => (),
+            Self::Plain(_) | Self::Hnsw(_) | Self::Ivf(_) | Self::Vamana(_) => (),
             Self::SparseRam(index) => index.fill_idf_statistics(idf),
             Self::SparseImmutableRam(index) => index.fill_idf_statistics(idf),
             Self::SparseMmap(index) => index.fill_idf_statistics(idf),
@@ -123,6 +168,12 @@ impl VectorIndex for VectorIndexEnum {
             VectorIndexEnum::Hnsw(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
+            VectorIndexEnum::Ivf(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
+            VectorIndexEnum::Vamana(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
             VectorIndexEnum::SparseRam(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
@@ -157,6 +208,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::Hnsw(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::Ivf(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::Vamana(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseImmutableRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseMmap(index) => index.get_telemetry_data(detail),
@@ -179,6 +232,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.files(),
             VectorIndexEnum::Hnsw(index) => index.files(),
+            VectorIndexEnum::Ivf(index) => index.files(),
+            VectorIndexEnum::Vamana(index) => index.files(),
             VectorIndexEnum::SparseRam(index) => index.files(),
             VectorIndexEnum::SparseImmutableRam(index) => index.files(),
             VectorIndexEnum::SparseMmap(index) => index.files(),
@@ -195,6 +250,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.indexed_vector_count(),
             Self::Hnsw(index) => index.indexed_vector_count(),
+            Self::Ivf(index) => index.indexed_vector_count(),
+            Self::Vamana(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.indexed_vector_count(),
             Self::SparseImmutableRam(index) => index.indexed_vector_count(),
             Self::SparseMmap(index) => index.indexed_vector_count(),
@@ -211,6 +268,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::Hnsw(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::Ivf(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::Vamana(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseRam(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseImmutableRam(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseMmap(index) => index.size_of_searchable_vectors_in_bytes(),
@@ -237,6 +296,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.update_vector(id, vector),
             Self::Hnsw(index) => index.update_vector(id, vector),
+            Self::Ivf(index) => index.update_vector(id, vector),
+            Self::Vamana(index) => index.update_vector(id, vector),
             Self::SparseRam(index) => index.update_vector(id, vector),
             Self::SparseImmutableRam(index) => index.update_vector(id, vector),
             Self::SparseMmap(index) => index.update_vector(id, vector),
@@ -0,0 +1,151 @@
+//! Search helpers, shared by the vector indexes, which support quantized scoring with rescoring
+
+use std::sync::atomic::AtomicBool;
+
+use bitvec::prelude::BitSlice;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoredPointOffset;
+
+use crate::common::operation_error::OperationResult;
+use crate::common::score_range::{truncate_with_ties, ScoreRange};
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::QueryVector;
+use crate::id_tracker::IdTrackerSS;
+use crate::types::{
+    default_quantization_ignore_value, default_quantization_oversampling_value, SearchParams,
+};
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::{
+    new_stoppable_raw_scorer, RawScorer, VectorStorage, VectorStorageEnum,
+};
+
+/// Range of the range search in the scale of the raw scores, if requested
+pub fn score_range(
+    vector: &QueryVector,
+    params: Option<&SearchParams>,
+    vector_query_context: &VectorQueryContext,
+    vector_storage: &VectorStorageEnum,
+) -> Option<ScoreRange> {
+    ScoreRange::from_params(
+        vector,
+        params,
+        vector_query_context.range_score_threshold(),
+        vector_storage.distance(),
+    )
+}
+
+pub fn is_quantized_search(
+    quantized_storage: Option<&QuantizedVectors>,
+    params: Option<&SearchParams>,
+) -> bool {
+    let ignore_quantization = params
+        .and_then(|p| p.quantization)
+        .map(|q| q.ignore)
+        .unwrap_or(default_quantization_ignore_value());
+    quantized_storage.is_some() && !ignore_quantization
+}
+
+pub fn construct_search_scorer<'a>(
+    vector: &QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    quantized_storage: Option<&'a QuantizedVectors>,
+    deleted_points: &'a BitSlice,
+    params: Option<&SearchParams>,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let quantization_enabled = is_quantized_search(quantized_storage, params);
+    match quantized_storage {
+        Some(quantized_storage) if quantization_enabled => quantized_storage.raw_scorer(
+            vector.to_owned(),
+            deleted_points,
+            vector_storage.deleted_vector_bitslice(),
+            is_stopped,
+            hardware_counter,
+        ),
+        _ => new_stoppable_raw_scorer(
+            vector.to_owned(),
+            vector_storage,
+            deleted_points,
+            is_stopped,
+            hardware_counter,
+        ),
+    }
+}
+
+pub fn get_oversampled_top(
+    quantized_storage: Option<&QuantizedVectors>,
+    params: Option<&SearchParams>,
+    top: usize,
+) -> usize {
+    let quantization_enabled = is_quantized_search(quantized_storage, params);
+
+    let oversampling_value = params
+        .and_then(|p| p.quantization)
+        .map(|q| q.oversampling)
+        .unwrap_or(default_quantization_oversampling_value());
+
+    match oversampling_value {
+        Some(oversampling) if quantization_enabled && oversampling > 1.0 => {
+            (oversampling * top as f64) as usize
+        }
+        _ => top,
+    }
+}
+
+/// Rescore the oversampled result with the original vectors, if required, and cut it to `top`.
+/// Pages of a range search keep the points tied with the last one.
+#[allow(clippy::too_many_arguments)]
+pub fn postprocess_search_result(
+    search_result: Vec<ScoredPointOffset>,
+    vector: &QueryVector,
+    params: Option<&SearchParams>,
+    range: Option<ScoreRange>,
+    top: usize,
+    id_tracker: &IdTrackerSS,
+    vector_storage: &VectorStorageEnum,
+    quantized_vectors: Option<&QuantizedVectors>,
+    is_stopped: &AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Vec<ScoredPointOffset>> {
+    let quantization_enabled = is_quantized_search(quantized_vectors, params);
+
+    let default_rescoring = quantized_vectors
+        .map(|q| q.default_rescoring())
+        .unwrap_or(false);
+    let rescore = quantization_enabled
+        && params
+            .and_then(|p| p.quantization)
+            .and_then(|q| q.rescore)
+            .unwrap_or(default_rescoring);
+
+    let mut postprocess_result = if rescore {
+        let raw_scorer = new_stoppable_raw_scorer(
+            vector.to_owned(),
+            vector_storage,
+            id_tracker.deleted_point_bitslice(),
+            is_stopped,
+            hardware_counter,
+        )?;
+
+        let mut ids_iterator = search_result.iter().map(|x| x.idx);
+        let mut re_scored = raw_scorer.score_points_unfiltered(&mut ids_iterator);
+
+        re_scored.sort_unstable();
+        re_scored.reverse();
+
+        // Quantized scores are approximate, so the range is checked once more
+        if let Some(range) = range {
+            re_scored.retain(|scored| range.contains(scored.score));
+        }
+        re_scored
+    } else {
+        search_result
+    };
+    if range.is_some() {
+        truncate_with_ties(&mut postprocess_result, top, |scored| scored.score);
+    } else {
+        postprocess_result.truncate(top);
+    }
+    Ok(postprocess_result)
+}
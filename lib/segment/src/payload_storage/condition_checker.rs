@@ -4,10 +4,11 @@ use std::str::FromStr;
 
 use serde_json::Value;
 
+use crate::index::field_index::levenshtein::LevenshteinAutomaton;
 use crate::types::{
     AnyVariants, DateTimePayloadType, FieldCondition, FloatPayloadType, GeoBoundingBox, GeoPoint,
-    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchText, MatchValue, Range,
-    RangeInterface, ValueVariants, ValuesCount,
+    GeoPolygon, GeoRadius, Match, MatchAny, MatchExcept, MatchPhrase, MatchPrefix, MatchRegex,
+    MatchSubstring, MatchText, MatchValue, Range, RangeInterface, ValueVariants, ValuesCount,
 };
 
 /// Threshold representing the point to which iterating through an IndexSet is more efficient than using hashing.
@@ -53,6 +54,10 @@ impl ValueChecker for FieldCondition {
                 .is_some_and(|range_interface| match range_interface {
                     RangeInterface::Float(condition) => condition.check_match(payload),
                     RangeInterface::DateTime(condition) => condition.check_match(payload),
+                    RangeInterface::Keyword(condition) => match condition.as_datetime_range() {
+                        Some(datetime_condition) => datetime_condition.check_match(payload),
+                        None => condition.check_match(payload),
+                    },
                 })
             || geo_radius
                 .as_ref()
@@ -95,6 +100,30 @@ impl ValueChecker for Match {
                 Value::String(stored) => stored.contains(text),
                 _ => false,
             },
+            Match::Phrase(MatchPhrase { phrase, slop }) => match payload {
+                Value::String(stored) => check_phrase_words(stored, phrase, slop.unwrap_or(0)),
+                _ => false,
+            },
+            // Same as in the keyword index, the whole value is matched
+            Match::Fuzzy(fuzzy) => match payload {
+                Value::String(stored) => {
+                    LevenshteinAutomaton::new(&fuzzy.fuzzy, fuzzy.max_edits(&fuzzy.fuzzy))
+                        .is_match(stored)
+                }
+                _ => false,
+            },
+            Match::Prefix(MatchPrefix { prefix }) => match payload {
+                Value::String(stored) => stored.starts_with(prefix.as_str()),
+                _ => false,
+            },
+            Match::Substring(MatchSubstring { substring }) => match payload {
+                Value::String(stored) => stored.contains(substring.as_str()),
+                _ => false,
+            },
+            Match::Regex(MatchRegex { regex }) => match payload {
+                Value::String(stored) => regex.is_match(stored),
+                _ => false,
+            },
             Match::Any(MatchAny { any }) => match (payload, any) {
                 (Value::String(stored), AnyVariants::Strings(list)) => {
                     if list.len() < INDEXSET_ITER_THRESHOLD {
@@ -144,6 +173,28 @@ impl ValueChecker for Match {
     }
 }
 
+/// Check the phrase against the text without a full-text index, splitting both by whitespace.
+///
+/// Words of the phrase must occur in the same order, with at most `slop` other words in between.
+fn check_phrase_words(text: &str, phrase: &str, slop: u32) -> bool {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let phrase_words: Vec<&str> = phrase.split_whitespace().collect();
+    let Some(first_phrase_word) = phrase_words.first() else {
+        return false;
+    };
+
+    let window_len = phrase_words.len() + slop as usize;
+    (0..words.len())
+        .filter(|&start| words[start] == *first_phrase_word)
+        .any(|start| {
+            let window_end = (start + window_len).min(words.len());
+            let mut window = words[start..window_end].iter();
+            phrase_words
+                .iter()
+                .all(|phrase_word| window.any(|word| word == phrase_word))
+        })
+}
+
 impl ValueChecker for Range<FloatPayloadType> {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -165,6 +216,12 @@ impl ValueChecker for Range<DateTimePayloadType> {
     }
 }
 
+impl ValueChecker for Range<String> {
+    fn check_match(&self, payload: &Value) -> bool {
+        payload.as_str().is_some_and(|s| self.check_keyword(s))
+    }
+}
+
 impl ValueChecker for GeoBoundingBox {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
@@ -231,7 +288,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::types::GeoPoint;
+    use crate::types::{GeoPoint, RegexPattern};
 
     #[test]
     fn test_geo_matching() {
@@ -265,6 +322,51 @@ mod tests {
         assert!(!miss_geo_query.check(&berlin_and_moscow));
     }
 
+    #[test]
+    fn test_phrase_matching() {
+        let text = json!("the quick brown fox jumps over the lazy dog");
+
+        assert!(Match::new_phrase("quick brown fox", None).check(&text));
+        assert!(!Match::new_phrase("brown quick fox", None).check(&text));
+        assert!(!Match::new_phrase("quick fox", None).check(&text));
+        assert!(Match::new_phrase("quick fox", Some(1)).check(&text));
+        assert!(!Match::new_phrase("quick jumps", Some(1)).check(&text));
+        assert!(Match::new_phrase("quick jumps", Some(2)).check(&text));
+        assert!(Match::new_phrase("the lazy", None).check(&text));
+        assert!(!Match::new_phrase("", None).check(&text));
+    }
+
+    #[test]
+    fn test_fuzzy_matching() {
+        let keyword = json!("red-apple");
+
+        assert!(Match::new_fuzzy("red-appel", None).check(&keyword));
+        assert!(Match::new_fuzzy("red-apple", Some(0)).check(&keyword));
+        assert!(!Match::new_fuzzy("red-aple", Some(0)).check(&keyword));
+        // The whole value is matched, not its words
+        assert!(!Match::new_fuzzy("apple", None).check(&keyword));
+        assert!(!Match::new_fuzzy("", None).check(&keyword));
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        let keyword = json!("qdrant-v1.13.0");
+
+        assert!(Match::new_prefix("qdrant-").check(&keyword));
+        assert!(!Match::new_prefix("v1").check(&keyword));
+        assert!(Match::new_substring("v1.13").check(&keyword));
+        assert!(!Match::new_substring("Qdrant").check(&keyword));
+
+        let regex = |pattern: &str| Match::new_regex(RegexPattern::new(pattern).unwrap());
+        assert!(regex(r"v\d+\.\d+").check(&keyword));
+        assert!(!regex(r"^v\d+").check(&keyword));
+        assert!(regex(r"^qdrant-v1\.(12|13)\.").check(&keyword));
+
+        // Only strings can match
+        assert!(!Match::new_prefix("1").check(&json!(13)));
+        assert!(Match::new_prefix("rust").check(&json!(["go", "rust"])));
+    }
+
     #[test]
     fn test_value_count() {
         let countries = json!([
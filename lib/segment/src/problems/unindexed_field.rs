@@ -208,6 +208,20 @@ fn infer_schema_from_any_variants(value: &AnyVariants) -> Vec<PayloadFieldSchema
     }
 }
 
+fn infer_text_schema() -> PayloadFieldSchema {
+    PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(TextIndexParams {
+        r#type: TextIndexType::Text,
+        tokenizer: TokenizerType::default(),
+        min_token_len: None,
+        max_token_len: None,
+        lowercase: None,
+        ascii_folding: None,
+        stopwords: None,
+        stemmer: None,
+        on_disk: None,
+    }))
+}
+
 fn infer_schema_from_field_condition(field_condition: &FieldCondition) -> Vec<PayloadFieldSchema> {
     let FieldCondition {
         key: _key,
@@ -224,17 +238,13 @@ fn infer_schema_from_field_condition(field_condition: &FieldCondition) -> Vec<Pa
     if let Some(r#match) = r#match {
         inferred.extend(match r#match {
             Match::Value(match_value) => infer_schema_from_match_value(match_value),
-            Match::Text(_match_text) => {
-                vec![PayloadFieldSchema::FieldParams(PayloadSchemaParams::Text(
-                    TextIndexParams {
-                        r#type: TextIndexType::Text,
-                        tokenizer: TokenizerType::default(),
-                        min_token_len: None,
-                        max_token_len: None,
-                        lowercase: None,
-                        on_disk: None,
-                    },
-                ))]
+            Match::Text(_) | Match::Phrase(_) => vec![infer_text_schema()],
+            Match::Fuzzy(_) => vec![
+                PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword),
+                infer_text_schema(),
+            ],
+            Match::Prefix(_) | Match::Substring(_) | Match::Regex(_) => {
+                vec![PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword)]
             }
             Match::Any(match_any) => infer_schema_from_any_variants(&match_any.any),
             Match::Except(match_except) => infer_schema_from_any_variants(&match_except.except),
@@ -249,6 +259,12 @@ fn infer_schema_from_field_condition(field_condition: &FieldCondition) -> Vec<Pa
                 inferred.push(PayloadFieldSchema::FieldType(PayloadSchemaType::Float));
                 inferred.push(PayloadFieldSchema::FieldType(PayloadSchemaType::Integer));
             }
+            RangeInterface::Keyword(keyword_range) => {
+                if keyword_range.as_datetime_range().is_some() {
+                    inferred.push(PayloadFieldSchema::FieldType(PayloadSchemaType::Datetime));
+                }
+                inferred.push(PayloadFieldSchema::FieldType(PayloadSchemaType::Keyword));
+            }
         }
     }
     if geo_bounding_box.is_some() || geo_radius.is_some() || geo_polygon.is_some() {
@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::iterator_ext::IteratorExt;
+use common::types::ScoreType;
+use ordered_float::OrderedFloat;
+
+use super::Segment;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::bm25::Bm25;
+use crate::index::PayloadIndex;
+use crate::spaces::tools::peek_top_largest_iterable;
+use crate::types::{Filter, PointIdType};
+
+impl Segment {
+    /// Score points of the segment by BM25 relevance of the `bm25.key` full-text field
+    /// to the `bm25.text` query.
+    ///
+    /// Term statistics and document lengths are taken from this segment only.
+    /// Returns points with the highest scores first.
+    pub fn read_by_bm25(
+        &self,
+        bm25: &Bm25,
+        limit: Option<usize>,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        let payload_index = self.payload_index.borrow();
+        let id_tracker = self.id_tracker.borrow();
+
+        let text_index = payload_index
+            .field_indexes
+            .get(&bm25.key)
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_full_text()))
+            .ok_or_else(|| OperationError::MissingFullTextIndexForBm25 {
+                key: bm25.key.to_string(),
+            })?;
+
+        let scores = text_index.bm25_scores(&bm25.text, bm25.params());
+
+        let filter_context = filter.map(|filter| payload_index.filter_context(filter, hw_counter));
+
+        let scored_points = scores
+            .into_iter()
+            .check_stop(|| is_stopped.load(Ordering::Relaxed))
+            .filter(|(internal_id, _)| {
+                filter_context
+                    .as_ref()
+                    .map_or(true, |filter_context| filter_context.check(*internal_id))
+            })
+            .filter_map(|(internal_id, score)| {
+                id_tracker
+                    .external_id(internal_id)
+                    .map(|external_id| (OrderedFloat(score), external_id))
+            });
+
+        let mut page = match limit {
+            Some(limit) => peek_top_largest_iterable(scored_points, limit),
+            None => scored_points.collect(),
+        };
+        page.sort_unstable_by(|a, b| b.cmp(a));
+
+        let page = page
+            .into_iter()
+            .map(|(OrderedFloat(score), external_id)| (score, external_id))
+            .collect();
+
+        Ok(page)
+    }
+}
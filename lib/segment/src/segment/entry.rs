@@ -7,7 +7,7 @@ use std::thread::{self};
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::tar_ext;
-use common::types::TelemetryDetail;
+use common::types::{ScoreType, TelemetryDetail};
 use io::storage_version::VERSION_FILE;
 use uuid::Uuid;
 
@@ -15,6 +15,7 @@ use super::Segment;
 use crate::common::operation_error::OperationError::TypeInferenceError;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::common::{check_named_vectors, check_query_vectors, check_stopped, check_vector_name};
+use crate::data_types::bm25::Bm25;
 use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
 use crate::data_types::order_by::{OrderBy, OrderValue};
@@ -126,6 +127,11 @@ impl SegmentEntry for Segment {
                         .clear_payload(internal_id, hw_counter)?;
                     segment.id_tracker.borrow_mut().drop(point_id)?;
 
+                    // Reconnect graph around the deleted point
+                    for vector_name in segment.vector_data.keys() {
+                        segment.repair_deleted_vector(vector_name, internal_id);
+                    }
+
                     // Before, we propagated point deletions to also delete its vectors. This turns
                     // out to be problematic because this sometimes makes us loose vector data
                     // because we cannot control the order of segment flushes.
@@ -187,8 +193,13 @@ impl SegmentEntry for Segment {
                             received_name: vector_name.to_owned(),
                         }
                     })?;
-                    let mut vector_storage = vector_data.vector_storage.borrow_mut();
-                    let is_deleted = vector_storage.delete_vector(internal_id)?;
+                    let is_deleted = vector_data
+                        .vector_storage
+                        .borrow_mut()
+                        .delete_vector(internal_id)?;
+                    if is_deleted {
+                        segment.repair_deleted_vector(vector_name, internal_id);
+                    }
                     Ok((is_deleted, Some(internal_id)))
                 })
             }
@@ -376,6 +387,17 @@ impl SegmentEntry for Segment {
         }
     }
 
+    fn read_scored_by_bm25<'a>(
+        &'a self,
+        limit: Option<usize>,
+        filter: Option<&'a Filter>,
+        bm25: &'a Bm25,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<(ScoreType, PointIdType)>> {
+        self.read_by_bm25(bm25, limit, filter, is_stopped, hw_counter)
+    }
+
     fn read_random_filtered(
         &self,
         limit: usize,
@@ -596,6 +618,11 @@ impl SegmentEntry for Segment {
             .values()
             .map(|v| v.vector_storage.borrow().flusher())
             .collect();
+        let vector_index_flushers: Vec<_> = self
+            .vector_data
+            .values()
+            .map(|v| v.vector_index.borrow().flusher())
+            .collect();
         let state = self.get_state();
         let current_path = self.current_path.clone();
         let id_tracker_mapping_flusher = self.id_tracker.borrow().mapping_flusher();
@@ -660,6 +687,11 @@ impl SegmentEntry for Segment {
                     OperationError::service_error(format!("Failed to flush vector_storage: {err}"))
                 })?;
             }
+            for vector_index_flusher in vector_index_flushers {
+                vector_index_flusher().map_err(|err| {
+                    OperationError::service_error(format!("Failed to flush vector_index: {err}"))
+                })?;
+            }
             payload_index_flusher().map_err(|err| {
                 OperationError::service_error(format!("Failed to flush payload_index: {err}"))
             })?;
@@ -1,3 +1,4 @@
+mod bm25;
 mod entry;
 mod facet;
 mod order_by;
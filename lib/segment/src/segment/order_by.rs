@@ -24,10 +24,10 @@ impl Segment {
         let payload_index = self.payload_index.borrow();
         let id_tracker = self.id_tracker.borrow();
 
-        let numeric_index = payload_index
+        let orderable_index = payload_index
             .field_indexes
             .get(&order_by.key)
-            .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_orderable()))
             .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
                 key: order_by.key.to_string(),
             })?;
@@ -41,7 +41,7 @@ impl Segment {
             .check_stop(|| is_stopped.load(Ordering::Relaxed))
             .flat_map(|internal_id| {
                 // Repeat a point for as many values as it has
-                numeric_index
+                orderable_index
                     .get_ordering_values(internal_id)
                     // But only those which start from `start_from`
                     .filter(|value| match order_by.direction() {
@@ -88,15 +88,15 @@ impl Segment {
     ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
         let payload_index = self.payload_index.borrow();
 
-        let numeric_index = payload_index
+        let orderable_index = payload_index
             .field_indexes
             .get(&order_by.key)
-            .and_then(|indexes| indexes.iter().find_map(|index| index.as_numeric()))
+            .and_then(|indexes| indexes.iter().find_map(|index| index.as_orderable()))
             .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
                 key: order_by.key.to_string(),
             })?;
 
-        let range_iter = numeric_index.stream_range(&order_by.as_range());
+        let range_iter = orderable_index.stream_range(&order_by.as_range());
 
         let directed_range_iter = match order_by.direction() {
             Direction::Asc => Either::Left(range_iter),
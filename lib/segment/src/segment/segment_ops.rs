@@ -111,6 +111,29 @@ impl Segment {
         Ok(new_index)
     }
 
+    /// Reconnect the vector index around the deleted vector
+    ///
+    /// Deletion is already applied at this point, and repair only improves search quality.
+    /// So errors are logged instead of failing the operation.
+    pub(super) fn repair_deleted_vector(
+        &self,
+        vector_name: &VectorName,
+        internal_id: PointOffsetType,
+    ) {
+        let Some(vector_data) = self.vector_data.get(vector_name) else {
+            return;
+        };
+        let result = vector_data
+            .vector_index
+            .borrow_mut()
+            .repair_deleted_point(internal_id);
+        if let Err(err) = result {
+            log::error!(
+                "Failed to repair index of vector {vector_name} after deletion of point {internal_id}: {err}",
+            );
+        }
+    }
+
     /// Operation wrapped, which handles previous and new errors in the segment, automatically
     /// updates versions and skips operations if the segment version is too old
     ///
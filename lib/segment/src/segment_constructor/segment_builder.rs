@@ -120,7 +120,7 @@ impl SegmentBuilder {
                 database.clone(),
                 &vector_storage_path,
                 vector_name,
-                &sparse_vector_config.storage_type,
+                sparse_vector_config,
                 &stopped,
             )?;
 
@@ -16,18 +16,20 @@ use uuid::Uuid;
 
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
-use crate::data_types::vectors::DEFAULT_VECTOR_NAME;
+use crate::data_types::vectors::{PackedBits, DEFAULT_VECTOR_NAME};
 use crate::id_tracker::immutable_id_tracker::ImmutableIdTracker;
 use crate::id_tracker::simple_id_tracker::SimpleIdTracker;
 use crate::id_tracker::{IdTracker, IdTrackerEnum, IdTrackerSS};
 use crate::index::hnsw_index::gpu::gpu_devices_manager::LockedGpuDevice;
 use crate::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use crate::index::ivf_index::ivf::{IVFIndex, IvfIndexOpenArgs};
 use crate::index::plain_vector_index::PlainVectorIndex;
 use crate::index::sparse_index::sparse_index_config::SparseIndexType;
 use crate::index::sparse_index::sparse_vector_index::{
     self, SparseVectorIndex, SparseVectorIndexOpenArgs,
 };
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vamana_index::vamana::{VamanaIndex, VamanaIndexOpenArgs};
 use crate::index::VectorIndexEnum;
 use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 use crate::payload_storage::on_disk_payload_storage::OnDiskPayloadStorage;
@@ -36,30 +38,36 @@ use crate::payload_storage::simple_payload_storage::SimplePayloadStorage;
 use crate::segment::{Segment, SegmentVersion, VectorData, SEGMENT_STATE_FILE};
 use crate::types::{
     Distance, Indexes, PayloadStorageType, SegmentConfig, SegmentState, SegmentType, SeqNumberType,
-    SparseVectorStorageType, VectorDataConfig, VectorName, VectorStorageDatatype,
-    VectorStorageType,
+    SparseVectorDataConfig, SparseVectorStorageType, VectorDataConfig, VectorName,
+    VectorStorageDatatype, VectorStorageType,
 };
 use crate::vector_storage::dense::appendable_dense_vector_storage::{
-    open_appendable_in_ram_vector_storage, open_appendable_in_ram_vector_storage_byte,
-    open_appendable_in_ram_vector_storage_half, open_appendable_memmap_vector_storage,
-    open_appendable_memmap_vector_storage_byte, open_appendable_memmap_vector_storage_half,
+    open_appendable_in_ram_vector_storage, open_appendable_in_ram_vector_storage_bit,
+    open_appendable_in_ram_vector_storage_byte, open_appendable_in_ram_vector_storage_half,
+    open_appendable_in_ram_vector_storage_int8, open_appendable_memmap_vector_storage,
+    open_appendable_memmap_vector_storage_bit, open_appendable_memmap_vector_storage_byte,
+    open_appendable_memmap_vector_storage_half, open_appendable_memmap_vector_storage_int8,
 };
 use crate::vector_storage::dense::memmap_dense_vector_storage::{
-    open_memmap_vector_storage, open_memmap_vector_storage_byte, open_memmap_vector_storage_half,
+    open_memmap_vector_storage, open_memmap_vector_storage_bit, open_memmap_vector_storage_byte,
+    open_memmap_vector_storage_half, open_memmap_vector_storage_int8,
 };
 use crate::vector_storage::dense::simple_dense_vector_storage::{
-    open_simple_dense_byte_vector_storage, open_simple_dense_half_vector_storage,
+    open_simple_dense_bit_vector_storage, open_simple_dense_byte_vector_storage,
+    open_simple_dense_half_vector_storage, open_simple_dense_int8_vector_storage,
     open_simple_dense_vector_storage,
 };
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::{
     open_appendable_in_ram_multi_vector_storage, open_appendable_in_ram_multi_vector_storage_byte,
-    open_appendable_in_ram_multi_vector_storage_half, open_appendable_memmap_multi_vector_storage,
+    open_appendable_in_ram_multi_vector_storage_half,
+    open_appendable_in_ram_multi_vector_storage_int8, open_appendable_memmap_multi_vector_storage,
     open_appendable_memmap_multi_vector_storage_byte,
     open_appendable_memmap_multi_vector_storage_half,
+    open_appendable_memmap_multi_vector_storage_int8,
 };
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::{
     open_simple_multi_dense_vector_storage, open_simple_multi_dense_vector_storage_byte,
-    open_simple_multi_dense_vector_storage_half,
+    open_simple_multi_dense_vector_storage_half, open_simple_multi_dense_vector_storage_int8,
 };
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::sparse::mmap_sparse_vector_storage::MmapSparseVectorStorage;
@@ -101,6 +109,7 @@ pub(crate) fn open_vector_storage(
     vector_name: &VectorName,
 ) -> OperationResult<VectorStorageEnum> {
     let storage_element_type = vector_config.datatype.unwrap_or_default();
+    check_bit_vector_config(vector_config)?;
 
     match vector_config.storage_type {
         // In memory
@@ -125,6 +134,14 @@ pub(crate) fn open_vector_storage(
                         *multi_vec_config,
                         stopped,
                     ),
+                    VectorStorageDatatype::Int8 => open_simple_multi_dense_vector_storage_int8(
+                        database.clone(),
+                        &db_column_name,
+                        vector_config.size,
+                        vector_config.distance,
+                        *multi_vec_config,
+                        stopped,
+                    ),
                     VectorStorageDatatype::Float16 => open_simple_multi_dense_vector_storage_half(
                         database.clone(),
                         &db_column_name,
@@ -133,6 +150,7 @@ pub(crate) fn open_vector_storage(
                         *multi_vec_config,
                         stopped,
                     ),
+                    VectorStorageDatatype::Bit => Err(bit_multivector_error()),
                 }
             } else {
                 match storage_element_type {
@@ -150,6 +168,13 @@ pub(crate) fn open_vector_storage(
                         vector_config.distance,
                         stopped,
                     ),
+                    VectorStorageDatatype::Int8 => open_simple_dense_int8_vector_storage(
+                        database.clone(),
+                        &db_column_name,
+                        vector_config.size,
+                        vector_config.distance,
+                        stopped,
+                    ),
                     VectorStorageDatatype::Float16 => open_simple_dense_half_vector_storage(
                         database.clone(),
                         &db_column_name,
@@ -157,6 +182,13 @@ pub(crate) fn open_vector_storage(
                         vector_config.distance,
                         stopped,
                     ),
+                    VectorStorageDatatype::Bit => open_simple_dense_bit_vector_storage(
+                        database.clone(),
+                        &db_column_name,
+                        vector_config.size / PackedBits::BITS,
+                        vector_config.distance,
+                        stopped,
+                    ),
                 }
             }
         }
@@ -179,6 +211,14 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Int8 => {
+                        open_appendable_memmap_multi_vector_storage_int8(
+                            vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        )
+                    }
                     VectorStorageDatatype::Float16 => {
                         open_appendable_memmap_multi_vector_storage_half(
                             vector_storage_path,
@@ -187,6 +227,7 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Bit => Err(bit_multivector_error()),
                 }
             } else {
                 match storage_element_type {
@@ -200,11 +241,21 @@ pub(crate) fn open_vector_storage(
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Int8 => open_memmap_vector_storage_int8(
+                        vector_storage_path,
+                        vector_config.size,
+                        vector_config.distance,
+                    ),
                     VectorStorageDatatype::Float16 => open_memmap_vector_storage_half(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Bit => open_memmap_vector_storage_bit(
+                        vector_storage_path,
+                        vector_config.size / PackedBits::BITS,
+                        vector_config.distance,
+                    ),
                 }
             }
         }
@@ -226,6 +277,14 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Int8 => {
+                        open_appendable_memmap_multi_vector_storage_int8(
+                            vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        )
+                    }
                     VectorStorageDatatype::Float16 => {
                         open_appendable_memmap_multi_vector_storage_half(
                             vector_storage_path,
@@ -234,6 +293,7 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Bit => Err(bit_multivector_error()),
                 }
             } else {
                 match storage_element_type {
@@ -247,11 +307,21 @@ pub(crate) fn open_vector_storage(
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Int8 => open_appendable_memmap_vector_storage_int8(
+                        vector_storage_path,
+                        vector_config.size,
+                        vector_config.distance,
+                    ),
                     VectorStorageDatatype::Float16 => open_appendable_memmap_vector_storage_half(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Bit => open_appendable_memmap_vector_storage_bit(
+                        vector_storage_path,
+                        vector_config.size / PackedBits::BITS,
+                        vector_config.distance,
+                    ),
                 }
             }
         }
@@ -272,6 +342,14 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Int8 => {
+                        open_appendable_in_ram_multi_vector_storage_int8(
+                            vector_storage_path,
+                            vector_config.size,
+                            vector_config.distance,
+                            *multi_vec_config,
+                        )
+                    }
                     VectorStorageDatatype::Float16 => {
                         open_appendable_in_ram_multi_vector_storage_half(
                             vector_storage_path,
@@ -280,6 +358,7 @@ pub(crate) fn open_vector_storage(
                             *multi_vec_config,
                         )
                     }
+                    VectorStorageDatatype::Bit => Err(bit_multivector_error()),
                 }
             } else {
                 match storage_element_type {
@@ -293,17 +372,72 @@ pub(crate) fn open_vector_storage(
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Int8 => open_appendable_in_ram_vector_storage_int8(
+                        vector_storage_path,
+                        vector_config.size,
+                        vector_config.distance,
+                    ),
                     VectorStorageDatatype::Float16 => open_appendable_in_ram_vector_storage_half(
                         vector_storage_path,
                         vector_config.size,
                         vector_config.distance,
                     ),
+                    VectorStorageDatatype::Bit => open_appendable_in_ram_vector_storage_bit(
+                        vector_storage_path,
+                        vector_config.size / PackedBits::BITS,
+                        vector_config.distance,
+                    ),
                 }
             }
         }
     }
 }
 
+/// Bit vectors are packed into bytes and can only be compared bitwise
+fn check_bit_vector_config(vector_config: &VectorDataConfig) -> OperationResult<()> {
+    let is_bit_datatype = vector_config.datatype == Some(VectorStorageDatatype::Bit);
+
+    if vector_config.distance.is_bitwise() && !is_bit_datatype {
+        return Err(OperationError::ValidationError {
+            description: format!(
+                "{:?} distance is only supported for bit datatype",
+                vector_config.distance,
+            ),
+        });
+    }
+
+    if !is_bit_datatype {
+        return Ok(());
+    }
+
+    if !vector_config.distance.is_bitwise() {
+        return Err(OperationError::ValidationError {
+            description: format!(
+                "{:?} distance is not supported for bit datatype, use Hamming or Jaccard",
+                vector_config.distance,
+            ),
+        });
+    }
+
+    if vector_config.size % PackedBits::BITS != 0 {
+        return Err(OperationError::ValidationError {
+            description: format!(
+                "Size of bit vectors must be a multiple of {}, got {}",
+                PackedBits::BITS,
+                vector_config.size,
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+fn bit_multivector_error() -> OperationError {
+    OperationError::ValidationError {
+        description: "Multivectors are not supported for bit datatype".to_string(),
+    }
+}
+
 pub(crate) fn open_segment_db(
     segment_path: &Path,
     config: &SegmentConfig,
@@ -406,6 +540,24 @@ pub(crate) fn open_vector_index(
             payload_index,
             hnsw_config: hnsw_config.clone(),
         })?),
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IVFIndex::open(IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            ivf_config: ivf_config.clone(),
+        })?),
+        Indexes::Vamana(vamana_config) => {
+            VectorIndexEnum::Vamana(VamanaIndex::open(VamanaIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                vamana_config: vamana_config.clone(),
+            })?)
+        }
     })
 }
 
@@ -438,6 +590,28 @@ pub(crate) fn build_vector_index(
             },
             build_args,
         )?),
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IVFIndex::build(
+            IvfIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                ivf_config: ivf_config.clone(),
+            },
+            build_args,
+        )?),
+        Indexes::Vamana(vamana_config) => VectorIndexEnum::Vamana(VamanaIndex::build(
+            VamanaIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                vamana_config: vamana_config.clone(),
+            },
+            build_args,
+        )?),
     })
 }
 
@@ -461,6 +635,12 @@ pub(crate) fn create_sparse_vector_index(
                 description: format!("{a:?} datatype is not supported"),
             })?
         }
+        // Sparse vectors can't be bit-packed, and have no signed integer storage
+        (_, a @ (VectorStorageDatatype::Bit | VectorStorageDatatype::Int8), _) => {
+            Err(OperationError::ValidationError {
+                description: format!("{a:?} datatype is not supported"),
+            })?
+        }
 
         (SparseIndexType::MutableRam, _, _) => {
             VectorIndexEnum::SparseRam(SparseVectorIndex::open(args)?)
@@ -502,16 +682,17 @@ pub(crate) fn create_sparse_vector_storage(
     database: Arc<RwLock<DB>>,
     path: &Path,
     vector_name: &VectorName,
-    storage_type: &SparseVectorStorageType,
+    sparse_vector_config: &SparseVectorDataConfig,
     stopped: &AtomicBool,
 ) -> OperationResult<VectorStorageEnum> {
-    match storage_type {
+    let distance = sparse_vector_config.distance;
+    match sparse_vector_config.storage_type {
         SparseVectorStorageType::OnDisk => {
             let db_column_name = get_vector_name_with_prefix(DB_VECTOR_CF, vector_name);
-            open_simple_sparse_vector_storage(database, &db_column_name, stopped)
+            open_simple_sparse_vector_storage(database, &db_column_name, distance, stopped)
         }
         SparseVectorStorageType::Mmap => {
-            let mmap_storage = MmapSparseVectorStorage::open_or_create(path)?;
+            let mmap_storage = MmapSparseVectorStorage::open_or_create(path, distance)?;
             Ok(VectorStorageEnum::SparseMmap(mmap_storage))
         }
     }
@@ -570,7 +751,7 @@ fn create_segment(
             database.clone(),
             &vector_storage_path,
             vector_name,
-            &sparse_config.storage_type,
+            sparse_config,
             stopped,
         )?);
 
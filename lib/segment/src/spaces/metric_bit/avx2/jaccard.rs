@@ -0,0 +1,64 @@
+use std::arch::x86_64::*;
+
+use super::{hsum256_epi64, popcount256_epi64};
+use crate::spaces::metric_bit::simple_jaccard::jaccard_ratio;
+
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_jaccard_similarity_bits(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    // count accumulators for 4x64 bit integers
+    let mut intersection_acc = _mm256_setzero_si256();
+    let mut union_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes
+        let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+        let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        intersection_acc = _mm256_add_epi64(
+            intersection_acc,
+            popcount256_epi64(_mm256_and_si256(p1, p2)),
+        );
+        union_acc = _mm256_add_epi64(union_acc, popcount256_epi64(_mm256_or_si256(p1, p2)));
+    }
+
+    let mut intersection = hsum256_epi64(intersection_acc) as u32;
+    let mut union = hsum256_epi64(union_acc) as u32;
+
+    for _ in 0..len % 32 {
+        intersection += (*ptr1 & *ptr2).count_ones();
+        union += (*ptr1 | *ptr2).count_ones();
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+    }
+
+    jaccard_ratio(intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_bit::simple_jaccard::jaccard_similarity_bits;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx2") {
+            let v1: Vec<u8> = (0..100).map(|i| (i * 37 % 256) as u8).collect();
+            let v2: Vec<u8> = (0..100).map(|i| (i * 91 % 256) as u8).collect();
+
+            let jaccard_simd = unsafe { avx_jaccard_similarity_bits(&v1, &v2) };
+            let jaccard = jaccard_similarity_bits(&v1, &v2);
+            assert_eq!(jaccard_simd, jaccard);
+        } else {
+            println!("avx test skipped");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::arch::x86_64::*;
+
+pub mod hamming;
+pub mod jaccard;
+
+/// Number of set bits in each 64-bit lane of the vector.
+///
+/// Bits of each nibble are counted with a lookup table, see <http://0x80.pl/articles/sse-popcount.html>
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn popcount256_epi64(v: __m256i) -> __m256i {
+    let lookup = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, 0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3,
+        3, 4,
+    );
+    let low_mask = _mm256_set1_epi8(0x0f);
+
+    let low = _mm256_and_si256(v, low_mask);
+    let high = _mm256_and_si256(_mm256_srli_epi16(v, 4), low_mask);
+    let counts = _mm256_add_epi8(
+        _mm256_shuffle_epi8(lookup, low),
+        _mm256_shuffle_epi8(lookup, high),
+    );
+
+    // Horizontal sum of the byte counts into 64-bit lanes
+    _mm256_sad_epu8(counts, _mm256_setzero_si256())
+}
+
+/// Sum of the 64-bit lanes of the vector
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn hsum256_epi64(v: __m256i) -> u64 {
+    let sum128 = _mm_add_epi64(_mm256_castsi256_si128(v), _mm256_extracti128_si256(v, 1));
+    (_mm_cvtsi128_si64(sum128) + _mm_extract_epi64(sum128, 1)) as u64
+}
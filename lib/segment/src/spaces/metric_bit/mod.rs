@@ -0,0 +1,11 @@
+pub mod simple_hamming;
+pub mod simple_jaccard;
+
+#[cfg(target_arch = "x86_64")]
+pub mod avx2;
+
+#[cfg(target_arch = "aarch64")]
+pub mod neon;
+
+#[cfg(target_arch = "x86_64")]
+pub mod popcnt;
@@ -0,0 +1,55 @@
+use std::arch::aarch64::*;
+
+use crate::spaces::metric_bit::simple_jaccard::jaccard_ratio;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_jaccard_similarity_bits(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    let mut intersection: u32 = 0;
+    let mut union: u32 = 0;
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_u8(ptr1);
+        let p2 = vld1q_u8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        intersection += u32::from(vaddlvq_u8(vcntq_u8(vandq_u8(p1, p2))));
+        union += u32::from(vaddlvq_u8(vcntq_u8(vorrq_u8(p1, p2))));
+    }
+
+    for _ in 0..len % 16 {
+        intersection += (*ptr1 & *ptr2).count_ones();
+        union += (*ptr1 | *ptr2).count_ones();
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+    }
+
+    jaccard_ratio(intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_bit::simple_jaccard::jaccard_similarity_bits;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let v1: Vec<u8> = (0..100).map(|i| (i * 37 % 256) as u8).collect();
+            let v2: Vec<u8> = (0..100).map(|i| (i * 91 % 256) as u8).collect();
+
+            let jaccard_simd = unsafe { neon_jaccard_similarity_bits(&v1, &v2) };
+            let jaccard = jaccard_similarity_bits(&v1, &v2);
+            assert_eq!(jaccard_simd, jaccard);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
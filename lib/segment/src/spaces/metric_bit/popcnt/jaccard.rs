@@ -0,0 +1,54 @@
+use crate::spaces::metric_bit::simple_jaccard::jaccard_ratio;
+
+#[target_feature(enable = "popcnt")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn popcnt_jaccard_similarity_bits(v1: &[u8], v2: &[u8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("popcnt"));
+
+    let mut ptr1: *const u8 = v1.as_ptr();
+    let mut ptr2: *const u8 = v2.as_ptr();
+
+    let mut intersection = 0;
+    let mut union = 0;
+    let len = v1.len();
+    for _ in 0..len / 8 {
+        // load 8 bytes
+        let p1 = ptr1.cast::<u64>().read_unaligned();
+        let p2 = ptr2.cast::<u64>().read_unaligned();
+        ptr1 = ptr1.add(8);
+        ptr2 = ptr2.add(8);
+
+        intersection += (p1 & p2).count_ones();
+        union += (p1 | p2).count_ones();
+    }
+
+    for _ in 0..len % 8 {
+        intersection += (*ptr1 & *ptr2).count_ones();
+        union += (*ptr1 | *ptr2).count_ones();
+        ptr1 = ptr1.add(1);
+        ptr2 = ptr2.add(1);
+    }
+
+    jaccard_ratio(intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_bit::simple_jaccard::jaccard_similarity_bits;
+
+    #[test]
+    fn test_spaces_popcnt() {
+        if is_x86_feature_detected!("popcnt") {
+            let v1: Vec<u8> = (0..100).map(|i| (i * 37 % 256) as u8).collect();
+            let v2: Vec<u8> = (0..100).map(|i| (i * 91 % 256) as u8).collect();
+
+            let jaccard_simd = unsafe { popcnt_jaccard_similarity_bits(&v1, &v2) };
+            let jaccard = jaccard_similarity_bits(&v1, &v2);
+            assert_eq!(jaccard_simd, jaccard);
+        } else {
+            println!("popcnt test skipped");
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, PackedBits, VectorElementTypeBit};
+use crate::spaces::metric::{Metric, MetricPostProcessing};
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_bit::avx2::hamming::avx_hamming_similarity_bits;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_bit::neon::hamming::neon_hamming_similarity_bits;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_bit::popcnt::hamming::popcnt_hamming_similarity_bits;
+use crate::spaces::simple::HammingMetric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        let v1 = PackedBits::as_bytes(v1);
+        let v2 = PackedBits::as_bytes(v2);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { avx_hamming_similarity_bits(v1, v2) };
+            }
+
+            if is_x86_feature_detected!("popcnt") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { popcnt_hamming_similarity_bits(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_hamming_similarity_bits(v1, v2) };
+            }
+        }
+
+        hamming_similarity_bits(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+/// Negated number of differing bits
+pub fn hamming_similarity_bits(v1: &[u8], v2: &[u8]) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (a ^ b).count_ones())
+        .sum::<u32>() as ScoreType)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamming_similarity() {
+        let v1 = PackedBits::pack(&[1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0]);
+        let v2 = PackedBits::pack(&[1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(v1.len(), 2);
+
+        let score = <HammingMetric as Metric<VectorElementTypeBit>>::similarity(&v1, &v2);
+        assert_eq!(score, -3.0);
+        assert_eq!(HammingMetric::postprocess(score), 3.0);
+
+        let score = <HammingMetric as Metric<VectorElementTypeBit>>::similarity(&v1, &v1);
+        assert_eq!(score, 0.0);
+    }
+}
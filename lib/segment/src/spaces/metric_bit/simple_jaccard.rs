@@ -0,0 +1,106 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, PackedBits, VectorElementTypeBit};
+use crate::spaces::metric::{Metric, MetricPostProcessing};
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_bit::avx2::jaccard::avx_jaccard_similarity_bits;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_bit::neon::jaccard::neon_jaccard_similarity_bits;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_bit::popcnt::jaccard::popcnt_jaccard_similarity_bits;
+use crate::spaces::simple::JaccardMetric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(any(
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        let v1 = PackedBits::as_bytes(v1);
+        let v2 = PackedBits::as_bytes(v2);
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+                return unsafe { avx_jaccard_similarity_bits(v1, v2) };
+            }
+
+            if is_x86_feature_detected!("popcnt") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { popcnt_jaccard_similarity_bits(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_jaccard_similarity_bits(v1, v2) };
+            }
+        }
+
+        jaccard_similarity_bits(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for JaccardMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score
+    }
+}
+
+/// Ratio of the number of common set bits to the number of bits set in any of the vectors.
+///
+/// Two empty vectors are considered identical.
+pub fn jaccard_ratio(intersection: u32, union: u32) -> ScoreType {
+    if union == 0 {
+        1.0
+    } else {
+        intersection as ScoreType / union as ScoreType
+    }
+}
+
+pub fn jaccard_similarity_bits(v1: &[u8], v2: &[u8]) -> ScoreType {
+    let (intersection, union) = v1
+        .iter()
+        .zip(v2)
+        .fold((0, 0), |(intersection, union), (a, b)| {
+            (
+                intersection + (a & b).count_ones(),
+                union + (a | b).count_ones(),
+            )
+        });
+    jaccard_ratio(intersection, union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jaccard_similarity() {
+        let v1 = PackedBits::pack(&[1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0]);
+        let v2 = PackedBits::pack(&[1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+
+        // 3 common bits out of 6 set bits
+        let score = <JaccardMetric as Metric<VectorElementTypeBit>>::similarity(&v1, &v2);
+        assert_eq!(score, 0.5);
+
+        let score = <JaccardMetric as Metric<VectorElementTypeBit>>::similarity(&v1, &v1);
+        assert_eq!(score, 1.0);
+
+        let empty = PackedBits::pack(&[0.0; 16]);
+        let score = <JaccardMetric as Metric<VectorElementTypeBit>>::similarity(&empty, &empty);
+        assert_eq!(score, 1.0);
+    }
+}
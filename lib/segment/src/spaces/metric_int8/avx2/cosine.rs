@@ -0,0 +1,103 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_cosine_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulators for 8x32 bit integers
+    let mut dot_acc = _mm256_setzero_si256();
+    let mut norm1_acc = _mm256_setzero_si256();
+    let mut norm2_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes
+        let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+        let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        // sign-extend each 16x8 bit half into 16x16 bit integers
+        let p1_low = _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p1));
+        let p1_high = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p1, 1));
+        let p2_low = _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p2));
+        let p2_high = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p2, 1));
+
+        // calculate 16bit multiplications with adding pairs into 32 bit accumulators
+        norm1_acc = _mm256_add_epi32(norm1_acc, _mm256_madd_epi16(p1_low, p1_low));
+        norm2_acc = _mm256_add_epi32(norm2_acc, _mm256_madd_epi16(p2_low, p2_low));
+        dot_acc = _mm256_add_epi32(dot_acc, _mm256_madd_epi16(p1_low, p2_low));
+
+        norm1_acc = _mm256_add_epi32(norm1_acc, _mm256_madd_epi16(p1_high, p1_high));
+        norm2_acc = _mm256_add_epi32(norm2_acc, _mm256_madd_epi16(p2_high, p2_high));
+        dot_acc = _mm256_add_epi32(dot_acc, _mm256_madd_epi16(p1_high, p2_high));
+    }
+
+    // convert 8x32 bit integers into 8x32 bit floats and calculate horizontal sum
+    let mut dot_product = hsum256_ps_avx(_mm256_cvtepi32_ps(dot_acc));
+    let mut norm1 = hsum256_ps_avx(_mm256_cvtepi32_ps(norm1_acc));
+    let mut norm2 = hsum256_ps_avx(_mm256_cvtepi32_ps(norm2_acc));
+
+    let remainder = len % 32;
+    if remainder != 0 {
+        let mut remainder_dot_product = 0;
+        let mut remainder_norm1 = 0;
+        let mut remainder_norm2 = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_dot_product += i32::from(v1) * i32::from(v2);
+            remainder_norm1 += i32::from(v1) * i32::from(v1);
+            remainder_norm2 += i32::from(v2) * i32::from(v2);
+        }
+        dot_product += remainder_dot_product as f32;
+        norm1 += remainder_norm1 as f32;
+        norm2 += remainder_norm2 as f32;
+    }
+
+    let denominator = norm1 * norm2;
+    if denominator == 0.0 {
+        return 0.0;
+    }
+
+    dot_product / denominator.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_cosine::cosine_similarity_int8;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { avx_cosine_similarity_int8(&v1, &v2) };
+            let dot = cosine_similarity_int8(&v1, &v2);
+            assert!((dot_simd - dot).abs() < 1e-6);
+
+            let zero = vec![0; 100];
+            let dot_simd = unsafe { avx_cosine_similarity_int8(&zero, &v2) };
+            assert_eq!(dot_simd, 0.0);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
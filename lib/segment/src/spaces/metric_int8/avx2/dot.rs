@@ -0,0 +1,83 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_dot_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 8x32 bit integers
+    let mut dot_acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes
+        let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+        let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        // sign-extend each 16x8 bit half into 16x16 bit integers
+        let p1_low = _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p1));
+        let p1_high = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p1, 1));
+        let p2_low = _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p2));
+        let p2_high = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p2, 1));
+
+        // calculate 16bit multiplication with adding pairs into 32 bit accumulator
+        let dot_low = _mm256_madd_epi16(p1_low, p2_low);
+        dot_acc = _mm256_add_epi32(dot_acc, dot_low);
+
+        let dot_high = _mm256_madd_epi16(p1_high, p2_high);
+        dot_acc = _mm256_add_epi32(dot_acc, dot_high);
+    }
+
+    // convert 8x32 bit integers into 8x32 bit floats and calculate horizontal sum
+    let dot_ps = _mm256_cvtepi32_ps(dot_acc);
+    let mut score = hsum256_ps_avx(dot_ps);
+
+    let remainder = len % 32;
+    if remainder != 0 {
+        let mut remainder_dot = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_dot += i32::from(v1) * i32::from(v2);
+        }
+        score += remainder_dot as f32;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_dot::dot_similarity_int8;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { avx_dot_similarity_int8(&v1, &v2) };
+            let dot = dot_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
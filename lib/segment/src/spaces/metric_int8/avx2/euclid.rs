@@ -0,0 +1,88 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::simple_avx::hsum256_ps_avx;
+
+#[target_feature(enable = "avx")]
+#[target_feature(enable = "avx2")]
+#[target_feature(enable = "fma")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx_euclid_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx"));
+    debug_assert!(is_x86_feature_detected!("avx2"));
+    debug_assert!(is_x86_feature_detected!("fma"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 8x32 bit integers
+    let mut acc = _mm256_setzero_si256();
+    let len = v1.len();
+    for _ in 0..len / 32 {
+        // load 32 bytes
+        let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+        let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+        ptr1 = ptr1.add(32);
+        ptr2 = ptr2.add(32);
+
+        // the difference of two 8 bit integers always fits into 16 bits
+        let diff_low = _mm256_sub_epi16(
+            _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p1)),
+            _mm256_cvtepi8_epi16(_mm256_castsi256_si128(p2)),
+        );
+        let diff_high = _mm256_sub_epi16(
+            _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p1, 1)),
+            _mm256_cvtepi8_epi16(_mm256_extracti128_si256(p2, 1)),
+        );
+
+        // calculate 16bit multiplication with adding pairs into 32 bit accumulator
+        let mul16 = _mm256_madd_epi16(diff_low, diff_low);
+        acc = _mm256_add_epi32(acc, mul16);
+
+        let mul16 = _mm256_madd_epi16(diff_high, diff_high);
+        acc = _mm256_add_epi32(acc, mul16);
+    }
+
+    // convert 8x32 bit integers into 8x32 bit floats and calculate horizontal sum
+    let mul_ps = _mm256_cvtepi32_ps(acc);
+    let mut score = hsum256_ps_avx(mul_ps);
+
+    let remainder = len % 32;
+    if remainder != 0 {
+        let mut remainder_score = 0;
+        for _ in 0..remainder {
+            let v1 = i32::from(*ptr1);
+            let v2 = i32::from(*ptr2);
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            let diff = v1 - v2;
+            remainder_score += diff * diff;
+        }
+        score += remainder_score as f32;
+    }
+
+    -score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_euclid::euclid_similarity_int8;
+
+    #[test]
+    fn test_spaces_avx() {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("avx2")
+            && is_x86_feature_detected!("fma")
+        {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { avx_euclid_similarity_int8(&v1, &v2) };
+            let dot = euclid_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod cosine;
+pub mod dot;
+pub mod euclid;
@@ -0,0 +1,63 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_dot_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    let mut mul1 = vdupq_n_s32(0);
+    let mut mul2 = vdupq_n_s32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_s8(ptr1);
+        let p2 = vld1q_s8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // product of two 8 bit integers always fits into 16 bits
+        let mul_low = vmull_s8(vget_low_s8(p1), vget_low_s8(p2));
+        let mul_high = vmull_s8(vget_high_s8(p1), vget_high_s8(p2));
+        mul1 = vpadalq_s16(mul1, mul_low);
+        mul2 = vpadalq_s16(mul2, mul_high);
+    }
+    let mut score = vaddvq_s32(vaddq_s32(mul1, mul2)) as f32;
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_score = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_score += i32::from(v1) * i32::from(v2);
+        }
+        score += remainder_score as f32;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_int8::simple_dot::dot_similarity_int8;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { neon_dot_similarity_int8(&v1, &v2) };
+            let dot = dot_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::arch::aarch64::*;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_euclid_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    let mut mul1 = vdupq_n_u32(0);
+    let mut mul2 = vdupq_n_u32(0);
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        let p1 = vld1q_s8(ptr1);
+        let p2 = vld1q_s8(ptr2);
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // absolute difference of two signed 8 bit integers always fits into unsigned 8 bits
+        let abs_diff = vreinterpretq_u8_s8(vabdq_s8(p1, p2));
+        let abs_diff_low = vget_low_u8(abs_diff);
+        let abs_diff_high = vget_high_u8(abs_diff);
+
+        let mul_low = vmull_u8(abs_diff_low, abs_diff_low);
+        let mul_high = vmull_u8(abs_diff_high, abs_diff_high);
+
+        mul1 = vpadalq_u16(mul1, mul_low);
+        mul2 = vpadalq_u16(mul2, mul_high);
+    }
+    let mut score = vaddvq_u32(vaddq_u32(mul1, mul2)) as f32;
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_score = 0;
+        for _ in 0..remainder {
+            let v1 = i32::from(*ptr1);
+            let v2 = i32::from(*ptr2);
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            let diff = v1 - v2;
+            remainder_score += diff * diff;
+        }
+        score += remainder_score as f32;
+    }
+
+    -score
+}
+
+#[cfg(test)]
+mod tests {
+    use std::arch::is_aarch64_feature_detected;
+
+    use super::*;
+    use crate::spaces::metric_int8::simple_euclid::euclid_similarity_int8;
+
+    #[test]
+    fn test_spaces_neon() {
+        if is_aarch64_feature_detected!("neon") {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { neon_euclid_similarity_int8(&v1, &v2) };
+            let dot = euclid_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("neon test skipped");
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeInt8};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_int8::avx2::cosine::avx_cosine_similarity_int8;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_int8::neon::cosine::neon_cosine_similarity_int8;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::spaces::metric_int8::sse2::cosine::sse_cosine_similarity_int8;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+use crate::spaces::simple::{CosineMetric, MIN_DIM_SIZE_SIMD};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeInt8> for CosineMetric {
+    fn distance() -> Distance {
+        Distance::Cosine
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_cosine_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse")
+                && is_x86_feature_detected!("sse2")
+                && v1.len() >= MIN_DIM_SIZE_SIMD
+            {
+                return unsafe { sse_cosine_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_cosine_similarity_int8(v1, v2) };
+            }
+        }
+
+        cosine_similarity_int8(v1, v2)
+    }
+
+    /// Integer vectors can't be normalized in place, norms are computed on comparison
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn cosine_similarity_int8(
+    v1: &[VectorElementTypeInt8],
+    v2: &[VectorElementTypeInt8],
+) -> ScoreType {
+    let mut dot_product = 0;
+    let mut norm1 = 0;
+    let mut norm2 = 0;
+
+    for (a, b) in v1.iter().zip(v2) {
+        dot_product += i32::from(*a) * i32::from(*b);
+        norm1 += i32::from(*a) * i32::from(*a);
+        norm2 += i32::from(*b) * i32::from(*b);
+    }
+
+    if norm1 == 0 || norm2 == 0 {
+        return 0.0;
+    }
+
+    dot_product as ScoreType / (norm1 as ScoreType * norm2 as ScoreType).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity_int8() {
+        let v1: Vec<i8> = vec![3, -4, 0, 0, 0, 0, 0, 0];
+        let v2: Vec<i8> = vec![-6, 8, 0, 0, 0, 0, 0, 0];
+
+        // Opposite directions
+        assert!((cosine_similarity_int8(&v1, &v2) + 1.0).abs() < 1e-6);
+        assert!((cosine_similarity_int8(&v1, &v1) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zero() {
+        let v1: Vec<i8> = vec![0, 0, 0, 0, 0, 0, 0, 0];
+        let v2: Vec<i8> = vec![-128, 127, 0, -2, 3, -4, 5, -6];
+
+        assert_eq!(cosine_similarity_int8(&v1, &v2), 0.0);
+        assert_eq!(cosine_similarity_int8(&v2, &v1), 0.0);
+        assert_eq!(cosine_similarity_int8(&v1, &v1), 0.0);
+    }
+}
@@ -0,0 +1,83 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeInt8};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_int8::avx2::dot::avx_dot_similarity_int8;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_int8::neon::dot::neon_dot_similarity_int8;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::spaces::metric_int8::sse2::dot::sse_dot_similarity_int8;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+use crate::spaces::simple::{DotProductMetric, MIN_DIM_SIZE_SIMD};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeInt8> for DotProductMetric {
+    fn distance() -> Distance {
+        Distance::Dot
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_dot_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse")
+                && is_x86_feature_detected!("sse2")
+                && v1.len() >= MIN_DIM_SIZE_SIMD
+            {
+                return unsafe { sse_dot_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_dot_similarity_int8(v1, v2) };
+            }
+        }
+
+        dot_similarity_int8(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn dot_similarity_int8(
+    v1: &[VectorElementTypeInt8],
+    v2: &[VectorElementTypeInt8],
+) -> ScoreType {
+    let mut dot_product = 0;
+
+    for (a, b) in v1.iter().zip(v2) {
+        dot_product += i32::from(*a) * i32::from(*b);
+    }
+
+    dot_product as ScoreType
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_similarity_int8() {
+        let v1: Vec<i8> = vec![-128, 127, -1, 0, 5];
+        let v2: Vec<i8> = vec![-128, -128, 1, 100, -3];
+
+        let score = <DotProductMetric as Metric<VectorElementTypeInt8>>::similarity(&v1, &v2);
+        assert_eq!(score, (16384 - 16256 - 1 - 15) as ScoreType);
+    }
+}
@@ -0,0 +1,101 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeInt8};
+use crate::spaces::metric::Metric;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_int8::avx2::euclid::avx_euclid_similarity_int8;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_int8::neon::euclid::neon_euclid_similarity_int8;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::spaces::metric_int8::sse2::euclid::sse_euclid_similarity_int8;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+use crate::spaces::simple::{EuclidMetric, MIN_DIM_SIZE_SIMD};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeInt8> for EuclidMetric {
+    fn distance() -> Distance {
+        Distance::Euclid
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx")
+                && is_x86_feature_detected!("avx2")
+                && is_x86_feature_detected!("fma")
+                && v1.len() >= MIN_DIM_SIZE_AVX
+            {
+                return unsafe { avx_euclid_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("sse")
+                && is_x86_feature_detected!("sse2")
+                && v1.len() >= MIN_DIM_SIZE_SIMD
+            {
+                return unsafe { sse_euclid_similarity_int8(v1, v2) };
+            }
+        }
+
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+                return unsafe { neon_euclid_similarity_int8(v1, v2) };
+            }
+        }
+
+        euclid_similarity_int8(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn euclid_similarity_int8(
+    v1: &[VectorElementTypeInt8],
+    v2: &[VectorElementTypeInt8],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| {
+            let diff = i32::from(*a) - i32::from(*b);
+            diff * diff
+        })
+        .sum::<i32>() as ScoreType
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::data_types::primitive::PrimitiveVectorElement;
+    use crate::data_types::vectors::{TypedDenseVector, VectorElementType};
+
+    #[test]
+    fn test_conversion_to_int8() {
+        let dense_vector = DenseVector::from(vec![-300.0, -128.0, -1.0, 0.0, 2.0, 127.0, 200.0]);
+        let preprocessed_vector =
+            <EuclidMetric as Metric<VectorElementType>>::preprocess(dense_vector);
+        let typed_dense_vector =
+            VectorElementTypeInt8::slice_from_float_cow(Cow::from(preprocessed_vector));
+        let expected: TypedDenseVector<VectorElementTypeInt8> =
+            vec![-128, -128, -1, 0, 2, 127, 127];
+        assert_eq!(typed_dense_vector, expected);
+    }
+
+    #[test]
+    fn test_euclid_similarity_int8() {
+        let v1: Vec<i8> = vec![-128, 127, 0];
+        let v2: Vec<i8> = vec![127, -128, 3];
+
+        assert_eq!(
+            euclid_similarity_int8(&v1, &v2),
+            -(2 * 255 * 255 + 9) as ScoreType
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeInt8};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::ManhattanMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeInt8> for ManhattanMetric {
+    fn distance() -> Distance {
+        Distance::Manhattan
+    }
+
+    fn similarity(v1: &[VectorElementTypeInt8], v2: &[VectorElementTypeInt8]) -> ScoreType {
+        manhattan_similarity_int8(v1, v2)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+pub fn manhattan_similarity_int8(
+    v1: &[VectorElementTypeInt8],
+    v2: &[VectorElementTypeInt8],
+) -> ScoreType {
+    -v1.iter()
+        .zip(v2)
+        .map(|(a, b)| (i32::from(*a) - i32::from(*b)).abs())
+        .sum::<i32>() as ScoreType
+}
@@ -0,0 +1,96 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::metric_int8::sse2::cvtepi8_epi16_sse2;
+use crate::spaces::simple_sse::hsum128_ps_sse;
+
+#[target_feature(enable = "sse")]
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_cosine_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse"));
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulators for 4x32 bit integers
+    let mut dot_acc = _mm_setzero_si128();
+    let mut norm1_acc = _mm_setzero_si128();
+    let mut norm2_acc = _mm_setzero_si128();
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        // load 16 bytes
+        let p1 = _mm_loadu_si128(ptr1.cast::<__m128i>());
+        let p2 = _mm_loadu_si128(ptr2.cast::<__m128i>());
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        let (p1_low, p1_high) = cvtepi8_epi16_sse2(p1);
+        let (p2_low, p2_high) = cvtepi8_epi16_sse2(p2);
+
+        // calculate 16bit multiplications with adding pairs into 32 bit accumulators
+        norm1_acc = _mm_add_epi32(norm1_acc, _mm_madd_epi16(p1_low, p1_low));
+        norm2_acc = _mm_add_epi32(norm2_acc, _mm_madd_epi16(p2_low, p2_low));
+        dot_acc = _mm_add_epi32(dot_acc, _mm_madd_epi16(p1_low, p2_low));
+
+        norm1_acc = _mm_add_epi32(norm1_acc, _mm_madd_epi16(p1_high, p1_high));
+        norm2_acc = _mm_add_epi32(norm2_acc, _mm_madd_epi16(p2_high, p2_high));
+        dot_acc = _mm_add_epi32(dot_acc, _mm_madd_epi16(p1_high, p2_high));
+    }
+
+    // convert 4x32 bit integers into 4x32 bit floats and calculate horizontal sum
+    let mut dot_product = hsum128_ps_sse(_mm_cvtepi32_ps(dot_acc));
+    let mut norm1 = hsum128_ps_sse(_mm_cvtepi32_ps(norm1_acc));
+    let mut norm2 = hsum128_ps_sse(_mm_cvtepi32_ps(norm2_acc));
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_dot_product = 0;
+        let mut remainder_norm1 = 0;
+        let mut remainder_norm2 = 0;
+        for _ in 0..remainder {
+            let v1 = *ptr1;
+            let v2 = *ptr2;
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            remainder_dot_product += i32::from(v1) * i32::from(v2);
+            remainder_norm1 += i32::from(v1) * i32::from(v1);
+            remainder_norm2 += i32::from(v2) * i32::from(v2);
+        }
+        dot_product += remainder_dot_product as f32;
+        norm1 += remainder_norm1 as f32;
+        norm2 += remainder_norm2 as f32;
+    }
+
+    let denominator = norm1 * norm2;
+    if denominator == 0.0 {
+        return 0.0;
+    }
+
+    dot_product / denominator.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_cosine::cosine_similarity_int8;
+
+    #[test]
+    fn test_spaces_sse() {
+        if is_x86_feature_detected!("sse2") && is_x86_feature_detected!("sse") {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { sse_cosine_similarity_int8(&v1, &v2) };
+            let dot = cosine_similarity_int8(&v1, &v2);
+            assert!((dot_simd - dot).abs() < 1e-6);
+
+            let zero = vec![0; 100];
+            let dot_simd = unsafe { sse_cosine_similarity_int8(&zero, &v2) };
+            assert_eq!(dot_simd, 0.0);
+        } else {
+            println!("sse2 test skipped");
+        }
+    }
+}
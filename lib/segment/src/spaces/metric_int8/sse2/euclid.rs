@@ -0,0 +1,80 @@
+use std::arch::x86_64::*;
+
+use crate::spaces::metric_int8::sse2::cvtepi8_epi16_sse2;
+use crate::spaces::simple_sse::hsum128_ps_sse;
+
+#[target_feature(enable = "sse")]
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sse_euclid_similarity_int8(v1: &[i8], v2: &[i8]) -> f32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("sse"));
+    debug_assert!(is_x86_feature_detected!("sse2"));
+
+    let mut ptr1: *const i8 = v1.as_ptr();
+    let mut ptr2: *const i8 = v2.as_ptr();
+
+    // sum accumulator for 4x32 bit integers
+    let mut acc = _mm_setzero_si128();
+    let len = v1.len();
+    for _ in 0..len / 16 {
+        // load 16 bytes
+        let p1 = _mm_loadu_si128(ptr1.cast::<__m128i>());
+        let p2 = _mm_loadu_si128(ptr2.cast::<__m128i>());
+        ptr1 = ptr1.add(16);
+        ptr2 = ptr2.add(16);
+
+        // the difference of two 8 bit integers always fits into 16 bits
+        let (p1_low, p1_high) = cvtepi8_epi16_sse2(p1);
+        let (p2_low, p2_high) = cvtepi8_epi16_sse2(p2);
+        let diff_low = _mm_sub_epi16(p1_low, p2_low);
+        let diff_high = _mm_sub_epi16(p1_high, p2_high);
+
+        // calculate 16bit multiplication with adding pairs into 32 bit accumulator
+        let mul16 = _mm_madd_epi16(diff_low, diff_low);
+        acc = _mm_add_epi32(acc, mul16);
+
+        let mul16 = _mm_madd_epi16(diff_high, diff_high);
+        acc = _mm_add_epi32(acc, mul16);
+    }
+
+    // convert 4x32 bit integers into 4x32 bit floats and calculate horizontal sum
+    let mul_ps = _mm_cvtepi32_ps(acc);
+    let mut score = hsum128_ps_sse(mul_ps);
+
+    let remainder = len % 16;
+    if remainder != 0 {
+        let mut remainder_score = 0;
+        for _ in 0..remainder {
+            let v1 = i32::from(*ptr1);
+            let v2 = i32::from(*ptr2);
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+            let diff = v1 - v2;
+            remainder_score += diff * diff;
+        }
+        score += remainder_score as f32;
+    }
+
+    -score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spaces::metric_int8::simple_euclid::euclid_similarity_int8;
+
+    #[test]
+    fn test_spaces_sse() {
+        if is_x86_feature_detected!("sse2") && is_x86_feature_detected!("sse") {
+            let v1: Vec<i8> = (0..100).map(|i| (i * 37 % 256 - 128) as i8).collect();
+            let v2: Vec<i8> = (0..100).map(|i| (i * 91 % 256 - 128) as i8).collect();
+
+            let dot_simd = unsafe { sse_euclid_similarity_int8(&v1, &v2) };
+            let dot = euclid_similarity_int8(&v1, &v2);
+            assert_eq!(dot_simd, dot);
+        } else {
+            println!("sse2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use std::arch::x86_64::*;
+
+pub mod cosine;
+pub mod dot;
+pub mod euclid;
+
+/// Sign-extend the lower and the upper halves of 16x8 bit integers into 8x16 bit integers.
+///
+/// Each byte is duplicated into both bytes of a 16 bit lane, and shifted back with the sign.
+#[target_feature(enable = "sse2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn cvtepi8_epi16_sse2(v: __m128i) -> (__m128i, __m128i) {
+    (
+        _mm_srai_epi16(_mm_unpacklo_epi8(v, v), 8),
+        _mm_srai_epi16(_mm_unpackhi_epi8(v, v), 8),
+    )
+}
@@ -33,6 +33,12 @@ pub struct EuclidMetric;
 #[derive(Clone)]
 pub struct ManhattanMetric;
 
+#[derive(Clone)]
+pub struct HammingMetric;
+
+#[derive(Clone)]
+pub struct JaccardMetric;
+
 impl Metric<VectorElementType> for EuclidMetric {
     fn distance() -> Distance {
         Distance::Euclid
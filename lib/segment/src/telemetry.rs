@@ -51,6 +51,12 @@ pub struct VectorIndexSearchesTelemetry {
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub unfiltered_sparse: OperationDurationStatistics,
 
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub unfiltered_ivf: OperationDurationStatistics,
+
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub unfiltered_vamana: OperationDurationStatistics,
+
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub filtered_plain: OperationDurationStatistics,
 
@@ -66,8 +72,22 @@ pub struct VectorIndexSearchesTelemetry {
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub filtered_sparse: OperationDurationStatistics,
 
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub filtered_ivf: OperationDurationStatistics,
+
+    #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
+    pub filtered_vamana: OperationDurationStatistics,
+
     #[serde(skip_serializing_if = "OperationDurationStatistics::is_empty")]
     pub unfiltered_exact: OperationDurationStatistics,
+
+    /// Fraction of graph links, which lead to deleted points
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_links_ratio: Option<f64>,
+
+    /// Fraction of sparse postings, which were dropped by static pruning
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pruned_postings_ratio: Option<f64>,
 }
 
 impl Anonymize for SegmentTelemetry {
@@ -149,6 +169,7 @@ impl Anonymize for SparseVectorDataConfig {
         SparseVectorDataConfig {
             index: self.index.anonymize(),
             storage_type: self.storage_type,
+            distance: self.distance,
         }
     }
 }
@@ -160,12 +181,18 @@ impl Anonymize for VectorIndexSearchesTelemetry {
             unfiltered_plain: self.unfiltered_plain.anonymize(),
             unfiltered_hnsw: self.unfiltered_hnsw.anonymize(),
             unfiltered_sparse: self.unfiltered_sparse.anonymize(),
+            unfiltered_ivf: self.unfiltered_ivf.anonymize(),
+            unfiltered_vamana: self.unfiltered_vamana.anonymize(),
             filtered_plain: self.filtered_plain.anonymize(),
             filtered_small_cardinality: self.filtered_small_cardinality.anonymize(),
             filtered_large_cardinality: self.filtered_large_cardinality.anonymize(),
             filtered_exact: self.filtered_exact.anonymize(),
             filtered_sparse: self.filtered_sparse.anonymize(),
+            filtered_ivf: self.filtered_ivf.anonymize(),
+            filtered_vamana: self.filtered_vamana.anonymize(),
             unfiltered_exact: self.filtered_exact.anonymize(),
+            dead_links_ratio: self.dead_links_ratio,
+            pruned_postings_ratio: self.pruned_postings_ratio,
         }
     }
 }
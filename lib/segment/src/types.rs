@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{self, Display, Formatter};
 use std::hash::Hash;
-use std::ops::Deref;
+use std::ops::{Bound, Deref};
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -36,7 +36,9 @@ use crate::index::field_index::CardinalityEstimation;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::json_path::JsonPath;
 use crate::spaces::metric::MetricPostProcessing;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 
 pub type PayloadKeyType = JsonPath;
 pub type PayloadKeyTypeRef<'a> = &'a JsonPath;
@@ -222,6 +224,10 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>
+    Jaccard,
 }
 
 impl Distance {
@@ -231,13 +237,34 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
+        }
+    }
+
+    /// Inverse of [`Distance::postprocess_score`], brings a displayed score into the scale of internal scores
+    pub fn preprocess_score(&self, score: ScoreType) -> ScoreType {
+        match self {
+            Distance::Cosine | Distance::Dot | Distance::Jaccard => score,
+            // Internal score is the negated squared distance
+            Distance::Euclid => -(score * score),
+            // Internal score is the negated distance
+            Distance::Manhattan | Distance::Hamming => -score.abs(),
+        }
+    }
+
+    /// Whether the distance is only defined for bit vectors
+    pub fn is_bitwise(&self) -> bool {
+        match self {
+            Distance::Hamming | Distance::Jaccard => true,
+            Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => false,
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
-            Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Cosine | Distance::Dot | Distance::Jaccard => Order::LargeBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming => Order::SmallBetter,
         }
     }
 
@@ -412,6 +439,16 @@ pub struct SearchParams {
     /// Size of the beam in a beam-search. Larger the value - more accurate the result, more time required for search.
     pub hnsw_ef: Option<usize>,
 
+    /// Params relevant to IVF index
+    /// Number of clusters to scan. Larger the value - more accurate the result, more time required for search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ivf_nprobe: Option<usize>,
+
+    /// Params relevant to Vamana index
+    /// Size of the candidate list in a beam-search. Larger the value - more accurate the result, more disk reads required for search.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vamana_search_list: Option<usize>,
+
     /// Search without approximation. If set to true, search may run long but with exact results.
     #[serde(default)]
     pub exact: bool,
@@ -426,6 +463,22 @@ pub struct SearchParams {
     /// guarantee that all uploaded vectors will be included in search results
     #[serde(default)]
     pub indexed_only: bool,
+
+    /// Range search: return all points within the `score_threshold` of the request, best first,
+    /// page by page instead of a fixed number of nearest candidates. `limit` is the size of a page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<RangeSearchParams>,
+}
+
+/// Params of the range search.
+///
+/// Points with equal scores are never split between pages, so a page may contain more than `limit` points.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct RangeSearchParams {
+    /// Score of the last point of the previous page, only points with a worse score are returned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<ScoreType>,
 }
 
 /// Collection default values
@@ -491,6 +544,16 @@ pub enum Indexes {
     /// Use filterable HNSW index for approximate search. Is very fast even on a very huge collections,
     /// but require additional space to store index and additional time to build it.
     Hnsw(HnswConfig),
+    /// Use IVF (inverted file) index for approximate search. Vectors are clustered and only the
+    /// clusters closest to the query are scanned. Much cheaper to build than HNSW and has
+    /// predictable memory usage, but search is slower for the same precision.
+    /// Clusters hold point ids only, the scanned vectors are scored with the quantization of the
+    /// collection, if configured.
+    Ivf(IvfConfig),
+    /// Use disk-resident Vamana graph index for approximate search. Graph links are stored on disk
+    /// next to full-precision vectors, only quantized vectors are kept in RAM for navigation.
+    /// Suitable for very large collections with a small RAM budget.
+    Vamana(VamanaConfig),
 }
 
 impl Indexes {
@@ -498,6 +561,8 @@ impl Indexes {
         match self {
             Indexes::Plain {} => false,
             Indexes::Hnsw(_) => true,
+            Indexes::Ivf(_) => true,
+            Indexes::Vamana(_) => true,
         }
     }
 }
@@ -552,6 +617,103 @@ impl HnswConfig {
     }
 }
 
+/// Config of IVF index
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct IvfConfig {
+    /// Number of clusters the vectors are partitioned into.
+    /// If 0 - selected automatically as the square root of the number of vectors.
+    #[serde(default)]
+    pub nlist: usize,
+    /// Number of clusters closest to the query, which are scanned during the search.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[serde(default = "default_ivf_nprobe")]
+    #[validate(range(min = 1))]
+    pub nprobe: usize,
+}
+
+impl IvfConfig {
+    /// Detect configuration mismatch against `other` that requires rebuilding
+    ///
+    /// Only the number of clusters affects the built index,
+    /// `nprobe` is applied during the search.
+    pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
+        self.nlist != other.nlist
+    }
+}
+
+impl Default for IvfConfig {
+    fn default() -> Self {
+        IvfConfig {
+            nlist: 0,
+            nprobe: default_ivf_nprobe(),
+        }
+    }
+}
+
+const fn default_ivf_nprobe() -> usize {
+    16
+}
+
+/// Config of Vamana index
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct VamanaConfig {
+    /// Maximal number of edges per node in the graph. Larger the value - more accurate the search, more space required on disk.
+    #[serde(default = "default_vamana_max_degree")]
+    #[validate(range(min = 1))]
+    pub max_degree: usize,
+    /// Size of the candidate list during the index building. Larger the value - more accurate the search, more time required to build index.
+    #[serde(default = "default_vamana_build_search_list")]
+    #[validate(range(min = 1))]
+    pub build_search_list: usize,
+    /// Default size of the candidate list during the search.
+    /// Larger the value - more accurate the result, more disk reads required for search.
+    #[serde(default = "default_vamana_search_list")]
+    #[validate(range(min = 1))]
+    pub search_list: usize,
+    /// Number of graph nodes, read from disk at once during the search.
+    #[serde(default = "default_vamana_beam_width")]
+    #[validate(range(min = 1))]
+    pub beam_width: usize,
+}
+
+impl VamanaConfig {
+    /// Detect configuration mismatch against `other` that requires rebuilding
+    ///
+    /// `search_list` and `beam_width` are applied during the search.
+    pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
+        self.max_degree != other.max_degree || self.build_search_list != other.build_search_list
+    }
+}
+
+impl Default for VamanaConfig {
+    fn default() -> Self {
+        VamanaConfig {
+            max_degree: default_vamana_max_degree(),
+            build_search_list: default_vamana_build_search_list(),
+            search_list: default_vamana_search_list(),
+            beam_width: default_vamana_beam_width(),
+        }
+    }
+}
+
+const fn default_vamana_max_degree() -> usize {
+    64
+}
+
+const fn default_vamana_build_search_list() -> usize {
+    128
+}
+
+const fn default_vamana_search_list() -> usize {
+    100
+}
+
+const fn default_vamana_beam_width() -> usize {
+    4
+}
+
 const fn default_max_indexing_threads() -> usize {
     0
 }
@@ -571,6 +733,8 @@ pub enum CompressionRatio {
 pub enum ScalarType {
     #[default]
     Int8,
+    Int4,
+    Int2,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq)]
@@ -578,6 +742,8 @@ pub enum ScalarType {
 pub struct ScalarQuantizationConfig {
     /// Type of quantization to use
     /// If `int8` - 8 bit quantization will be used
+    /// If `int4` - 4 bit quantization will be used, two dimensions are packed into one byte
+    /// If `int2` - 2 bit quantization will be used, four dimensions are packed into one byte
     pub r#type: ScalarType,
     /// Quantile for quantization. Expected value range in [0.5, 1.0]. If not set - use the whole range of values
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -612,6 +778,12 @@ pub struct ProductQuantizationConfig {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+
+    /// Learn an orthogonal rotation of vectors before product quantization (OPQ).
+    /// Reduces quantization error on data with correlated dimensions,
+    /// but makes quantization slower. Not applied for Manhattan distance. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opq: Option<bool>,
 }
 
 impl ProductQuantizationConfig {
@@ -640,11 +812,46 @@ impl std::hash::Hash for ScalarQuantizationConfig {
 
 impl Eq for ScalarQuantizationConfig {}
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationEncoding {
+    /// One bit per dimension
+    #[default]
+    OneBit,
+    /// Two bits per dimension, which distinguish three levels of the value
+    TwoBits,
+    /// One bit per dimension and one extra bit per each pair of dimensions
+    OneAndHalfBits,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryQuantizationQueryEncoding {
+    /// Query is encoded the same way as the stored vectors
+    #[default]
+    Default,
+    /// Query is quantized into 4 bits per stored bit
+    #[serde(rename = "scalar4bits")]
+    Scalar4Bits,
+    /// Query is quantized into 8 bits per stored bit
+    #[serde(rename = "scalar8bits")]
+    Scalar8Bits,
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub struct BinaryQuantizationConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+    /// Number of bits used to encode each dimension.
+    /// More bits preserve more information at the cost of memory. Default: `one_bit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<BinaryQuantizationEncoding>,
+    /// Encoding of the query.
+    /// Asymmetric encodings keep the query in higher precision than the stored vectors,
+    /// which improves accuracy of the search. Default: same as the stored vectors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_encoding: Option<BinaryQuantizationQueryEncoding>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Eq, Hash)]
@@ -1030,6 +1237,10 @@ pub enum VectorStorageDatatype {
     Float16,
     // Unsigned 8-bit integer
     Uint8,
+    // Single bit, 8 dimensions are packed into a byte
+    Bit,
+    // Signed 8-bit integer
+    Int8,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
@@ -1042,8 +1253,17 @@ pub struct MultiVectorConfig {
 #[derive(Debug, Default, Deserialize, Serialize, JsonSchema, Eq, PartialEq, Copy, Clone, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum MultiVectorComparator {
+    /// Sum of the best similarities of each query vector
     #[default]
     MaxSim,
+    /// Same as `MaxSim`, but the best similarity of each query vector is multiplied by its weight.
+    /// Queries without weights are scored as with `MaxSim`.
+    WeightedMaxSim,
+    /// Symmetric Chamfer similarity: mean of best similarities in both directions, averaged
+    Chamfer,
+    /// Similarity of the mean-pooled vectors.
+    /// With quantization, only `Dot` distance is supported.
+    MeanPool,
 }
 
 impl VectorStorageType {
@@ -1085,7 +1305,7 @@ impl VectorDataConfig {
     pub fn is_appendable(&self) -> bool {
         let is_index_appendable = match self.index {
             Indexes::Plain {} => true,
-            Indexes::Hnsw(_) => false,
+            Indexes::Hnsw(_) | Indexes::Ivf(_) | Indexes::Vamana(_) => false,
         };
         let is_storage_appendable = match self.storage_type {
             VectorStorageType::Memory => true,
@@ -1109,6 +1329,26 @@ pub enum SparseVectorStorageType {
     Mmap,
 }
 
+/// Similarity function used to compare sparse vectors
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SparseDistance {
+    /// Plain dot product of the vector values
+    #[default]
+    Dot,
+    /// Dot product divided by the norms of both vectors.
+    /// Norms of the stored vectors are precomputed on insertion.
+    Cosine,
+}
+
+impl From<SparseDistance> for Distance {
+    fn from(distance: SparseDistance) -> Self {
+        match distance {
+            SparseDistance::Dot => Distance::Dot,
+            SparseDistance::Cosine => Distance::Cosine,
+        }
+    }
+}
+
 /// Config of single sparse vector data storage
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Validate)]
 #[serde(rename_all = "snake_case")]
@@ -1119,6 +1359,10 @@ pub struct SparseVectorDataConfig {
     /// Type of storage this sparse vector uses
     #[serde(default = "default_sparse_vector_storage_type_when_not_in_config")]
     pub storage_type: SparseVectorStorageType,
+
+    /// Similarity function used to score this sparse vector
+    #[serde(default)]
+    pub distance: SparseDistance,
 }
 
 /// If the storage type is not in config, it means it is the OnDisk variant
@@ -1672,6 +1916,127 @@ impl From<String> for MatchText {
     }
 }
 
+/// Full-text match of the phrase. Tokens of the phrase must occur in the same order.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPhrase {
+    pub phrase: String,
+    /// Maximum number of other tokens allowed between the phrase tokens in total. At most 64.
+    /// Default: 0 - tokens must be adjacent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slop: Option<u32>,
+}
+
+impl MatchPhrase {
+    pub fn slop(&self) -> u32 {
+        self.slop.unwrap_or(0)
+    }
+}
+
+/// Maximum number of other tokens allowed between the tokens of a phrase match
+pub const MAX_PHRASE_SLOP: u32 = 64;
+
+/// Maximum number of edits allowed in fuzzy matching
+pub const MAX_FUZZY_EDITS: u8 = 2;
+
+/// Typo-tolerant match of the text.
+///
+/// For full-text index, each token of the text must match some token of the document.
+/// For keyword index, the whole text is matched against the keywords.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchFuzzy {
+    pub fuzzy: String,
+    /// Maximum number of edits (insertions, deletions or substitutions of a character)
+    /// to consider a word matching. At most 2.
+    /// Default: depends on the length of the word - 0 for up to 2 characters,
+    /// 1 for up to 5 characters, and 2 for longer words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_edits: Option<u8>,
+}
+
+impl MatchFuzzy {
+    /// Maximum number of edits allowed for the given word of the query
+    pub fn max_edits(&self, word: &str) -> u8 {
+        match self.max_edits {
+            Some(max_edits) => max_edits,
+            None => match word.chars().count() {
+                0..=2 => 0,
+                3..=5 => 1,
+                _ => MAX_FUZZY_EDITS,
+            },
+        }
+    }
+}
+
+/// Match of strings, starting with the given prefix. Case-sensitive.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchPrefix {
+    pub prefix: String,
+}
+
+/// Match of strings, containing the given substring. Case-sensitive.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchSubstring {
+    pub substring: String,
+}
+
+/// Match of strings by a regular expression.
+/// The expression is not anchored, use `^` and `$` to match the whole string.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct MatchRegex {
+    #[schemars(with = "String")]
+    pub regex: RegexPattern,
+}
+
+/// Maximum size of a compiled regular expression, to prevent excessive memory usage
+const REGEX_SIZE_LIMIT: usize = 1024 * 1024;
+
+/// Regular expression, compiled once when the request is parsed
+#[derive(Debug, Clone)]
+pub struct RegexPattern(regex::Regex);
+
+impl RegexPattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::RegexBuilder::new(pattern)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+            .map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+}
+
+impl PartialEq for RegexPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for RegexPattern {}
+
+impl Serialize for RegexPattern {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RegexPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Exact match on any of the given values
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -1692,6 +2057,11 @@ pub struct MatchExcept {
 pub enum MatchInterface {
     Value(MatchValue),
     Text(MatchText),
+    Phrase(MatchPhrase),
+    Fuzzy(MatchFuzzy),
+    Prefix(MatchPrefix),
+    Substring(MatchSubstring),
+    Regex(MatchRegex),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -1702,6 +2072,11 @@ pub enum MatchInterface {
 pub enum Match {
     Value(MatchValue),
     Text(MatchText),
+    Phrase(MatchPhrase),
+    Fuzzy(MatchFuzzy),
+    Prefix(MatchPrefix),
+    Substring(MatchSubstring),
+    Regex(MatchRegex),
     Any(MatchAny),
     Except(MatchExcept),
 }
@@ -1715,6 +2090,36 @@ impl Match {
         Self::Text(MatchText { text: text.into() })
     }
 
+    pub fn new_phrase(phrase: &str, slop: Option<u32>) -> Self {
+        Self::Phrase(MatchPhrase {
+            phrase: phrase.into(),
+            slop,
+        })
+    }
+
+    pub fn new_fuzzy(fuzzy: &str, max_edits: Option<u8>) -> Self {
+        Self::Fuzzy(MatchFuzzy {
+            fuzzy: fuzzy.into(),
+            max_edits,
+        })
+    }
+
+    pub fn new_prefix(prefix: &str) -> Self {
+        Self::Prefix(MatchPrefix {
+            prefix: prefix.into(),
+        })
+    }
+
+    pub fn new_substring(substring: &str) -> Self {
+        Self::Substring(MatchSubstring {
+            substring: substring.into(),
+        })
+    }
+
+    pub fn new_regex(regex: RegexPattern) -> Self {
+        Self::Regex(MatchRegex { regex })
+    }
+
     pub fn new_any(any: AnyVariants) -> Self {
         Self::Any(MatchAny { any })
     }
@@ -1735,6 +2140,21 @@ impl From<MatchInterface> for Match {
         match value {
             MatchInterface::Value(value) => Self::Value(MatchValue { value: value.value }),
             MatchInterface::Text(text) => Self::Text(MatchText { text: text.text }),
+            MatchInterface::Phrase(phrase) => Self::Phrase(MatchPhrase {
+                phrase: phrase.phrase,
+                slop: phrase.slop,
+            }),
+            MatchInterface::Fuzzy(fuzzy) => Self::Fuzzy(MatchFuzzy {
+                fuzzy: fuzzy.fuzzy,
+                max_edits: fuzzy.max_edits,
+            }),
+            MatchInterface::Prefix(prefix) => Self::Prefix(MatchPrefix {
+                prefix: prefix.prefix,
+            }),
+            MatchInterface::Substring(substring) => Self::Substring(MatchSubstring {
+                substring: substring.substring,
+            }),
+            MatchInterface::Regex(regex) => Self::Regex(MatchRegex { regex: regex.regex }),
             MatchInterface::Any(any) => Self::Any(MatchAny { any: any.any }),
             MatchInterface::Except(except) => Self::Except(MatchExcept {
                 except: except.except,
@@ -1821,12 +2241,22 @@ impl From<Vec<IntPayloadType>> for MatchExcept {
 #[serde(untagged)]
 pub enum RangeInterface {
     Float(Range<FloatPayloadType>),
+    // Deserialized before `Keyword`, so that datetime ranges stay datetime ranges for peers.
+    // On keyword indexes, it selects keywords which can be parsed as datetime within the range.
     DateTime(Range<DateTimePayloadType>),
+    // Range of strings, which are not all datetimes. Lexicographic on keyword indexes.
+    // On datetime indexes and non-indexed payload it is a datetime range,
+    // if all bounds can be parsed as datetime.
+    Keyword(Range<String>),
 }
 
 /// Range filter request
 #[macro_rules_attribute::macro_rules_derive(crate::common::macros::schemars_rename_generics)]
-#[derive_args(< FloatPayloadType > => "Range", < DateTimePayloadType > => "DatetimeRange")]
+#[derive_args(
+    < FloatPayloadType > => "Range",
+    < DateTimePayloadType > => "DatetimeRange",
+    < String > => "KeywordRange"
+)]
 #[derive(Debug, Deserialize, Serialize, Default, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct Range<T> {
@@ -1893,6 +2323,49 @@ impl<T: Copy + PartialOrd> Range<T> {
     }
 }
 
+impl Range<String> {
+    pub fn check_keyword(&self, keyword: &str) -> bool {
+        self.lt.as_ref().map_or(true, |x| keyword < x.as_str())
+            && self.gt.as_ref().map_or(true, |x| keyword > x.as_str())
+            && self.lte.as_ref().map_or(true, |x| keyword <= x.as_str())
+            && self.gte.as_ref().map_or(true, |x| keyword >= x.as_str())
+    }
+
+    /// Same range in terms of datetime, if all bounds can be parsed as datetime
+    pub fn as_datetime_range(&self) -> Option<Range<DateTimePayloadType>> {
+        let parse = |bound: &Option<String>| {
+            bound
+                .as_deref()
+                .map(DateTimePayloadType::from_str)
+                .transpose()
+                .ok()
+        };
+        Some(Range {
+            lt: parse(&self.lt)?,
+            gt: parse(&self.gt)?,
+            gte: parse(&self.gte)?,
+            lte: parse(&self.lte)?,
+        })
+    }
+
+    /// Strictest lower and upper bounds of the range
+    pub fn as_bounds(&self) -> (Bound<&str>, Bound<&str>) {
+        let lower = match (self.gt.as_deref(), self.gte.as_deref()) {
+            (Some(gt), Some(gte)) if gte > gt => Bound::Included(gte),
+            (Some(gt), _) => Bound::Excluded(gt),
+            (None, Some(gte)) => Bound::Included(gte),
+            (None, None) => Bound::Unbounded,
+        };
+        let upper = match (self.lt.as_deref(), self.lte.as_deref()) {
+            (Some(lt), Some(lte)) if lte < lt => Bound::Included(lte),
+            (Some(lt), _) => Bound::Excluded(lt),
+            (None, Some(lte)) => Bound::Included(lte),
+            (None, None) => Bound::Unbounded,
+        };
+        (lower, upper)
+    }
+}
+
 /// Values count filter request
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Copy, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -2137,6 +2610,18 @@ impl FieldCondition {
         }
     }
 
+    pub fn new_keyword_range(key: JsonPath, keyword_range: Range<String>) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: Some(RangeInterface::Keyword(keyword_range)),
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            values_count: None,
+        }
+    }
+
     pub fn new_geo_bounding_box(key: JsonPath, geo_bounding_box: GeoBoundingBox) -> Self {
         Self {
             key,
@@ -2210,18 +2695,37 @@ impl FieldCondition {
             Match::Except(match_except) => match_except.except.len(),
             Match::Value(_) => 0,
             Match::Text(_) => 0,
+            Match::Phrase(_) => 0,
+            Match::Fuzzy(_) => 0,
+            Match::Prefix(_) => 0,
+            Match::Substring(_) => 0,
+            Match::Regex(_) => 0,
         }
     }
 }
 
 pub fn validate_field_condition(field_condition: &FieldCondition) -> Result<(), ValidationError> {
     if field_condition.all_fields_none() {
-        Err(ValidationError::new(
+        return Err(ValidationError::new(
             "At least one field condition must be specified",
-        ))
-    } else {
-        Ok(())
+        ));
     }
+    match &field_condition.r#match {
+        Some(Match::Fuzzy(fuzzy))
+            if fuzzy
+                .max_edits
+                .is_some_and(|max_edits| max_edits > MAX_FUZZY_EDITS) =>
+        {
+            return Err(ValidationError::new("Fuzzy match allows at most 2 edits"));
+        }
+        Some(Match::Phrase(phrase)) if phrase.slop() > MAX_PHRASE_SLOP => {
+            return Err(ValidationError::new(
+                "Phrase match allows slop of at most 64",
+            ));
+        }
+        _ => {}
+    }
+    Ok(())
 }
 
 /// Payload field
@@ -2922,6 +3426,32 @@ mod tests {
         assert_eq!(datetime, deserialized);
     }
 
+    #[test]
+    fn test_range_interface_deserialization() {
+        let range: RangeInterface = serde_json::from_str(r#"{"gte": 1.5}"#).unwrap();
+        assert!(matches!(range, RangeInterface::Float(_)));
+
+        let range: RangeInterface =
+            serde_json::from_str(r#"{"gte": "2020-03-01", "lt": "2020-03-01T12:00:00Z"}"#).unwrap();
+        let RangeInterface::DateTime(datetime_range) = range else {
+            panic!("datetime bounds must be parsed as datetime range, got {range:?}");
+        };
+        assert_eq!(
+            datetime_range.gte,
+            Some(DateTimePayloadType::from_str("2020-03-01T00:00:00Z").unwrap()),
+        );
+        assert!(
+            datetime_range.check_range(DateTimePayloadType::from_str("2020-03-01 06:00").unwrap())
+        );
+
+        let range: RangeInterface =
+            serde_json::from_str(r#"{"gte": "2020-03-01", "lt": "v2"}"#).unwrap();
+        let RangeInterface::Keyword(keyword_range) = range else {
+            panic!("non-datetime bounds must be kept as keywords, got {range:?}");
+        };
+        assert_eq!(keyword_range.as_datetime_range(), None);
+    }
+
     #[test]
     fn test_datetime_deserialization_equivalency() {
         let datetime_str = "2020-03-01T01:02:03.123456Z";
@@ -3217,6 +3747,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_match_regex() {
+        let query = r#"
+        {
+            "key": "version",
+            "match": { "regex": "^v1\\.\\d+" }
+        }
+        "#;
+        let condition: FieldCondition = serde_json::from_str(query).unwrap();
+        let Some(Match::Regex(MatchRegex { regex })) = condition.r#match else {
+            panic!("Match::Regex expected")
+        };
+        assert_eq!(regex.as_str(), r"^v1\.\d+");
+        assert!(regex.is_match("v1.13"));
+        assert!(!regex.is_match("v2.0"));
+
+        let query = r#"
+        {
+            "key": "version",
+            "match": { "regex": "v1.(" }
+        }
+        "#;
+        let result: Result<FieldCondition, _> = serde_json::from_str(query);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_match_any_mixed_types() {
         let query = r#"
@@ -8,6 +8,7 @@ use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 use super::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
 use super::query_scorer::custom_query_scorer::CustomQueryScorer;
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::score_range::{select_page, ScoreRange};
 use crate::data_types::vectors::{DenseVector, QueryVector, VectorElementType, VectorInternal};
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
@@ -16,6 +17,7 @@ use crate::vector_storage::dense::memmap_dense_vector_storage::MemmapDenseVector
 use crate::vector_storage::dense::mmap_dense_vectors::MmapDenseVectors;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
 use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::raw_scorer::bitwise_distance_error;
 use crate::vector_storage::{RawScorer, VectorStorage as _, DEFAULT_STOPPED};
 
 pub fn new<'a>(
@@ -203,6 +205,46 @@ where
 
         pq.into_sorted_vec()
     }
+
+    fn peek_range_iter(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        range: ScoreRange,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        if top == 0 {
+            return vec![];
+        }
+
+        // Ties with the last point of the page are only known after all points are scored
+        let mut in_range = Vec::new();
+        let points_stream = points
+            .take_while(|_| !self.is_stopped.load(Ordering::Relaxed))
+            .filter(|point_id| self.check_vector(*point_id));
+
+        self.storage
+            .read_vectors_async(points_stream, |_, point_id, other_vector| {
+                let score = self.query_scorer.score(other_vector);
+                if range.contains(score) {
+                    in_range.push(ScoredPointOffset {
+                        idx: point_id,
+                        score,
+                    });
+                }
+            })
+            .unwrap();
+
+        // ToDo: io_uring is experimental, it can fail if it is not supported.
+        // Instead of silently falling back to the sync implementation, we prefer to panic
+        // and notify the user that they better use the default IO implementation.
+
+        select_page(in_range, top)
+    }
+
+    fn peek_range_all(&self, range: ScoreRange, top: usize) -> Vec<ScoredPointOffset> {
+        let mut point_ids = 0..self.points_count;
+        self.peek_range_iter(&mut point_ids, range, top)
+    }
 }
 
 struct AsyncRawScorerBuilder<'a> {
@@ -246,6 +288,9 @@ impl<'a> AsyncRawScorerBuilder<'a> {
             Distance::Euclid => self._build_with_metric::<EuclidMetric>(),
             Distance::Dot => self._build_with_metric::<DotProductMetric>(),
             Distance::Manhattan => self._build_with_metric::<ManhattanMetric>(),
+            distance @ (Distance::Hamming | Distance::Jaccard) => {
+                Err(bitwise_distance_error(distance))
+            }
         }
     }
 
@@ -289,7 +334,7 @@ impl<'a> AsyncRawScorerBuilder<'a> {
                     VectorInternal::Sparse(_sparse_vector) => Err(OperationError::service_error(
                         "sparse vectors are not supported for async scorer",
                     )), // TODO(sparse) add support?
-                    VectorInternal::MultiDense(_multi_dense_vector) => {
+                    VectorInternal::MultiDense(_) | VectorInternal::WeightedMultiDense(_) => {
                         Err(OperationError::service_error(
                             "multi-dense vectors are not supported for async scorer",
                         ))
@@ -193,6 +193,18 @@ pub fn open_appendable_memmap_vector_storage_byte(
     )))
 }
 
+pub fn open_appendable_memmap_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_memmap_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableMemmapInt8(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_vector_storage_half(
     path: &Path,
     dim: usize,
@@ -205,6 +217,18 @@ pub fn open_appendable_memmap_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_memmap_vector_storage_bit(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_memmap_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableMemmapBit(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -258,6 +282,18 @@ pub fn open_appendable_in_ram_vector_storage_byte(
     )))
 }
 
+pub fn open_appendable_in_ram_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_in_ram_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableInRamInt8(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_in_ram_vector_storage_half(
     path: &Path,
     dim: usize,
@@ -270,6 +306,18 @@ pub fn open_appendable_in_ram_vector_storage_half(
     )))
 }
 
+pub fn open_appendable_in_ram_vector_storage_bit(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_in_ram_vector_storage_impl(path, dim, distance)?;
+
+    Ok(VectorStorageEnum::DenseAppendableInRamBit(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_in_ram_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
@@ -60,6 +60,16 @@ pub fn open_memmap_vector_storage_byte(
     Ok(VectorStorageEnum::DenseMemmapByte(storage))
 }
 
+pub fn open_memmap_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_memmap_vector_storage_with_async_io_impl(path, dim, distance, get_async_scorer())?;
+    Ok(VectorStorageEnum::DenseMemmapInt8(storage))
+}
+
 pub fn open_memmap_vector_storage_half(
     path: &Path,
     dim: usize,
@@ -70,6 +80,16 @@ pub fn open_memmap_vector_storage_half(
     Ok(VectorStorageEnum::DenseMemmapHalf(storage))
 }
 
+pub fn open_memmap_vector_storage_bit(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_memmap_vector_storage_with_async_io_impl(path, dim, distance, get_async_scorer())?;
+    Ok(VectorStorageEnum::DenseMemmapBit(storage))
+}
+
 pub fn open_memmap_vector_storage_with_async_io(
     path: &Path,
     dim: usize,
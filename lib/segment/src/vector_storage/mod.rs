@@ -10,8 +10,8 @@ mod vector_storage_base;
 mod tests;
 
 #[cfg(target_os = "linux")]
-mod async_io;
-mod async_io_mock;
+pub(crate) mod async_io;
+pub(crate) mod async_io_mock;
 mod bitvec;
 pub mod chunked_vector_storage;
 pub mod common;
@@ -318,6 +318,20 @@ pub fn open_appendable_memmap_multi_vector_storage_byte(
     )))
 }
 
+pub fn open_appendable_memmap_multi_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_memmap_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableMemmapInt8(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_multi_vector_storage_half(
     path: &Path,
     dim: usize,
@@ -411,6 +425,20 @@ pub fn open_appendable_in_ram_multi_vector_storage_byte(
     )))
 }
 
+pub fn open_appendable_in_ram_multi_vector_storage_int8(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    multi_vector_config: MultiVectorConfig,
+) -> OperationResult<VectorStorageEnum> {
+    let storage =
+        open_appendable_in_ram_multi_vector_storage_impl(path, dim, distance, multi_vector_config)?;
+
+    Ok(VectorStorageEnum::MultiDenseAppendableInRamInt8(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_in_ram_multi_vector_storage_half(
     path: &Path,
     dim: usize,
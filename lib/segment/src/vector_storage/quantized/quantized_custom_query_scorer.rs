@@ -4,7 +4,6 @@ use std::marker::PhantomData;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoreType};
 
-use crate::data_types::named_vectors::CowMultiVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedDenseVector, TypedMultiDenseVector,
@@ -12,8 +11,11 @@ use crate::data_types::vectors::{
 use crate::spaces::metric::Metric;
 use crate::types::QuantizationConfig;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
+use crate::vector_storage::quantized::quantized_multivector_storage::EncodedMultiVectors;
 use crate::vector_storage::query::{Query, TransformInto};
-use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::query_scorer::{
+    convert_multi_query, preprocess_multi_query, QueryScorer,
+};
 
 pub struct QuantizedCustomQueryScorer<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors, TQuery>
 where
@@ -90,6 +92,7 @@ where
         mut hardware_counter: HardwareCounterCell,
     ) -> Self
     where
+        TEncodedVectors: EncodedMultiVectors<TEncodedQuery>,
         TOriginalQuery: Query<TypedMultiDenseVector<TElement>>
             + TransformInto<TQuery, TypedMultiDenseVector<TElement>, TEncodedQuery>
             + Clone,
@@ -98,15 +101,8 @@ where
     {
         let original_query: TOriginalQuery = raw_query
             .transform(|vector| {
-                let mut preprocessed = Vec::new();
-                for slice in vector.multi_vectors() {
-                    preprocessed.extend_from_slice(&TMetric::preprocess(slice.to_vec()));
-                }
-                let preprocessed = MultiDenseVectorInternal::new(preprocessed, vector.dim);
-                let converted =
-                    TElement::from_float_multivector(CowMultiVector::Owned(preprocessed))
-                        .to_owned();
-                Ok(converted)
+                let preprocessed = preprocess_multi_query::<TElement, TMetric>(&vector);
+                Ok(convert_multi_query(preprocessed))
             })
             .unwrap();
 
@@ -117,7 +113,11 @@ where
                     TMetric::distance(),
                     &original_vector.flattened_vectors,
                 );
-                Ok(quantized_storage.encode_query(&original_vector_prequantized))
+                Ok(quantized_storage.encode_multi_query(
+                    &original_vector_prequantized,
+                    // Weights only apply to nearest queries
+                    None,
+                ))
             })
             .unwrap();
 
@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::fs::canonicalize;
 use std::marker::PhantomData;
 use std::ops::DerefMut;
@@ -97,6 +98,38 @@ impl MultivectorOffsetsStorage for MultivectorOffsetsStorageMmap {
     }
 }
 
+thread_local! {
+    /// Best similarities of each vector of a point, reused by Chamfer scoring between calls
+    static MAX_SIMS: RefCell<Vec<ScoreType>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f` on the thread-local buffer of `count` best similarities, reset to negative infinity
+fn with_max_sims<R>(count: usize, f: impl FnOnce(&mut [ScoreType]) -> R) -> R {
+    MAX_SIMS.with_borrow_mut(|max_sims| {
+        max_sims.clear();
+        max_sims.resize(count, ScoreType::NEG_INFINITY);
+        f(max_sims)
+    })
+}
+
+/// Multivector query, encoded vector by vector
+#[derive(Debug)]
+pub struct EncodedMultivectorQuery<TEncodedQuery> {
+    vectors: Vec<TEncodedQuery>,
+    /// Weights of the query vectors, used by the weighted MaxSim comparator
+    weights: Option<Vec<ScoreType>>,
+}
+
+/// Quantized storage of multivectors, which keeps the weights of query vectors on encoding
+pub trait EncodedMultiVectors<TEncodedQuery>: EncodedVectors<TEncodedQuery> {
+    /// Encode a flattened multivector query with optional weights of its vectors
+    fn encode_multi_query(
+        &self,
+        query: &[VectorElementType],
+        weights: Option<&[ScoreType]>,
+    ) -> TEncodedQuery;
+}
+
 #[derive(Debug)]
 pub struct QuantizedMultivectorStorage<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage>
 where
@@ -160,15 +193,17 @@ where
     }
 
     /// Custom `score_max_similarity` implementation for quantized vectors
+    ///
+    /// If the query has weights, max similarities are weighted by them.
     fn score_point_max_similarity(
         &self,
-        query: &Vec<TEncodedQuery>,
+        query: &EncodedMultivectorQuery<TEncodedQuery>,
         vector_index: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
         let offset = self.offsets.get_offset(vector_index);
         let mut sum = 0.0;
-        for inner_query in query {
+        for (query_index, inner_query) in query.vectors.iter().enumerate() {
             let mut max_sim = ScoreType::NEG_INFINITY;
             // manual `max_by` for performance
             for i in 0..offset.count {
@@ -179,12 +214,74 @@ where
                     max_sim = sim;
                 }
             }
+            let weight = query
+                .weights
+                .as_ref()
+                .map_or(1.0, |weights| weights[query_index]);
             // sum of max similarity
-            sum += max_sim;
+            sum += weight * max_sim;
         }
         sum
     }
 
+    /// Custom `score_chamfer_similarity` implementation for quantized vectors
+    fn score_point_chamfer_similarity(
+        &self,
+        query: &EncodedMultivectorQuery<TEncodedQuery>,
+        vector_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset = self.offsets.get_offset(vector_index);
+        with_max_sims(offset.count as usize, |max_sims_point| {
+            let mut sum_query = 0.0;
+            for inner_query in &query.vectors {
+                let mut max_sim = ScoreType::NEG_INFINITY;
+                for (i, max_sim_point) in max_sims_point.iter_mut().enumerate() {
+                    let sim = self.quantized_storage.score_point(
+                        inner_query,
+                        offset.start + i as PointOffsetType,
+                        hw_counter,
+                    );
+                    if sim > max_sim {
+                        max_sim = sim;
+                    }
+                    if sim > *max_sim_point {
+                        *max_sim_point = sim;
+                    }
+                }
+                sum_query += max_sim;
+            }
+            let sum_point: ScoreType = max_sims_point.iter().sum();
+            let mean_query = sum_query / query.vectors.len() as ScoreType;
+            let mean_point = sum_point / offset.count as ScoreType;
+            (mean_query + mean_point) / 2.0
+        })
+    }
+
+    /// Approximation of `score_mean_pooled_similarity` for quantized vectors
+    ///
+    /// Pooled vectors of points are not available, so the pooled query is compared
+    /// with each vector of the point, and similarities are averaged.
+    /// It is exact for `Dot` distance only, so collections reject quantization of mean-pooled
+    /// multivectors with other distances.
+    fn score_point_mean_pooled_similarity(
+        &self,
+        query: &EncodedMultivectorQuery<TEncodedQuery>,
+        vector_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset = self.offsets.get_offset(vector_index);
+        let mut sum = 0.0;
+        for inner_query in &query.vectors {
+            for i in 0..offset.count {
+                sum +=
+                    self.quantized_storage
+                        .score_point(inner_query, offset.start + i, hw_counter);
+            }
+        }
+        sum / (query.vectors.len() * offset.count as usize) as ScoreType
+    }
+
     /// Custom `score_max_similarity` implementation for quantized vectors
     fn score_internal_max_similarity(
         &self,
@@ -214,6 +311,64 @@ where
         sum
     }
 
+    /// Custom `score_chamfer_similarity` implementation for quantized vectors
+    fn score_internal_chamfer_similarity(
+        &self,
+        vector_a_index: PointOffsetType,
+        vector_b_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset_a = self.offsets.get_offset(vector_a_index);
+        let offset_b = self.offsets.get_offset(vector_b_index);
+        with_max_sims(offset_b.count as usize, |max_sims_b| {
+            let mut sum_a = 0.0;
+            for a in 0..offset_a.count {
+                let mut max_sim = ScoreType::NEG_INFINITY;
+                for (b, max_sim_b) in max_sims_b.iter_mut().enumerate() {
+                    let sim = self.quantized_storage.score_internal(
+                        offset_a.start + a,
+                        offset_b.start + b as PointOffsetType,
+                        hw_counter,
+                    );
+                    if sim > max_sim {
+                        max_sim = sim;
+                    }
+                    if sim > *max_sim_b {
+                        *max_sim_b = sim;
+                    }
+                }
+                sum_a += max_sim;
+            }
+            let sum_b: ScoreType = max_sims_b.iter().sum();
+            let mean_a = sum_a / offset_a.count as ScoreType;
+            let mean_b = sum_b / offset_b.count as ScoreType;
+            (mean_a + mean_b) / 2.0
+        })
+    }
+
+    /// Approximation of `score_mean_pooled_similarity` for quantized vectors,
+    /// see [`Self::score_point_mean_pooled_similarity`]
+    fn score_internal_mean_pooled_similarity(
+        &self,
+        vector_a_index: PointOffsetType,
+        vector_b_index: PointOffsetType,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let offset_a = self.offsets.get_offset(vector_a_index);
+        let offset_b = self.offsets.get_offset(vector_b_index);
+        let mut sum = 0.0;
+        for a in 0..offset_a.count {
+            for b in 0..offset_b.count {
+                sum += self.quantized_storage.score_internal(
+                    offset_a.start + a,
+                    offset_b.start + b,
+                    hw_counter,
+                );
+            }
+        }
+        sum / (offset_a.count as usize * offset_b.count as usize) as ScoreType
+    }
+
     pub fn inner_storage(&self) -> &QuantizedStorage {
         &self.quantized_storage
     }
@@ -225,9 +380,14 @@ where
     pub fn vectors_count(&self) -> usize {
         self.offsets.len()
     }
+
+    pub fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
 }
 
-impl<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage> EncodedVectors<Vec<TEncodedQuery>>
+impl<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage>
+    EncodedVectors<EncodedMultivectorQuery<TEncodedQuery>>
     for QuantizedMultivectorStorage<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage>
 where
     TEncodedQuery: Sized,
@@ -250,25 +410,26 @@ where
         )
     }
 
-    fn encode_query(&self, query: &[VectorElementType]) -> Vec<TEncodedQuery> {
-        let multi_vector = TypedMultiDenseVectorRef {
-            dim: self.dim,
-            flattened_vectors: query,
-        };
-        multi_vector
-            .multi_vectors()
-            .map(|inner_vector| self.quantized_storage.encode_query(inner_vector))
-            .collect()
+    fn encode_query(&self, query: &[VectorElementType]) -> EncodedMultivectorQuery<TEncodedQuery> {
+        self.encode_multi_query(query, None)
     }
 
     fn score_point(
         &self,
-        query: &Vec<TEncodedQuery>,
+        query: &EncodedMultivectorQuery<TEncodedQuery>,
         i: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
         match self.multi_vector_config.comparator {
-            MultiVectorComparator::MaxSim => self.score_point_max_similarity(query, i, hw_counter),
+            MultiVectorComparator::MaxSim | MultiVectorComparator::WeightedMaxSim => {
+                self.score_point_max_similarity(query, i, hw_counter)
+            }
+            MultiVectorComparator::Chamfer => {
+                self.score_point_chamfer_similarity(query, i, hw_counter)
+            }
+            MultiVectorComparator::MeanPool => {
+                self.score_point_mean_pooled_similarity(query, i, hw_counter)
+            }
         }
     }
 
@@ -279,7 +440,73 @@ where
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
         match self.multi_vector_config.comparator {
-            MultiVectorComparator::MaxSim => self.score_internal_max_similarity(i, j, hw_counter),
+            // Stored vectors have no weights
+            MultiVectorComparator::MaxSim | MultiVectorComparator::WeightedMaxSim => {
+                self.score_internal_max_similarity(i, j, hw_counter)
+            }
+            MultiVectorComparator::Chamfer => {
+                self.score_internal_chamfer_similarity(i, j, hw_counter)
+            }
+            MultiVectorComparator::MeanPool => {
+                self.score_internal_mean_pooled_similarity(i, j, hw_counter)
+            }
+        }
+    }
+}
+
+impl<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage>
+    EncodedMultiVectors<EncodedMultivectorQuery<TEncodedQuery>>
+    for QuantizedMultivectorStorage<TEncodedQuery, QuantizedStorage, TMultivectorOffsetsStorage>
+where
+    TEncodedQuery: Sized,
+    QuantizedStorage: EncodedVectors<TEncodedQuery>,
+    TMultivectorOffsetsStorage: MultivectorOffsetsStorage,
+{
+    fn encode_multi_query(
+        &self,
+        query: &[VectorElementType],
+        weights: Option<&[ScoreType]>,
+    ) -> EncodedMultivectorQuery<TEncodedQuery> {
+        let multi_vector = TypedMultiDenseVectorRef {
+            dim: self.dim,
+            flattened_vectors: query,
+        };
+        let encode_vectors = || {
+            multi_vector
+                .multi_vectors()
+                .map(|inner_vector| self.quantized_storage.encode_query(inner_vector))
+                .collect()
+        };
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim | MultiVectorComparator::Chamfer => {
+                EncodedMultivectorQuery {
+                    vectors: encode_vectors(),
+                    weights: None,
+                }
+            }
+            MultiVectorComparator::WeightedMaxSim => {
+                debug_assert!(weights.map_or(true, |weights| {
+                    weights.len() == multi_vector.vectors_count()
+                }));
+                EncodedMultivectorQuery {
+                    vectors: encode_vectors(),
+                    weights: weights.map(<[ScoreType]>::to_vec),
+                }
+            }
+            MultiVectorComparator::MeanPool => {
+                let mut pooled = vec![0.0; self.dim];
+                for inner_vector in multi_vector.multi_vectors() {
+                    for (pooled_value, value) in pooled.iter_mut().zip(inner_vector) {
+                        *pooled_value += value;
+                    }
+                }
+                let count = multi_vector.vectors_count() as VectorElementType;
+                pooled.iter_mut().for_each(|value| *value /= count);
+                EncodedMultivectorQuery {
+                    vectors: vec![self.quantized_storage.encode_query(&pooled)],
+                    weights: None,
+                }
+            }
         }
     }
 }
@@ -9,7 +9,8 @@ use crate::data_types::vectors::{DenseVector, MultiDenseVectorInternal};
 use crate::spaces::metric::Metric;
 use crate::types::QuantizationConfig;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
-use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::quantized::quantized_multivector_storage::EncodedMultiVectors;
+use crate::vector_storage::query_scorer::{preprocess_multi_query, QueryScorer};
 
 pub struct QuantizedQueryScorer<'a, TElement, TMetric, TEncodedQuery, TEncodedVectors>
 where
@@ -57,14 +58,18 @@ where
 
     pub fn new_multi(
         raw_query: &MultiDenseVectorInternal,
+        weights: Option<&[ScoreType]>,
         quantized_data: &'a TEncodedVectors,
         quantization_config: &QuantizationConfig,
         hardware_counter: HardwareCounterCell,
-    ) -> Self {
+    ) -> Self
+    where
+        TEncodedVectors: EncodedMultiVectors<TEncodedQuery>,
+    {
+        let preprocessed = preprocess_multi_query::<TElement, TMetric>(raw_query);
         let mut query = Vec::new();
-        for inner_vector in raw_query.multi_vectors() {
-            let inner_preprocessed = TMetric::preprocess(inner_vector.to_vec());
-            let inner_converted = TElement::slice_from_float_cow(Cow::Owned(inner_preprocessed));
+        for inner_preprocessed in preprocessed.multi_vectors() {
+            let inner_converted = TElement::slice_from_float_cow(Cow::Borrowed(inner_preprocessed));
             let inner_prequantized = TElement::quantization_preprocess(
                 quantization_config,
                 TMetric::distance(),
@@ -73,7 +78,7 @@ where
             query.extend_from_slice(&inner_prequantized);
         }
 
-        let query = quantized_data.encode_query(&query);
+        let query = quantized_data.encode_multi_query(&query, weights);
 
         Self {
             query,
@@ -2,21 +2,24 @@ use std::sync::atomic::AtomicBool;
 
 use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::ScoreType;
 use quantization::EncodedVectors;
 
 use super::quantized_custom_query_scorer::QuantizedCustomQueryScorer;
+use super::quantized_multivector_storage::EncodedMultiVectors;
 use super::quantized_query_scorer::QuantizedQueryScorer;
 use super::quantized_vectors::QuantizedVectorStorage;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf,
+    VectorElementTypeHalf, VectorElementTypeInt8,
 };
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 use crate::vector_storage::query::{ContextQuery, DiscoveryQuery, RecoQuery, TransformInto};
+use crate::vector_storage::raw_scorer::bitwise_distance_error;
 use crate::vector_storage::{raw_scorer_from_query_scorer, RawScorer};
 
 pub(super) struct QuantizedScorerBuilder<'a> {
@@ -66,6 +69,9 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementType, ManhattanMetric>()
                 }
+                distance @ (Distance::Hamming | Distance::Jaccard) => {
+                    Err(bitwise_distance_error(*distance))
+                }
             },
             VectorStorageDatatype::Uint8 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeByte, CosineMetric>(),
@@ -76,6 +82,22 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeByte, ManhattanMetric>()
                 }
+                distance @ (Distance::Hamming | Distance::Jaccard) => {
+                    Err(bitwise_distance_error(*distance))
+                }
+            },
+            VectorStorageDatatype::Int8 => match self.distance {
+                Distance::Cosine => self.build_with_metric::<VectorElementTypeInt8, CosineMetric>(),
+                Distance::Euclid => self.build_with_metric::<VectorElementTypeInt8, EuclidMetric>(),
+                Distance::Dot => {
+                    self.build_with_metric::<VectorElementTypeInt8, DotProductMetric>()
+                }
+                Distance::Manhattan => {
+                    self.build_with_metric::<VectorElementTypeInt8, ManhattanMetric>()
+                }
+                distance @ (Distance::Hamming | Distance::Jaccard) => {
+                    Err(bitwise_distance_error(*distance))
+                }
             },
             VectorStorageDatatype::Float16 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeHalf, CosineMetric>(),
@@ -86,7 +108,13 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeHalf, ManhattanMetric>()
                 }
+                distance @ (Distance::Hamming | Distance::Jaccard) => {
+                    Err(bitwise_distance_error(*distance))
+                }
             },
+            VectorStorageDatatype::Bit => Err(OperationError::service_error(
+                "Quantization is not supported for bit vectors",
+            )),
         }
     }
 
@@ -102,6 +130,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::ScalarMmap(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::ScalarPackedRam(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarPackedMmap(storage) => {
+                self.new_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
             QuantizedVectorStorage::PQRam(storage) => {
                 self.new_quantized_scorer::<TElement, TMetric, _>(storage)
             }
@@ -120,6 +154,12 @@ impl<'a> QuantizedScorerBuilder<'a> {
             QuantizedVectorStorage::ScalarMmapMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
             }
+            QuantizedVectorStorage::ScalarPackedRamMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
+            QuantizedVectorStorage::ScalarPackedMmapMulti(storage) => {
+                self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
+            }
             QuantizedVectorStorage::PQRamMulti(storage) => {
                 self.new_multi_quantized_scorer::<TElement, TMetric, _>(storage)
             }
@@ -202,7 +242,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
 
     fn new_multi_quantized_scorer<TElement, TMetric, TEncodedQuery>(
         self,
-        quantized_storage: &'a impl EncodedVectors<TEncodedQuery>,
+        quantized_storage: &'a impl EncodedMultiVectors<TEncodedQuery>,
     ) -> OperationResult<Box<dyn RawScorer + 'a>>
     where
         TElement: PrimitiveVectorElement,
@@ -223,8 +263,10 @@ impl<'a> QuantizedScorerBuilder<'a> {
 
         match query {
             QueryVector::Nearest(vector) => {
+                let weights = vector.weights().map(<[ScoreType]>::to_vec);
                 let query_scorer = QuantizedQueryScorer::<TElement, TMetric, _, _>::new_multi(
                     &MultiDenseVectorInternal::try_from(vector)?,
+                    weights.as_deref(),
                     quantized_storage,
                     quantization_config,
                     hardware_counter,
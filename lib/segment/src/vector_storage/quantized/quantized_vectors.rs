@@ -6,9 +6,12 @@ use bitvec::slice::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use io::file_operations::{atomic_save_json, read_json};
-use quantization::encoded_vectors_binary::{EncodedBinVector, EncodedVectorsBin};
+use quantization::encoded_vectors_binary::{
+    EncodedQueryBQ, EncodedVectorsBin, Encoding, QueryEncoding,
+};
 use quantization::{
-    EncodedQueryPQ, EncodedQueryU8, EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8,
+    EncodedQueryPQ, EncodedQueryPacked, EncodedQueryU8, EncodedStorage, EncodedStorageBuilder,
+    EncodedVectors, EncodedVectorsPQ, EncodedVectorsPacked, EncodedVectorsU8, PackedType,
 };
 use serde::{Deserialize, Serialize};
 
@@ -22,9 +25,10 @@ use crate::common::vector_utils::TrySetCapacityExact;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType};
 use crate::types::{
-    BinaryQuantization, BinaryQuantizationConfig, CompressionRatio, Distance, MultiVectorConfig,
+    BinaryQuantization, BinaryQuantizationConfig, BinaryQuantizationEncoding,
+    BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig,
     ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
-    ScalarQuantizationConfig, VectorStorageDatatype,
+    ScalarQuantizationConfig, ScalarType, VectorStorageDatatype,
 };
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
 use crate::vector_storage::quantized::quantized_mmap_storage::{
@@ -65,6 +69,18 @@ type ScalarMmapMulti = QuantizedMultivectorStorage<
     MultivectorOffsetsStorageMmap,
 >;
 
+type ScalarPackedRamMulti = QuantizedMultivectorStorage<
+    EncodedQueryPacked,
+    EncodedVectorsPacked<ChunkedVectors<u8>>,
+    Vec<MultivectorOffset>,
+>;
+
+type ScalarPackedMmapMulti = QuantizedMultivectorStorage<
+    EncodedQueryPacked,
+    EncodedVectorsPacked<QuantizedMmapStorage>,
+    MultivectorOffsetsStorageMmap,
+>;
+
 type PQRamMulti = QuantizedMultivectorStorage<
     EncodedQueryPQ,
     EncodedVectorsPQ<ChunkedVectors<u8>>,
@@ -78,13 +94,13 @@ type PQMmapMulti = QuantizedMultivectorStorage<
 >;
 
 type BinaryRamMulti = QuantizedMultivectorStorage<
-    EncodedBinVector<u8>,
+    EncodedQueryBQ<u8>,
     EncodedVectorsBin<u8, ChunkedVectors<u8>>,
     Vec<MultivectorOffset>,
 >;
 
 type BinaryMmapMulti = QuantizedMultivectorStorage<
-    EncodedBinVector<u8>,
+    EncodedQueryBQ<u8>,
     EncodedVectorsBin<u8, QuantizedMmapStorage>,
     MultivectorOffsetsStorageMmap,
 >;
@@ -92,12 +108,16 @@ type BinaryMmapMulti = QuantizedMultivectorStorage<
 pub enum QuantizedVectorStorage {
     ScalarRam(EncodedVectorsU8<ChunkedVectors<u8>>),
     ScalarMmap(EncodedVectorsU8<QuantizedMmapStorage>),
+    ScalarPackedRam(EncodedVectorsPacked<ChunkedVectors<u8>>),
+    ScalarPackedMmap(EncodedVectorsPacked<QuantizedMmapStorage>),
     PQRam(EncodedVectorsPQ<ChunkedVectors<u8>>),
     PQMmap(EncodedVectorsPQ<QuantizedMmapStorage>),
     BinaryRam(EncodedVectorsBin<u128, ChunkedVectors<u8>>),
     BinaryMmap(EncodedVectorsBin<u128, QuantizedMmapStorage>),
     ScalarRamMulti(ScalarRamMulti),
     ScalarMmapMulti(ScalarMmapMulti),
+    ScalarPackedRamMulti(ScalarPackedRamMulti),
+    ScalarPackedMmapMulti(ScalarPackedMmapMulti),
     PQRamMulti(PQRamMulti),
     PQMmapMulti(PQMmapMulti),
     BinaryRamMulti(BinaryRamMulti),
@@ -131,12 +151,16 @@ impl QuantizedVectors {
         match self.storage_impl {
             QuantizedVectorStorage::ScalarRam(_) => false,
             QuantizedVectorStorage::ScalarMmap(_) => false,
+            QuantizedVectorStorage::ScalarPackedRam(_) => false,
+            QuantizedVectorStorage::ScalarPackedMmap(_) => false,
             QuantizedVectorStorage::PQRam(_) => false,
             QuantizedVectorStorage::PQMmap(_) => false,
             QuantizedVectorStorage::BinaryRam(_) => false,
             QuantizedVectorStorage::BinaryMmap(_) => false,
             QuantizedVectorStorage::ScalarRamMulti(_) => true,
             QuantizedVectorStorage::ScalarMmapMulti(_) => true,
+            QuantizedVectorStorage::ScalarPackedRamMulti(_) => true,
+            QuantizedVectorStorage::ScalarPackedMmapMulti(_) => true,
             QuantizedVectorStorage::PQRamMulti(_) => true,
             QuantizedVectorStorage::PQMmapMulti(_) => true,
             QuantizedVectorStorage::BinaryRamMulti(_) => true,
@@ -173,6 +197,12 @@ impl QuantizedVectors {
         match &self.storage_impl {
             QuantizedVectorStorage::ScalarRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::ScalarMmap(storage) => storage.save(&data_path, &meta_path)?,
+            QuantizedVectorStorage::ScalarPackedRam(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
+            QuantizedVectorStorage::ScalarPackedMmap(storage) => {
+                storage.save(&data_path, &meta_path)?
+            }
             QuantizedVectorStorage::PQRam(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::PQMmap(storage) => storage.save(&data_path, &meta_path)?,
             QuantizedVectorStorage::BinaryRam(storage) => storage.save(&data_path, &meta_path)?,
@@ -183,6 +213,12 @@ impl QuantizedVectors {
             QuantizedVectorStorage::ScalarMmapMulti(storage) => {
                 storage.save_multi(&data_path, &meta_path, &offsets_path)?
             }
+            QuantizedVectorStorage::ScalarPackedRamMulti(storage) => {
+                storage.save_multi(&data_path, &meta_path, &offsets_path)?
+            }
+            QuantizedVectorStorage::ScalarPackedMmapMulti(storage) => {
+                storage.save_multi(&data_path, &meta_path, &offsets_path)?
+            }
             QuantizedVectorStorage::PQRamMulti(storage) => {
                 storage.save_multi(&data_path, &meta_path, &offsets_path)?
             }
@@ -228,6 +264,9 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseSimpleByte(v) => {
                 Self::create_impl(v, quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseSimpleInt8(v) => {
+                Self::create_impl(v, quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseSimpleHalf(v) => {
                 Self::create_impl(v, quantization_config, path, max_threads, stopped)
             }
@@ -237,6 +276,9 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseMemmapByte(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseMemmapInt8(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -246,6 +288,9 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -255,9 +300,18 @@ impl QuantizedVectors {
             VectorStorageEnum::DenseAppendableInRamByte(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => {
+                Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 Self::create_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::DenseSimpleBit(_)
+            | VectorStorageEnum::DenseMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableInRamBit(_) => Err(OperationError::service_error(
+                "Quantization is not supported for bit vectors",
+            )),
             VectorStorageEnum::SparseSimple(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::SparseMmap(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::MultiDenseSimple(v) => {
@@ -266,6 +320,9 @@ impl QuantizedVectors {
             VectorStorageEnum::MultiDenseSimpleByte(v) => {
                 Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => {
+                Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::MultiDenseSimpleHalf(v) => {
                 Self::create_multi_impl(v, quantization_config, path, max_threads, stopped)
             }
@@ -275,6 +332,9 @@ impl QuantizedVectors {
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -284,6 +344,9 @@ impl QuantizedVectors {
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => {
+                Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 Self::create_multi_impl(v.as_ref(), quantization_config, path, max_threads, stopped)
             }
@@ -478,8 +541,9 @@ impl QuantizedVectors {
             let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
             match &config.quantization_config {
                 QuantizationConfig::Scalar(ScalarQuantization { scalar }) => {
-                    if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
-                        QuantizedVectorStorage::ScalarRamMulti(
+                    let in_ram = Self::is_ram(scalar.always_ram, on_disk_vector_storage);
+                    match (Self::packed_type(&scalar.r#type), in_ram) {
+                        (None, true) => QuantizedVectorStorage::ScalarRamMulti(
                             QuantizedMultivectorStorage::load_multi(
                                 &data_path,
                                 &meta_path,
@@ -487,9 +551,8 @@ impl QuantizedVectors {
                                 &config.vector_parameters,
                                 multivector_config,
                             )?,
-                        )
-                    } else {
-                        QuantizedVectorStorage::ScalarMmapMulti(
+                        ),
+                        (None, false) => QuantizedVectorStorage::ScalarMmapMulti(
                             QuantizedMultivectorStorage::load_multi(
                                 &data_path,
                                 &meta_path,
@@ -497,7 +560,25 @@ impl QuantizedVectors {
                                 &config.vector_parameters,
                                 multivector_config,
                             )?,
-                        )
+                        ),
+                        (Some(_), true) => QuantizedVectorStorage::ScalarPackedRamMulti(
+                            QuantizedMultivectorStorage::load_multi(
+                                &data_path,
+                                &meta_path,
+                                &offsets_path,
+                                &config.vector_parameters,
+                                multivector_config,
+                            )?,
+                        ),
+                        (Some(_), false) => QuantizedVectorStorage::ScalarPackedMmapMulti(
+                            QuantizedMultivectorStorage::load_multi(
+                                &data_path,
+                                &meta_path,
+                                &offsets_path,
+                                &config.vector_parameters,
+                                multivector_config,
+                            )?,
+                        ),
                     }
                 }
                 QuantizationConfig::Product(ProductQuantization { product: pq }) => {
@@ -548,18 +629,34 @@ impl QuantizedVectors {
         } else {
             match &config.quantization_config {
                 QuantizationConfig::Scalar(ScalarQuantization { scalar }) => {
-                    if Self::is_ram(scalar.always_ram, on_disk_vector_storage) {
-                        QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::load(
+                    let in_ram = Self::is_ram(scalar.always_ram, on_disk_vector_storage);
+                    match (Self::packed_type(&scalar.r#type), in_ram) {
+                        (None, true) => QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::load(
                             &data_path,
                             &meta_path,
                             &config.vector_parameters,
-                        )?)
-                    } else {
-                        QuantizedVectorStorage::ScalarMmap(EncodedVectorsU8::load(
-                            &data_path,
-                            &meta_path,
-                            &config.vector_parameters,
-                        )?)
+                        )?),
+                        (None, false) => {
+                            QuantizedVectorStorage::ScalarMmap(EncodedVectorsU8::load(
+                                &data_path,
+                                &meta_path,
+                                &config.vector_parameters,
+                            )?)
+                        }
+                        (Some(_), true) => {
+                            QuantizedVectorStorage::ScalarPackedRam(EncodedVectorsPacked::load(
+                                &data_path,
+                                &meta_path,
+                                &config.vector_parameters,
+                            )?)
+                        }
+                        (Some(_), false) => {
+                            QuantizedVectorStorage::ScalarPackedMmap(EncodedVectorsPacked::load(
+                                &data_path,
+                                &meta_path,
+                                &config.vector_parameters,
+                            )?)
+                        }
                     }
                 }
                 QuantizationConfig::Product(ProductQuantization { product: pq }) => {
@@ -612,6 +709,18 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        if let Some(packed_type) = Self::packed_type(&scalar_config.r#type) {
+            return Self::create_scalar_packed(
+                vectors,
+                vector_parameters,
+                scalar_config,
+                packed_type,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            );
+        }
+
         let quantized_vector_size =
             EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
         let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
@@ -655,6 +764,20 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        if let Some(packed_type) = Self::packed_type(&scalar_config.r#type) {
+            return Self::create_scalar_packed_multi(
+                vectors,
+                offsets,
+                vector_parameters,
+                scalar_config,
+                packed_type,
+                multi_vector_config,
+                path,
+                on_disk_vector_storage,
+                stopped,
+            );
+        }
+
         let quantized_vector_size =
             EncodedVectorsU8::<QuantizedMmapStorage>::get_quantized_vector_size(vector_parameters);
         let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
@@ -703,6 +826,151 @@ impl QuantizedVectors {
         }
     }
 
+    fn create_scalar_packed<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        vector_parameters: &quantization::VectorParameters,
+        scalar_config: &ScalarQuantizationConfig,
+        packed_type: PackedType,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let quantized_vector_size =
+            EncodedVectorsPacked::<QuantizedMmapStorage>::get_quantized_vector_size(
+                vector_parameters,
+                packed_type,
+            );
+        let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            Ok(QuantizedVectorStorage::ScalarPackedRam(
+                EncodedVectorsPacked::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    packed_type,
+                    scalar_config.quantile,
+                    stopped,
+                )?,
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            Ok(QuantizedVectorStorage::ScalarPackedMmap(
+                EncodedVectorsPacked::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    packed_type,
+                    scalar_config.quantile,
+                    stopped,
+                )?,
+            ))
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_scalar_packed_multi<'a>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone,
+        offsets: impl Iterator<Item = MultivectorOffset>,
+        vector_parameters: &quantization::VectorParameters,
+        scalar_config: &ScalarQuantizationConfig,
+        packed_type: PackedType,
+        multi_vector_config: MultiVectorConfig,
+        path: &Path,
+        on_disk_vector_storage: bool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizedVectorStorage> {
+        let quantized_vector_size =
+            EncodedVectorsPacked::<QuantizedMmapStorage>::get_quantized_vector_size(
+                vector_parameters,
+                packed_type,
+            );
+        let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
+        if in_ram {
+            let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
+            storage_builder.try_set_capacity_exact(vector_parameters.count)?;
+            let quantized_storage = EncodedVectorsPacked::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                packed_type,
+                scalar_config.quantile,
+                stopped,
+            )?;
+            Ok(QuantizedVectorStorage::ScalarPackedRamMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    offsets.collect(),
+                    multi_vector_config,
+                ),
+            ))
+        } else {
+            let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
+            let storage_builder = QuantizedMmapStorageBuilder::new(
+                mmap_data_path.as_path(),
+                vector_parameters.count,
+                quantized_vector_size,
+            )?;
+            let quantized_storage = EncodedVectorsPacked::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                packed_type,
+                scalar_config.quantile,
+                stopped,
+            )?;
+            let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
+            create_offsets_file_from_iter(&offsets_path, vector_parameters.count, offsets)?;
+            Ok(QuantizedVectorStorage::ScalarPackedMmapMulti(
+                QuantizedMultivectorStorage::new(
+                    vector_parameters.dim,
+                    quantized_storage,
+                    MultivectorOffsetsStorage::load(&offsets_path)?,
+                    multi_vector_config,
+                ),
+            ))
+        }
+    }
+
+    /// Encode vectors with product quantization, optionally with a learned rotation (OPQ)
+    #[allow(clippy::too_many_arguments)]
+    fn encode_pq<'a, TStorage: EncodedStorage>(
+        vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
+        storage_builder: impl EncodedStorageBuilder<TStorage> + Send,
+        vector_parameters: &quantization::VectorParameters,
+        pq_config: &ProductQuantizationConfig,
+        bucket_size: usize,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> Result<EncodedVectorsPQ<TStorage>, quantization::EncodingError> {
+        if pq_config.opq == Some(true) {
+            EncodedVectorsPQ::encode_opq(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                bucket_size,
+                max_threads,
+                stopped,
+            )
+        } else {
+            EncodedVectorsPQ::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                bucket_size,
+                max_threads,
+                stopped,
+            )
+        }
+    }
+
     fn create_pq<'a>(
         vectors: impl Iterator<Item = impl AsRef<[VectorElementType]> + 'a> + Clone + Send,
         vector_parameters: &quantization::VectorParameters,
@@ -722,10 +990,11 @@ impl QuantizedVectors {
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
-            Ok(QuantizedVectorStorage::PQRam(EncodedVectorsPQ::encode(
+            Ok(QuantizedVectorStorage::PQRam(Self::encode_pq(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                pq_config,
                 bucket_size,
                 max_threads,
                 stopped,
@@ -737,10 +1006,11 @@ impl QuantizedVectors {
                 vector_parameters.count,
                 quantized_vector_size,
             )?;
-            Ok(QuantizedVectorStorage::PQMmap(EncodedVectorsPQ::encode(
+            Ok(QuantizedVectorStorage::PQMmap(Self::encode_pq(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                pq_config,
                 bucket_size,
                 max_threads,
                 stopped,
@@ -770,10 +1040,11 @@ impl QuantizedVectors {
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
-            let quantized_storage = EncodedVectorsPQ::encode(
+            let quantized_storage = Self::encode_pq(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                pq_config,
                 bucket_size,
                 max_threads,
                 stopped,
@@ -793,10 +1064,11 @@ impl QuantizedVectors {
                 vector_parameters.count,
                 quantized_vector_size,
             )?;
-            let quantized_storage = EncodedVectorsPQ::encode(
+            let quantized_storage = Self::encode_pq(
                 vectors,
                 storage_builder,
                 vector_parameters,
+                pq_config,
                 bucket_size,
                 max_threads,
                 stopped,
@@ -822,16 +1094,26 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        let encoding = Self::binary_encoding(binary_config);
+        let query_encoding = Self::binary_query_encoding(binary_config);
         let quantized_vector_size =
             EncodedVectorsBin::<u128, QuantizedMmapStorage>::get_quantized_vector_size_from_params(
                 vector_parameters,
+                encoding,
             );
         let in_ram = Self::is_ram(binary_config.always_ram, on_disk_vector_storage);
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
             Ok(QuantizedVectorStorage::BinaryRam(
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, stopped)?,
+                EncodedVectorsBin::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    encoding,
+                    query_encoding,
+                    stopped,
+                )?,
             ))
         } else {
             let mmap_data_path = path.join(QUANTIZED_DATA_PATH);
@@ -841,7 +1123,14 @@ impl QuantizedVectors {
                 quantized_vector_size,
             )?;
             Ok(QuantizedVectorStorage::BinaryMmap(
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, stopped)?,
+                EncodedVectorsBin::encode(
+                    vectors,
+                    storage_builder,
+                    vector_parameters,
+                    encoding,
+                    query_encoding,
+                    stopped,
+                )?,
             ))
         }
     }
@@ -857,16 +1146,25 @@ impl QuantizedVectors {
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<QuantizedVectorStorage> {
+        let encoding = Self::binary_encoding(binary_config);
+        let query_encoding = Self::binary_query_encoding(binary_config);
         let quantized_vector_size =
             EncodedVectorsBin::<u8, QuantizedMmapStorage>::get_quantized_vector_size_from_params(
                 vector_parameters,
+                encoding,
             );
         let in_ram = Self::is_ram(binary_config.always_ram, on_disk_vector_storage);
         if in_ram {
             let mut storage_builder = ChunkedVectors::<u8>::new(quantized_vector_size);
             storage_builder.try_set_capacity_exact(vector_parameters.count)?;
-            let quantized_storage =
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, stopped)?;
+            let quantized_storage = EncodedVectorsBin::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                encoding,
+                query_encoding,
+                stopped,
+            )?;
             Ok(QuantizedVectorStorage::BinaryRamMulti(
                 QuantizedMultivectorStorage::new(
                     vector_parameters.dim,
@@ -882,8 +1180,14 @@ impl QuantizedVectors {
                 vector_parameters.count,
                 quantized_vector_size,
             )?;
-            let quantized_storage =
-                EncodedVectorsBin::encode(vectors, storage_builder, vector_parameters, stopped)?;
+            let quantized_storage = EncodedVectorsBin::encode(
+                vectors,
+                storage_builder,
+                vector_parameters,
+                encoding,
+                query_encoding,
+                stopped,
+            )?;
             let offsets_path = path.join(QUANTIZED_OFFSETS_PATH);
             create_offsets_file_from_iter(&offsets_path, vector_parameters.count, offsets)?;
             Ok(QuantizedVectorStorage::BinaryMmapMulti(
@@ -897,6 +1201,31 @@ impl QuantizedVectors {
         }
     }
 
+    fn binary_encoding(binary_config: &BinaryQuantizationConfig) -> Encoding {
+        match binary_config.encoding.unwrap_or_default() {
+            BinaryQuantizationEncoding::OneBit => Encoding::OneBit,
+            BinaryQuantizationEncoding::TwoBits => Encoding::TwoBits,
+            BinaryQuantizationEncoding::OneAndHalfBits => Encoding::OneAndHalfBits,
+        }
+    }
+
+    fn binary_query_encoding(binary_config: &BinaryQuantizationConfig) -> QueryEncoding {
+        match binary_config.query_encoding.unwrap_or_default() {
+            BinaryQuantizationQueryEncoding::Default => QueryEncoding::SameAsStorage,
+            BinaryQuantizationQueryEncoding::Scalar4Bits => QueryEncoding::Scalar4bits,
+            BinaryQuantizationQueryEncoding::Scalar8Bits => QueryEncoding::Scalar8bits,
+        }
+    }
+
+    /// Scalar types with less than 8 bits per dimension are stored packed
+    fn packed_type(scalar_type: &ScalarType) -> Option<PackedType> {
+        match scalar_type {
+            ScalarType::Int8 => None,
+            ScalarType::Int4 => Some(PackedType::Int4),
+            ScalarType::Int2 => Some(PackedType::Int2),
+        }
+    }
+
     fn is_ram(always_ram: Option<bool>, on_disk_vector_storage: bool) -> bool {
         !on_disk_vector_storage || always_ram == Some(true)
     }
@@ -914,8 +1243,13 @@ impl QuantizedVectors {
                 Distance::Euclid => quantization::DistanceType::L2,
                 Distance::Dot => quantization::DistanceType::Dot,
                 Distance::Manhattan => quantization::DistanceType::L1,
+                // Bits are quantized in the unpacked form, where L1 equals the number of differing bits
+                Distance::Hamming => quantization::DistanceType::L1,
+                Distance::Jaccard => quantization::DistanceType::Dot,
             },
-            invert: distance == Distance::Euclid || distance == Distance::Manhattan,
+            invert: distance == Distance::Euclid
+                || distance == Distance::Manhattan
+                || distance == Distance::Hamming,
         }
     }
 
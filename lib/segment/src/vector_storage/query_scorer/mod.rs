@@ -1,7 +1,13 @@
+use std::borrow::Cow;
+
 use common::types::{PointOffsetType, ScoreType};
 
+use crate::data_types::named_vectors::CowMultiVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::TypedMultiDenseVectorRef;
+use crate::data_types::vectors::{
+    DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+    VectorElementType,
+};
 use crate::spaces::metric::Metric;
 use crate::types::{MultiVectorComparator, MultiVectorConfig};
 use crate::vector_storage::chunked_vector_storage::VectorOffsetType;
@@ -50,16 +56,133 @@ pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     sum
 }
 
-fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+/// Weighted MaxSim metric, metric for multi-dense vectors
+/// Same as [`score_max_similarity`], but the max similarity of each `a` vector is multiplied by its weight.
+/// This metric is also implemented in `QuantizedMultivectorStorage` structure for quantized data.
+pub fn score_weighted_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    weights_a: &[ScoreType],
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    debug_assert!(!multi_dense_a.is_empty());
+    debug_assert!(!multi_dense_b.is_empty());
+    debug_assert_eq!(multi_dense_a.vectors_count(), weights_a.len());
+    let mut sum = 0.0;
+    for (dense_a, weight) in multi_dense_a.multi_vectors().zip(weights_a) {
+        let mut max_sim = ScoreType::NEG_INFINITY;
+        for dense_b in multi_dense_b.multi_vectors() {
+            let sim = TMetric::similarity(dense_a, dense_b);
+            if sim > max_sim {
+                max_sim = sim;
+            }
+        }
+        sum += weight * max_sim;
+    }
+    sum
+}
+
+/// Symmetric Chamfer similarity, metric for multi-dense vectors
+/// Average of the mean best similarity of `a` vectors to `b`, and of `b` vectors to `a`.
+/// This metric is also implemented in `QuantizedMultivectorStorage` structure for quantized data.
+pub fn score_chamfer_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    debug_assert!(!multi_dense_a.is_empty());
+    debug_assert!(!multi_dense_b.is_empty());
+    let mut sum_a = 0.0;
+    let mut max_sims_b = vec![ScoreType::NEG_INFINITY; multi_dense_b.vectors_count()];
+    for dense_a in multi_dense_a.multi_vectors() {
+        let mut max_sim = ScoreType::NEG_INFINITY;
+        for (dense_b, max_sim_b) in multi_dense_b.multi_vectors().zip(max_sims_b.iter_mut()) {
+            let sim = TMetric::similarity(dense_a, dense_b);
+            if sim > max_sim {
+                max_sim = sim;
+            }
+            if sim > *max_sim_b {
+                *max_sim_b = sim;
+            }
+        }
+        sum_a += max_sim;
+    }
+    let sum_b: ScoreType = max_sims_b.iter().sum();
+    let mean_a = sum_a / multi_dense_a.vectors_count() as ScoreType;
+    let mean_b = sum_b / multi_dense_b.vectors_count() as ScoreType;
+    (mean_a + mean_b) / 2.0
+}
+
+/// Mean-pooled similarity, metric for multi-dense vectors
+/// Both multi-vectors are averaged into a single vector, which are compared with the metric.
+pub fn score_mean_pooled_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense_a: TypedMultiDenseVectorRef<T>,
+    multi_dense_b: TypedMultiDenseVectorRef<T>,
+) -> ScoreType {
+    let pooled_a = mean_pool::<T, TMetric>(multi_dense_a);
+    let pooled_b = mean_pool::<T, TMetric>(multi_dense_b);
+    TMetric::similarity(&pooled_a, &pooled_b)
+}
+
+fn mean_pool<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense: TypedMultiDenseVectorRef<T>,
+) -> Vec<T> {
+    debug_assert!(!multi_dense.is_empty());
+    let mut pooled = DenseVector::new();
+    for dense in multi_dense.multi_vectors() {
+        let dense = T::slice_to_float_cow(Cow::Borrowed(dense));
+        pooled.resize(dense.len(), 0.0);
+        for (pooled_value, value) in pooled.iter_mut().zip(dense.iter()) {
+            *pooled_value += value;
+        }
+    }
+    let count = multi_dense.vectors_count() as VectorElementType;
+    pooled.iter_mut().for_each(|value| *value /= count);
+    T::slice_from_float_cow(Cow::Owned(TMetric::preprocess(pooled))).into_owned()
+}
+
+/// Score multi-vectors with the comparator of the config.
+/// `weights_a` are the weights of the `a` vectors, if `a` is a weighted query.
+pub fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     multi_vector_config: &MultiVectorConfig,
     multi_dense_a: TypedMultiDenseVectorRef<T>,
+    weights_a: Option<&[ScoreType]>,
     multi_dense_b: TypedMultiDenseVectorRef<T>,
 ) -> ScoreType {
     match multi_vector_config.comparator {
         MultiVectorComparator::MaxSim => {
             score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
         }
+        MultiVectorComparator::WeightedMaxSim => match weights_a {
+            Some(weights_a) => {
+                score_weighted_max_similarity::<T, TMetric>(multi_dense_a, weights_a, multi_dense_b)
+            }
+            None => score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b),
+        },
+        MultiVectorComparator::Chamfer => {
+            score_chamfer_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
+        }
+        MultiVectorComparator::MeanPool => {
+            score_mean_pooled_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
+        }
+    }
+}
+
+/// Preprocess each vector of a multi-vector query with the metric
+pub fn preprocess_multi_query<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    query: &MultiDenseVectorInternal,
+) -> MultiDenseVectorInternal {
+    let mut preprocessed = DenseVector::with_capacity(query.flattened_vectors.len());
+    for slice in query.multi_vectors() {
+        let inner_preprocessed = TMetric::preprocess(slice.to_vec());
+        preprocessed.extend_from_slice(&inner_preprocessed);
     }
+    MultiDenseVectorInternal::new(preprocessed, query.dim)
+}
+
+/// Convert a preprocessed multi-vector query into the element type of the storage
+pub fn convert_multi_query<T: PrimitiveVectorElement>(
+    preprocessed: MultiDenseVectorInternal,
+) -> TypedMultiDenseVector<T> {
+    T::from_float_multivector(CowMultiVector::Owned(preprocessed)).to_owned()
 }
 
 /// Check if ids are rather contiguous to enable further optimizations
@@ -104,6 +227,76 @@ fn is_read_with_prefetch_efficient(ids: impl IntoIterator<Item = usize>) -> bool
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::spaces::simple::{CosineMetric, DotProductMetric};
+
+    fn multi_vector(flattened_vectors: &[VectorElementType]) -> TypedMultiDenseVectorRef<'_, f32> {
+        TypedMultiDenseVectorRef {
+            flattened_vectors,
+            dim: 2,
+        }
+    }
+
+    #[test]
+    fn test_chamfer_similarity() {
+        let a = multi_vector(&[1.0, 0.0, 0.0, 1.0]);
+        let b = multi_vector(&[1.0, 0.0]);
+
+        // a -> b: (1 + 0) / 2, b -> a: 1
+        let score = score_chamfer_similarity::<_, DotProductMetric>(a, b);
+        assert_eq!(score, 0.75);
+        assert_eq!(score, score_chamfer_similarity::<_, DotProductMetric>(b, a));
+    }
+
+    #[test]
+    fn test_mean_pooled_similarity() {
+        let a = multi_vector(&[1.0, 0.0, 0.0, 1.0]);
+        let b = multi_vector(&[1.0, 0.0, 1.0, 2.0]);
+
+        // [0.5, 0.5] * [1.0, 1.0]
+        let score = score_mean_pooled_similarity::<_, DotProductMetric>(a, b);
+        assert_eq!(score, 1.0);
+    }
+
+    #[test]
+    fn test_weighted_max_similarity() {
+        let query = MultiDenseVectorInternal::new(vec![3.0, 4.0, 0.0, 2.0], 2);
+        let weights = [2.0, 0.5];
+        let point = multi_vector(&[1.0, 0.0, 0.0, 1.0]);
+
+        let preprocessed = preprocess_multi_query::<f32, CosineMetric>(&query);
+        assert_close(&preprocessed.flattened_vectors, &[0.6, 0.8, 0.0, 1.0]);
+
+        let weighted = MultiVectorConfig {
+            comparator: MultiVectorComparator::WeightedMaxSim,
+        };
+        let score = score_multi::<f32, CosineMetric>(
+            &weighted,
+            TypedMultiDenseVectorRef::from(&preprocessed),
+            Some(&weights),
+            point,
+        );
+        // 2.0 * 0.8 + 0.5 * 1.0
+        assert!((score - 2.1).abs() < 1e-6);
+
+        // Weights don't change the score of other comparators
+        let max_sim = MultiVectorConfig {
+            comparator: MultiVectorComparator::MaxSim,
+        };
+        let score = score_multi::<f32, CosineMetric>(
+            &max_sim,
+            TypedMultiDenseVectorRef::from(&preprocessed),
+            Some(&weights),
+            point,
+        );
+        assert!((score - 1.8).abs() < 1e-6);
+    }
+
+    fn assert_close(actual: &[VectorElementType], expected: &[VectorElementType]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6, "{actual:?} != {expected:?}");
+        }
+    }
 
     #[test]
     fn test_check_ids_rather_contiguous() {
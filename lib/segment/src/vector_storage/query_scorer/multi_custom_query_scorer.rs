@@ -3,11 +3,10 @@ use std::marker::PhantomData;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoreType};
 
-use super::score_multi;
-use crate::data_types::named_vectors::CowMultiVector;
+use super::{convert_multi_query, preprocess_multi_query, score_multi};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+    MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
 };
 use crate::spaces::metric::Metric;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
@@ -50,15 +49,8 @@ impl<
         let query = query
             .transform(|vector| {
                 dim = vector.dim;
-                let mut preprocessed = DenseVector::new();
-                for slice in vector.multi_vectors() {
-                    preprocessed.extend_from_slice(&TMetric::preprocess(slice.to_vec()));
-                }
-                let preprocessed = MultiDenseVectorInternal::new(preprocessed, vector.dim);
-                let converted =
-                    TElement::from_float_multivector(CowMultiVector::Owned(preprocessed))
-                        .to_owned();
-                Ok(converted)
+                let preprocessed = preprocess_multi_query::<TElement, TMetric>(&vector);
+                Ok(convert_multi_query(preprocessed))
             })
             .unwrap();
 
@@ -95,6 +87,8 @@ impl<
             score_multi::<TElement, TMetric>(
                 self.vector_storage.multi_vector_config(),
                 TypedMultiDenseVectorRef::from(example),
+                // Weights only apply to nearest queries
+                None,
                 against,
             )
         })
@@ -3,11 +3,10 @@ use std::marker::PhantomData;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoreType};
 
-use super::score_multi;
-use crate::data_types::named_vectors::CowMultiVector;
+use super::{convert_multi_query, preprocess_multi_query, score_multi};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+    MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
 };
 use crate::spaces::metric::Metric;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
@@ -22,6 +21,8 @@ pub struct MultiMetricQueryScorer<
 > {
     vector_storage: &'a TVectorStorage,
     query: TypedMultiDenseVector<TElement>,
+    /// Weights of the query vectors, used by the weighted MaxSim comparator
+    weights: Option<Vec<ScoreType>>,
     metric: PhantomData<TMetric>,
     hardware_counter: HardwareCounterCell,
 }
@@ -35,19 +36,17 @@ impl<
 {
     pub fn new(
         query: &MultiDenseVectorInternal,
+        weights: Option<&[ScoreType]>,
         vector_storage: &'a TVectorStorage,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self {
-        let mut preprocessed = DenseVector::new();
-        for slice in query.multi_vectors() {
-            preprocessed.extend_from_slice(&TMetric::preprocess(slice.to_vec()));
-        }
-        let preprocessed = MultiDenseVectorInternal::new(preprocessed, query.dim);
+        let preprocessed = preprocess_multi_query::<TElement, TMetric>(query);
 
         hardware_counter.set_cpu_multiplier(query.dim * size_of::<TElement>());
 
         Self {
-            query: TElement::from_float_multivector(CowMultiVector::Owned(preprocessed)).to_owned(),
+            query: convert_multi_query(preprocessed),
+            weights: weights.map(<[ScoreType]>::to_vec),
             vector_storage,
             metric: PhantomData,
             hardware_counter,
@@ -57,6 +56,7 @@ impl<
     fn score_multi(
         &self,
         multi_dense_a: TypedMultiDenseVectorRef<TElement>,
+        weights_a: Option<&[ScoreType]>,
         multi_dense_b: TypedMultiDenseVectorRef<TElement>,
     ) -> ScoreType {
         self.hardware_counter
@@ -67,12 +67,17 @@ impl<
         score_multi::<TElement, TMetric>(
             self.vector_storage.multi_vector_config(),
             multi_dense_a,
+            weights_a,
             multi_dense_b,
         )
     }
 
     fn score_ref(&self, v2: TypedMultiDenseVectorRef<TElement>) -> ScoreType {
-        self.score_multi(TypedMultiDenseVectorRef::from(&self.query), v2)
+        self.score_multi(
+            TypedMultiDenseVectorRef::from(&self.query),
+            self.weights.as_deref(),
+            v2,
+        )
     }
 }
 
@@ -85,18 +90,12 @@ impl<
 {
     #[inline]
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
-        self.score_multi(
-            TypedMultiDenseVectorRef::from(&self.query),
-            self.vector_storage.get_multi(idx),
-        )
+        self.score_ref(self.vector_storage.get_multi(idx))
     }
 
     #[inline]
     fn score(&self, v2: &TypedMultiDenseVector<TElement>) -> ScoreType {
-        self.score_multi(
-            TypedMultiDenseVectorRef::from(&self.query),
-            TypedMultiDenseVectorRef::from(v2),
-        )
+        self.score_ref(TypedMultiDenseVectorRef::from(v2))
     }
 
     fn score_stored_batch(&self, ids: &[PointOffsetType], scores: &mut [ScoreType]) {
@@ -117,6 +116,6 @@ impl<
     fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
         let v1 = self.vector_storage.get_multi(point_a);
         let v2 = self.vector_storage.get_multi(point_b);
-        self.score_multi(v1, v2)
+        self.score_multi(v1, None, v2)
     }
 }
@@ -2,6 +2,7 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::{PointOffsetType, ScoreType};
 use sparse::common::sparse_vector::SparseVector;
 
+use crate::types::Distance;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
 use crate::vector_storage::query::{Query, TransformInto};
 use crate::vector_storage::query_scorer::QueryScorer;
@@ -14,6 +15,8 @@ pub struct SparseCustomQueryScorer<
 > {
     vector_storage: &'a TVectorStorage,
     query: TQuery,
+    /// Whether scores are normalized by the vector norms
+    cosine: bool,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -28,8 +31,12 @@ impl<
         vector_storage: &'a TVectorStorage,
         hardware_counter: HardwareCounterCell,
     ) -> Self {
+        let cosine = vector_storage.distance() == Distance::Cosine;
         let query: TQuery = TransformInto::transform(query, |mut vector| {
             vector.sort_by_indices();
+            if cosine {
+                vector.normalize_by(vector.norm());
+            }
             Ok(vector)
         })
         .unwrap();
@@ -37,6 +44,7 @@ impl<
         Self {
             vector_storage,
             query,
+            cosine,
             hardware_counter,
         }
     }
@@ -47,10 +55,17 @@ impl<TVectorStorage: SparseVectorStorage, TQuery: Query<SparseVector>> QueryScor
 {
     #[inline]
     fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
-        let stored = self
+        let mut stored = self
             .vector_storage
             .get_sparse(idx)
             .expect("Failed to get sparse vector");
+        if self.cosine {
+            let norm = self
+                .vector_storage
+                .get_norm(idx)
+                .unwrap_or_else(|| stored.norm());
+            stored.normalize_by(norm);
+        }
         self.query.score_by(|example| {
             let cpu_units = example.indices.len() + stored.indices.len();
             self.hardware_counter.cpu_counter().incr_delta(cpu_units);
@@ -69,10 +84,12 @@ impl<TVectorStorage: SparseVectorStorage, TQuery: Query<SparseVector>> QueryScor
     }
 
     fn score(&self, v: &SparseVector) -> ScoreType {
+        let norm = self.cosine.then(|| v.norm()).filter(|&norm| norm > 0.0);
         self.query.score_by(|example| {
             let cpu_units = v.indices.len() + example.indices.len();
             self.hardware_counter.cpu_counter().incr_delta(cpu_units);
-            example.score(v).unwrap_or(0.0)
+            let score = example.score(v).unwrap_or(0.0);
+            norm.map_or(score, |norm| score / norm)
         })
     }
 
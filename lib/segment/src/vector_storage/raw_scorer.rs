@@ -12,12 +12,15 @@ use super::query_scorer::multi_custom_query_scorer::MultiCustomQueryScorer;
 use super::query_scorer::sparse_custom_query_scorer::SparseCustomQueryScorer;
 use super::{DenseVectorStorage, MultiVectorStorage, SparseVectorStorage, VectorStorageEnum};
 use crate::common::operation_error::{OperationError, OperationResult};
+use crate::common::score_range::{truncate_with_ties, ScoreRange};
 use crate::data_types::vectors::{
-    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf,
+    DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeBit,
+    VectorElementTypeByte, VectorElementTypeHalf, VectorElementTypeInt8,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::Distance;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
@@ -88,6 +91,17 @@ pub trait RawScorer {
     ) -> Vec<ScoredPointOffset>;
 
     fn peek_top_all(&self, top: usize) -> Vec<ScoredPointOffset>;
+
+    /// Page of a range search: best `top` points within the `range`, together with the points
+    /// tied with the last one, see [`select_page`](crate::common::score_range::select_page)
+    fn peek_range_iter(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        range: ScoreRange,
+        top: usize,
+    ) -> Vec<ScoredPointOffset>;
+
+    fn peek_range_all(&self, range: ScoreRange, top: usize) -> Vec<ScoredPointOffset>;
 }
 
 pub struct RawScorerImpl<'a, TVector: ?Sized, TQueryScorer>
@@ -123,9 +137,15 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseSimpleByte(vs) => {
             raw_scorer_byte_impl(query, vs, point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseSimpleInt8(vs) => {
+            raw_scorer_int8_impl(query, vs, point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::DenseSimpleHalf(vs) => {
             raw_scorer_half_impl(query, vs, point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseSimpleBit(vs) => {
+            raw_scorer_bit_impl(query, vs, point_deleted, is_stopped, hc)
+        }
 
         VectorStorageEnum::DenseMemmap(vs) => {
             if vs.has_async_reader() {
@@ -155,9 +175,15 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseMemmapByte(vs) => {
             raw_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseMemmapInt8(vs) => {
+            raw_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::DenseMemmapHalf(vs) => {
             raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseMemmapBit(vs) => {
+            raw_scorer_bit_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
 
         VectorStorageEnum::DenseAppendableMemmap(vs) => {
             raw_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
@@ -165,18 +191,30 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::DenseAppendableMemmapByte(vs) => {
             raw_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseAppendableMemmapInt8(vs) => {
+            raw_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
             raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseAppendableMemmapBit(vs) => {
+            raw_scorer_bit_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::DenseAppendableInRam(vs) => {
             raw_scorer_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
         VectorStorageEnum::DenseAppendableInRamByte(vs) => {
             raw_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseAppendableInRamInt8(vs) => {
+            raw_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::DenseAppendableInRamHalf(vs) => {
             raw_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::DenseAppendableInRamBit(vs) => {
+            raw_scorer_bit_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::SparseSimple(vs) => {
             raw_sparse_scorer_impl(query, vs, point_deleted, is_stopped, hc)
         }
@@ -189,6 +227,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseSimpleByte(vs) => {
             raw_multi_scorer_byte_impl(query, vs, point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::MultiDenseSimpleInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs, point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::MultiDenseSimpleHalf(vs) => {
             raw_multi_scorer_half_impl(query, vs, point_deleted, is_stopped, hc)
         }
@@ -198,6 +239,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => {
             raw_multi_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::MultiDenseAppendableMemmapInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
             raw_multi_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
@@ -207,6 +251,9 @@ pub fn new_stoppable_raw_scorer<'a>(
         VectorStorageEnum::MultiDenseAppendableInRamByte(vs) => {
             raw_multi_scorer_byte_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
+        VectorStorageEnum::MultiDenseAppendableInRamInt8(vs) => {
+            raw_multi_scorer_int8_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
+        }
         VectorStorageEnum::MultiDenseAppendableInRamHalf(vs) => {
             raw_multi_scorer_half_impl(query, vs.as_ref(), point_deleted, is_stopped, hc)
         }
@@ -332,6 +379,7 @@ pub fn raw_scorer_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementType>
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -436,6 +484,7 @@ pub fn raw_scorer_byte_impl<'a, TVectorStorage: DenseVectorStorage<VectorElement
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -504,6 +553,111 @@ fn new_scorer_byte_with_metric<
     }
 }
 
+pub fn raw_scorer_int8_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeInt8>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_scorer_int8_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Euclid => new_scorer_int8_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Dot => new_scorer_int8_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Manhattan => new_scorer_int8_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
+    }
+}
+
+fn new_scorer_int8_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeInt8> + 'a,
+    TVectorStorage: DenseVectorStorage<VectorElementTypeInt8>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MetricQueryScorer::<VectorElementTypeInt8, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+                hardware_counter,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
 pub fn raw_scorer_half_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeHalf>>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
@@ -540,6 +694,7 @@ pub fn raw_scorer_half_impl<'a, TVectorStorage: DenseVectorStorage<VectorElement
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -608,6 +763,108 @@ fn new_scorer_half_with_metric<
     }
 }
 
+pub fn raw_scorer_bit_impl<'a, TVectorStorage: DenseVectorStorage<VectorElementTypeBit>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Hamming => new_scorer_bit_with_metric::<HammingMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Jaccard => new_scorer_bit_with_metric::<JaccardMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        distance @ (Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan) => {
+            Err(OperationError::service_error(format!(
+                "{distance:?} distance is not supported for bit vectors"
+            )))
+        }
+    }
+}
+
+fn new_scorer_bit_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeBit> + 'a,
+    TVectorStorage: DenseVectorStorage<VectorElementTypeBit>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter_cell: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
+            MetricQueryScorer::<VectorElementTypeBit, TMetric, _>::new(
+                vector.try_into()?,
+                vector_storage,
+                hardware_counter_cell,
+            ),
+            point_deleted,
+            vec_deleted,
+            is_stopped,
+        ),
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<DenseVector> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeBit, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                    hardware_counter_cell,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<DenseVector> = discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeBit, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                    hardware_counter_cell,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<DenseVector> = context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                CustomQueryScorer::<VectorElementTypeBit, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                    hardware_counter_cell,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
+/// Hamming and Jaccard distances are only defined for bit vectors
+pub(crate) fn bitwise_distance_error(distance: Distance) -> OperationError {
+    OperationError::service_error(format!(
+        "{distance:?} distance is only supported for bit vectors"
+    ))
+}
+
 pub fn raw_scorer_from_query_scorer<'a, TVector, TQueryScorer>(
     query_scorer: TQueryScorer,
     point_deleted: &'a BitSlice,
@@ -663,6 +920,7 @@ pub fn raw_multi_scorer_impl<'a, TVectorStorage: MultiVectorStorage<VectorElemen
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -679,16 +937,20 @@ fn new_multi_scorer_with_metric<
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     let vec_deleted = vector_storage.deleted_vector_bitslice();
     match query {
-        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
-            MultiMetricQueryScorer::<VectorElementType, TMetric, _>::new(
-                &vector.try_into()?,
-                vector_storage,
-                hardware_counter,
-            ),
-            point_deleted,
-            vec_deleted,
-            is_stopped,
-        ),
+        QueryVector::Nearest(vector) => {
+            let weights = vector.weights().map(<[ScoreType]>::to_vec);
+            raw_scorer_from_query_scorer(
+                MultiMetricQueryScorer::<VectorElementType, TMetric, _>::new(
+                    &vector.try_into()?,
+                    weights.as_deref(),
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Recommend(reco_query) => {
             let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -769,6 +1031,7 @@ pub fn raw_multi_scorer_byte_impl<'a, TVectorStorage: MultiVectorStorage<VectorE
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -785,16 +1048,20 @@ fn new_multi_scorer_byte_with_metric<
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     let vec_deleted = vector_storage.deleted_vector_bitslice();
     match query {
-        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
-            MultiMetricQueryScorer::<VectorElementTypeByte, TMetric, _>::new(
-                &vector.try_into()?,
-                vector_storage,
-                hardware_counter,
-            ),
-            point_deleted,
-            vec_deleted,
-            is_stopped,
-        ),
+        QueryVector::Nearest(vector) => {
+            let weights = vector.weights().map(<[ScoreType]>::to_vec);
+            raw_scorer_from_query_scorer(
+                MultiMetricQueryScorer::<VectorElementTypeByte, TMetric, _>::new(
+                    &vector.try_into()?,
+                    weights.as_deref(),
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Recommend(reco_query) => {
             let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -839,6 +1106,117 @@ fn new_multi_scorer_byte_with_metric<
     }
 }
 
+pub fn raw_multi_scorer_int8_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeInt8>>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage.distance() {
+        Distance::Cosine => new_multi_scorer_int8_with_metric::<CosineMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Euclid => new_multi_scorer_int8_with_metric::<EuclidMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Dot => new_multi_scorer_int8_with_metric::<DotProductMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        Distance::Manhattan => new_multi_scorer_int8_with_metric::<ManhattanMetric, _>(
+            query,
+            vector_storage,
+            point_deleted,
+            is_stopped,
+            hardware_counter,
+        ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
+    }
+}
+
+fn new_multi_scorer_int8_with_metric<
+    'a,
+    TMetric: Metric<VectorElementTypeInt8> + 'a,
+    TVectorStorage: MultiVectorStorage<VectorElementTypeInt8>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    point_deleted: &'a BitSlice,
+    is_stopped: &'a AtomicBool,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    let vec_deleted = vector_storage.deleted_vector_bitslice();
+    match query {
+        QueryVector::Nearest(vector) => {
+            let weights = vector.weights().map(<[ScoreType]>::to_vec);
+            raw_scorer_from_query_scorer(
+                MultiMetricQueryScorer::<VectorElementTypeInt8, TMetric, _>::new(
+                    &vector.try_into()?,
+                    weights.as_deref(),
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Recommend(reco_query) => {
+            let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    reco_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Discovery(discovery_query) => {
+            let discovery_query: DiscoveryQuery<MultiDenseVectorInternal> =
+                discovery_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    discovery_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+        QueryVector::Context(context_query) => {
+            let context_query: ContextQuery<MultiDenseVectorInternal> =
+                context_query.transform_into()?;
+            raw_scorer_from_query_scorer(
+                MultiCustomQueryScorer::<VectorElementTypeInt8, TMetric, _, _, _>::new(
+                    context_query,
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
+    }
+}
+
 pub fn raw_multi_scorer_half_impl<'a, TVectorStorage: MultiVectorStorage<VectorElementTypeHalf>>(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
@@ -875,6 +1253,7 @@ pub fn raw_multi_scorer_half_impl<'a, TVectorStorage: MultiVectorStorage<VectorE
             is_stopped,
             hardware_counter,
         ),
+        distance @ (Distance::Hamming | Distance::Jaccard) => Err(bitwise_distance_error(distance)),
     }
 }
 
@@ -891,16 +1270,20 @@ fn new_multi_scorer_half_with_metric<
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     let vec_deleted = vector_storage.deleted_vector_bitslice();
     match query {
-        QueryVector::Nearest(vector) => raw_scorer_from_query_scorer(
-            MultiMetricQueryScorer::<VectorElementTypeHalf, TMetric, _>::new(
-                &vector.try_into()?,
-                vector_storage,
-                hardware_counter,
-            ),
-            point_deleted,
-            vec_deleted,
-            is_stopped,
-        ),
+        QueryVector::Nearest(vector) => {
+            let weights = vector.weights().map(<[ScoreType]>::to_vec);
+            raw_scorer_from_query_scorer(
+                MultiMetricQueryScorer::<VectorElementTypeHalf, TMetric, _>::new(
+                    &vector.try_into()?,
+                    weights.as_deref(),
+                    vector_storage,
+                    hardware_counter,
+                ),
+                point_deleted,
+                vec_deleted,
+                is_stopped,
+            )
+        }
         QueryVector::Recommend(reco_query) => {
             let reco_query: RecoQuery<MultiDenseVectorInternal> = reco_query.transform_into()?;
             raw_scorer_from_query_scorer(
@@ -1005,6 +1388,48 @@ where
         &self,
         points: &mut dyn Iterator<Item = PointOffsetType>,
         top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        self.peek_top_iter_where(points, top, |_| true)
+    }
+
+    fn peek_top_all(&self, top: usize) -> Vec<ScoredPointOffset> {
+        let mut point_ids = 0..self.point_deleted.len() as PointOffsetType;
+        self.peek_top_iter(&mut point_ids, top)
+    }
+
+    fn peek_range_iter(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        range: ScoreRange,
+        top: usize,
+    ) -> Vec<ScoredPointOffset> {
+        if top == 0 {
+            return vec![];
+        }
+        // Ties with the last point of the page are only known after all points are scored
+        let mut in_range =
+            self.peek_top_iter_where(points, usize::MAX, |score| range.contains(score));
+        truncate_with_ties(&mut in_range, top, |point| point.score);
+        in_range
+    }
+
+    fn peek_range_all(&self, range: ScoreRange, top: usize) -> Vec<ScoredPointOffset> {
+        let mut point_ids = 0..self.point_deleted.len() as PointOffsetType;
+        self.peek_range_iter(&mut point_ids, range, top)
+    }
+}
+
+impl<TVector, TQueryScorer> RawScorerImpl<'_, TVector, TQueryScorer>
+where
+    TVector: ?Sized,
+    TQueryScorer: QueryScorer<TVector>,
+{
+    /// Best `top` points among the ones with an accepted score
+    fn peek_top_iter_where(
+        &self,
+        points: &mut dyn Iterator<Item = PointOffsetType>,
+        top: usize,
+        accept_score: impl Fn(ScoreType) -> bool,
     ) -> Vec<ScoredPointOffset> {
         if top == 0 {
             return vec![];
@@ -1039,6 +1464,9 @@ where
                 .score_stored_batch(&chunk[..chunk_size], &mut scores_buffer[..chunk_size]);
 
             for i in 0..chunk_size {
+                if !accept_score(scores_buffer[i]) {
+                    continue;
+                }
                 pq.push(ScoredPointOffset {
                     idx: chunk[i],
                     score: scores_buffer[i],
@@ -1048,11 +1476,6 @@ where
 
         pq.into_sorted_vec()
     }
-
-    fn peek_top_all(&self, top: usize) -> Vec<ScoredPointOffset> {
-        let mut point_ids = 0..self.point_deleted.len() as PointOffsetType;
-        self.peek_top_iter(&mut point_ids, top)
-    }
 }
 
 #[inline]
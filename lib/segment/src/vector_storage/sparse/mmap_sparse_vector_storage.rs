@@ -9,20 +9,24 @@ use common::iterator_ext::IteratorExt;
 use common::types::PointOffsetType;
 use gridstore::config::{Compression, StorageOptions};
 use gridstore::Gridstore;
+use memory::madvise::AdviceSetting;
 use parking_lot::RwLock;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimWeight;
 
-use super::simple_sparse_vector_storage::SPARSE_VECTOR_DISTANCE;
 use crate::common::operation_error::{check_process_stopped, OperationError, OperationResult};
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::vectors::VectorRef;
-use crate::types::VectorStorageDatatype;
+use crate::types::{Distance, SparseDistance, VectorStorageDatatype};
+use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
+use crate::vector_storage::chunked_vector_storage::{ChunkedVectorStorage, VectorOffsetType};
 use crate::vector_storage::dense::dynamic_mmap_flags::DynamicMmapFlags;
 use crate::vector_storage::sparse::stored_sparse_vectors::StoredSparseVector;
 use crate::vector_storage::{SparseVectorStorage, VectorStorage};
 
 const DELETED_DIRNAME: &str = "deleted";
 const STORAGE_DIRNAME: &str = "store";
+const NORMS_DIRNAME: &str = "norms";
 
 /// When resizing bitslice, grow by this extra amount.
 const BITSLICE_GROWTH_SLACK: usize = 1024;
@@ -37,20 +41,23 @@ pub struct MmapSparseVectorStorage {
     deleted_count: usize,
     /// Maximum point offset in the storage + 1. This also means the total amount of point offsets
     next_point_offset: usize,
+    distance: SparseDistance,
+    /// L2 norms of the vectors, only kept for cosine distance
+    norms: Option<ChunkedMmapVectors<DimWeight>>,
 }
 
 impl MmapSparseVectorStorage {
-    pub fn open_or_create(path: &Path) -> OperationResult<Self> {
+    pub fn open_or_create(path: &Path, distance: SparseDistance) -> OperationResult<Self> {
         let deleted_dir = path.join(DELETED_DIRNAME);
         if deleted_dir.is_dir() {
             // Storage already exists, open it
-            return Self::open(path);
+            return Self::open(path, distance);
         }
 
-        Self::create(path)
+        Self::create(path, distance)
     }
 
-    fn open(path: &Path) -> OperationResult<Self> {
+    fn open(path: &Path, distance: SparseDistance) -> OperationResult<Self> {
         let path = path.to_path_buf();
 
         // Storage
@@ -72,15 +79,19 @@ impl MmapSparseVectorStorage {
             .max(Some(storage.max_point_id() as usize))
             .unwrap_or_default();
 
+        let norms = Self::open_norms(&path, distance)?;
+
         Ok(Self {
             storage: Arc::new(RwLock::new(storage)),
             deleted,
             deleted_count,
             next_point_offset,
+            distance,
+            norms,
         })
     }
 
-    fn create(path: &Path) -> OperationResult<Self> {
+    fn create(path: &Path, distance: SparseDistance) -> OperationResult<Self> {
         let path = path.to_path_buf();
 
         // Storage
@@ -102,14 +113,35 @@ impl MmapSparseVectorStorage {
         let deleted_path = path.join(DELETED_DIRNAME);
         let deleted = DynamicMmapFlags::open(&deleted_path)?;
 
+        let norms = Self::open_norms(&path, distance)?;
+
         Ok(Self {
             storage: Arc::new(RwLock::new(storage)),
             deleted,
             deleted_count: 0,
             next_point_offset: 0,
+            distance,
+            norms,
         })
     }
 
+    fn open_norms(
+        path: &Path,
+        distance: SparseDistance,
+    ) -> OperationResult<Option<ChunkedMmapVectors<DimWeight>>> {
+        match distance {
+            SparseDistance::Dot => Ok(None),
+            SparseDistance::Cosine => ChunkedMmapVectors::open(
+                &path.join(NORMS_DIRNAME),
+                1,
+                Some(false),
+                AdviceSetting::Global,
+                Some(false),
+            )
+            .map(Some),
+        }
+    }
+
     fn set_deleted_flag(&mut self, key: PointOffsetType, deleted: bool) -> OperationResult<bool> {
         if (key as usize) < self.deleted.len() {
             return Ok(self.deleted.set(key, deleted));
@@ -153,6 +185,9 @@ impl MmapSparseVectorStorage {
             storage_guard
                 .put_value(key, &StoredSparseVector::from(vector), hw_counter)
                 .map_err(OperationError::service_error)?;
+            if let Some(norms) = &mut self.norms {
+                norms.insert(key as VectorOffsetType, &[vector.norm()])?;
+            }
         } else {
             // delete vector
             storage_guard.delete_value(key);
@@ -177,11 +212,16 @@ impl SparseVectorStorage for MmapSparseVectorStorage {
             .map(SparseVector::try_from)
             .transpose()
     }
+
+    fn get_norm(&self, key: PointOffsetType) -> Option<DimWeight> {
+        let norms = self.norms.as_ref()?;
+        ChunkedVectorStorage::get(norms, key as VectorOffsetType).map(|norm| norm[0])
+    }
 }
 
 impl VectorStorage for MmapSparseVectorStorage {
-    fn distance(&self) -> crate::types::Distance {
-        SPARSE_VECTOR_DISTANCE
+    fn distance(&self) -> Distance {
+        Distance::from(self.distance)
     }
 
     fn datatype(&self) -> crate::types::VectorStorageDatatype {
@@ -250,8 +290,12 @@ impl VectorStorage for MmapSparseVectorStorage {
     fn flusher(&self) -> crate::common::Flusher {
         let storage = self.storage.clone();
         let deleted_flags_flusher = self.deleted.flusher();
+        let norms_flusher = self.norms.as_ref().map(|norms| norms.flusher());
         Box::new(move || {
             deleted_flags_flusher()?;
+            if let Some(norms_flusher) = norms_flusher {
+                norms_flusher()?;
+            }
             storage.read().flush().map_err(|err| {
                 OperationError::service_error(format!(
                     "Failed to flush mmap sparse vector storage: {err}"
@@ -264,6 +308,9 @@ impl VectorStorage for MmapSparseVectorStorage {
     fn files(&self) -> Vec<std::path::PathBuf> {
         let mut files = self.storage.read().files();
         files.extend(self.deleted.files());
+        if let Some(norms) = &self.norms {
+            files.extend(norms.files());
+        }
 
         files
     }
@@ -300,10 +347,11 @@ mod test {
 
     use sparse::common::sparse_vector;
 
+    use crate::types::SparseDistance;
     use crate::vector_storage::sparse::mmap_sparse_vector_storage::{
         MmapSparseVectorStorage, VectorRef,
     };
-    use crate::vector_storage::VectorStorage;
+    use crate::vector_storage::{SparseVectorStorage, VectorStorage};
 
     fn visit_files_recursively(dir: &Path, cb: &mut impl FnMut(PathBuf)) -> std::io::Result<()> {
         if dir.is_dir() {
@@ -326,7 +374,9 @@ mod test {
             .prefix("test_storage")
             .tempdir()
             .unwrap();
-        let storage = MmapSparseVectorStorage::open_or_create(tmp_dir.path()).unwrap();
+        let storage =
+            MmapSparseVectorStorage::open_or_create(tmp_dir.path(), SparseDistance::Cosine)
+                .unwrap();
 
         let mut existing_files = HashSet::new();
         visit_files_recursively(tmp_dir.path(), &mut |path| {
@@ -352,7 +402,9 @@ mod test {
         };
 
         {
-            let mut storage = MmapSparseVectorStorage::open_or_create(tmp_dir.path()).unwrap();
+            let mut storage =
+                MmapSparseVectorStorage::open_or_create(tmp_dir.path(), SparseDistance::Dot)
+                    .unwrap();
 
             storage.insert_vector(0, VectorRef::from(&vector)).unwrap();
             storage.insert_vector(2, VectorRef::from(&vector)).unwrap();
@@ -360,7 +412,7 @@ mod test {
             storage.flusher()().unwrap();
         }
 
-        let storage = MmapSparseVectorStorage::open(tmp_dir.path()).unwrap();
+        let storage = MmapSparseVectorStorage::open(tmp_dir.path(), SparseDistance::Dot).unwrap();
         let result_vector = storage.get_vector(0);
 
         match result_vector {
@@ -370,4 +422,35 @@ mod test {
             _ => panic!("Expected sparse vector"),
         };
     }
+
+    #[test]
+    fn test_norms_persisted() {
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("test_storage")
+            .tempdir()
+            .unwrap();
+
+        let vector = sparse_vector::SparseVector {
+            indices: vec![1, 2],
+            values: vec![3.0, 4.0],
+        };
+
+        {
+            let mut storage =
+                MmapSparseVectorStorage::open_or_create(tmp_dir.path(), SparseDistance::Cosine)
+                    .unwrap();
+            storage.insert_vector(0, VectorRef::from(&vector)).unwrap();
+            storage.insert_vector(3, VectorRef::from(&vector)).unwrap();
+            assert_eq!(storage.get_norm(3), Some(5.0));
+            storage.flusher()().unwrap();
+        }
+
+        let storage =
+            MmapSparseVectorStorage::open(tmp_dir.path(), SparseDistance::Cosine).unwrap();
+        assert_eq!(storage.get_norm(0), Some(5.0));
+        assert_eq!(storage.get_norm(3), Some(5.0));
+
+        let storage = MmapSparseVectorStorage::open(tmp_dir.path(), SparseDistance::Dot).unwrap();
+        assert_eq!(storage.get_norm(0), None);
+    }
 }
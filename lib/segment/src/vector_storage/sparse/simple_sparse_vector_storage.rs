@@ -15,13 +15,11 @@ use crate::common::rocksdb_wrapper::DatabaseColumnWrapper;
 use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::vectors::VectorRef;
-use crate::types::{Distance, VectorStorageDatatype};
+use crate::types::{Distance, SparseDistance, VectorStorageDatatype};
 use crate::vector_storage::bitvec::bitvec_set_deleted;
 use crate::vector_storage::common::StoredRecord;
 use crate::vector_storage::{SparseVectorStorage, VectorStorage, VectorStorageEnum};
 
-pub const SPARSE_VECTOR_DISTANCE: Distance = Distance::Dot;
-
 type StoredSparseVector = StoredRecord<SparseVector>;
 
 /// In-memory vector storage with on-update persistence using `store`
@@ -36,11 +34,15 @@ pub struct SimpleSparseVectorStorage {
     total_vector_count: usize,
     /// Total number of non-zero elements in all vectors. Used to estimate average vector size.
     total_sparse_size: usize,
+    distance: SparseDistance,
+    /// L2 norms of the vectors, only kept for cosine distance
+    norms: Option<Vec<DimWeight>>,
 }
 
 pub fn open_simple_sparse_vector_storage(
     database: Arc<RwLock<DB>>,
     database_column_name: &str,
+    distance: SparseDistance,
     stopped: &AtomicBool,
 ) -> OperationResult<VectorStorageEnum> {
     let (mut deleted, mut deleted_count) = (BitVec::new(), 0);
@@ -48,6 +50,7 @@ pub fn open_simple_sparse_vector_storage(
     let db_wrapper = DatabaseColumnScheduledUpdateWrapper::new(db_wrapper);
     let mut total_vector_count = 0;
     let mut total_sparse_size = 0;
+    let mut norms = (distance == SparseDistance::Cosine).then(Vec::new);
     db_wrapper.lock_db().iter()?;
     for (key, value) in db_wrapper.lock_db().iter()? {
         let point_id: PointOffsetType = bincode::deserialize(&key)
@@ -61,6 +64,9 @@ pub fn open_simple_sparse_vector_storage(
             deleted_count += 1;
         } else {
             total_sparse_size += stored_record.vector.values.len();
+            if let Some(norms) = &mut norms {
+                set_norm(norms, point_id, stored_record.vector.norm());
+            }
         }
         total_vector_count = total_vector_count.max(point_id as usize + 1);
 
@@ -73,9 +79,19 @@ pub fn open_simple_sparse_vector_storage(
         deleted_count,
         total_vector_count,
         total_sparse_size,
+        distance,
+        norms,
     }))
 }
 
+fn set_norm(norms: &mut Vec<DimWeight>, key: PointOffsetType, norm: DimWeight) {
+    let key = key as usize;
+    if key >= norms.len() {
+        norms.resize(key + 1, 0.0);
+    }
+    norms[key] = norm;
+}
+
 impl SimpleSparseVectorStorage {
     /// Set deleted flag for given key. Returns previous deleted state.
     #[inline]
@@ -110,6 +126,9 @@ impl SimpleSparseVectorStorage {
                 self.total_sparse_size = self.total_sparse_size.saturating_sub(vector.values.len());
             } else {
                 self.total_sparse_size += vector.values.len();
+                if let Some(norms) = &mut self.norms {
+                    set_norm(norms, key, vector.norm());
+                }
             }
         }
 
@@ -160,11 +179,15 @@ impl SparseVectorStorage for SimpleSparseVectorStorage {
             Ok(None)
         }
     }
+
+    fn get_norm(&self, key: PointOffsetType) -> Option<DimWeight> {
+        self.norms.as_ref()?.get(key as usize).copied()
+    }
 }
 
 impl VectorStorage for SimpleSparseVectorStorage {
     fn distance(&self) -> Distance {
-        SPARSE_VECTOR_DISTANCE
+        Distance::from(self.distance)
     }
 
     fn datatype(&self) -> VectorStorageDatatype {
@@ -146,6 +146,7 @@ fn product_x4() -> WithQuantization {
     let config = ProductQuantizationConfig {
         compression: crate::types::CompressionRatio::X4,
         always_ram: Some(true),
+        opq: None,
     }
     .into();
 
@@ -160,6 +161,8 @@ fn product_x4() -> WithQuantization {
 fn binary() -> WithQuantization {
     let config = BinaryQuantizationConfig {
         always_ram: Some(true),
+        encoding: None,
+        query_encoding: None,
     }
     .into();
 
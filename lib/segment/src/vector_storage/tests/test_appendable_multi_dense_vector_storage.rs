@@ -6,16 +6,17 @@ use atomic_refcell::AtomicRefCell;
 use common::types::PointOffsetType;
 use common::validation::MAX_MULTIVECTOR_FLATTENED_LEN;
 use rstest::rstest;
+use serde::Serialize;
 use tempfile::Builder;
 
-use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
+use crate::common::rocksdb_wrapper::{open_db, DatabaseColumnWrapper, DB_VECTOR_CF};
 use crate::data_types::vectors::{
     MultiDenseVectorInternal, QueryVector, TypedMultiDenseVectorRef, VectorElementType, VectorRef,
 };
 use crate::fixtures::payload_context_fixture::FixtureIdTracker;
 use crate::id_tracker::IdTrackerSS;
 use crate::types::{Distance, MultiVectorConfig};
-use crate::vector_storage::common::CHUNK_SIZE;
+use crate::vector_storage::common::{StoredRecord, CHUNK_SIZE};
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::open_appendable_memmap_multi_vector_storage;
 use crate::vector_storage::multi_dense::simple_multi_dense_vector_storage::open_simple_multi_dense_vector_storage;
 use crate::vector_storage::{
@@ -74,13 +75,19 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
         match storage as &VectorStorageEnum {
             VectorStorageEnum::DenseSimple(_) => unreachable!(),
             VectorStorageEnum::DenseSimpleByte(_) => unreachable!(),
+            VectorStorageEnum::DenseSimpleInt8(_) => unreachable!(),
             VectorStorageEnum::DenseSimpleHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseSimpleBit(_) => unreachable!(),
             VectorStorageEnum::DenseMemmap(_) => unreachable!(),
             VectorStorageEnum::DenseMemmapByte(_) => unreachable!(),
+            VectorStorageEnum::DenseMemmapInt8(_) => unreachable!(),
             VectorStorageEnum::DenseMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseMemmapBit(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmap(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmapByte(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableMemmapBit(_) => unreachable!(),
             VectorStorageEnum::SparseSimple(_) => unreachable!(),
             VectorStorageEnum::SparseMmap(_) => unreachable!(),
             VectorStorageEnum::MultiDenseSimple(v) => {
@@ -89,6 +96,7 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
                 }
             }
             VectorStorageEnum::MultiDenseSimpleByte(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseSimpleInt8(_) => unreachable!(),
             VectorStorageEnum::MultiDenseSimpleHalf(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 for (orig, vec) in orig_iter.zip(v.iterate_inner_vectors()) {
@@ -96,12 +104,16 @@ fn do_test_delete_points(vector_dim: usize, vec_count: usize, storage: &mut Vect
                 }
             }
             VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableInRam(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableInRamByte(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableInRamInt8(_) => unreachable!(),
             VectorStorageEnum::DenseAppendableInRamHalf(_) => unreachable!(),
+            VectorStorageEnum::DenseAppendableInRamBit(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableInRam(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(_) => unreachable!(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(_) => unreachable!(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(_) => unreachable!(),
         };
     }
@@ -360,3 +372,49 @@ fn test_large_multi_dense_vector_storage(
         }
     }
 }
+
+/// Storages written by earlier versions must keep loading: the stored multivector is bincode
+/// encoded, so any change of its fields changes the format.
+#[test]
+fn test_open_simple_multi_dense_vector_storage_written_before() {
+    /// Layout of a stored multivector as written before query weights were introduced
+    #[derive(Serialize)]
+    struct LegacyMultiDenseVector {
+        flattened_vectors: Vec<VectorElementType>,
+        dim: usize,
+    }
+
+    let vec_dim = 4;
+    let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+    let points = multi_points_fixtures(3, vec_dim);
+
+    {
+        let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
+        let db_wrapper = DatabaseColumnWrapper::new(db, DB_VECTOR_CF);
+        for (i, multi) in points.iter().enumerate() {
+            let record = StoredRecord {
+                deleted: i == 1,
+                vector: LegacyMultiDenseVector {
+                    flattened_vectors: multi.flattened_vectors.clone(),
+                    dim: multi.dim,
+                },
+            };
+            db_wrapper
+                .put(
+                    bincode::serialize(&(i as PointOffsetType)).unwrap(),
+                    bincode::serialize(&record).unwrap(),
+                )
+                .unwrap();
+        }
+        db_wrapper.flusher()().unwrap();
+    }
+
+    let storage = create_vector_storage(MultiDenseStorageType::SimpleRamFloat, vec_dim, dir.path());
+    assert_eq!(storage.total_vector_count(), points.len());
+    assert_eq!(storage.deleted_vector_count(), 1);
+    for (i, multi) in points.iter().enumerate() {
+        let stored_vec = storage.get_vector(i as PointOffsetType);
+        let multi_dense: TypedMultiDenseVectorRef<_> = stored_vec.as_vec_ref().try_into().unwrap();
+        assert_eq!(&multi_dense.to_owned(), multi);
+    }
+}
@@ -11,6 +11,7 @@ use crate::common::rocksdb_wrapper::{open_db, DB_VECTOR_CF};
 use crate::data_types::vectors::QueryVector;
 use crate::fixtures::payload_context_fixture::FixtureIdTracker;
 use crate::id_tracker::IdTrackerSS;
+use crate::types::SparseDistance;
 use crate::vector_storage::query::RecoQuery;
 use crate::vector_storage::sparse::mmap_sparse_vector_storage::MmapSparseVectorStorage;
 use crate::vector_storage::sparse::simple_sparse_vector_storage::open_simple_sparse_vector_storage;
@@ -127,8 +128,13 @@ fn do_test_update_from_delete_points(storage: &mut VectorStorageEnum) {
     {
         let dir2 = Builder::new().prefix("db_dir").tempdir().unwrap();
         let db = open_db(dir2.path(), &[DB_VECTOR_CF]).unwrap();
-        let mut storage2 =
-            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
+        let mut storage2 = open_simple_sparse_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            SparseDistance::Dot,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
 
         points.iter().enumerate().for_each(|(i, opt_vec)| {
             if let Some(vec) = opt_vec {
@@ -257,28 +263,40 @@ fn test_delete_points_in_simple_sparse_vector_storage() {
 
     {
         let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-        let mut storage =
-            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
+        let mut storage = open_simple_sparse_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            SparseDistance::Dot,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
         do_test_delete_points(&mut storage);
         storage.flusher()().unwrap();
     }
     let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let _storage =
-        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
+    let _storage = open_simple_sparse_vector_storage(
+        db,
+        DB_VECTOR_CF,
+        SparseDistance::Dot,
+        &AtomicBool::new(false),
+    )
+    .unwrap();
 }
 
 #[test]
 fn test_delete_points_in_mmap_sparse_vector_storage() {
     let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
-    let mut storage =
-        VectorStorageEnum::SparseMmap(MmapSparseVectorStorage::open_or_create(dir.path()).unwrap());
+    let mut storage = VectorStorageEnum::SparseMmap(
+        MmapSparseVectorStorage::open_or_create(dir.path(), SparseDistance::Dot).unwrap(),
+    );
     do_test_delete_points(&mut storage);
 
     storage.flusher()().unwrap();
 
     drop(storage);
 
-    let _storage = MmapSparseVectorStorage::open_or_create(dir.path()).unwrap();
+    let _storage =
+        MmapSparseVectorStorage::open_or_create(dir.path(), SparseDistance::Dot).unwrap();
 }
 
 #[test]
@@ -286,37 +304,51 @@ fn test_update_from_delete_points_simple_sparse_vector_storage() {
     let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
     {
         let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-        let mut storage =
-            open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
+        let mut storage = open_simple_sparse_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            SparseDistance::Dot,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
         do_test_update_from_delete_points(&mut storage);
         storage.flusher()().unwrap();
     }
 
     let db = open_db(dir.path(), &[DB_VECTOR_CF]).unwrap();
-    let _storage =
-        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap();
+    let _storage = open_simple_sparse_vector_storage(
+        db,
+        DB_VECTOR_CF,
+        SparseDistance::Dot,
+        &AtomicBool::new(false),
+    )
+    .unwrap();
 }
 
 #[test]
 fn test_update_from_delete_points_mmap_sparse_vector_storage() {
     let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
 
-    let mut storage =
-        VectorStorageEnum::SparseMmap(MmapSparseVectorStorage::open_or_create(dir.path()).unwrap());
+    let mut storage = VectorStorageEnum::SparseMmap(
+        MmapSparseVectorStorage::open_or_create(dir.path(), SparseDistance::Dot).unwrap(),
+    );
 
     do_test_update_from_delete_points(&mut storage);
     storage.flusher()().unwrap();
 
     drop(storage);
 
-    let _storage =
-        VectorStorageEnum::SparseMmap(MmapSparseVectorStorage::open_or_create(dir.path()).unwrap());
+    let _storage = VectorStorageEnum::SparseMmap(
+        MmapSparseVectorStorage::open_or_create(dir.path(), SparseDistance::Dot).unwrap(),
+    );
 }
 
 #[test]
 fn test_persistence_in_mmap_sparse_vector_storage() {
     do_test_persistence(|path| {
-        VectorStorageEnum::SparseMmap(MmapSparseVectorStorage::open_or_create(path).unwrap())
+        VectorStorageEnum::SparseMmap(
+            MmapSparseVectorStorage::open_or_create(path, SparseDistance::Dot).unwrap(),
+        )
     });
 }
 
@@ -324,6 +356,12 @@ fn test_persistence_in_mmap_sparse_vector_storage() {
 fn test_persistence_in_simple_sparse_vector_storage() {
     do_test_persistence(|path| {
         let db = open_db(path, &[DB_VECTOR_CF]).unwrap();
-        open_simple_sparse_vector_storage(db, DB_VECTOR_CF, &AtomicBool::new(false)).unwrap()
+        open_simple_sparse_vector_storage(
+            db,
+            DB_VECTOR_CF,
+            SparseDistance::Dot,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
     });
 }
@@ -5,6 +5,7 @@ use std::sync::atomic::AtomicBool;
 use bitvec::prelude::BitSlice;
 use common::types::PointOffsetType;
 use sparse::common::sparse_vector::SparseVector;
+use sparse::common::types::DimWeight;
 
 use super::dense::memmap_dense_vector_storage::MemmapDenseVectorStorage;
 use super::dense::simple_dense_vector_storage::SimpleDenseVectorStorage;
@@ -18,8 +19,8 @@ use crate::common::Flusher;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf, VectorInternal, VectorRef,
+    MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType, VectorElementTypeBit,
+    VectorElementTypeByte, VectorElementTypeHalf, VectorElementTypeInt8, VectorInternal, VectorRef,
 };
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::chunked_mmap_vectors::ChunkedMmapVectors;
@@ -130,6 +131,11 @@ pub trait DenseVectorStorage<T: PrimitiveVectorElement>: VectorStorage {
 pub trait SparseVectorStorage: VectorStorage {
     fn get_sparse(&self, key: PointOffsetType) -> OperationResult<SparseVector>;
     fn get_sparse_opt(&self, key: PointOffsetType) -> OperationResult<Option<SparseVector>>;
+
+    /// Precomputed L2 norm of the stored vector.
+    ///
+    /// Only available if the storage uses cosine distance.
+    fn get_norm(&self, key: PointOffsetType) -> Option<DimWeight>;
 }
 
 pub trait MultiVectorStorage<T: PrimitiveVectorElement>: VectorStorage {
@@ -151,10 +157,14 @@ pub trait MultiVectorStorage<T: PrimitiveVectorElement>: VectorStorage {
 pub enum VectorStorageEnum {
     DenseSimple(SimpleDenseVectorStorage<VectorElementType>),
     DenseSimpleByte(SimpleDenseVectorStorage<VectorElementTypeByte>),
+    DenseSimpleInt8(SimpleDenseVectorStorage<VectorElementTypeInt8>),
     DenseSimpleHalf(SimpleDenseVectorStorage<VectorElementTypeHalf>),
+    DenseSimpleBit(SimpleDenseVectorStorage<VectorElementTypeBit>),
     DenseMemmap(Box<MemmapDenseVectorStorage<VectorElementType>>),
     DenseMemmapByte(Box<MemmapDenseVectorStorage<VectorElementTypeByte>>),
+    DenseMemmapInt8(Box<MemmapDenseVectorStorage<VectorElementTypeInt8>>),
     DenseMemmapHalf(Box<MemmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseMemmapBit(Box<MemmapDenseVectorStorage<VectorElementTypeBit>>),
     DenseAppendableMemmap(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -171,6 +181,14 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableMemmapInt8(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeInt8,
+                ChunkedMmapVectors<VectorElementTypeInt8>,
+            >,
+        >,
+    ),
     DenseAppendableMemmapHalf(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -179,6 +197,14 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableMemmapBit(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeBit,
+                ChunkedMmapVectors<VectorElementTypeBit>,
+            >,
+        >,
+    ),
     DenseAppendableInRam(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -195,6 +221,14 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableInRamInt8(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeInt8,
+                InRamPersistedVectors<VectorElementTypeInt8>,
+            >,
+        >,
+    ),
     DenseAppendableInRamHalf(
         Box<
             AppendableMmapDenseVectorStorage<
@@ -203,10 +237,19 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    DenseAppendableInRamBit(
+        Box<
+            AppendableMmapDenseVectorStorage<
+                VectorElementTypeBit,
+                InRamPersistedVectors<VectorElementTypeBit>,
+            >,
+        >,
+    ),
     SparseSimple(SimpleSparseVectorStorage),
     SparseMmap(MmapSparseVectorStorage),
     MultiDenseSimple(SimpleMultiDenseVectorStorage<VectorElementType>),
     MultiDenseSimpleByte(SimpleMultiDenseVectorStorage<VectorElementTypeByte>),
+    MultiDenseSimpleInt8(SimpleMultiDenseVectorStorage<VectorElementTypeInt8>),
     MultiDenseSimpleHalf(SimpleMultiDenseVectorStorage<VectorElementTypeHalf>),
     MultiDenseAppendableMemmap(
         Box<
@@ -226,6 +269,15 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    MultiDenseAppendableMemmapInt8(
+        Box<
+            AppendableMmapMultiDenseVectorStorage<
+                VectorElementTypeInt8,
+                ChunkedMmapVectors<VectorElementTypeInt8>,
+                ChunkedMmapVectors<MultivectorMmapOffset>,
+            >,
+        >,
+    ),
     MultiDenseAppendableMemmapHalf(
         Box<
             AppendableMmapMultiDenseVectorStorage<
@@ -253,6 +305,15 @@ pub enum VectorStorageEnum {
             >,
         >,
     ),
+    MultiDenseAppendableInRamInt8(
+        Box<
+            AppendableMmapMultiDenseVectorStorage<
+                VectorElementTypeInt8,
+                InRamPersistedVectors<VectorElementTypeInt8>,
+                InRamPersistedVectors<MultivectorMmapOffset>,
+            >,
+        >,
+    ),
     MultiDenseAppendableInRamHalf(
         Box<
             AppendableMmapMultiDenseVectorStorage<
@@ -269,26 +330,37 @@ impl VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(_) => None,
             VectorStorageEnum::DenseSimpleByte(_) => None,
+            VectorStorageEnum::DenseSimpleInt8(_) => None,
             VectorStorageEnum::DenseSimpleHalf(_) => None,
+            VectorStorageEnum::DenseSimpleBit(_) => None,
             VectorStorageEnum::DenseMemmap(_) => None,
             VectorStorageEnum::DenseMemmapByte(_) => None,
+            VectorStorageEnum::DenseMemmapInt8(_) => None,
             VectorStorageEnum::DenseMemmapHalf(_) => None,
+            VectorStorageEnum::DenseMemmapBit(_) => None,
             VectorStorageEnum::DenseAppendableMemmap(_) => None,
             VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapInt8(_) => None,
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapBit(_) => None,
             VectorStorageEnum::DenseAppendableInRam(_) => None,
             VectorStorageEnum::DenseAppendableInRamByte(_) => None,
+            VectorStorageEnum::DenseAppendableInRamInt8(_) => None,
             VectorStorageEnum::DenseAppendableInRamHalf(_) => None,
+            VectorStorageEnum::DenseAppendableInRamBit(_) => None,
             VectorStorageEnum::SparseSimple(_) => None,
             VectorStorageEnum::SparseMmap(_) => None,
             VectorStorageEnum::MultiDenseSimple(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseSimpleByte(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseSimpleInt8(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseSimpleHalf(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmap(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableInRam(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableInRamByte(s) => Some(s.multi_vector_config()),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(s) => Some(s.multi_vector_config()),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(s) => Some(s.multi_vector_config()),
         }
     }
@@ -299,34 +371,62 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseSimpleByte(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseSimpleInt8(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseSimpleHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            // Each stored element packs multiple dimensions
+            VectorStorageEnum::DenseSimpleBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * VectorElementTypeBit::BITS])
+            }
             VectorStorageEnum::DenseMemmap(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
             VectorStorageEnum::DenseMemmapByte(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseMemmapInt8(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            // Each stored element packs multiple dimensions
+            VectorStorageEnum::DenseMemmapBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * VectorElementTypeBit::BITS])
+            }
             VectorStorageEnum::DenseAppendableMemmap(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            // Each stored element packs multiple dimensions
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * VectorElementTypeBit::BITS])
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
             VectorStorageEnum::DenseAppendableInRamByte(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim()])
+            }
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            // Each stored element packs multiple dimensions
+            VectorStorageEnum::DenseAppendableInRamBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * VectorElementTypeBit::BITS])
+            }
             VectorStorageEnum::SparseSimple(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::SparseMmap(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::MultiDenseSimple(v) => {
@@ -335,6 +435,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseSimpleByte(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
             VectorStorageEnum::MultiDenseSimpleHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -344,6 +447,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -353,6 +459,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => {
+                VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
+            }
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 VectorInternal::from(MultiDenseVectorInternal::placeholder(v.vector_dim()))
             }
@@ -363,24 +472,38 @@ impl VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseSimpleByte(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapByte(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::DenseAppendableInRam(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::DenseAppendableInRamHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::SparseSimple(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::SparseMmap(_v) => {
                 unreachable!(
@@ -389,6 +512,7 @@ impl VectorStorageEnum {
             }
             VectorStorageEnum::MultiDenseSimple(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 v.size_of_available_vectors_in_bytes()
@@ -396,6 +520,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
@@ -405,6 +532,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
@@ -417,26 +547,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.distance(),
             VectorStorageEnum::DenseSimpleByte(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.distance(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.distance(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.distance(),
             VectorStorageEnum::DenseMemmap(v) => v.distance(),
             VectorStorageEnum::DenseMemmapByte(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.distance(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.distance(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.distance(),
             VectorStorageEnum::SparseSimple(v) => v.distance(),
             VectorStorageEnum::SparseMmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimple(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.distance(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.distance(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.distance(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.distance(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.distance(),
         }
     }
@@ -445,26 +586,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.datatype(),
             VectorStorageEnum::DenseSimpleByte(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.datatype(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.datatype(),
             VectorStorageEnum::DenseMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.datatype(),
             VectorStorageEnum::SparseSimple(v) => v.datatype(),
             VectorStorageEnum::SparseMmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimple(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.datatype(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.datatype(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.datatype(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.datatype(),
         }
     }
@@ -473,26 +625,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::DenseSimpleByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.is_on_disk(),
             VectorStorageEnum::SparseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::SparseMmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimple(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.is_on_disk(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.is_on_disk(),
         }
     }
@@ -501,26 +664,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::DenseSimpleByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.total_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::SparseMmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimple(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.total_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.total_vector_count(),
         }
     }
@@ -529,26 +703,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.get_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseSimpleBit(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.get_vector(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.get_vector(key),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.get_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector(key),
             VectorStorageEnum::SparseMmap(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.get_vector(key),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.get_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.get_vector(key),
         }
     }
@@ -557,26 +742,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseSimpleBit(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.get_vector_opt(key),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.get_vector_opt(key),
             VectorStorageEnum::SparseSimple(v) => v.get_vector_opt(key),
             VectorStorageEnum::SparseMmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.get_vector_opt(key),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.get_vector_opt(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.get_vector_opt(key),
         }
     }
@@ -585,26 +781,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseSimpleByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseSimpleHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseSimpleBit(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseMemmapBit(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableInRam(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.insert_vector(key, vector),
             VectorStorageEnum::SparseSimple(v) => v.insert_vector(key, vector),
             VectorStorageEnum::SparseMmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimple(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.insert_vector(key, vector),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.insert_vector(key, vector),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.insert_vector(key, vector),
         }
     }
@@ -617,24 +824,35 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseSimpleByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseSimpleHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseSimpleBit(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseMemmapBit(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableInRam(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseSimple(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseMmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::MultiDenseSimple(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => {
                 v.update_from(other_vectors, stopped)
@@ -642,6 +860,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
@@ -651,6 +872,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => {
+                v.update_from(other_vectors, stopped)
+            }
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
@@ -661,26 +885,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.flusher(),
             VectorStorageEnum::DenseSimpleByte(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.flusher(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.flusher(),
             VectorStorageEnum::DenseMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.flusher(),
             VectorStorageEnum::SparseSimple(v) => v.flusher(),
             VectorStorageEnum::SparseMmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimple(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.flusher(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.flusher(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.flusher(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.flusher(),
         }
     }
@@ -689,26 +924,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.files(),
             VectorStorageEnum::DenseSimpleByte(v) => v.files(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.files(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.files(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.files(),
             VectorStorageEnum::DenseMemmap(v) => v.files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.files(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.files(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.files(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.files(),
             VectorStorageEnum::SparseSimple(v) => v.files(),
             VectorStorageEnum::SparseMmap(v) => v.files(),
             VectorStorageEnum::MultiDenseSimple(v) => v.files(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.files(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.files(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.files(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.files(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.files(),
         }
     }
@@ -717,26 +963,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseSimpleBit(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.delete_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::SparseMmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.delete_vector(key),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.delete_vector(key),
         }
     }
@@ -745,26 +1002,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseSimpleByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseSimpleHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseSimpleBit(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRam(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseMmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimple(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.is_deleted_vector(key),
         }
     }
@@ -773,26 +1041,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseMmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.deleted_vector_count(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.deleted_vector_count(),
         }
     }
@@ -801,26 +1080,37 @@ impl VectorStorage for VectorStorageEnum {
         match self {
             VectorStorageEnum::DenseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseSimpleByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseSimpleHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseSimpleBit(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRam(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRamByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableInRamInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableInRamHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableInRamBit(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseMmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimple(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimpleByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseSimpleInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseSimpleHalf(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableMemmapInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRam(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRamByte(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::MultiDenseAppendableInRamInt8(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseAppendableInRamHalf(v) => v.deleted_vector_bitslice(),
         }
     }
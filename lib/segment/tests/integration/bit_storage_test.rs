@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::cpu::CpuPermit;
+use common::types::{ScoredPointOffset, TelemetryDetail};
+use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use rstest::rstest;
+use segment::data_types::vectors::{
+    only_default_vector, QueryVector, VectorInternal, DEFAULT_VECTOR_NAME,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::hnsw_index::num_rayon_threads;
+use segment::index::VectorIndex;
+use segment::segment_constructor::{build_segment, VectorIndexBuildArgs};
+use segment::types::{
+    Distance, HnswConfig, Indexes, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageDatatype, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+fn bit_segment_config(
+    dim: usize,
+    distance: Distance,
+    storage_type: VectorStorageType,
+) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: Some(VectorStorageDatatype::Bit),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+fn bits(vector: &[f32]) -> Vec<bool> {
+    vector.iter().map(|&value| value > 0.0).collect()
+}
+
+fn expected_score(distance: Distance, a: &[bool], b: &[bool]) -> f32 {
+    match distance {
+        Distance::Hamming => a.iter().zip(b).filter(|(a, b)| a != b).count() as f32,
+        Distance::Jaccard => {
+            let intersection = a.iter().zip(b).filter(|(a, b)| **a && **b).count();
+            let union = a.iter().zip(b).filter(|(a, b)| **a || **b).count();
+            if union == 0 {
+                1.0
+            } else {
+                intersection as f32 / union as f32
+            }
+        }
+        Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => {
+            unreachable!()
+        }
+    }
+}
+
+#[rstest]
+fn test_bit_storage_search(
+    #[values(Distance::Hamming, Distance::Jaccard)] distance: Distance,
+    #[values(
+        VectorStorageType::Memory,
+        VectorStorageType::ChunkedMmap,
+        VectorStorageType::InRamChunkedMmap
+    )]
+    storage_type: VectorStorageType,
+) {
+    let dim = 72;
+    let num_vectors: u64 = 500;
+    let top = 10;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let config = bit_segment_config(dim, distance, storage_type);
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let vectors = (0..num_vectors)
+        .map(|_| random_vector(&mut rnd, dim))
+        .collect_vec();
+    for (n, vector) in vectors.iter().enumerate() {
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                (n as u64).into(),
+                only_default_vector(vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    // Stored vectors are unpacked into zeros and ones
+    let stored = segment.vector(DEFAULT_VECTOR_NAME, 0.into()).unwrap();
+    let expected: Vec<f32> = bits(&vectors[0])
+        .into_iter()
+        .map(|bit| if bit { 1.0 } else { 0.0 })
+        .collect();
+    assert_eq!(stored, Some(VectorInternal::Dense(expected)));
+
+    for _ in 0..10 {
+        let query = random_vector(&mut rnd, dim);
+        let query_bits = bits(&query);
+
+        let result = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &query.into(),
+                &WithPayload::default(),
+                &false.into(),
+                None,
+                top,
+                None,
+            )
+            .unwrap();
+        assert_eq!(result.len(), top);
+
+        let mut expected_scores = vectors
+            .iter()
+            .map(|vector| expected_score(distance, &query_bits, &bits(vector)))
+            .collect_vec();
+        match distance {
+            Distance::Hamming => expected_scores.sort_by(|a, b| a.total_cmp(b)),
+            _ => expected_scores.sort_by(|a, b| b.total_cmp(a)),
+        }
+
+        for (point, expected_score) in result.iter().zip(&expected_scores) {
+            assert!((point.score - expected_score).abs() < 1e-5);
+        }
+    }
+}
+
+fn scores(result: &[ScoredPointOffset]) -> Vec<f32> {
+    result.iter().map(|point| point.score).collect()
+}
+
+#[rstest]
+fn test_bit_storage_hnsw(#[values(Distance::Hamming, Distance::Jaccard)] distance: Distance) {
+    let dim = 64;
+    let num_vectors: u64 = 1_000;
+    let top = 10;
+    let ef = 64;
+    let attempts = 20;
+    let max_failures = 2;
+
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+    let stopped = AtomicBool::new(false);
+
+    let config = bit_segment_config(dim, distance, VectorStorageType::Memory);
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+    for n in 0..num_vectors {
+        let vector = random_vector(&mut rnd, dim);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 0,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    };
+    let permit_cpu_count = num_rayon_threads(hnsw_config.max_indexing_threads);
+    let permit = Arc::new(CpuPermit::dummy(permit_cpu_count as u32));
+    let vector_data = &segment.vector_data[DEFAULT_VECTOR_NAME];
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: vector_data.vector_storage.clone(),
+            quantized_vectors: vector_data.quantized_vectors.clone(),
+            payload_index: segment.payload_index.clone(),
+            hnsw_config,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            stopped: &stopped,
+        },
+    )
+    .unwrap();
+
+    let mut failures = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rnd, dim).into();
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(ef),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+        let plain_result = vector_data
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        // Bitwise distances have many ties, so only scores are compared
+        if scores(&index_result[0]) != scores(&plain_result[0]) {
+            failures += 1;
+        }
+    }
+
+    // Search was performed with the graph
+    assert_eq!(
+        hnsw_index
+            .get_telemetry_data(TelemetryDetail::default())
+            .unfiltered_hnsw
+            .count,
+        attempts,
+    );
+    assert!(
+        failures <= max_failures,
+        "failures: {failures} of {attempts}"
+    );
+}
+
+#[test]
+fn test_bit_storage_config_validation() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    // Bit vectors can't be compared with float distances
+    let config = bit_segment_config(64, Distance::Cosine, VectorStorageType::Memory);
+    assert!(build_segment(dir.path(), &config, true).is_err());
+
+    // Size must be a multiple of the packing width
+    let config = bit_segment_config(63, Distance::Hamming, VectorStorageType::Memory);
+    assert!(build_segment(dir.path(), &config, true).is_err());
+
+    // Bitwise distances require bit vectors
+    let mut config = bit_segment_config(64, Distance::Jaccard, VectorStorageType::Memory);
+    config
+        .vector_data
+        .get_mut(DEFAULT_VECTOR_NAME)
+        .unwrap()
+        .datatype = None;
+    assert!(build_segment(dir.path(), &config, true).is_err());
+}
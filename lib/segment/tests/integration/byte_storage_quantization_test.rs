@@ -13,15 +13,18 @@ use segment::data_types::vectors::{
     only_default_vector, DenseVector, QueryVector, DEFAULT_VECTOR_NAME,
 };
 use segment::entry::entry_point::SegmentEntry;
-use segment::fixtures::payload_fixtures::{random_dense_byte_vector, random_int_payload};
+use segment::fixtures::payload_fixtures::{
+    random_dense_byte_vector, random_dense_int8_vector, random_int_payload,
+};
 use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
 use segment::index::hnsw_index::num_rayon_threads;
 use segment::index::{PayloadIndex, VectorIndex};
 use segment::segment_constructor::build_segment;
 use segment::types::{
-    BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
-    HnswConfig, Indexes, PayloadSchemaType, ProductQuantizationConfig, QuantizationSearchParams,
-    Range, ScalarQuantizationConfig, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
+    BinaryQuantizationConfig, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding,
+    CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes,
+    PayloadSchemaType, ProductQuantizationConfig, QuantizationSearchParams, Range,
+    ScalarQuantizationConfig, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
     VectorStorageDatatype, VectorStorageType,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
@@ -41,6 +44,7 @@ enum QuantizationVariant {
     Scalar,
     PQ,
     Binary,
+    BinaryTwoBitsAsymmetric,
 }
 
 fn random_vector<R>(rnd_gen: &mut R, dim: usize, data_type: VectorStorageDatatype) -> DenseVector
@@ -55,6 +59,8 @@ where
             vector
         }
         VectorStorageDatatype::Uint8 => random_dense_byte_vector(rnd_gen, dim),
+        VectorStorageDatatype::Int8 => random_dense_int8_vector(rnd_gen, dim),
+        VectorStorageDatatype::Bit => unreachable!(),
     }
 }
 
@@ -135,6 +141,15 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     32, // ef
     5., // min_acc out of 100
 )]
+#[case::nearest_binary_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::Int8,
+    QuantizationVariant::Binary,
+    Distance::Dot,
+    128, // dim
+    32, // ef
+    5., // min_acc out of 100
+)]
 #[case::discovery_binary_dot(
     QueryVariant::Discovery,
     VectorStorageDatatype::Uint8,
@@ -180,6 +195,15 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     64, // ef
     15., // min_acc out of 100
 )]
+#[case::nearest_binary_two_bits_cosine(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::Uint8,
+    QuantizationVariant::BinaryTwoBitsAsymmetric,
+    Distance::Cosine,
+    128, // dim
+    32, // ef
+    25., // min_acc out of 100
+)]
 #[case::nearest_scalar_dot(
     QueryVariant::Nearest,
     VectorStorageDatatype::Float16,
@@ -198,6 +222,15 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     32, // ef
     80., // min_acc out of 100
 )]
+#[case::nearest_scalar_dot(
+    QueryVariant::Nearest,
+    VectorStorageDatatype::Int8,
+    QuantizationVariant::Scalar,
+    Distance::Dot,
+    32, // dim
+    32, // ef
+    80., // min_acc out of 100
+)]
 #[case::nearest_scalar_cosine(
     QueryVariant::Nearest,
     VectorStorageDatatype::Uint8,
@@ -264,7 +297,7 @@ fn test_byte_storage_binary_quantization_hnsw(
     let int_key = "int";
 
     let mut segment_byte = build_segment(dir_byte.path(), &config_byte, true).unwrap();
-    // check that `segment_byte` uses byte or half storage
+    // check that `segment_byte` uses byte, int8 or half storage
     {
         let borrowed_storage = segment_byte.vector_data[DEFAULT_VECTOR_NAME]
             .vector_storage
@@ -272,6 +305,7 @@ fn test_byte_storage_binary_quantization_hnsw(
         let raw_storage: &VectorStorageEnum = &borrowed_storage;
         assert!(
             matches!(raw_storage, &VectorStorageEnum::DenseSimpleByte(_))
+                | matches!(raw_storage, &VectorStorageEnum::DenseSimpleInt8(_))
                 | matches!(raw_storage, &VectorStorageEnum::DenseSimpleHalf(_))
         );
     }
@@ -314,9 +348,21 @@ fn test_byte_storage_binary_quantization_hnsw(
         QuantizationVariant::PQ => ProductQuantizationConfig {
             compression: CompressionRatio::X8,
             always_ram: None,
+            opq: None,
+        }
+        .into(),
+        QuantizationVariant::Binary => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        }
+        .into(),
+        QuantizationVariant::BinaryTwoBitsAsymmetric => BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: Some(BinaryQuantizationEncoding::TwoBits),
+            query_encoding: Some(BinaryQuantizationQueryEncoding::Scalar8Bits),
         }
         .into(),
-        QuantizationVariant::Binary => BinaryQuantizationConfig { always_ram: None }.into(),
     };
 
     segment_byte
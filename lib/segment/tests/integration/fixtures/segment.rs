@@ -11,8 +11,8 @@ use segment::segment::Segment;
 use segment::segment_constructor::build_segment;
 use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
 use segment::types::{
-    Distance, Indexes, SegmentConfig, SparseVectorDataConfig, SparseVectorStorageType,
-    VectorDataConfig, VectorName, VectorStorageType,
+    Distance, Indexes, SegmentConfig, SparseDistance, SparseVectorDataConfig,
+    SparseVectorStorageType, VectorDataConfig, VectorName, VectorStorageType,
 };
 use sparse::common::sparse_vector::SparseVector;
 
@@ -263,6 +263,7 @@ pub fn build_segment_sparse_1(path: &Path) -> Segment {
                 SparseVectorDataConfig {
                     index: SparseIndexConfig::new(None, SparseIndexType::MutableRam, None),
                     storage_type: SparseVectorStorageType::default(),
+                    distance: SparseDistance::default(),
                 },
             )]),
             payload_storage_type: Default::default(),
@@ -355,6 +356,7 @@ pub fn build_segment_sparse_2(path: &Path) -> Segment {
                 SparseVectorDataConfig {
                     index: SparseIndexConfig::new(None, SparseIndexType::MutableRam, None),
                     storage_type: SparseVectorStorageType::default(),
+                    distance: SparseDistance::default(),
                 },
             )]),
             payload_storage_type: Default::default(),
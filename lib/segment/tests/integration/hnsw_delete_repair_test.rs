@@ -0,0 +1,270 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::cpu::CpuPermit;
+use common::types::{DetailsLevel, PointOffsetType, TelemetryDetail};
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use segment::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_vector;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::{VectorIndex, VectorIndexEnum};
+use segment::segment::Segment;
+use segment::segment_constructor::segment_builder::SegmentBuilder;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::segment_constructor::{load_segment, VectorIndexBuildArgs};
+use segment::types::{Distance, HnswConfig, Indexes, SeqNumberType};
+use segment::vector_storage::VectorStorage;
+use tempfile::Builder;
+
+const DIM: usize = 8;
+const NUM_VECTORS: u64 = 2_000;
+
+fn hnsw_config() -> HnswConfig {
+    HnswConfig {
+        m: 8,
+        ef_construct: 32,
+        full_scan_threshold: 1,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+    }
+}
+
+fn open_args<'a>(path: &'a Path, segment: &Segment) -> HnswIndexOpenArgs<'a> {
+    HnswIndexOpenArgs {
+        path,
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        hnsw_config: hnsw_config(),
+    }
+}
+
+fn dead_links_ratio(hnsw_index: &HNSWIndex) -> f64 {
+    let detail = TelemetryDetail {
+        level: DetailsLevel::Level2,
+        histograms: false,
+    };
+    hnsw_index
+        .get_telemetry_data(detail)
+        .dead_links_ratio
+        .unwrap()
+}
+
+/// Fraction of the exact top, found by the index
+fn recall(hnsw_index: &HNSWIndex, segment: &Segment, queries: &[QueryVector]) -> f64 {
+    let top = 10;
+    let mut found = 0;
+    for query in queries {
+        let index_result = hnsw_index
+            .search(&[query], None, top, None, &Default::default())
+            .unwrap();
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[query], None, top, None, &Default::default())
+            .unwrap();
+        let plain_ids: HashSet<_> = plain_result[0].iter().map(|p| p.idx).collect();
+        found += index_result[0]
+            .iter()
+            .filter(|p| plain_ids.contains(&p.idx))
+            .count();
+    }
+    found as f64 / (top * queries.len()) as f64
+}
+
+#[test]
+fn test_hnsw_repair_deleted_points() {
+    let stopped = AtomicBool::new(false);
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), DIM, Distance::Cosine).unwrap();
+    for n in 0..NUM_VECTORS {
+        let vector = random_vector(&mut rnd, DIM);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let mut hnsw_index = HNSWIndex::build(
+        open_args(hnsw_dir.path(), &segment),
+        VectorIndexBuildArgs {
+            permit: Arc::new(CpuPermit::dummy(2)),
+            old_indices: &[],
+            gpu_device: None,
+            stopped: &stopped,
+        },
+    )
+    .unwrap();
+    assert_eq!(dead_links_ratio(&hnsw_index), 0.0);
+
+    // Delete half of the points
+    let mut deleted = Vec::new();
+    for n in 0..NUM_VECTORS {
+        if rnd.random_bool(0.5) {
+            let internal_id = segment.id_tracker.borrow().internal_id(n.into()).unwrap();
+            segment
+                .delete_point(NUM_VECTORS + n, n.into(), &hw_counter)
+                .unwrap();
+            deleted.push(internal_id);
+        }
+    }
+
+    let queries: Vec<QueryVector> = (0..100)
+        .map(|_| random_vector(&mut rnd, DIM).into())
+        .collect();
+
+    let unrepaired_ratio = dead_links_ratio(&hnsw_index);
+    let unrepaired_recall = recall(&hnsw_index, &segment, &queries);
+    assert!(unrepaired_ratio > 0.3, "dead links: {unrepaired_ratio}");
+
+    for &point_id in &deleted {
+        hnsw_index.repair_deleted_point(point_id).unwrap();
+    }
+
+    let repaired_ratio = dead_links_ratio(&hnsw_index);
+    let repaired_recall = recall(&hnsw_index, &segment, &queries);
+    assert!(
+        repaired_ratio < unrepaired_ratio,
+        "dead links: {unrepaired_ratio} -> {repaired_ratio}",
+    );
+    assert!(
+        repaired_recall >= unrepaired_recall,
+        "recall: {unrepaired_recall} -> {repaired_recall}",
+    );
+
+    // Deleted points are never returned
+    let deleted_set: HashSet<PointOffsetType> = deleted.iter().copied().collect();
+    for query in &queries {
+        let result = hnsw_index
+            .search(&[query], None, 10, None, &Default::default())
+            .unwrap();
+        assert!(result[0].iter().all(|p| !deleted_set.contains(&p.idx)));
+    }
+
+    // Repaired links are persisted on flush
+    hnsw_index.flusher()().unwrap();
+    assert!(hnsw_index
+        .files()
+        .iter()
+        .any(|file| file.ends_with("links_repaired.bin")));
+    drop(hnsw_index);
+
+    let hnsw_index = HNSWIndex::open(open_args(hnsw_dir.path(), &segment)).unwrap();
+    assert_eq!(dead_links_ratio(&hnsw_index), repaired_ratio);
+    assert_eq!(recall(&hnsw_index, &segment, &queries), repaired_recall);
+}
+
+fn build_hnsw_segment(dir: &Path, source: &Segment) -> Segment {
+    let temp_dir = Builder::new().prefix("segment_temp_dir").tempdir().unwrap();
+    let stopped = AtomicBool::new(false);
+
+    let mut config = source.segment_config.clone();
+    config
+        .vector_data
+        .get_mut(DEFAULT_VECTOR_NAME)
+        .unwrap()
+        .index = Indexes::Hnsw(hnsw_config());
+
+    let mut builder = SegmentBuilder::new(dir, temp_dir.path(), &config).unwrap();
+    builder.update(&[source], &stopped).unwrap();
+    builder.build(CpuPermit::dummy(2), &stopped).unwrap()
+}
+
+fn segment_dead_links_ratio(segment: &Segment) -> f64 {
+    match &*segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_index
+        .borrow()
+    {
+        VectorIndexEnum::Hnsw(hnsw_index) => dead_links_ratio(hnsw_index),
+        _ => panic!("unexpected vector index type"),
+    }
+}
+
+#[test]
+fn test_segment_delete_repairs_hnsw() {
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let source_dir = Builder::new().prefix("source_dir").tempdir().unwrap();
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut source = build_simple_segment(source_dir.path(), DIM, Distance::Cosine).unwrap();
+    for n in 0..NUM_VECTORS {
+        let vector = random_vector(&mut rnd, DIM);
+        source
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let mut segment = build_hnsw_segment(dir.path(), &source);
+    let unrepaired = build_hnsw_segment(dir.path(), &source);
+    assert_eq!(segment_dead_links_ratio(&segment), 0.0);
+
+    // Delete half of the points and vectors, bypassing the repair in one of the segments
+    for n in 0..NUM_VECTORS {
+        if !rnd.random_bool(0.5) {
+            continue;
+        }
+        let internal_id = unrepaired
+            .id_tracker
+            .borrow()
+            .internal_id(n.into())
+            .unwrap();
+        if n % 2 == 0 {
+            segment
+                .delete_point(NUM_VECTORS + n, n.into(), &hw_counter)
+                .unwrap();
+            unrepaired.id_tracker.borrow_mut().drop(n.into()).unwrap();
+        } else {
+            segment
+                .delete_vector(NUM_VECTORS + n, n.into(), DEFAULT_VECTOR_NAME, &hw_counter)
+                .unwrap();
+            unrepaired.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .borrow_mut()
+                .delete_vector(internal_id)
+                .unwrap();
+        }
+    }
+
+    let unrepaired_ratio = segment_dead_links_ratio(&unrepaired);
+    let repaired_ratio = segment_dead_links_ratio(&segment);
+    assert!(
+        repaired_ratio < unrepaired_ratio,
+        "dead links: {unrepaired_ratio} -> {repaired_ratio}",
+    );
+
+    // Repaired links are saved with the segment
+    segment.flush(true, false).unwrap();
+    let path = segment.current_path.clone();
+    drop(segment);
+    let segment = load_segment(&path, &AtomicBool::new(false))
+        .unwrap()
+        .unwrap();
+    assert_eq!(segment_dead_links_ratio(&segment), repaired_ratio);
+}
@@ -25,7 +25,7 @@ use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
     CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswConfig, Indexes,
     ProductQuantizationConfig, QuantizationConfig, QuantizationSearchParams,
-    ScalarQuantizationConfig, SearchParams,
+    ScalarQuantizationConfig, ScalarType, SearchParams,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use tempfile::Builder;
@@ -345,6 +345,34 @@ fn hnsw_quantized_search_manhattan_test() {
     );
 }
 
+#[test]
+fn hnsw_quantized_search_int4_cosine_test() {
+    hnsw_quantized_search_test(
+        Distance::Cosine,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int4,
+            quantile: Some(0.99),
+            always_ram: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_quantized_search_int2_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        5003,
+        ScalarQuantizationConfig {
+            r#type: ScalarType::Int2,
+            quantile: Some(0.99),
+            always_ram: Some(true),
+        }
+        .into(),
+    );
+}
+
 #[test]
 fn hnsw_product_quantization_cosine_test() {
     hnsw_quantized_search_test(
@@ -353,6 +381,7 @@ fn hnsw_product_quantization_cosine_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
     );
@@ -366,6 +395,21 @@ fn hnsw_product_quantization_euclid_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
+        }
+        .into(),
+    );
+}
+
+#[test]
+fn hnsw_product_quantization_opq_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        1003,
+        ProductQuantizationConfig {
+            compression: CompressionRatio::X4,
+            always_ram: Some(true),
+            opq: Some(true),
         }
         .into(),
     );
@@ -379,6 +423,7 @@ fn hnsw_product_quantization_manhattan_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
     );
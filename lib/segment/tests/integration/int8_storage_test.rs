@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use rstest::rstest;
+use segment::data_types::vectors::{
+    only_default_multi_vector, only_default_vector, MultiDenseVectorInternal, QueryVector,
+    VectorInternal, DEFAULT_VECTOR_NAME,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_dense_int8_vector;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, MultiVectorConfig, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageDatatype, VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+const DIM: usize = 40;
+const NUM_VECTORS: u64 = 300;
+const TOP: usize = 10;
+
+fn int8_segment_config(
+    distance: Distance,
+    storage_type: VectorStorageType,
+    multivector_config: Option<MultiVectorConfig>,
+) -> SegmentConfig {
+    SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: DIM,
+                distance,
+                storage_type,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config,
+                datatype: Some(VectorStorageDatatype::Int8),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    }
+}
+
+/// Score of two integer vectors, computed in floats
+fn expected_score(distance: Distance, a: &[f32], b: &[f32]) -> f32 {
+    let dot = a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+    match distance {
+        Distance::Dot => dot,
+        Distance::Cosine => {
+            let norm_a = a.iter().map(|a| a * a).sum::<f32>().sqrt();
+            let norm_b = b.iter().map(|b| b * b).sum::<f32>().sqrt();
+            dot / (norm_a * norm_b)
+        }
+        Distance::Euclid => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f32>()
+            .sqrt(),
+        Distance::Manhattan => a.iter().zip(b).map(|(a, b)| (a - b).abs()).sum(),
+        Distance::Hamming | Distance::Jaccard => unreachable!(),
+    }
+}
+
+fn assert_scores(distance: Distance, scores: &[f32], mut expected_scores: Vec<f32>) {
+    match distance {
+        Distance::Euclid | Distance::Manhattan => expected_scores.sort_by(|a, b| a.total_cmp(b)),
+        _ => expected_scores.sort_by(|a, b| b.total_cmp(a)),
+    }
+
+    assert_eq!(scores.len(), TOP);
+    for (score, expected_score) in scores.iter().zip(&expected_scores) {
+        assert!(
+            (score - expected_score).abs() <= 1e-4 * expected_score.abs().max(1.0),
+            "{score} != {expected_score}",
+        );
+    }
+}
+
+#[rstest]
+fn test_int8_storage_search(
+    #[values(Distance::Cosine, Distance::Euclid, Distance::Dot, Distance::Manhattan)]
+    distance: Distance,
+    #[values(
+        VectorStorageType::Memory,
+        VectorStorageType::Mmap,
+        VectorStorageType::ChunkedMmap,
+        VectorStorageType::InRamChunkedMmap
+    )]
+    storage_type: VectorStorageType,
+) {
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let config = int8_segment_config(distance, storage_type, None);
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let vectors = (0..NUM_VECTORS)
+        .map(|_| random_dense_int8_vector(&mut rnd, DIM))
+        .collect_vec();
+    for (n, vector) in vectors.iter().enumerate() {
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                (n as u64).into(),
+                only_default_vector(vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    // Signed values are stored as is, without offset
+    let stored = segment.vector(DEFAULT_VECTOR_NAME, 0.into()).unwrap();
+    assert_eq!(stored, Some(VectorInternal::Dense(vectors[0].clone())));
+
+    for _ in 0..10 {
+        let query = random_dense_int8_vector(&mut rnd, DIM);
+
+        let result = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &query.clone().into(),
+                &WithPayload::default(),
+                &false.into(),
+                None,
+                TOP,
+                None,
+            )
+            .unwrap();
+
+        let scores = result.iter().map(|point| point.score).collect_vec();
+        let expected_scores = vectors
+            .iter()
+            .map(|vector| expected_score(distance, &query, vector))
+            .collect();
+        assert_scores(distance, &scores, expected_scores);
+    }
+}
+
+#[rstest]
+fn test_int8_multi_storage_search(
+    #[values(
+        VectorStorageType::Memory,
+        VectorStorageType::ChunkedMmap,
+        VectorStorageType::InRamChunkedMmap
+    )]
+    storage_type: VectorStorageType,
+) {
+    let distance = Distance::Dot;
+    let mut rnd = StdRng::seed_from_u64(42);
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let config = int8_segment_config(distance, storage_type, Some(MultiVectorConfig::default()));
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let random_multi_vector = |rnd: &mut StdRng| {
+        let vectors = (0..3)
+            .map(|_| random_dense_int8_vector(rnd, DIM))
+            .collect_vec();
+        MultiDenseVectorInternal::new(vectors.concat(), DIM)
+    };
+
+    let multi_vectors = (0..NUM_VECTORS)
+        .map(|_| random_multi_vector(&mut rnd))
+        .collect_vec();
+    for (n, multi_vector) in multi_vectors.iter().enumerate() {
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                (n as u64).into(),
+                only_default_multi_vector(multi_vector),
+                &hw_counter,
+            )
+            .unwrap();
+    }
+
+    let stored = segment.vector(DEFAULT_VECTOR_NAME, 0.into()).unwrap();
+    assert_eq!(
+        stored,
+        Some(VectorInternal::MultiDense(multi_vectors[0].clone()))
+    );
+
+    for _ in 0..10 {
+        let query = random_multi_vector(&mut rnd);
+
+        let result = segment
+            .search(
+                DEFAULT_VECTOR_NAME,
+                &QueryVector::from(query.clone()),
+                &WithPayload::default(),
+                &false.into(),
+                None,
+                TOP,
+                None,
+            )
+            .unwrap();
+
+        let scores = result.iter().map(|point| point.score).collect_vec();
+        // Sum of the best matches of each query vector
+        let expected_scores = multi_vectors
+            .iter()
+            .map(|multi_vector| {
+                query
+                    .multi_vectors()
+                    .map(|q| {
+                        multi_vector
+                            .multi_vectors()
+                            .map(|v| expected_score(distance, q, v))
+                            .fold(f32::NEG_INFINITY, f32::max)
+                    })
+                    .sum()
+            })
+            .collect();
+        assert_scores(distance, &scores, expected_scores);
+    }
+}
+
+#[test]
+fn test_int8_conversion_saturates() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let config = int8_segment_config(Distance::Dot, VectorStorageType::Memory, None);
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let mut vector = vec![0.0; DIM];
+    vector[..4].copy_from_slice(&[-1000.0, -128.0, 127.0, 1000.0]);
+    segment
+        .upsert_point(0, 0.into(), only_default_vector(&vector), &hw_counter)
+        .unwrap();
+
+    let stored = segment.vector(DEFAULT_VECTOR_NAME, 0.into()).unwrap();
+    let Some(VectorInternal::Dense(stored)) = stored else {
+        panic!("dense vector expected");
+    };
+    assert_eq!(&stored[..4], &[-128.0, -128.0, 127.0, 127.0]);
+}
@@ -0,0 +1,287 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::cpu::CpuPermit;
+use common::types::TelemetryDetail;
+use itertools::Itertools;
+use rand::prelude::StdRng;
+use rand::{Rng, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{only_default_vector, QueryVector, DEFAULT_VECTOR_NAME};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::{random_int_payload, random_vector};
+use segment::index::ivf_index::ivf::{IVFIndex, IvfIndexOpenArgs};
+use segment::index::{PayloadIndex, VectorIndex};
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment::Segment;
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::segment_constructor::VectorIndexBuildArgs;
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, IvfConfig, PayloadSchemaType, Range, SearchParams,
+    SeqNumberType,
+};
+use segment::vector_storage::query::RecoQuery;
+use tempfile::Builder;
+
+const DIM: usize = 8;
+const NUM_VECTORS: u64 = 3_000;
+const NLIST: usize = 32;
+const INT_KEY: &str = "int";
+
+fn build_segment(path: &Path, distance: Distance, rnd: &mut StdRng) -> Segment {
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(path, DIM, distance).unwrap();
+    for n in 0..NUM_VECTORS {
+        let idx = n.into();
+        let vector = random_vector(rnd, DIM);
+        let payload = payload_json! {INT_KEY: random_int_payload(rnd, 1..=1)};
+
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, idx, &payload, &hw_counter)
+            .unwrap();
+    }
+    segment
+        .payload_index
+        .borrow_mut()
+        .set_indexed(&JsonPath::new(INT_KEY), PayloadSchemaType::Integer)
+        .unwrap();
+    segment
+}
+
+fn open_args<'a>(path: &'a Path, segment: &Segment, nprobe: usize) -> IvfIndexOpenArgs<'a> {
+    IvfIndexOpenArgs {
+        path,
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        ivf_config: IvfConfig {
+            nlist: NLIST,
+            nprobe,
+        },
+    }
+}
+
+fn build_ivf_index(path: &Path, segment: &Segment, nprobe: usize) -> IVFIndex {
+    let stopped = AtomicBool::new(false);
+    IVFIndex::build(
+        open_args(path, segment, nprobe),
+        VectorIndexBuildArgs {
+            permit: Arc::new(CpuPermit::dummy(2)),
+            old_indices: &[],
+            gpu_device: None,
+            stopped: &stopped,
+        },
+    )
+    .unwrap()
+}
+
+/// Payload values are in range `0..500`
+fn random_range_filter(rnd: &mut StdRng, range_size: i64) -> Filter {
+    let left_range = rnd.random_range(0..500 - range_size);
+    Filter::new_must(Condition::Field(FieldCondition::new_range(
+        JsonPath::new(INT_KEY),
+        Range {
+            lt: None,
+            gt: None,
+            gte: Some(left_range as f64),
+            lte: Some((left_range + range_size) as f64),
+        },
+    )))
+}
+
+fn random_query(rnd: &mut StdRng, recommend: bool) -> QueryVector {
+    if recommend {
+        let positive = (0..2).map(|_| random_vector(rnd, DIM).into()).collect_vec();
+        let negative = (0..2).map(|_| random_vector(rnd, DIM).into()).collect_vec();
+        RecoQuery::new(positive, negative).into()
+    } else {
+        random_vector(rnd, DIM).into()
+    }
+}
+
+/// Scanning all clusters must give exactly the same result as the plain search
+#[rstest]
+#[case::cosine_nearest(Distance::Cosine, false)]
+#[case::euclid_nearest(Distance::Euclid, false)]
+#[case::dot_recommend(Distance::Dot, true)]
+fn test_ivf_all_clusters_exact(#[case] distance: Distance, #[case] recommend: bool) {
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let ivf_dir = Builder::new().prefix("ivf_dir").tempdir().unwrap();
+
+    let segment = build_segment(dir.path(), distance, &mut rnd);
+    let ivf_index = build_ivf_index(ivf_dir.path(), &segment, NLIST);
+
+    assert_eq!(ivf_index.indexed_vector_count(), NUM_VECTORS as usize);
+
+    let top = 10;
+    let attempts = 20;
+    for i in 0..attempts {
+        let query = random_query(&mut rnd, recommend);
+        let filter = random_range_filter(&mut rnd, 50);
+
+        for filter in [None, Some(&filter)] {
+            let index_result = ivf_index
+                .search(&[&query], filter, top, None, &Default::default())
+                .unwrap();
+            let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_index
+                .borrow()
+                .search(&[&query], filter, top, None, &Default::default())
+                .unwrap();
+            assert_eq!(index_result, plain_result);
+        }
+
+        let telemetry = ivf_index.get_telemetry_data(TelemetryDetail::default());
+        assert_eq!(telemetry.unfiltered_ivf.count, i + 1);
+    }
+}
+
+#[test]
+fn test_ivf_recall() {
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let ivf_dir = Builder::new().prefix("ivf_dir").tempdir().unwrap();
+
+    let segment = build_segment(dir.path(), Distance::Euclid, &mut rnd);
+    let ivf_index = build_ivf_index(ivf_dir.path(), &segment, NLIST / 4);
+
+    let top = 10;
+    let attempts = 100;
+    let mut found = 0;
+    for _ in 0..attempts {
+        let query = random_query(&mut rnd, false);
+
+        let index_result = ivf_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        assert_eq!(index_result[0].len(), top);
+        let plain_ids: HashSet<_> = plain_result[0].iter().map(|p| p.idx).collect();
+        found += index_result[0]
+            .iter()
+            .filter(|p| plain_ids.contains(&p.idx))
+            .count();
+    }
+
+    let recall = found as f64 / (top * attempts) as f64;
+    eprintln!("recall = {recall}");
+    assert!(recall >= 0.8, "recall: {recall}");
+
+    // Filter matches more points, than expected in the probed clusters, so clusters are probed
+    let hw_counter = HardwareCounterCell::new();
+    for i in 0..attempts {
+        let query = random_query(&mut rnd, false);
+        let filter = random_range_filter(&mut rnd, 300);
+        let filtered_ids: HashSet<_> = segment
+            .payload_index
+            .borrow()
+            .query_points(&filter, &hw_counter)
+            .into_iter()
+            .collect();
+
+        let index_result = ivf_index
+            .search(&[&query], Some(&filter), top, None, &Default::default())
+            .unwrap();
+        assert_eq!(index_result[0].len(), top);
+        assert!(index_result[0]
+            .iter()
+            .all(|p| filtered_ids.contains(&p.idx)));
+
+        let telemetry = ivf_index.get_telemetry_data(TelemetryDetail::default());
+        assert_eq!(telemetry.filtered_ivf.count, i + 1);
+    }
+
+    // Search with all clusters probed is exact
+    let query = random_query(&mut rnd, false);
+    let params = SearchParams {
+        ivf_nprobe: Some(NLIST),
+        ..Default::default()
+    };
+    let index_result = ivf_index
+        .search(&[&query], None, top, Some(&params), &Default::default())
+        .unwrap();
+    let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_index
+        .borrow()
+        .search(&[&query], None, top, None, &Default::default())
+        .unwrap();
+    assert_eq!(index_result, plain_result);
+}
+
+#[test]
+fn test_ivf_small_cardinality_and_reopen() {
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let ivf_dir = Builder::new().prefix("ivf_dir").tempdir().unwrap();
+
+    let segment = build_segment(dir.path(), Distance::Cosine, &mut rnd);
+    let ivf_index = build_ivf_index(ivf_dir.path(), &segment, 4);
+
+    let hw_counter = HardwareCounterCell::new();
+    let top = 5;
+    let query = random_query(&mut rnd, false);
+
+    // Filter matches only a few points, which are scored directly
+    let filter = random_range_filter(&mut rnd, 0);
+    assert!(
+        segment
+            .payload_index
+            .borrow()
+            .query_points(&filter, &hw_counter)
+            .len()
+            < NUM_VECTORS as usize * 4 / NLIST
+    );
+
+    let index_result = ivf_index
+        .search(&[&query], Some(&filter), top, None, &Default::default())
+        .unwrap();
+    let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+        .vector_index
+        .borrow()
+        .search(&[&query], Some(&filter), top, None, &Default::default())
+        .unwrap();
+    assert_eq!(index_result, plain_result);
+
+    let telemetry = ivf_index.get_telemetry_data(TelemetryDetail::default());
+    assert_eq!(telemetry.filtered_small_cardinality.count, 1);
+    assert_eq!(telemetry.filtered_ivf.count, 0);
+
+    // Loaded index gives the same results
+    let unfiltered_result = ivf_index
+        .search(&[&query], None, top, None, &Default::default())
+        .unwrap();
+    drop(ivf_index);
+
+    let ivf_index = IVFIndex::open(open_args(ivf_dir.path(), &segment, 4)).unwrap();
+    assert_eq!(ivf_index.indexed_vector_count(), NUM_VECTORS as usize);
+    let reopened_result = ivf_index
+        .search(&[&query], None, top, None, &Default::default())
+        .unwrap();
+    assert_eq!(unfiltered_result, reopened_result);
+}
@@ -1,4 +1,5 @@
 mod batch_search_test;
+mod bit_storage_test;
 mod byte_storage_hnsw_test;
 mod byte_storage_quantization_test;
 mod disbalanced_vectors_test;
@@ -9,8 +10,11 @@ mod filtrable_hnsw_test;
 mod fixtures;
 #[cfg(feature = "gpu")]
 mod gpu_hnsw_test;
+mod hnsw_delete_repair_test;
 mod hnsw_discover_test;
 mod hnsw_quantized_search_test;
+mod int8_storage_test;
+mod ivf_search_test;
 mod multivector_filtrable_hnsw_test;
 mod multivector_hnsw_test;
 mod multivector_quantization_test;
@@ -22,3 +26,4 @@ mod segment_on_disk_snapshot;
 mod segment_tests;
 mod sparse_discover_test;
 mod sparse_vector_index_search_tests;
+mod vamana_search_test;
@@ -83,6 +83,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
             Distance::Manhattan => {
                 <ManhattanMetric as Metric<VectorElementType>>::preprocess(vector.clone())
             }
+            Distance::Hamming | Distance::Jaccard => unreachable!(),
         };
         let vector_multi = MultiDenseVectorInternal::new(preprocessed_vector, vector.len());
 
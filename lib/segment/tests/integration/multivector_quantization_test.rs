@@ -11,7 +11,8 @@ use rand::prelude::StdRng;
 use rand::{Rng, SeedableRng};
 use rstest::rstest;
 use segment::data_types::vectors::{
-    only_default_multi_vector, MultiDenseVectorInternal, QueryVector, DEFAULT_VECTOR_NAME,
+    only_default_multi_vector, MultiDenseVectorInternal, QueryVector, WeightedMultiDenseVector,
+    DEFAULT_VECTOR_NAME,
 };
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_fixtures::{random_int_payload, random_multi_vector};
@@ -22,9 +23,9 @@ use segment::json_path::JsonPath;
 use segment::segment_constructor::build_segment;
 use segment::types::{
     BinaryQuantizationConfig, CompressionRatio, Condition, Distance, FieldCondition, Filter,
-    HnswConfig, Indexes, MultiVectorConfig, PayloadSchemaType, ProductQuantizationConfig,
-    QuantizationSearchParams, Range, ScalarQuantizationConfig, SearchParams, SegmentConfig,
-    SeqNumberType, VectorDataConfig, VectorStorageType,
+    HnswConfig, Indexes, MultiVectorComparator, MultiVectorConfig, PayloadSchemaType,
+    ProductQuantizationConfig, QuantizationSearchParams, Range, ScalarQuantizationConfig,
+    SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageType,
 };
 use segment::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use segment::vector_storage::query::{ContextPair, DiscoveryQuery, RecoQuery};
@@ -53,6 +54,14 @@ fn random_vector<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> MultiDenseVectorIn
     vector
 }
 
+fn random_weighted_vector<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> WeightedMultiDenseVector {
+    let vector = random_vector(rnd, dim);
+    let weights = (0..vector.vectors_count())
+        .map(|_| rnd.random_range(0.1..2.0))
+        .collect();
+    WeightedMultiDenseVector::new(vector, weights)
+}
+
 fn random_discovery_query<R: Rng + ?Sized>(rnd: &mut R, dim: usize) -> QueryVector {
     let num_pairs: usize = rnd.random_range(1..MAX_EXAMPLE_PAIRS);
 
@@ -103,6 +112,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::Binary,
     Distance::Dot,
+    MultiVectorComparator::MaxSim,
     128, // dim
     32, // ef
     false,
@@ -112,6 +122,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Discovery,
     QuantizationVariant::Binary,
     Distance::Dot,
+    MultiVectorComparator::MaxSim,
     128, // dim
     128, // ef
     false,
@@ -121,6 +132,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::RecommendBestScore,
     QuantizationVariant::Binary,
     Distance::Dot,
+    MultiVectorComparator::MaxSim,
     128, // dim
     64, // ef
     false,
@@ -130,6 +142,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::Binary,
     Distance::Cosine,
+    MultiVectorComparator::MaxSim,
     128, // dim
     32, // ef
     false,
@@ -139,6 +152,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Discovery,
     QuantizationVariant::Binary,
     Distance::Cosine,
+    MultiVectorComparator::MaxSim,
     128, // dim
     128, // ef
     false,
@@ -148,6 +162,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::RecommendBestScore,
     QuantizationVariant::Binary,
     Distance::Cosine,
+    MultiVectorComparator::MaxSim,
     128, // dim
     64, // ef
     false,
@@ -157,6 +172,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::Scalar,
     Distance::Dot,
+    MultiVectorComparator::MaxSim,
     32, // dim
     32, // ef
     false,
@@ -166,6 +182,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::Scalar,
     Distance::Cosine,
+    MultiVectorComparator::MaxSim,
     32, // dim
     32, // ef
     false,
@@ -175,6 +192,7 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::PQ,
     Distance::Dot,
+    MultiVectorComparator::MaxSim,
     16, // dim
     32, // ef
     false,
@@ -184,15 +202,48 @@ fn sames_count(a: &[Vec<ScoredPointOffset>], b: &[Vec<ScoredPointOffset>]) -> us
     QueryVariant::Nearest,
     QuantizationVariant::Scalar,
     Distance::Cosine,
+    MultiVectorComparator::MaxSim,
     32, // dim
     32, // ef
     true,
     80., // min_acc out of 100
 )]
+#[case::nearest_scalar_cosine_weighted_max_sim(
+    QueryVariant::Nearest,
+    QuantizationVariant::Scalar,
+    Distance::Cosine,
+    MultiVectorComparator::WeightedMaxSim,
+    32, // dim
+    32, // ef
+    false,
+    70., // min_acc out of 100
+)]
+#[case::nearest_scalar_cosine_chamfer(
+    QueryVariant::Nearest,
+    QuantizationVariant::Scalar,
+    Distance::Cosine,
+    MultiVectorComparator::Chamfer,
+    32, // dim
+    32, // ef
+    false,
+    70., // min_acc out of 100
+)]
+#[case::nearest_scalar_dot_mean_pool(
+    QueryVariant::Nearest,
+    QuantizationVariant::Scalar,
+    Distance::Dot,
+    MultiVectorComparator::MeanPool,
+    32, // dim
+    32, // ef
+    false,
+    70., // min_acc out of 100
+)]
+#[allow(clippy::too_many_arguments)]
 fn test_multivector_quantization_hnsw(
     #[case] query_variant: QueryVariant,
     #[case] quantization_variant: QuantizationVariant,
     #[case] distance: Distance,
+    #[case] comparator: MultiVectorComparator,
     #[case] dim: usize,
     #[case] ef: usize,
     #[case] on_disk: bool,
@@ -229,7 +280,7 @@ fn test_multivector_quantization_hnsw(
                 storage_type,
                 index: Indexes::Plain {},
                 quantization_config: None,
-                multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
+                multivector_config: Some(MultiVectorConfig { comparator }), // uses multivec config
                 datatype: None,
             },
         )]),
@@ -279,10 +330,13 @@ fn test_multivector_quantization_hnsw(
         QuantizationVariant::PQ => ProductQuantizationConfig {
             compression: CompressionRatio::X8,
             always_ram: Some(false),
+            opq: None,
         }
         .into(),
         QuantizationVariant::Binary => BinaryQuantizationConfig {
             always_ram: Some(false),
+            encoding: None,
+            query_encoding: None,
         }
         .into(),
     };
@@ -343,7 +397,12 @@ fn test_multivector_quantization_hnsw(
     let mut sames = 0;
     let attempts = 100;
     for _ in 0..attempts {
-        let query = random_query(&query_variant, &mut rnd, dim);
+        let query = match (&query_variant, comparator) {
+            (QueryVariant::Nearest, MultiVectorComparator::WeightedMaxSim) => {
+                random_weighted_vector(&mut rnd, dim).into()
+            }
+            _ => random_query(&query_variant, &mut rnd, dim),
+        };
 
         let range_size = 40;
         let left_range = rnd.random_range(0..400);
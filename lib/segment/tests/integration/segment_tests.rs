@@ -272,9 +272,12 @@ fn test_update_named_vector() {
     // do exact search
     let search_params = SearchParams {
         hnsw_ef: None,
+        ivf_nprobe: None,
+        vamana_search_list: None,
         exact: true,
         quantization: None,
         indexed_only: false,
+        range: None,
     };
     let nearest_upsert = segment
         .search(
@@ -17,7 +17,7 @@ use segment::index::VectorIndex;
 use segment::segment_constructor::{build_segment, create_sparse_vector_index_test};
 use segment::types::{
     Condition, Distance, ExtendedPointId, Filter, HasIdCondition, Indexes, PointIdType,
-    SegmentConfig, SeqNumberType, SparseVectorDataConfig, SparseVectorStorageType,
+    SegmentConfig, SeqNumberType, SparseDistance, SparseVectorDataConfig, SparseVectorStorageType,
     VectorDataConfig, VectorStorageDatatype, VectorStorageType, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD,
 };
 use segment::vector_storage::query::{ContextPair, DiscoveryQuery};
@@ -124,8 +124,10 @@ fn sparse_index_discover_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    pruning: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
+                distance: SparseDistance::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -172,6 +174,7 @@ fn sparse_index_discover_test() {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::ImmutableRam,
             datatype: Some(VectorStorageDatatype::Float32),
+            pruning: None,
         },
         id_tracker: sparse_segment.id_tracker.clone(),
         vector_storage: vector_storage.clone(),
@@ -264,8 +267,10 @@ fn sparse_index_hardware_measurement_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    pruning: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
+                distance: SparseDistance::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -291,6 +296,7 @@ fn sparse_index_hardware_measurement_test() {
             full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
             index_type: SparseIndexType::ImmutableRam,
             datatype: Some(VectorStorageDatatype::Float32),
+            pruning: None,
         },
         id_tracker: sparse_segment.id_tracker.clone(),
         vector_storage: vector_storage.clone(),
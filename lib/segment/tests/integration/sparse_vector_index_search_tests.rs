@@ -15,7 +15,9 @@ use segment::data_types::vectors::{QueryVector, VectorInternal};
 use segment::entry::entry_point::SegmentEntry;
 use segment::fixtures::payload_fixtures::STR_KEY;
 use segment::fixtures::sparse_fixtures::{fixture_sparse_index, fixture_sparse_index_from_iter};
-use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
+use segment::index::sparse_index::sparse_index_config::{
+    SparseIndexConfig, SparseIndexType, SparsePruningConfig,
+};
 use segment::index::sparse_index::sparse_vector_index::{
     SparseVectorIndex, SparseVectorIndexOpenArgs,
 };
@@ -26,9 +28,9 @@ use segment::segment_constructor::{build_segment, load_segment};
 use segment::types::PayloadFieldSchema::FieldType;
 use segment::types::PayloadSchemaType::Keyword;
 use segment::types::{
-    Condition, FieldCondition, Filter, ScoredPoint, SegmentConfig, SeqNumberType,
-    SparseVectorDataConfig, SparseVectorStorageType, VectorName, VectorStorageDatatype,
-    DEFAULT_SPARSE_FULL_SCAN_THRESHOLD,
+    Condition, ExtendedPointId, FieldCondition, Filter, ScoredPoint, SegmentConfig, SeqNumberType,
+    SparseDistance, SparseVectorDataConfig, SparseVectorStorageType, VectorName,
+    VectorStorageDatatype, DEFAULT_SPARSE_FULL_SCAN_THRESHOLD,
 };
 use segment::vector_storage::VectorStorage;
 use segment::{fixture_for_all_indices, payload_json};
@@ -576,8 +578,10 @@ fn sparse_vector_index_persistence_test() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    pruning: None,
                 },
                 storage_type: SparseVectorStorageType::default(),
+                distance: SparseDistance::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -662,6 +666,7 @@ fn check_persistence<TInvertedIndex: InvertedIndex>(
                 full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                 index_type: SparseIndexType::Mmap,
                 datatype: Some(VectorStorageDatatype::Float32),
+                pruning: None,
             },
             id_tracker: segment.id_tracker.clone(),
             vector_storage: segment.vector_data[SPARSE_VECTOR_NAME]
@@ -735,6 +740,80 @@ fn check_sparse_vector_index_files<I: InvertedIndex>() {
     }
 }
 
+#[test]
+fn sparse_vector_index_static_pruning() {
+    fixture_for_all_indices!(check_sparse_vector_index_static_pruning::<_>());
+}
+
+fn check_sparse_vector_index_static_pruning<I: InvertedIndex>() {
+    let max_postings = 50;
+    let stopped = AtomicBool::new(false);
+
+    let data_dir = Builder::new().prefix("data_dir").tempdir().unwrap();
+    let index = fixture_sparse_index::<I, _>(
+        &mut StdRng::seed_from_u64(42),
+        NUM_VECTORS,
+        100,
+        LOW_FULL_SCAN_THRESHOLD,
+        data_dir.path(),
+    );
+    assert_eq!(
+        index
+            .get_telemetry_data(TelemetryDetail::default())
+            .pruned_postings_ratio,
+        None,
+    );
+
+    let pruned_index_dir = Builder::new().prefix("pruned_index").tempdir().unwrap();
+    let open_pruned_index = || -> SparseVectorIndex<I> {
+        SparseVectorIndex::open(SparseVectorIndexOpenArgs {
+            config: SparseIndexConfig {
+                full_scan_threshold: Some(LOW_FULL_SCAN_THRESHOLD),
+                index_type: SparseIndexType::Mmap,
+                datatype: None,
+                pruning: Some(SparsePruningConfig {
+                    max_postings: Some(max_postings),
+                    min_weight_ratio: None,
+                }),
+            },
+            id_tracker: index.id_tracker().clone(),
+            vector_storage: index.vector_storage().clone(),
+            payload_index: index.payload_index().clone(),
+            path: pruned_index_dir.path(),
+            stopped: &stopped,
+            tick_progress: || (),
+        })
+        .unwrap()
+    };
+
+    let pruned_index = open_pruned_index();
+    let inverted_index = pruned_index.inverted_index();
+    for dim_id in 0..=inverted_index.max_index().unwrap() {
+        let posting_list_len = inverted_index.posting_list_len(&dim_id).unwrap_or(0);
+        assert!(posting_list_len <= max_postings);
+    }
+
+    let pruned_ratio = pruned_index
+        .get_telemetry_data(TelemetryDetail::default())
+        .pruned_postings_ratio
+        .unwrap();
+    assert!(pruned_ratio > 0.0 && pruned_ratio < 1.0);
+
+    // the ratio is persisted together with the index
+    assert!(pruned_index
+        .files()
+        .iter()
+        .any(|file| file.ends_with("pruning_stats.json")));
+    drop(pruned_index);
+    let pruned_index = open_pruned_index();
+    assert_eq!(
+        pruned_index
+            .get_telemetry_data(TelemetryDetail::default())
+            .pruned_postings_ratio,
+        Some(pruned_ratio),
+    );
+}
+
 #[test]
 fn sparse_vector_test_large_index() {
     let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
@@ -747,8 +826,10 @@ fn sparse_vector_test_large_index() {
                     full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
                     index_type: SparseIndexType::MutableRam,
                     datatype: Some(VectorStorageDatatype::Float32),
+                    pruning: None,
                 },
                 storage_type: SparseVectorStorageType::OnDisk,
+                distance: SparseDistance::default(),
             },
         )]),
         payload_storage_type: Default::default(),
@@ -783,3 +864,86 @@ fn sparse_vector_test_large_index() {
         _ => panic!("unexpected vector index type"),
     }
 }
+
+#[test]
+fn sparse_vector_index_cosine_search() {
+    let dim = 16;
+    let num_vectors: u64 = 1_000;
+    let top = 10;
+    let mut rnd = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: Default::default(),
+        sparse_vector_data: HashMap::from([(
+            SPARSE_VECTOR_NAME.to_owned(),
+            SparseVectorDataConfig {
+                index: SparseIndexConfig {
+                    full_scan_threshold: Some(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD),
+                    index_type: SparseIndexType::MutableRam,
+                    datatype: Some(VectorStorageDatatype::Float32),
+                    pruning: None,
+                },
+                storage_type: SparseVectorStorageType::default(),
+                distance: SparseDistance::Cosine,
+            },
+        )]),
+        payload_storage_type: Default::default(),
+    };
+    let mut segment = build_segment(dir.path(), &config, true).unwrap();
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut vectors = Vec::new();
+    for n in 0..num_vectors {
+        let mut vector = random_full_sparse_vector(&mut rnd, dim);
+        vector.sort_by_indices();
+        vectors.push(vector.clone());
+        let mut named_vector = NamedVectors::default();
+        named_vector.insert(SPARSE_VECTOR_NAME.to_owned(), vector.into());
+        segment
+            .upsert_point(n as SeqNumberType, n.into(), named_vector, &hw_counter)
+            .unwrap();
+    }
+
+    let mut query = random_full_sparse_vector(&mut rnd, dim);
+    query.sort_by_indices();
+
+    let cosine = |vector: &SparseVector| {
+        let norms = query.norm() * vector.norm();
+        if norms == 0.0 {
+            return 0.0;
+        }
+        query.score(vector).unwrap_or(0.0) / norms
+    };
+
+    let search_result = segment
+        .search(
+            SPARSE_VECTOR_NAME,
+            &QueryVector::from(query.clone()),
+            &Default::default(),
+            &Default::default(),
+            None,
+            top,
+            None,
+        )
+        .unwrap();
+    assert_eq!(search_result.len(), top);
+
+    for point in &search_result {
+        let ExtendedPointId::NumId(id) = point.id else {
+            panic!("unexpected point id {:?}", point.id);
+        };
+        let expected = cosine(&vectors[id as usize]);
+        assert!(
+            (point.score - expected).abs() < 1e-4,
+            "score {} of point {id} is not the cosine similarity {expected}",
+            point.score,
+        );
+    }
+
+    let best_score = vectors.iter().map(cosine).fold(f32::MIN, f32::max);
+    assert!(best_score <= 1.0 + 1e-4);
+    assert!((search_result[0].score - best_score).abs() < 1e-4);
+}
@@ -152,6 +152,28 @@ impl SparseVector {
         score_vectors(&self.indices, &self.values, &other.indices, &other.values)
     }
 
+    /// Euclidean (L2) norm of the vector values.
+    pub fn norm(&self) -> DimWeight {
+        self.values
+            .iter()
+            .map(|value| value * value)
+            .sum::<DimWeight>()
+            .sqrt()
+    }
+
+    /// Divide all values by the given norm.
+    ///
+    /// The dot product of two vectors normalized by their own norms is their cosine similarity.
+    /// Zero norm (empty vector) leaves the vector untouched.
+    pub fn normalize_by(&mut self, norm: DimWeight) {
+        if norm == 0.0 {
+            return;
+        }
+        for value in self.values.iter_mut() {
+            *value /= norm;
+        }
+    }
+
     /// Construct a new vector that is the result of performing all indices-wise operations.
     /// Automatically sort input vectors if necessary.
     pub fn combine_aggregate(
@@ -368,6 +390,23 @@ mod tests {
         assert!(not_sorted.is_sorted());
     }
 
+    #[test]
+    fn normalize_test() {
+        let mut v1 = SparseVector::new(vec![1, 2, 3], vec![3.0, 0.0, 4.0]).unwrap();
+        let mut v2 = SparseVector::new(vec![1, 3, 5], vec![1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(v1.norm(), 5.0);
+
+        v1.normalize_by(v1.norm());
+        v2.normalize_by(v2.norm());
+        assert_eq!(v1.values, vec![0.6, 0.0, 0.8]);
+        assert!((v1.score(&v1).unwrap() - 1.0).abs() < 1e-6);
+        assert!((v1.score(&v2).unwrap() - 1.4 / 3f32.sqrt()).abs() < 1e-6);
+
+        let mut empty = SparseVector::default();
+        empty.normalize_by(empty.norm());
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn combine_aggregate_test() {
         // Test with missing index
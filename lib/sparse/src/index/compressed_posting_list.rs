@@ -50,6 +50,11 @@ pub struct CompressedPostingChunk<W> {
     /// An offset within id_data
     offset: u32,
 
+    /// Min and max weights in the chunk. Bound score contribution of the chunk without
+    /// decompression.
+    min_weight: DimWeight,
+    max_weight: DimWeight,
+
     /// Weight values for the chunk.
     weights: [W; BitPackerImpl::BLOCK_LEN],
 }
@@ -235,15 +240,22 @@ impl CompressedPostingBuilder {
                 let chunk_bits =
                     bitpacker.num_bits_strictly_sorted(initial.checked_sub(1), &this_chunk);
                 let chunk_size = BitPackerImpl::compressed_block_size(chunk_bits);
+                let weights: [W; BitPackerImpl::BLOCK_LEN] = chunk
+                    .iter()
+                    .map(|e| Weight::from_f32(quantization_params, e.weight))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .expect("Invalid chunk size");
+                // Bounds of the stored (possibly quantized) weights, not the original ones
+                let (min_weight, max_weight) =
+                    weight_bounds(weights.iter().map(|w| w.to_f32(quantization_params)))
+                        .expect("Chunk is not empty");
                 chunks.push(CompressedPostingChunk {
                     initial,
                     offset: data_size as u32,
-                    weights: chunk
-                        .iter()
-                        .map(|e| Weight::from_f32(quantization_params, e.weight))
-                        .collect::<Vec<_>>()
-                        .try_into()
-                        .expect("Invalid chunk size"),
+                    min_weight,
+                    max_weight,
+                    weights,
                 });
                 data_size += chunk_size;
             } else {
@@ -310,6 +322,19 @@ impl<'a, W: Weight> CompressedPostingListIterator<'a, W> {
         }
     }
 
+    /// Skip whole chunks, which end before `record_id`, without decompressing them
+    #[inline]
+    fn skip_to_chunk(&mut self, record_id: PointOffsetType) {
+        let current_chunk = self.pos / BitPackerImpl::BLOCK_LEN;
+        if let Some(next_chunks) = self.list.chunks.get(current_chunk + 1..) {
+            let skip = next_chunks.partition_point(|chunk| chunk.initial <= record_id);
+            if skip > 0 {
+                self.pos = (current_chunk + skip) * BitPackerImpl::BLOCK_LEN;
+                self.unpacked = false;
+            }
+        }
+    }
+
     #[inline]
     fn next(&mut self) -> Option<PostingElement> {
         let result = self.peek()?;
@@ -363,7 +388,8 @@ impl<W: Weight> PostingListIter for CompressedPostingListIterator<'_, W> {
 
     #[inline]
     fn skip_to(&mut self, record_id: PointOffsetType) -> Option<PostingElementEx> {
-        // TODO: optimize
+        self.skip_to_chunk(record_id);
+
         while let Some(e) = self.peek() {
             match e.record_id.cmp(&record_id) {
                 Ordering::Equal => return Some(e),
@@ -450,6 +476,41 @@ impl<W: Weight> PostingListIter for CompressedPostingListIterator<'_, W> {
         false
     }
 
+    fn weight_bounds(&self) -> Option<(DimWeight, DimWeight)> {
+        let chunks_len = self.list.chunks.len() * BitPackerImpl::BLOCK_LEN;
+        let chunks_bounds = self
+            .list
+            .chunks
+            .get(self.pos / BitPackerImpl::BLOCK_LEN..)
+            .unwrap_or_default()
+            .iter()
+            .flat_map(|chunk| [chunk.min_weight, chunk.max_weight]);
+        let remainders_bounds = self
+            .list
+            .remainders
+            .get(self.pos.saturating_sub(chunks_len)..)
+            .unwrap_or_default()
+            .iter()
+            .map(|e| e.weight.to_f32(self.list.multiplier));
+        weight_bounds(chunks_bounds.chain(remainders_bounds))
+    }
+
+    fn block_weight_bounds(
+        &mut self,
+        record_id: PointOffsetType,
+    ) -> Option<(DimWeight, DimWeight)> {
+        self.skip_to_chunk(record_id);
+        let chunk_index = self.pos / BitPackerImpl::BLOCK_LEN;
+        let chunk = self.list.chunks.get(chunk_index)?;
+        let next_start = match self.list.chunks.get(chunk_index + 1) {
+            Some(next_chunk) => Some(next_chunk.initial),
+            None => self.list.remainders.first().map(|e| e.record_id),
+        };
+        // Remainders are not bounded by chunks
+        let in_chunk = next_start.map_or(true, |start| record_id < start);
+        in_chunk.then_some((chunk.min_weight, chunk.max_weight))
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         CompressedPostingListStdIterator(self)
     }
@@ -466,6 +527,14 @@ impl<W: Weight> Iterator for CompressedPostingListStdIterator<'_, W> {
     }
 }
 
+/// Min and max of the weights, or `None` if there are no weights.
+fn weight_bounds(weights: impl Iterator<Item = DimWeight>) -> Option<(DimWeight, DimWeight)> {
+    weights.fold(None, |bounds, weight| match bounds {
+        None => Some((weight, weight)),
+        Some((min, max)) => Some((min.min(weight), max.max(weight))),
+    })
+}
+
 /// Find the amount of elements in the sorted array that are less or equal to `val`. In other words,
 /// the first index `i` such that `data[i] > val`, or `data.len()` if all elements are less or equal
 /// to `val`.
@@ -558,6 +627,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_skip_to_and_weight_bounds() {
+        let case = mk_case(320);
+        let list = CompressedPostingList::<f32>::from(case.clone());
+        let mut iter = list.iter();
+
+        assert_eq!(iter.weight_bounds(), Some((0.0, 319.0)));
+
+        // skip over the whole first chunk
+        let element = iter.skip_to(10200).unwrap();
+        assert_eq!((element.record_id, element.weight), case[200]);
+        assert_eq!(iter.weight_bounds(), Some((128.0, 319.0)));
+
+        // skip into the remainders
+        let element = iter.skip_to(10300).unwrap();
+        assert_eq!((element.record_id, element.weight), case[300]);
+        assert_eq!(iter.weight_bounds(), Some((300.0, 319.0)));
+
+        assert!(iter.skip_to(10320).is_none());
+        assert_eq!(iter.weight_bounds(), None);
+    }
+
+    #[test]
+    fn test_block_weight_bounds() {
+        let case = mk_case(320);
+        let list = CompressedPostingList::<f32>::from(case.clone());
+        let mut iter = list.iter();
+
+        assert_eq!(iter.block_weight_bounds(10050), Some((0.0, 127.0)));
+
+        // move to the second chunk without decompressing it
+        assert_eq!(iter.block_weight_bounds(10200), Some((128.0, 255.0)));
+        assert_eq!(iter.current_index(), 128);
+
+        // remainders are not covered by chunk bounds
+        assert_eq!(iter.block_weight_bounds(10300), None);
+        let element = iter.skip_to(10300).unwrap();
+        assert_eq!((element.record_id, element.weight), case[300]);
+    }
+
     #[test]
     fn test_count_le_sorted() {
         let data = [1, 2, 4, 5];
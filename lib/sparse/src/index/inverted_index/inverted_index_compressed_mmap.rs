@@ -33,7 +33,7 @@ pub struct Version;
 
 impl StorageVersion for Version {
     fn current_raw() -> &'static str {
-        "0.2.0"
+        "0.3.0"
     }
 }
 
@@ -45,6 +45,21 @@ impl InvertedIndexBuilder {
         self.total_sparse_size = self.total_sparse_size.saturating_add(sparse_size);
     }
 
+    /// Statically prune the posting lists of all dimensions, see [`PostingBuilder::prune`].
+    ///
+    /// Returns the number of dropped postings.
+    pub fn prune(&mut self, max_postings: Option<usize>, min_weight_ratio: Option<f32>) -> usize {
+        let pruned: usize = self
+            .posting_builders
+            .iter_mut()
+            .map(|posting_builder| posting_builder.prune(max_postings, min_weight_ratio))
+            .sum();
+        self.total_sparse_size = self
+            .total_sparse_size
+            .saturating_sub(pruned * size_of::<PostingElementEx>());
+        pruned
+    }
+
     /// Consumes the builder and returns an InvertedIndexRam
     pub fn build(self) -> InvertedIndexRam {
         if self.posting_builders.is_empty() {
@@ -136,6 +136,35 @@ impl PostingBuilder {
         self.elements.push(PostingElementEx::new(record_id, weight));
     }
 
+    /// Drop the postings with the smallest absolute weights.
+    ///
+    /// Keeps at most `max_postings` postings, and only the ones with absolute weight of at least
+    /// `min_weight_ratio` of the largest one. Returns the number of dropped postings.
+    pub fn prune(&mut self, max_postings: Option<usize>, min_weight_ratio: Option<f32>) -> usize {
+        let len = self.elements.len();
+
+        if let Some(max_postings) = max_postings {
+            if len > max_postings {
+                self.elements.select_nth_unstable_by(max_postings, |a, b| {
+                    b.weight.abs().total_cmp(&a.weight.abs())
+                });
+                self.elements.truncate(max_postings);
+            }
+        }
+
+        if let Some(min_weight_ratio) = min_weight_ratio {
+            let max_weight = self
+                .elements
+                .iter()
+                .map(|e| e.weight.abs())
+                .fold(0.0, f32::max);
+            let min_weight = max_weight * min_weight_ratio;
+            self.elements.retain(|e| e.weight.abs() >= min_weight);
+        }
+
+        len - self.elements.len()
+    }
+
     /// Consume the builder and return the posting list.
     pub fn build(mut self) -> PostingList {
         // Sort by id
@@ -225,6 +254,19 @@ impl PostingListIter for PostingListIterator<'_> {
         true
     }
 
+    fn weight_bounds(&self) -> Option<(DimWeight, DimWeight)> {
+        // Only the max weight is tracked
+        None
+    }
+
+    fn block_weight_bounds(
+        &mut self,
+        _record_id: PointOffsetType,
+    ) -> Option<(DimWeight, DimWeight)> {
+        // There are no blocks
+        None
+    }
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement> {
         self.elements.iter().cloned().map(PostingElement::from)
     }
@@ -338,6 +380,50 @@ mod tests {
         assert!(iter.peek().is_none());
     }
 
+    #[test]
+    fn test_posting_prune() {
+        let weights = [(1, 1.0), (2, -8.0), (3, 0.5), (4, 4.0), (5, 10.0), (6, 2.0)];
+
+        let mut builder = PostingBuilder::new();
+        weights
+            .iter()
+            .for_each(|&(id, weight)| builder.add(id, weight));
+        assert_eq!(builder.prune(Some(3), None), 3);
+        let ids = builder
+            .build()
+            .elements
+            .iter()
+            .map(|e| e.record_id)
+            .collect_vec();
+        assert_eq!(ids, vec![2, 4, 5]);
+
+        let mut builder = PostingBuilder::new();
+        weights
+            .iter()
+            .for_each(|&(id, weight)| builder.add(id, weight));
+        assert_eq!(builder.prune(None, Some(0.2)), 2);
+        let ids = builder
+            .build()
+            .elements
+            .iter()
+            .map(|e| e.record_id)
+            .collect_vec();
+        assert_eq!(ids, vec![2, 4, 5, 6]);
+
+        let mut builder = PostingBuilder::new();
+        weights
+            .iter()
+            .for_each(|&(id, weight)| builder.add(id, weight));
+        assert_eq!(builder.prune(Some(10), Some(0.5)), 4);
+        let ids = builder
+            .build()
+            .elements
+            .iter()
+            .map(|e| e.record_id)
+            .collect_vec();
+        assert_eq!(ids, vec![2, 5]);
+    }
+
     #[test]
     fn test_upsert_insert_last() {
         let mut builder = PostingBuilder::new();
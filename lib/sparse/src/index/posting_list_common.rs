@@ -75,5 +75,17 @@ pub trait PostingListIter {
     /// Whether the max_next_weight is reliable.
     fn reliable_max_next_weight() -> bool;
 
+    /// Min and max weights of the elements from the current position to the end.
+    ///
+    /// Returns `None` if the bounds are not known without a full scan, or the list is exhausted.
+    fn weight_bounds(&self) -> Option<(DimWeight, DimWeight)>;
+
+    /// Min and max weights of the block, which would contain `record_id`.
+    /// Moves to the block without decompressing it.
+    ///
+    /// Returns `None` if the block bounds are not known.
+    fn block_weight_bounds(&mut self, record_id: PointOffsetType)
+        -> Option<(DimWeight, DimWeight)>;
+
     fn into_std_iter(self) -> impl Iterator<Item = PostingElement>;
 }
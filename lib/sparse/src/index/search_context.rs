@@ -18,11 +18,29 @@ pub struct IndexedPostingListIterator<T: PostingListIter> {
     posting_list_iterator: T,
     query_index: DimId,
     query_weight: DimWeight,
+    /// Upper bound of the score contribution of the posting list, if known
+    max_score_contribution: Option<DimWeight>,
 }
 
 /// Making this larger makes the search faster but uses more (pooled) memory
 const ADVANCE_BATCH_SIZE: usize = 10_000;
 
+/// Upper bound of the score contribution of postings with weights within `bounds`
+///
+/// Weights are multiplied by the query weight, so the sign picks the bound.
+/// Points missing from the posting list don't contribute, so the bound is never negative.
+fn max_contribution(
+    (min_weight, max_weight): (DimWeight, DimWeight),
+    query_weight: DimWeight,
+) -> DimWeight {
+    let bound = if query_weight >= 0.0 {
+        max_weight * query_weight
+    } else {
+        min_weight * query_weight
+    };
+    bound.max(0.0)
+}
+
 pub struct SearchContext<'a, 'b, T: PostingListIter = PostingListIterator<'a>> {
     postings_iterators: Vec<IndexedPostingListIterator<T>>,
     query: RemappedSparseVector,
@@ -33,6 +51,10 @@ pub struct SearchContext<'a, 'b, T: PostingListIter = PostingListIterator<'a>> {
     max_record_id: PointOffsetType,         // max_record_id ids across all posting lists
     pooled: PooledScoresHandle<'b>,         // handle to pooled scores
     use_pruning: bool,
+    /// Whether the posting lists are sorted by `max_score_contribution` for MaxScore pruning
+    use_max_score: bool,
+    /// Number of posting lists at the head, which can not produce a top result on their own
+    non_essential: usize,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -65,10 +87,15 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                     let query_index = *id;
                     let query_weight = query.values[query_weight_offset];
 
+                    let max_score_contribution = it
+                        .weight_bounds()
+                        .map(|bounds| max_contribution(bounds, query_weight));
+
                     postings_iterators.push(IndexedPostingListIterator {
                         posting_list_iterator: it,
                         query_index,
                         query_weight,
+                        max_score_contribution,
                     });
                 }
             }
@@ -78,6 +105,19 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         // The max contribution per posting list that we calculate is not made to compute the max value of two negative numbers.
         // This is a limitation of the current pruning implementation.
         let use_pruning = T::reliable_max_next_weight() && query.values.iter().all(|v| *v >= 0.0);
+        // Weight bounds give the max contribution of each posting list, including negative
+        // query weights. Lists with the smallest contribution go first.
+        let use_max_score = !use_pruning
+            && postings_iterators
+                .iter()
+                .all(|posting| posting.max_score_contribution.is_some());
+        if use_max_score {
+            postings_iterators.sort_by(|a, b| {
+                a.max_score_contribution
+                    .partial_cmp(&b.max_score_contribution)
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
         let min_record_id = Some(min_record_id);
         SearchContext {
             postings_iterators,
@@ -89,6 +129,8 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
             max_record_id,
             pooled,
             use_pruning,
+            use_max_score,
+            non_essential: 0,
             hardware_counter,
         }
     }
@@ -149,7 +191,9 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
         self.pooled.scores.clear(); // keep underlying allocated memory
         self.pooled.scores.resize(batch_len as usize, 0.0);
 
-        for posting in self.postings_iterators.iter_mut() {
+        let (non_essential, essential) = self.postings_iterators.split_at_mut(self.non_essential);
+
+        for posting in essential.iter_mut() {
             posting.posting_list_iterator.for_each_till_id(
                 batch_last_id,
                 self.pooled.scores.as_mut_slice(),
@@ -164,10 +208,44 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
             );
         }
 
+        let non_essential_bound: DimWeight = non_essential
+            .iter()
+            .filter_map(|posting| posting.max_score_contribution)
+            .sum();
+
         for (local_index, &score) in self.pooled.scores.iter().enumerate() {
+            if score == 0.0 {
+                continue;
+            }
+            let real_id = batch_start_id + local_index as PointOffsetType;
+
+            let mut score = score;
+            if !non_essential.is_empty() {
+                // Complete the score from the non-essential posting lists, while it still has
+                // a chance to get into the top. The largest contributions go first.
+                let mut remaining_bound = non_essential_bound;
+                for posting in non_essential.iter_mut().rev() {
+                    let list_bound = posting.max_score_contribution.unwrap_or_default();
+                    remaining_bound -= list_bound;
+                    // Bound of the block, which may contain the point, is usually much tighter.
+                    // If it rules the point out, the block is not decompressed at all.
+                    let bound = posting
+                        .posting_list_iterator
+                        .block_weight_bounds(real_id)
+                        .map_or(list_bound, |bounds| {
+                            max_contribution(bounds, posting.query_weight)
+                        });
+                    if score + remaining_bound + bound <= self.top_results.threshold() {
+                        break;
+                    }
+                    if let Some(element) = posting.posting_list_iterator.skip_to(real_id) {
+                        score += element.weight * posting.query_weight;
+                    }
+                }
+            }
+
             // publish only the non-zero scores above the current min to beat
             if score != 0.0 && score > self.top_results.threshold() {
-                let real_id = batch_start_id + local_index as PointOffsetType;
                 // do not score if filter condition is not satisfied
                 if !filter_condition(real_id) {
                     continue;
@@ -179,6 +257,33 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                 self.top_results.push(score_point_offset);
             }
         }
+
+        // Points of the non-essential posting lists, which were not met in the essential ones,
+        // can not get into the top. Skip them, together with the whole blocks.
+        for posting in non_essential.iter_mut() {
+            match batch_last_id.checked_add(1) {
+                Some(next_id) => {
+                    posting.posting_list_iterator.skip_to(next_id);
+                }
+                None => posting.posting_list_iterator.skip_to_end(),
+            }
+        }
+    }
+
+    /// Number of posting lists at the head, which can not produce a result above `min_score`
+    ///
+    /// Assumes posting lists are sorted by `max_score_contribution`. Points, which are present
+    /// only in these lists, are skipped. The lists are used to complete the scores of the points
+    /// from the rest of the lists.
+    fn count_non_essential(&self, min_score: f32) -> usize {
+        let mut bound = 0.0;
+        self.postings_iterators
+            .iter()
+            .take_while(|posting| {
+                bound += posting.max_score_contribution.unwrap_or_default();
+                bound <= min_score
+            })
+            .count()
     }
 
     /// Compute scores for the last posting list quickly
@@ -291,8 +396,17 @@ impl<'a, 'b, T: PostingListIter> SearchContext<'a, 'b, T> {
                 posting_iterator.posting_list_iterator.len_to_end() != 0
             });
 
-            // update min_record_id
-            self.min_record_id = Self::next_min_id(&mut self.postings_iterators);
+            if self.use_max_score {
+                self.non_essential = self.count_non_essential(self.top_results.threshold());
+                if self.non_essential == self.postings_iterators.len() {
+                    // no point left can beat the current top
+                    break;
+                }
+            }
+
+            // update min_record_id, only essential posting lists can produce new results
+            self.min_record_id =
+                Self::next_min_id(&mut self.postings_iterators[self.non_essential..]);
 
             // check if all posting lists are exhausted
             if self.postings_iterators.is_empty() {
@@ -406,7 +520,8 @@ mod tests {
     use std::sync::OnceLock;
 
     use common::counter::hardware_accumulator::HwMeasurementAcc;
-    use rand::Rng;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
     use tempfile::TempDir;
 
     use super::*;
@@ -1050,4 +1165,121 @@ mod tests {
         //                                                  4 + 4 + 3 => 11
         assert_eq!(accumulator.get_cpu(), 11);
     }
+
+    #[test]
+    fn max_score_search_test<I: InvertedIndex>() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let dims = 10;
+
+        // Spread ids over several batches, higher dimensions have larger weights
+        let ids: Vec<PointOffsetType> = (1..50_000).step_by(5).collect();
+        let index = TestIndex::<I>::from_ram({
+            let mut builder = InvertedIndexBuilder::new();
+            for &id in &ids {
+                let vector: Vec<_> = (1..=dims)
+                    .filter(|_| rng.random_bool(0.5))
+                    .map(|dim| (dim, rng.random_range(0.1..1.0) * dim as DimWeight))
+                    .collect();
+                if !vector.is_empty() {
+                    builder.add(id, vector.try_into().unwrap());
+                }
+            }
+            builder.build()
+        });
+
+        // Negative query weights are supported as well
+        let query = RemappedSparseVector {
+            indices: (1..=dims).collect(),
+            values: (1..=dims).map(|_| rng.random_range(-0.5..1.0)).collect(),
+        };
+
+        let is_stopped = AtomicBool::new(false);
+        let mut search_context = SearchContext::new(
+            query.clone(),
+            5,
+            &index.index,
+            get_pooled_scores(),
+            &is_stopped,
+            HardwareCounterCell::new(),
+        );
+        assert_eq!(
+            search_context.use_max_score,
+            !I::Iter::reliable_max_next_weight(),
+        );
+        let result = search_context.search(&match_all);
+        if search_context.use_max_score {
+            assert!(search_context.non_essential > 0);
+        }
+
+        let mut plain_search_context = SearchContext::new(
+            query,
+            5,
+            &index.index,
+            get_pooled_scores(),
+            &is_stopped,
+            HardwareCounterCell::new(),
+        );
+        let expected = plain_search_context.plain_search(&ids);
+
+        assert_eq!(result.len(), expected.len());
+        for (found, expected) in result.iter().zip(&expected) {
+            assert!(
+                (found.score - expected.score).abs() <= 1e-3 * expected.score.abs().max(1.0),
+                "found: {found:?}, expected: {expected:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn max_score_mixed_weights_test<I: InvertedIndex>() {
+        // Negative query weights only lower the scores of the points in the first dimensions,
+        // the best points are the last ones of the positive dimension
+        let ids: Vec<PointOffsetType> = (1..50_000).step_by(7).collect();
+        let index = TestIndex::<I>::from_ram({
+            let mut builder = InvertedIndexBuilder::new();
+            for &id in &ids {
+                let vector = if id % 2 == 0 {
+                    vec![(1, 10.0), (2, 20.0), (3, 30.0)]
+                } else {
+                    vec![(4, 1.0 + id as DimWeight / 50_000.0)]
+                };
+                builder.add(id, vector.try_into().unwrap());
+            }
+            builder.build()
+        });
+
+        let query = RemappedSparseVector {
+            indices: vec![1, 2, 3, 4],
+            values: vec![-1.0, -1.0, -1.0, 1.0],
+        };
+
+        let is_stopped = AtomicBool::new(false);
+        let mut search_context = SearchContext::new(
+            query.clone(),
+            3,
+            &index.index,
+            get_pooled_scores(),
+            &is_stopped,
+            HardwareCounterCell::new(),
+        );
+        let result = search_context.search(&match_all);
+
+        let mut plain_search_context = SearchContext::new(
+            query,
+            3,
+            &index.index,
+            get_pooled_scores(),
+            &is_stopped,
+            HardwareCounterCell::new(),
+        );
+        let expected = plain_search_context.plain_search(&ids);
+
+        assert_eq!(result.len(), expected.len());
+        for (found, expected) in result.iter().zip(&expected) {
+            assert!(
+                (found.score - expected.score).abs() <= 1e-3 * expected.score.abs().max(1.0),
+                "found: {found:?}, expected: {expected:?}",
+            );
+        }
+    }
 }
@@ -177,6 +177,7 @@ impl TableOfContent {
                 .and_then(|i| i.quantization.clone()),
             Some(diff) => Some(diff),
         };
+        collection_params.check_quantization(quantization_config.as_ref())?;
 
         let strict_mode_config = match strict_mode_config {
             Some(diff) => {
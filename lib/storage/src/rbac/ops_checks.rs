@@ -133,6 +133,7 @@ impl CollectionAccessView<'_> {
     ) -> Result<(), StorageError> {
         match vector_query {
             VectorQuery::Nearest(nearest) => self.check_vector_input(nearest)?,
+            VectorQuery::NearestWithMmr(nearest) => self.check_vector_input(&nearest.nearest)?,
             VectorQuery::RecommendBestScore(reco) | VectorQuery::RecommendAverageVector(reco) => {
                 for vector_input in reco.flat_iter() {
                     self.check_vector_input(vector_input)?
@@ -55,6 +55,7 @@ fn collect_vector_input(vector: &VectorInput, batch: &mut BatchAccum) {
         VectorInput::DenseVector(_) => {}
         VectorInput::SparseVector(_) => {}
         VectorInput::MultiDenseVector(_) => {}
+        VectorInput::WeightedMultiDenseVector(_) => {}
         VectorInput::Id(_) => {}
     }
 }
@@ -98,7 +99,7 @@ fn collect_query(query: &Query, batch: &mut BatchAccum) {
                 }
             }
         }
-        Query::OrderBy(_) | Query::Fusion(_) | Query::Sample(_) => {}
+        Query::OrderBy(_) | Query::Fusion(_) | Query::Sample(_) | Query::Bm25(_) => {}
     }
 }
 
@@ -315,6 +316,7 @@ mod tests {
         let request = QueryGroupsRequestInternal {
             query: Some(QueryInterface::Query(Query::Nearest(NearestQuery {
                 nearest: VectorInput::Document(create_test_document("test")),
+                mmr: None,
             }))),
             prefetch: Some(vec![Prefetch {
                 query: Some(QueryInterface::Query(Query::Discover(DiscoverQuery {
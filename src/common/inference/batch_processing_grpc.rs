@@ -2,8 +2,8 @@ use std::collections::HashSet;
 
 use api::grpc::qdrant::vector_input::Variant;
 use api::grpc::qdrant::{
-    query, ContextInput, ContextInputPair, DiscoverInput, PrefetchQuery, Query, RecommendInput,
-    VectorInput,
+    query, ContextInput, ContextInputPair, DiscoverInput, NearestInputWithMmr, PrefetchQuery,
+    Query, RecommendInput, VectorInput,
 };
 use api::rest::schema as rest;
 use tonic::Status;
@@ -161,6 +161,13 @@ pub(crate) fn collect_query(query: &Query, batch: &mut BatchAccumGrpc) -> Result
         query::Variant::OrderBy(_) => {}
         query::Variant::Fusion(_) => {}
         query::Variant::Sample(_) => {}
+        query::Variant::Bm25(_) => {}
+        query::Variant::FusionParams(_) => {}
+        query::Variant::NearestWithMmr(NearestInputWithMmr { nearest, mmr: _ }) => {
+            if let Some(vector) = nearest {
+                collect_vector_input(vector, batch)?;
+            }
+        }
     }
 
     Ok(())
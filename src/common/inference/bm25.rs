@@ -0,0 +1,115 @@
+use api::rest::Document;
+use collection::operations::point_ops::VectorPersisted;
+use segment::index::field_index::full_text_index::bm25::{Bm25, Bm25Config};
+use serde_json::Value;
+use storage::content_manager::errors::StorageError;
+
+use super::service::{InferenceData, InferenceType};
+
+/// Documents of this model are converted into sparse vectors locally,
+/// without the inference service
+const BM25_MODEL_NAME: &str = "qdrant/bm25";
+
+pub(crate) fn is_bm25_model(data: &InferenceData) -> bool {
+    match data {
+        InferenceData::Document(doc) => doc.model.eq_ignore_ascii_case(BM25_MODEL_NAME),
+        InferenceData::Image(_) | InferenceData::Object(_) => false,
+    }
+}
+
+/// Convert a BM25 document into a sparse vector
+///
+/// Model options are parsed as [`Bm25Config`].
+pub(crate) fn infer_bm25(
+    data: &InferenceData,
+    inference_type: InferenceType,
+) -> Result<VectorPersisted, StorageError> {
+    let InferenceData::Document(Document { text, options, .. }) = data else {
+        return Err(StorageError::bad_request(format!(
+            "Model {BM25_MODEL_NAME} only supports documents, got {}",
+            data.type_name(),
+        )));
+    };
+
+    let options = Value::Object(
+        options
+            .options
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+    );
+    let config: Bm25Config = serde_json::from_value(options)
+        .map_err(|err| err.to_string())
+        .and_then(|config| check_bm25_config(&config).map(|()| config))
+        .map_err(|err| {
+            StorageError::bad_request(format!("Invalid options of model {BM25_MODEL_NAME}: {err}"))
+        })?;
+
+    let bm25 = Bm25::new(config);
+    let vector = match inference_type {
+        InferenceType::Update => bm25.doc_vector(text),
+        InferenceType::Search => bm25.query_vector(text),
+    };
+
+    Ok(VectorPersisted::Sparse(vector))
+}
+
+/// Parameters, which would produce infinite or NaN weights
+fn check_bm25_config(config: &Bm25Config) -> Result<(), String> {
+    let Bm25Config { k1, b, avg_len, .. } = *config;
+    if !(k1 >= 0.0 && k1.is_finite()) {
+        return Err(format!("k1 must be a non-negative number, got {k1}"));
+    }
+    if !(0.0..=1.0).contains(&b) {
+        return Err(format!("b must be within [0, 1], got {b}"));
+    }
+    if !(avg_len > 0.0 && avg_len.is_finite()) {
+        return Err(format!("avg_len must be a positive number, got {avg_len}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use api::rest::schema::Options;
+    use serde_json::json;
+
+    use super::*;
+
+    fn bm25_document(options: Option<HashMap<String, Value>>) -> InferenceData {
+        InferenceData::Document(Document {
+            text: "The quick brown fox".to_string(),
+            model: "Qdrant/BM25".to_string(),
+            options: Options { options },
+        })
+    }
+
+    #[test]
+    fn test_infer_bm25() {
+        let doc = bm25_document(None);
+        assert!(is_bm25_model(&doc));
+
+        let Ok(VectorPersisted::Sparse(vector)) = infer_bm25(&doc, InferenceType::Update) else {
+            panic!("expected sparse vector");
+        };
+        assert_eq!(vector.indices.len(), 4);
+
+        let doc = bm25_document(Some(HashMap::from([("k1".to_string(), json!("high"))])));
+        assert!(infer_bm25(&doc, InferenceType::Search).is_err());
+
+        for (option, value) in [
+            ("k1", json!(-1.0)),
+            ("b", json!(1.5)),
+            ("b", json!(-0.1)),
+            ("avg_len", json!(0.0)),
+            // renamed to `k1`
+            ("k", json!(1.2)),
+        ] {
+            let doc = bm25_document(Some(HashMap::from([(option.to_string(), value)])));
+            assert!(infer_bm25(&doc, InferenceType::Update).is_err(), "{option}");
+        }
+    }
+}
@@ -4,6 +4,7 @@ use collection::operations::point_ops::VectorPersisted;
 use storage::content_manager::errors::StorageError;
 
 use super::batch_processing::BatchAccum;
+use super::bm25::{infer_bm25, is_bm25_model};
 use super::service::{InferenceData, InferenceInput, InferenceService, InferenceType};
 use crate::common::inference::InferenceToken;
 
@@ -23,8 +24,18 @@ impl BatchAccumInferred {
         inference_type: InferenceType,
         inference_token: InferenceToken,
     ) -> Result<Self, StorageError> {
+        // BM25 documents are converted locally, the rest goes to the inference service
+        let (local_objects, objects): (Vec<_>, Vec<_>) =
+            objects.into_iter().partition(is_bm25_model);
+
+        let mut inferred = Self::new();
+        for data in local_objects {
+            let vector = infer_bm25(&data, inference_type)?;
+            inferred.objects.insert(data, vector);
+        }
+
         if objects.is_empty() {
-            return Ok(Self::new());
+            return Ok(inferred);
         }
 
         let Some(service) = InferenceService::get_global() else {
@@ -35,12 +46,7 @@ impl BatchAccumInferred {
 
         service.validate()?;
 
-        let objects_serialized: Vec<_> = objects.into_iter().collect();
-        let inference_inputs: Vec<_> = objects_serialized
-            .iter()
-            .cloned()
-            .map(InferenceInput::from)
-            .collect();
+        let inference_inputs: Vec<_> = objects.iter().cloned().map(InferenceInput::from).collect();
 
         let vectors = service
             .infer(inference_inputs, inference_type, inference_token)
@@ -55,9 +61,9 @@ impl BatchAccumInferred {
             ));
         }
 
-        let objects = objects_serialized.into_iter().zip(vectors).collect();
+        inferred.objects.extend(objects.into_iter().zip(vectors));
 
-        Ok(Self { objects })
+        Ok(inferred)
     }
 
     pub async fn from_batch_accum(
@@ -5,10 +5,11 @@ use api::grpc::qdrant::RecommendInput;
 use api::rest;
 use api::rest::RecommendStrategy;
 use collection::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Query,
-    VectorInputInternal, VectorQuery,
+    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Mmr, NearestWithMmr,
+    Query, VectorInputInternal, VectorQuery,
 };
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
+use segment::data_types::bm25::Bm25;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{VectorInternal, DEFAULT_VECTOR_NAME};
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
@@ -284,6 +285,19 @@ fn convert_query_with_inferred(
         Variant::OrderBy(order_by) => Query::OrderBy(OrderBy::try_from(order_by)?),
         Variant::Fusion(fusion) => Query::Fusion(FusionInternal::try_from(fusion)?),
         Variant::Sample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
+        Variant::Bm25(bm25) => Query::Bm25(Bm25::try_from(bm25)?),
+        Variant::FusionParams(fusion) => Query::Fusion(FusionInternal::try_from(fusion)?),
+        Variant::NearestWithMmr(grpc::NearestInputWithMmr { nearest, mmr }) => {
+            let nearest = nearest
+                .map(|v| convert_vector_input_with_inferred(v, inferred))
+                .transpose()?
+                .ok_or_else(|| {
+                    Status::invalid_argument("NearestInputWithMmr nearest is missing")
+                })?;
+            let mmr = Mmr::try_from(mmr.unwrap_or_default())?;
+
+            Query::Vector(VectorQuery::NearestWithMmr(NearestWithMmr { nearest, mmr }))
+        }
     };
 
     Ok(query)
@@ -307,9 +321,18 @@ fn convert_vector_input_with_inferred(
         Variant::Sparse(sparse) => Ok(VectorInputInternal::Vector(VectorInternal::Sparse(
             From::from(sparse),
         ))),
-        Variant::MultiDense(multi_dense) => Ok(VectorInputInternal::Vector(
-            VectorInternal::MultiDense(From::from(multi_dense)),
-        )),
+        Variant::MultiDense(multi_dense) => {
+            if !multi_dense.weights.is_empty()
+                && multi_dense.weights.len() != multi_dense.vectors.len()
+            {
+                return Err(Status::invalid_argument(
+                    "MultiDenseVector weights count must be equal to the vectors count",
+                ));
+            }
+            Ok(VectorInputInternal::Vector(VectorInternal::from(
+                multi_dense,
+            )))
+        }
         Variant::Document(doc) => {
             let doc: rest::Document = doc
                 .try_into()
@@ -1,12 +1,14 @@
 use api::rest::schema as rest;
 use collection::lookup::WithLookup;
 use collection::operations::universal_query::collection_query::{
-    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Query,
-    VectorInputInternal, VectorQuery,
+    CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, Mmr, NearestWithMmr,
+    Query, VectorInputInternal, VectorQuery,
 };
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
 use segment::data_types::order_by::OrderBy;
-use segment::data_types::vectors::{MultiDenseVectorInternal, VectorInternal, DEFAULT_VECTOR_NAME};
+use segment::data_types::vectors::{
+    MultiDenseVectorInternal, VectorInternal, WeightedMultiDenseVector, DEFAULT_VECTOR_NAME,
+};
 use segment::vector_storage::query::{ContextPair, ContextQuery, DiscoveryQuery, RecoQuery};
 use storage::content_manager::errors::StorageError;
 
@@ -138,6 +140,18 @@ fn convert_vector_input_with_inferred(
         rest::VectorInput::MultiDenseVector(multi_dense) => Ok(VectorInputInternal::Vector(
             VectorInternal::MultiDense(MultiDenseVectorInternal::new_unchecked(multi_dense)),
         )),
+        rest::VectorInput::WeightedMultiDenseVector(weighted) => {
+            let rest::WeightedMultiDenseVector {
+                multivector,
+                weights,
+            } = weighted;
+            Ok(VectorInputInternal::Vector(
+                VectorInternal::WeightedMultiDense(WeightedMultiDenseVector::new(
+                    MultiDenseVectorInternal::new_unchecked(multivector),
+                    weights,
+                )),
+            ))
+        }
         rest::VectorInput::Document(doc) => {
             let data = InferenceData::Document(doc);
             let vector = inferred.get_vector(&data).ok_or_else(|| {
@@ -175,8 +189,15 @@ fn convert_query_with_inferred(
     let query = rest::Query::from(query);
     match query {
         rest::Query::Nearest(nearest) => {
-            let vector = convert_vector_input_with_inferred(nearest.nearest, inferred)?;
-            Ok(Query::Vector(VectorQuery::Nearest(vector)))
+            let rest::NearestQuery { nearest, mmr } = nearest;
+            let vector = convert_vector_input_with_inferred(nearest, inferred)?;
+            match mmr {
+                Some(mmr) => Ok(Query::Vector(VectorQuery::NearestWithMmr(NearestWithMmr {
+                    nearest: vector,
+                    mmr: Mmr::from(mmr),
+                }))),
+                None => Ok(Query::Vector(VectorQuery::Nearest(vector))),
+            }
         }
         rest::Query::Recommend(recommend) => {
             let rest::RecommendInput {
@@ -230,6 +251,7 @@ fn convert_query_with_inferred(
         rest::Query::OrderBy(order_by) => Ok(Query::OrderBy(OrderBy::from(order_by.order_by))),
         rest::Query::Fusion(fusion) => Ok(Query::Fusion(FusionInternal::from(fusion.fusion))),
         rest::Query::Sample(sample) => Ok(Query::Sample(SampleInternal::from(sample.sample))),
+        rest::Query::Bm25(bm25) => Ok(Query::Bm25(bm25.bm25)),
     }
 }
 
@@ -404,6 +426,7 @@ mod tests {
         let inferred = create_test_inferred_batch();
         let nearest = NearestQuery {
             nearest: rest::VectorInput::Document(create_test_document("test")),
+            mmr: None,
         };
         let query = rest::QueryInterface::Query(rest::Query::Nearest(nearest));
 
@@ -273,3 +273,35 @@ def test_scroll_with_prefix(collection_name):
 
     assert response.ok
     assert len(response.json()['result']) == 3
+
+
+def test_scroll_with_phrase(collection_name):
+    def scroll_titles(match):
+        response = request_with_validation(
+            api='/collections/{collection_name}/points/scroll',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "limit": 10,
+                "with_payload": True,
+                "with_vector": False,
+                "filter": {
+                    "must": [
+                        {
+                            "key": "title",
+                            "match": match,
+                        }
+                    ]
+                }
+            }
+        )
+        assert response.ok
+        return sorted(point['payload']['title'] for point in response.json()['result']['points'])
+
+    assert scroll_titles({"phrase": "the last"}) == ["The Last Answer", "The Last Question", "The Last Trump"]
+
+    # Tokens must be in the same order
+    assert scroll_titles({"phrase": "last the", "slop": 2}) == []
+
+    assert scroll_titles({"phrase": "the question"}) == []
+    assert scroll_titles({"phrase": "the question", "slop": 1}) == ["The Last Question"]